@@ -0,0 +1,43 @@
+//! Sequential vs parallel batch validation
+//!
+//! Run with `cargo bench --features parallel --bench batch`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use pleme_brazilian_validators::batch;
+
+/// Formatted 11-digit values, about one in a hundred a valid CPF
+fn cpf_rows(count: usize) -> Vec<String> {
+    (0..count as u64)
+        .map(|i| {
+            let digits = format!("{:011}", i.wrapping_mul(2_654_435_761) % 100_000_000_000);
+            format!(
+                "{}.{}.{}-{}",
+                &digits[..3],
+                &digits[3..6],
+                &digits[6..9],
+                &digits[9..]
+            )
+        })
+        .collect()
+}
+
+fn bench_cpfs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate_cpfs");
+    group.sample_size(10);
+
+    for count in [10_000, 100_000, 1_000_000] {
+        let rows = cpf_rows(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::new("sequential", count), &rows, |b, rows| {
+            b.iter(|| batch::validate_cpfs(rows))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", count), &rows, |b, rows| {
+            b.iter(|| batch::par_validate_cpfs(rows))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_cpfs);
+criterion_main!(benches);
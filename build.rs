@@ -0,0 +1,39 @@
+//! Build script: compresses the `dataset` tables
+//!
+//! Each `data/*.tsv` table is deflated into `OUT_DIR`, from where the crate
+//! embeds it and inflates it on first use (see `src/blob.rs`). Without the
+//! `dataset` feature there is nothing to do.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "dataset")]
+    compress_tables();
+}
+
+#[cfg(feature = "dataset")]
+fn compress_tables() {
+    use std::{env, fs, path::Path};
+
+    println!("cargo:rerun-if-changed=data");
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+
+    for entry in fs::read_dir("data").expect("data/ is readable") {
+        let path = entry.expect("data/ is readable").path();
+        if path.extension().is_none_or(|extension| extension != "tsv") {
+            continue;
+        }
+
+        let table = fs::read(&path).expect("table is readable");
+        let compressed = miniz_oxide::deflate::compress_to_vec(&table, 10);
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .expect("UTF-8 file name");
+        fs::write(
+            Path::new(&out_dir).join(format!("{}.deflate", name)),
+            compressed,
+        )
+        .expect("OUT_DIR is writable");
+    }
+}
@@ -0,0 +1,58 @@
+#![no_main]
+
+//! Boleto, arrecadação, NF-e, PIX BR Code, IBAN and bank account parsers
+//! must accept arbitrary input without panicking, and parsed values must
+//! survive their accessors.
+
+use libfuzzer_sys::fuzz_target;
+use pleme_brazilian_validators::boleto::{self, arrecadacao, hybrid, DueDate};
+use pleme_brazilian_validators::pix::brcode;
+use pleme_brazilian_validators::{bank, bank_account, iban, nfe};
+
+fuzz_target!(|input: (&str, &str, &str, u16, u8, u8)| {
+    let (first, second, third, year, month, day) = input;
+
+    let _ = boleto::validate_barcode(first);
+    let _ = boleto::validate_digitable_line(first);
+    let _ = boleto::barcode_to_digitable_line(first);
+    let _ = boleto::digitable_line_to_barcode(first);
+    let _ = boleto::format_digitable_line_into(first, &mut [0; 64]);
+    if let Ok(parsed) = boleto::parse(first) {
+        let _ = parsed.bank();
+        let _ = parsed.due_date();
+        let _ = parsed.due_date_near(DueDate { year, month, day });
+    }
+    if let Ok(parsed) = arrecadacao::parse(first) {
+        let _ = parsed.amount_cents();
+    }
+    let _ = arrecadacao::validate(first);
+    if let Ok(parsed) = hybrid::parse(first, second, third) {
+        let _ = parsed.amount_cents();
+    }
+
+    let _ = nfe::validate_key(first);
+    let _ = nfe::validate_access_key(first);
+    let _ = nfe::parse(first);
+    let _ = nfe::format_key_grouped(first);
+
+    if let Ok(payload) = brcode::parse(first) {
+        let _ = payload.validate_semantics();
+        let _ = brcode::build(&payload);
+    }
+    let payload = brcode::PixPayload::new(first, second, third)
+        .with_txid(second)
+        .with_description(third)
+        .with_amount_cents(u64::from(year) * u64::from(day));
+    let _ = brcode::build(&payload);
+
+    let _ = iban::validate_br(first);
+    let _ = iban::parse_br(first);
+    let _ = iban::format(first);
+    let mut chars = third.chars();
+    let (account_type, owner) = (chars.next().unwrap_or('C'), chars.next().unwrap_or('1'));
+    let _ = iban::build(first, second, third, account_type, owner);
+
+    let _ = bank::validate_compe(first);
+    let _ = bank::validate_ispb(first);
+    let _ = bank_account::validate_account(first, second, third);
+});
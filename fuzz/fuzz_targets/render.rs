@@ -0,0 +1,96 @@
+#![no_main]
+
+//! Renderers must accept arbitrary options without panicking: masks with
+//! any mask character and visible counts, formatting into buffers of any
+//! size, every configuration, locale and address, and barcodes of any
+//! dimensions.
+
+use libfuzzer_sys::fuzz_target;
+use pleme_brazilian_validators::boleto::itf::{self, ItfOptions};
+use pleme_brazilian_validators::config::{Config, ValidationOptions};
+use pleme_brazilian_validators::endereco::{self, PostalAddress};
+use pleme_brazilian_validators::masking::MaskOptions;
+use pleme_brazilian_validators::nfe::{self, Code128Options};
+use pleme_brazilian_validators::scan::MaskStyle;
+use pleme_brazilian_validators::uf::Uf;
+use pleme_brazilian_validators::{
+    cep, cnpj, cpf, diagnostics, phone, pix, Document, DocumentKind, Locale,
+};
+
+const BARCODE: &str = "00193373700000001000500940144816060680935031";
+const KEY: &str = "35230811222333000181550010000000011000000011";
+
+fuzz_target!(|input: (&str, u8, char, u8, u8, u16, u32, u32, u32, u32)| {
+    let (text, flags, mask_char, leading, trailing, buf_len, width, ratio, height, quiet) = input;
+    let flag = |bit: u8| flags & (1 << bit) != 0;
+
+    let mask = MaskOptions {
+        mask_char,
+        visible_leading: usize::from(leading),
+        visible_trailing: usize::from(trailing),
+        keep_punctuation: flag(0),
+    };
+    let _ = mask.apply(text);
+    let _ = cpf::mask_with(text, &mask);
+    let _ = cnpj::mask_with(text, &mask);
+    let _ = phone::mask_with(text, &mask);
+    let _ = pix::mask_with(text, &mask);
+
+    let mut buf = vec![0; usize::from(buf_len % 64)];
+    let _ = cpf::format_into(text, &mut buf);
+    let _ = cnpj::format_into(text, &mut buf);
+    let _ = cep::format_into(text, &mut buf);
+    let _ = phone::format_into(text, &mut buf);
+
+    let locale = Locale::iter().nth(usize::from(leading) % Locale::iter().count());
+    let options = ValidationOptions {
+        strict: flag(1),
+        locale: locale.unwrap_or_default(),
+        mask_style: MaskStyle::ALL[usize::from(trailing) % MaskStyle::ALL.len()],
+        reject_denylisted: flag(2),
+        unicode_digits: flag(3),
+        ascii_names: flag(4),
+        non_geographic_phones: flag(5),
+    };
+    Config::with_options(options, || {
+        for kind in DocumentKind::iter() {
+            if let Err(error) = Document::validate_as(kind, text) {
+                let _ = error.localized(options.locale);
+            }
+            if let Err(diagnostic) = diagnostics::validate(text, kind) {
+                let _ = diagnostic.localized(options.locale);
+            }
+        }
+
+        let address = PostalAddress {
+            street: text.to_string(),
+            number: flag(6).then(|| text.to_string()),
+            complement: flag(7).then(|| text.to_string()),
+            neighborhood: text.to_string(),
+            city: text.to_string(),
+            uf: Uf::ALL[usize::from(leading) % Uf::ALL.len()],
+            cep: text.to_string(),
+        };
+        let _ = endereco::format_label(&address);
+    });
+
+    let itf_options = ItfOptions {
+        narrow_width: width,
+        wide_ratio: ratio,
+        height,
+        quiet_zone: quiet,
+    };
+    let _ = itf::encode(text, ratio);
+    let _ = itf::to_svg(text, &itf_options);
+    let _ = itf::to_svg(BARCODE, &itf_options);
+    let _ = itf::to_png(BARCODE, &itf_options);
+
+    let code128_options = Code128Options {
+        module_width: width,
+        height,
+        quiet_zone: quiet,
+    };
+    let _ = nfe::key_to_svg(text, &code128_options);
+    let _ = nfe::key_to_svg(KEY, &code128_options);
+    let _ = nfe::key_to_png(KEY, &code128_options);
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+//! Redaction and extraction must never panic and never split the text on a
+//! non-character boundary, whatever the input.
+
+use libfuzzer_sys::fuzz_target;
+use pleme_brazilian_validators::extract;
+use pleme_brazilian_validators::scan::{redact, MaskStyle};
+use pleme_brazilian_validators::secret::Secret;
+
+fuzz_target!(|input: &str| {
+    for style in [MaskStyle::Partial, MaskStyle::Full, MaskStyle::Label] {
+        let _ = redact(input, style);
+    }
+    let _ = Secret::new(input).to_string();
+    for found in extract::scan(input) {
+        let _ = found.normalized();
+    }
+});
@@ -0,0 +1,153 @@
+#![no_main]
+
+//! Every public validator, formatter and mask must accept arbitrary input
+//! without panicking.
+
+use libfuzzer_sys::fuzz_target;
+use pleme_brazilian_validators::tokenize::Tokenizer;
+use pleme_brazilian_validators::uf::Uf;
+use pleme_brazilian_validators::{
+    bank, caepf, card, cei, cep, certidao, cest, cnae, cnh, cno, cnpj, cns, cpf, diagnostics,
+    document, gtin, ibge, ie, matching, ncm, ocr, passaporte, phone, pis, pix, placa, processo_cnj,
+    renavam, rg, titulo_eleitor, unicode, Cep, Cnpj, Cpf, DocumentKind, Phone, PixKey,
+};
+
+fuzz_target!(|input: &str| {
+    let _ = cpf::validate(input);
+    let _ = cpf::format(input);
+    let _ = cpf::mask(input);
+    let _ = cpf::is_cpf_format(input);
+    let _ = cpf::inspect(input);
+    let _ = cpf::fiscal_region(input);
+    let _ = Cpf::parse(input);
+
+    let _ = cnpj::validate(input);
+    let _ = cnpj::format(input);
+    let _ = cnpj::mask(input);
+    let _ = cnpj::inspect(input);
+    let _ = cnpj::extract_base(input);
+    let _ = cnpj::extract_branch(input);
+    let _ = cnpj::is_main_branch(input);
+    let _ = Cnpj::parse(input);
+
+    let _ = cep::validate(input);
+    let _ = cep::format(input);
+    let _ = cep::mask(input);
+    let _ = cep::inspect(input);
+    let _ = cep::get_region_name(input);
+    let _ = cep::extract_region(input);
+    let _ = cep::extract_subregion(input);
+    let _ = cep::extract_sector(input);
+    let _ = cep::uf_for_cep(input);
+    let _ = cep::search_prefix(input);
+    let _ = cep::is_cep_geral(input);
+    let _ = cep::get_subregion_name(input);
+    let _ = Cep::parse(input);
+
+    let _ = phone::validate(input);
+    let _ = phone::format(input);
+    let _ = phone::mask(input);
+    let _ = phone::inspect(input);
+    let _ = phone::kind(input);
+    let _ = phone::extract_ddd(input);
+    let _ = phone::get_state_for_ddd(input);
+    let _ = phone::ddd_info(input);
+    let _ = phone::uf_for_ddd(input);
+    let _ = phone::add_ninth_digit(input);
+    let _ = phone::strip_ninth_digit(input);
+    let _ = phone::parse_dial_string(input);
+    let _ = phone::to_e164(input);
+    let _ = phone::from_e164(input);
+    let _ = Phone::parse(input);
+
+    let _ = pix::validate_with_type(input);
+    let _ = pix::detect_type(input);
+    let _ = pix::normalize(input);
+    let _ = pix::mask(input);
+    let _ = PixKey::parse(input);
+
+    let tokenizer = Tokenizer::new(&[7; 32]);
+    let _ = tokenizer.tokenize_cpf(input);
+    let _ = tokenizer.detokenize_cpf(input);
+    let _ = tokenizer.tokenize_cnpj(input);
+    let _ = tokenizer.detokenize_cnpj(input);
+
+    let _ = cnh::validate(input);
+    let _ = cnh::format(input);
+    let _ = cnh::mask(input);
+    let _ = titulo_eleitor::validate(input);
+    let _ = titulo_eleitor::format(input);
+    let _ = titulo_eleitor::mask(input);
+    let _ = titulo_eleitor::extract_state(input);
+    let _ = titulo_eleitor::state_code(input);
+    let _ = placa::validate(input);
+    let _ = placa::format(input);
+    let _ = placa::detect_format(input);
+    let _ = placa::to_mercosul(input);
+    let _ = renavam::validate(input);
+    let _ = pis::validate(input);
+    let _ = pis::format(input);
+    let _ = cns::validate(input);
+    let _ = cns::format(input);
+    let _ = cns::mask(input);
+    let _ = cns::is_provisional(input);
+    let _ = passaporte::validate(input);
+    let _ = passaporte::mask(input);
+    let _ = certidao::parse(input);
+    let _ = certidao::format(input);
+    let _ = cei::validate(input);
+    let _ = cei::format(input);
+    let _ = caepf::validate(input);
+    let _ = caepf::format(input);
+    let _ = cno::validate(input);
+    let _ = cno::format(input);
+
+    let _ = card::validate(input);
+    let _ = card::format(input);
+    let _ = card::mask(input);
+    let _ = card::detect_brand(input);
+    let _ = processo_cnj::parse(input);
+    let _ = processo_cnj::format(input);
+
+    let _ = gtin::validate(input);
+    let _ = gtin::is_brazilian_prefix(input);
+    let _ = cnae::validate(input);
+    let _ = cnae::format(input);
+    let _ = cnae::section(input);
+    let _ = cnae::division_name(input);
+    let _ = ncm::validate(input);
+    let _ = ncm::format(input);
+    let _ = ncm::chapter(input);
+    let _ = cest::validate(input);
+    let _ = cest::format(input);
+    let _ = cest::segment(input);
+
+    let _ = ibge::validate_municipio(input);
+    let _ = ibge::uf_from_municipio(input);
+    let _ = ibge::municipality_name(input);
+    let _ = input.parse::<Uf>();
+    let _ = ie::possible_ufs(input);
+    let _ = bank::by_compe(input);
+    let _ = bank::by_ispb(input);
+
+    let _ = document::detect(input);
+    let _ = ocr::correct(input);
+    for uf in Uf::iter() {
+        let _ = ie::validate(input, uf);
+        let _ = ie::format(input, uf);
+        let _ = rg::validate(input, Some(uf));
+        let _ = ibge::municipality_code(input, uf);
+    }
+    let _ = rg::validate(input, None);
+    let _ = rg::format(input);
+    let _ = rg::mask(input);
+
+    for kind in DocumentKind::iter() {
+        let _ = document::Document::validate_as(kind, input);
+        let _ = ocr::validate(input, kind);
+        let _ = unicode::validate(input, kind);
+        let _ = diagnostics::validate(input, kind);
+        let _ = diagnostics::check_digits(input, kind);
+        let _ = matching::similarity(input, "12345678909", kind);
+    }
+});
@@ -0,0 +1,105 @@
+#!/usr/bin/env python3
+"""Regenerate src/bank/registry.rs from the Banco Central participant lists.
+
+Every STR participant with a COMPE code becomes a registry entry, with its
+ISPB and short name as published in the STR list. The `pix` flag is set for
+institutions present in the latest PIX participant list.
+
+Usage: scripts/update-banks.py
+Requires: Python 3 (standard library only)
+"""
+
+import csv
+import datetime
+import io
+import pathlib
+import sys
+import urllib.error
+import urllib.request
+
+STR_URL = "https://www.bcb.gov.br/content/estabilidadefinanceira/str1/ParticipantesSTR.csv"
+PIX_URL = (
+    "https://www.bcb.gov.br/content/estabilidadefinanceira/participantes_pix/"
+    "lista-participantes-instituicoes-em-adesao-pix-{date}.csv"
+)
+OUT = pathlib.Path(__file__).resolve().parent.parent / "src" / "bank" / "registry.rs"
+
+HEADER = """\
+//! Banco Central participant registry
+//!
+//! Generated by `scripts/update-banks.py` from the STR and PIX participant
+//! lists; do not edit by hand.
+
+use super::{bank, Bank};
+
+/// Known institutions, by COMPE code
+#[rustfmt::skip]
+"""
+
+
+def fetch(url):
+    with urllib.request.urlopen(url, timeout=60) as response:
+        data = response.read()
+    for encoding in ("utf-8-sig", "latin-1"):
+        try:
+            return data.decode(encoding)
+        except UnicodeDecodeError:
+            continue
+    raise ValueError(f"cannot decode {url}")
+
+
+def rows(text):
+    delimiter = ";" if text.split("\n", 1)[0].count(";") > 0 else ","
+    return list(csv.DictReader(io.StringIO(text), delimiter=delimiter))
+
+
+def column(row, *names):
+    for key, value in row.items():
+        if key and key.strip().lower() in names:
+            return (value or "").strip()
+    raise KeyError(f"none of {names} in {list(row)}")
+
+
+def pix_participants():
+    """ISPBs of the latest PIX participant list (published per day)"""
+    today = datetime.date.today()
+    for days in range(15):
+        date = (today - datetime.timedelta(days=days)).strftime("%Y%m%d")
+        try:
+            text = fetch(PIX_URL.format(date=date))
+        except urllib.error.HTTPError:
+            continue
+        return {column(row, "ispb").zfill(8) for row in rows(text)}
+    sys.exit("no PIX participant list published in the last 15 days")
+
+
+def rust_string(value):
+    return '"' + value.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def main():
+    pix = pix_participants()
+    banks = {}
+    for row in rows(fetch(STR_URL)):
+        code = column(row, "número_código", "numero_codigo")
+        if not code.isdigit():
+            continue
+        compe = code.zfill(3)
+        ispb = column(row, "ispb").zfill(8)
+        name = " ".join(column(row, "nome_reduzido").split())
+        banks.setdefault(compe, (ispb, name, ispb in pix))
+
+    if len(banks) < 100:
+        sys.exit(f"expected at least 100 institutions, got {len(banks)}")
+
+    lines = [HEADER, f"pub(super) const BANKS: [Bank; {len(banks)}] = [\n"]
+    for compe, (ispb, name, has_pix) in sorted(banks.items()):
+        args = f'"{compe}", "{ispb}", {rust_string(name)}, {str(has_pix).lower()}'
+        lines.append(f"    bank({args}),\n")
+    lines.append("];\n")
+    OUT.write_text("".join(lines), encoding="utf-8")
+    print(f"wrote {len(banks)} institutions to {OUT.relative_to(OUT.parents[2])}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Regenerate data/municipios.tsv from the IBGE localidades API.
+#
+# Writes one `code<TAB>name` line per municipality, sorted by code, as
+# embedded by the `dataset` feature (see src/ibge/municipios.rs).
+#
+# Usage: scripts/update-municipios.sh
+# Requires: curl, jq
+
+set -euo pipefail
+
+URL="https://servicodados.ibge.gov.br/api/v1/localidades/municipios"
+OUT="$(dirname "$0")/../data/municipios.tsv"
+
+curl -fsSL --compressed "$URL" \
+  | jq -r '.[] | "\(.id)\t\(.nome)"' \
+  | LC_ALL=C sort -t "$(printf '\t')" -k1,1 \
+  > "$OUT.tmp"
+
+count=$(wc -l < "$OUT.tmp")
+if [ "$count" -lt 5570 ]; then
+  echo "expected at least 5570 municipalities, got $count" >&2
+  rm "$OUT.tmp"
+  exit 1
+fi
+
+mv "$OUT.tmp" "$OUT"
+echo "wrote $count municipalities to data/municipios.tsv"
@@ -0,0 +1,147 @@
+//! Accent-free output for legacy systems
+//!
+//! Fixed-width banking files (CNAB) and other legacy interfaces reject
+//! non-ASCII bytes. With
+//! [`ascii_names`](crate::config::ValidationOptions::ascii_names) enabled,
+//! the functions returning geographic names (UF and region names, CEP
+//! regions, DDD descriptions) return their accent-free variants
+//! (`"Sao Paulo"`). [`to_ascii`] applies the same folding to any string.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::config::Config;
+//! use pleme_brazilian_validators::uf::Uf;
+//!
+//! let options = Config::builder().ascii_names(true).build();
+//! Config::with_options(options, || {
+//!     assert_eq!(Uf::SP.name(), "Sao Paulo");
+//! });
+//! assert_eq!(Uf::SP.name(), "São Paulo");
+//! ```
+
+use crate::config::Config;
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{LazyLock, RwLock};
+
+/// ASCII variants of the static names returned so far
+///
+/// Only the crate's own `&'static str` names are interned, so the table is
+/// bounded by the size of the embedded data.
+#[cfg(feature = "std")]
+static ASCII_NAMES: LazyLock<RwLock<HashMap<&'static str, &'static str>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Strip the accent of a Portuguese letter, keeping its case
+pub(crate) fn fold_char(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+        'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => 'A',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'É' | 'È' | 'Ê' | 'Ë' => 'E',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+        'ç' => 'c',
+        'Ç' => 'C',
+        'ñ' => 'n',
+        'Ñ' => 'N',
+        'ª' => 'a',
+        'º' => 'o',
+        c => c,
+    }
+}
+
+/// Remove Portuguese accents, keeping case
+///
+/// Other non-ASCII characters are kept; the result is only guaranteed to
+/// be ASCII for Portuguese text.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ascii::to_ascii;
+///
+/// assert_eq!(to_ascii("Ribeirão Preto"), "Ribeirao Preto");
+/// assert_eq!(to_ascii("GOIÂNIA"), "GOIANIA");
+/// assert_eq!(to_ascii("Curitiba"), "Curitiba");
+/// ```
+pub fn to_ascii(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.chars().map(fold_char).collect())
+    }
+}
+
+/// Return a static name in the configured form
+pub(crate) fn name(name: &'static str) -> &'static str {
+    if !Config::current().ascii_names || name.is_ascii() {
+        return name;
+    }
+    interned(name)
+}
+
+/// ASCII variant of a static name, computed once
+#[cfg(feature = "std")]
+fn interned(name: &'static str) -> &'static str {
+    if let Some(ascii) = ASCII_NAMES
+        .read()
+        .ok()
+        .and_then(|names| names.get(name).copied())
+    {
+        return ascii;
+    }
+
+    let ascii: &'static str = Box::leak(to_ascii(name).into_owned().into_boxed_str());
+    match ASCII_NAMES.write() {
+        Ok(mut names) => names.entry(name).or_insert(ascii),
+        Err(_) => ascii,
+    }
+}
+
+/// ASCII variant of a static name: unreachable without `std`, where
+/// options cannot be installed and `ascii_names` stays off
+#[cfg(not(feature = "std"))]
+fn interned(name: &'static str) -> &'static str {
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii_borrows_ascii_input() {
+        assert!(matches!(to_ascii("Sul"), Cow::Borrowed(_)));
+        assert_eq!(
+            to_ascii("Maranhão, Piauí e Ceará"),
+            "Maranhao, Piaui e Ceara"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ascii_names_option() {
+        use crate::{cep, phone};
+
+        let options = Config::builder().ascii_names(true).build();
+        Config::with_options(options, || {
+            assert_eq!(cep::get_region_name("01310-100"), Some("Grande Sao Paulo"));
+            assert_eq!(
+                phone::get_state_for_ddd("16"),
+                Some("Sao Paulo (Ribeirao Preto)")
+            );
+            assert!(crate::uf::Uf::ALL.iter().all(|uf| uf.name().is_ascii()));
+            // Interned: the same static string is returned every time
+            assert!(std::ptr::eq(
+                crate::uf::Uf::PA.name(),
+                crate::uf::Uf::PA.name()
+            ));
+        });
+    }
+}
@@ -0,0 +1,231 @@
+//! LGPD audit records for document handling
+//!
+//! Regulated applications must be able to show how personal documents
+//! were handled: what was done, when and for which declared purpose. An
+//! [`AuditRecord`] captures one operation on one document, carrying only
+//! its masked form, so the audit trail can be shipped to log storage
+//! without becoming a second copy of the personal data.
+//!
+//! With the `serialization` feature, records serialize to flat JSON
+//! objects ready for structured logging.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::audit::{self, Operation};
+//! use pleme_brazilian_validators::DocumentKind;
+//!
+//! let (result, record) = audit::validate(DocumentKind::Cpf, "123.456.789-09", "account_opening");
+//! assert!(result.is_ok());
+//! assert_eq!(record.operation, Operation::Validate);
+//! assert_eq!(record.masked, "123.***.***-09");
+//! assert_eq!(record.purpose, "account_opening");
+//! assert!(record.is_success());
+//! ```
+
+use crate::config::Config;
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::scan::{self, MaskStyle};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Kind of operation performed on a document
+///
+/// Serialized as `"validate"`, `"mask"`, `"format"` or `"lookup"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Operation {
+    /// Validation of the document
+    Validate,
+    /// Masking for display or logging
+    Mask,
+    /// Formatting for display
+    Format,
+    /// Query to an external data source
+    Lookup,
+}
+
+/// Audit record of one operation on one document
+///
+/// Never holds the raw document: [`AuditRecord::new`] masks it with the
+/// configured [`mask_style`](crate::config::ValidationOptions::mask_style),
+/// and values the kind's mask cannot handle are masked in full.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AuditRecord {
+    /// Kind of document handled
+    pub kind: DocumentKind,
+    /// Operation performed
+    pub operation: Operation,
+    /// Masked document
+    pub masked: String,
+    /// Declared purpose of the processing (e.g. `"account_opening"`)
+    pub purpose: String,
+    /// When the operation happened, in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Error code if the operation failed (see
+    /// [`BrazilianValidationError::error_code`])
+    pub error_code: Option<String>,
+}
+
+impl AuditRecord {
+    /// Record a successful operation performed now
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::audit::{AuditRecord, Operation};
+    /// use pleme_brazilian_validators::DocumentKind;
+    ///
+    /// let record = AuditRecord::new(DocumentKind::Phone, Operation::Lookup, "11987654321", "fraud_check");
+    /// assert_eq!(record.masked, "(11) *****-4321");
+    /// ```
+    pub fn new(
+        kind: DocumentKind,
+        operation: Operation,
+        document: &str,
+        purpose: impl Into<String>,
+    ) -> Self {
+        AuditRecord {
+            kind,
+            operation,
+            masked: mask_value(kind, document),
+            purpose: purpose.into(),
+            timestamp: unix_seconds(SystemTime::now()),
+            error_code: None,
+        }
+    }
+
+    /// Mark the operation as failed with the given error
+    pub fn with_error(mut self, error: &BrazilianValidationError) -> Self {
+        self.error_code = Some(error.error_code().to_string());
+        self
+    }
+
+    /// Override the time of the operation
+    pub fn at(mut self, time: SystemTime) -> Self {
+        self.timestamp = unix_seconds(time);
+        self
+    }
+
+    /// Check whether the operation succeeded
+    pub fn is_success(&self) -> bool {
+        self.error_code.is_none()
+    }
+}
+
+/// Validate a document and record the operation
+///
+/// Runs the kind's `validate` function, hooks and configuration included.
+pub fn validate(
+    kind: DocumentKind,
+    document: &str,
+    purpose: impl Into<String>,
+) -> (ValidationResult<String>, AuditRecord) {
+    let result = kind.validate(document);
+    let record = AuditRecord::new(kind, Operation::Validate, document, purpose);
+    let record = match &result {
+        Ok(_) => record,
+        Err(error) => record.with_error(error),
+    };
+    (result, record)
+}
+
+/// Mask a document and record the operation
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::audit;
+/// use pleme_brazilian_validators::DocumentKind;
+///
+/// let (masked, record) = audit::mask(DocumentKind::Cnpj, "11222333000181", "invoice_display");
+/// assert_eq!(masked, "11.***.***/**01-81");
+/// assert_eq!(record.masked, masked);
+/// ```
+pub fn mask(
+    kind: DocumentKind,
+    document: &str,
+    purpose: impl Into<String>,
+) -> (String, AuditRecord) {
+    let record = AuditRecord::new(kind, Operation::Mask, document, purpose);
+    (record.masked.clone(), record)
+}
+
+/// Mask a document with the configured style, in full if the kind's mask
+/// would return it unchanged
+fn mask_value(kind: DocumentKind, document: &str) -> String {
+    let masked = scan::mask_document(kind, document, Config::current().mask_style);
+    if masked == document && !document.is_empty() {
+        scan::mask_document(kind, document, MaskStyle::Full)
+    } else {
+        masked
+    }
+}
+
+/// Seconds since the Unix epoch, saturating at zero for earlier times
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_failed_validation_is_recorded() {
+        let (result, record) = validate(DocumentKind::Cpf, "123.456.789-00", "signup");
+        assert!(result.is_err());
+        assert!(!record.is_success());
+        assert_eq!(record.error_code.as_deref(), Some("INVALID_CHECK_DIGITS"));
+        assert!(!record.masked.contains("456"));
+    }
+
+    #[test]
+    fn test_raw_value_never_recorded() {
+        // The CPF mask cannot handle a 10-digit value: masked in full
+        let record = AuditRecord::new(DocumentKind::Cpf, Operation::Format, "123.456.789-0", "x");
+        assert_eq!(record.masked, "***.***.***-*");
+
+        let record = AuditRecord::new(DocumentKind::Uf, Operation::Validate, "SP", "x");
+        assert_eq!(record.masked, "**");
+    }
+
+    #[test]
+    fn test_configured_mask_style() {
+        let options = Config::builder().mask_style(MaskStyle::Label).build();
+        Config::with_options(options, || {
+            let (masked, _) = mask(DocumentKind::Cpf, "12345678909", "x");
+            assert_eq!(masked, "[CPF]");
+        });
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let record = AuditRecord::new(DocumentKind::Cep, Operation::Lookup, "01310-100", "x")
+            .at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        assert_eq!(record.timestamp, 1_700_000_000);
+        assert!(AuditRecord::new(DocumentKind::Cep, Operation::Lookup, "", "x").timestamp > 0);
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_serializes_to_flat_json() {
+        let record = AuditRecord::new(DocumentKind::Cpf, Operation::Validate, "12345678909", "kyc")
+            .at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"kind":"cpf","operation":"validate","masked":"123.***.***-09","purpose":"kyc","timestamp":1700000000,"error_code":null}"#
+        );
+        let parsed: AuditRecord =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+        assert_eq!(parsed, record);
+    }
+}
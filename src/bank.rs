@@ -0,0 +1,204 @@
+//! COMPE and ISPB financial institution registry
+//!
+//! Institutions are identified by their COMPE code (3 digits, printed on
+//! boletos and used in TED transfers) and by their ISPB (8 digits, the
+//! first digits of their CNPJ, used by PIX and the STR). The embedded
+//! table lists the most common institutions; `scripts/update-banks.py`
+//! regenerates it from the Banco Central STR and PIX participant lists. Use
+//! `lookup::bank` (feature `lookup`) for institutions missing from it.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::bank;
+//!
+//! let itau = bank::by_compe("341").unwrap();
+//! assert_eq!(itau.ispb, "60701190");
+//! assert_eq!(bank::by_ispb("60701190"), Some(itau));
+//! ```
+
+use crate::error::{BrazilianValidationError, ValidationResult};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+mod registry;
+
+use registry::BANKS;
+
+/// Number of digits of a COMPE code
+pub const COMPE_LEN: usize = 3;
+
+/// Number of digits of an ISPB
+pub const ISPB_LEN: usize = 8;
+
+/// Financial institution with a COMPE code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bank {
+    /// COMPE code (3 digits)
+    pub compe: &'static str,
+    /// ISPB (8 digits)
+    pub ispb: &'static str,
+    /// Short name
+    pub name: &'static str,
+    /// Whether the institution offers PIX to its customers
+    pub pix: bool,
+}
+
+/// Table entry
+const fn bank(compe: &'static str, ispb: &'static str, name: &'static str, pix: bool) -> Bank {
+    Bank {
+        compe,
+        ispb,
+        name,
+        pix,
+    }
+}
+
+/// Every institution in the registry, by COMPE code
+pub fn all() -> &'static [Bank] {
+    &BANKS
+}
+
+/// Find an institution by COMPE code
+///
+/// Codes with fewer than 3 digits are zero-padded (`"1"` is Banco do
+/// Brasil). Returns `None` for malformed codes and institutions missing
+/// from the registry.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::bank::by_compe;
+///
+/// assert_eq!(by_compe("001").unwrap().ispb, "00000000");
+/// assert_eq!(by_compe("1"), by_compe("001"));
+/// assert!(by_compe("999").is_none());
+/// ```
+pub fn by_compe(code: &str) -> Option<&'static Bank> {
+    let code = validate_compe(code).ok()?;
+    BANKS.iter().find(|bank| bank.compe == code)
+}
+
+/// Find an institution by ISPB
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::bank::by_ispb;
+///
+/// assert_eq!(by_ispb("18236120").unwrap().compe, "260");
+/// assert!(by_ispb("1823612").is_none());
+/// ```
+pub fn by_ispb(ispb: &str) -> Option<&'static Bank> {
+    let ispb = validate_ispb(ispb).ok()?;
+    BANKS.iter().find(|bank| bank.ispb == ispb)
+}
+
+/// Validate the format of a COMPE code
+///
+/// Any code of 1 to 3 digits is accepted, registered or not.
+///
+/// # Returns
+/// * `Ok(String)` - Code zero-padded to 3 digits
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::bank::validate_compe;
+///
+/// assert_eq!(validate_compe(" 33 ").unwrap(), "033");
+/// assert!(validate_compe("3410").is_err());
+/// ```
+pub fn validate_compe(code: &str) -> ValidationResult<String> {
+    let code = code.trim();
+    check_digits(code, COMPE_LEN)?;
+    if code.is_empty() {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: COMPE_LEN,
+            actual: 0,
+        });
+    }
+    Ok(format!("{:0>3}", code))
+}
+
+/// Validate the format of an ISPB (8 digits)
+///
+/// Any 8-digit code is accepted, registered or not.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::bank::validate_ispb;
+///
+/// assert_eq!(validate_ispb("60701190").unwrap(), "60701190");
+/// assert!(validate_ispb("6070119").is_err());
+/// ```
+pub fn validate_ispb(ispb: &str) -> ValidationResult<String> {
+    let ispb = ispb.trim();
+    check_digits(ispb, ISPB_LEN)?;
+    if ispb.len() != ISPB_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: ISPB_LEN,
+            actual: ispb.len(),
+        });
+    }
+    Ok(ispb.to_string())
+}
+
+/// Check that a code has only ASCII digits, at most `max` of them
+fn check_digits(code: &str, max: usize) -> ValidationResult<()> {
+    if !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    if code.len() > max {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: max,
+            actual: code.len(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_sorted_and_well_formed() {
+        for pair in BANKS.windows(2) {
+            assert!(pair[0].compe < pair[1].compe, "{}", pair[1].compe);
+        }
+        for bank in BANKS {
+            assert_eq!(validate_compe(bank.compe).as_deref(), Ok(bank.compe));
+            assert_eq!(validate_ispb(bank.ispb).as_deref(), Ok(bank.ispb));
+            assert_eq!(by_ispb(bank.ispb), Some(&bank));
+        }
+    }
+
+    #[test]
+    fn test_lookups() {
+        let caixa = by_compe("104").unwrap();
+        assert_eq!(caixa.ispb, "00360305");
+        assert!(caixa.pix);
+        assert_eq!(by_compe("0x1"), None);
+        assert_eq!(by_ispb("00000000").unwrap().compe, "001");
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        assert_eq!(
+            validate_compe(""),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 3,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            validate_compe("34a"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_ispb("607011900"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 8,
+                actual: 9
+            })
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Banco Central participant registry
+//!
+//! Curated subset of the most common institutions, under their usual names.
+//! `scripts/update-banks.py` replaces it with every STR participant, under
+//! the short names published by the Banco Central.
+
+use super::{bank, Bank};
+
+/// Known institutions, by COMPE code
+#[rustfmt::skip]
+pub(super) const BANKS: [Bank; 49] = [
+    bank("001", "00000000", "Banco do Brasil", true),
+    bank("004", "07237373", "Banco do Nordeste", true),
+    bank("021", "28127603", "Banestes", true),
+    bank("029", "33885724", "Banco Itaú Consignado", false),
+    bank("033", "90400888", "Santander", true),
+    bank("036", "06271464", "Banco Bradesco BBI", false),
+    bank("037", "04913711", "Banpará", true),
+    bank("041", "92702067", "Banrisul", true),
+    bank("047", "13009717", "Banese", true),
+    bank("063", "04184779", "Banco Bradescard", false),
+    bank("070", "00000208", "BRB - Banco de Brasília", true),
+    bank("074", "03017677", "Banco J. Safra", true),
+    bank("077", "00416968", "Banco Inter", true),
+    bank("085", "05463212", "Ailos", true),
+    bank("104", "00360305", "Caixa Econômica Federal", true),
+    bank("121", "10664513", "Agibank", true),
+    bank("133", "10398952", "Cresol", true),
+    bank("136", "00315557", "Unicred", true),
+    bank("184", "17298092", "Banco Itaú BBA", false),
+    bank("197", "16501555", "Stone", true),
+    bank("208", "30306294", "BTG Pactual", true),
+    bank("212", "92894922", "Banco Original", true),
+    bank("218", "71027866", "Banco BS2", true),
+    bank("224", "58616418", "Banco Fibra", false),
+    bank("237", "60746948", "Bradesco", true),
+    bank("246", "28195667", "Banco ABC Brasil", false),
+    bank("260", "18236120", "Nubank", true),
+    bank("290", "08561701", "PagBank", true),
+    bank("318", "61186680", "Banco BMG", true),
+    bank("323", "10573521", "Mercado Pago", true),
+    bank("332", "13140088", "Acesso Soluções de Pagamento", true),
+    bank("336", "31872495", "C6 Bank", true),
+    bank("341", "60701190", "Itaú Unibanco", true),
+    bank("364", "09089356", "Efí", true),
+    bank("380", "22896431", "PicPay", true),
+    bank("389", "17184037", "Banco Mercantil do Brasil", true),
+    bank("394", "07207996", "Bradesco Financiamentos", false),
+    bank("403", "37880206", "Cora", true),
+    bank("422", "58160789", "Banco Safra", true),
+    bank("536", "20855875", "Neon Pagamentos", true),
+    bank("623", "59285411", "Banco Pan", true),
+    bank("633", "68900810", "Banco Rendimento", true),
+    bank("637", "60889128", "Banco Sofisa", true),
+    bank("655", "59588111", "Banco Votorantim", true),
+    bank("707", "62232889", "Banco Daycoval", true),
+    bank("739", "00558456", "Banco Cetelem", false),
+    bank("745", "33479023", "Citibank", false),
+    bank("748", "01181521", "Sicredi", true),
+    bank("756", "02038232", "Sicoob", true),
+];
@@ -0,0 +1,413 @@
+//! Bank account (agência and conta) check-digit validation
+//!
+//! Each bank computes the check digits of its branches (agências) and
+//! accounts (contas) its own way. [`validate_account`] applies the
+//! published rules of the largest banks and a generic modulo 11 to the
+//! others, so typos are caught before a transfer reaches the bank.
+//!
+//! | COMPE | Bank            | Agência       | Conta                       |
+//! |-------|-----------------|---------------|-----------------------------|
+//! | 001   | Banco do Brasil | 4 + DV (`X`)  | 8 + DV (`X`), modulo 11     |
+//! | 033   | Santander       | 4             | 8 + DV, with the agência    |
+//! | 041   | Banrisul        | 4 + 2 DVs     | 9 + DV, modulo 11           |
+//! | 104   | Caixa           | 4             | 3 (operação) + 8 + DV       |
+//! | 237   | Bradesco        | 4 + DV (`P`)  | 7 + DV (`P`), modulo 11     |
+//! | 341   | Itaú            | 4             | 5 + DV, modulo 10           |
+//!
+//! The agência check digit is optional in the input: it is verified when
+//! present and computed otherwise.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::bank_account::validate_account;
+//!
+//! let account = validate_account("001", "1584-9", "210169-6").unwrap();
+//! assert_eq!(account.account, "00210169");
+//! assert_eq!(account.to_string(), "001 1584-9 00210169-6");
+//!
+//! assert!(validate_account("341", "2545", "02366-2").is_err());
+//! ```
+
+use crate::document::DocumentKind;
+use crate::dv::{self, Mod11Remainder};
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Number of digits of an agência, without check digit
+pub const BRANCH_LEN: usize = 4;
+
+/// Longest account number accepted by the generic rule, without check digit
+const GENERIC_ACCOUNT_MAX_LEN: usize = 13;
+
+/// Validated bank account
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BankAccount {
+    /// COMPE code of the bank (3 digits)
+    pub bank_code: String,
+    /// Agência (4 digits)
+    pub branch: String,
+    /// Agência check digit(s), for banks that define one
+    pub branch_check_digit: Option<String>,
+    /// Account number, zero-padded to the bank's length
+    pub account: String,
+    /// Account check digit (a digit, or `X`/`P` for some banks)
+    pub account_check_digit: char,
+}
+
+impl fmt::Display for BankAccount {
+    /// `banco agência[-dv] conta-dv`, the form accepted by
+    /// [`DocumentKind::BankAccount`] validation
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.bank_code, self.branch)?;
+        if let Some(check_digit) = &self.branch_check_digit {
+            write!(f, "-{}", check_digit)?;
+        }
+        write!(f, " {}-{}", self.account, self.account_check_digit)
+    }
+}
+
+/// Check-digit rules of a bank
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rule {
+    BancoDoBrasil,
+    Santander,
+    Banrisul,
+    Caixa,
+    Bradesco,
+    Itau,
+    Generic,
+}
+
+impl Rule {
+    fn for_bank(bank_code: &str) -> Self {
+        match bank_code {
+            "001" => Rule::BancoDoBrasil,
+            "033" => Rule::Santander,
+            "041" => Rule::Banrisul,
+            "104" => Rule::Caixa,
+            "237" => Rule::Bradesco,
+            "341" => Rule::Itau,
+            _ => Rule::Generic,
+        }
+    }
+
+    /// Digits of the account number, without check digit
+    fn account_len(self) -> usize {
+        match self {
+            Rule::BancoDoBrasil | Rule::Santander => 8,
+            Rule::Banrisul => 9,
+            Rule::Caixa => 11,
+            Rule::Bradesco => 7,
+            Rule::Itau => 5,
+            Rule::Generic => GENERIC_ACCOUNT_MAX_LEN,
+        }
+    }
+
+    /// Agência check digit(s), if the bank defines them
+    fn branch_check_digit(self, branch: &[u32]) -> Option<String> {
+        match self {
+            Rule::BancoDoBrasil => Some(mod11_symbol(branch, 'X').to_string()),
+            Rule::Bradesco => Some(mod11_symbol(branch, 'P').to_string()),
+            Rule::Banrisul => Some(banrisul_branch_check_digits(branch)),
+            _ => None,
+        }
+    }
+
+    /// Account check digit
+    fn account_check_digit(self, branch: &[u32], account: &[u32]) -> char {
+        let digit = match self {
+            Rule::BancoDoBrasil => return mod11_symbol(account, 'X'),
+            Rule::Bradesco => return mod11_symbol(account, 'P'),
+            Rule::Santander => {
+                const WEIGHTS: [u32; 14] = [9, 7, 3, 1, 0, 0, 9, 7, 1, 3, 1, 9, 7, 3];
+                let padded = branch.iter().chain(&[0, 0]).chain(account);
+                let sum: u32 = padded.zip(WEIGHTS).map(|(d, w)| d * w % 10).sum();
+                (10 - sum % 10) % 10
+            }
+            Rule::Banrisul => {
+                const WEIGHTS: [u32; 9] = [3, 2, 4, 7, 6, 5, 4, 3, 2];
+                let sum: u32 = account.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum();
+                match sum % 11 {
+                    0 => 0,
+                    1 => 6,
+                    remainder => 11 - remainder,
+                }
+            }
+            Rule::Caixa => {
+                let digits: Vec<u32> = branch.iter().chain(account).copied().collect();
+                let weights = dv::cyclic_weights(digits.len(), 2, 9);
+                let sum: u32 = digits.iter().zip(&weights).map(|(d, w)| d * w).sum();
+                sum * 10 % 11 % 10
+            }
+            Rule::Itau => {
+                let digits: Vec<u32> = branch.iter().chain(account).copied().collect();
+                dv::mod10_alternating(&digits).unwrap_or(0)
+            }
+            Rule::Generic => {
+                let weights = dv::cyclic_weights(account.len(), 2, 9);
+                dv::mod11_with(account, &weights, Mod11Remainder::Complement).unwrap_or(0)
+            }
+        };
+        char::from_digit(digit, 10).unwrap_or('0')
+    }
+}
+
+/// Validate an agência and conta against the rules of their bank
+///
+/// # Arguments
+/// * `bank_code` - COMPE code of the bank (e.g. `"341"`)
+/// * `agencia` - Agência, with or without its check digit(s)
+/// * `conta` - Account number followed by its check digit (punctuation is
+///   ignored; shorter numbers are zero-padded)
+///
+/// # Returns
+/// * `Ok(BankAccount)` - Normalized account
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::bank_account::validate_account;
+///
+/// let account = validate_account("237", "1234", "0238069-2").unwrap();
+/// assert_eq!(account.branch_check_digit.as_deref(), Some("3"));
+///
+/// assert!(validate_account("237", "1234-4", "0238069-2").is_err());
+/// ```
+pub fn validate_account(
+    bank_code: &str,
+    agencia: &str,
+    conta: &str,
+) -> ValidationResult<BankAccount> {
+    let result = validate_account_unobserved(bank_code, agencia, conta);
+    hooks::notify(DocumentKind::BankAccount, &result);
+    result
+}
+
+/// Validate without reporting to the validation hook (for internal use)
+pub(crate) fn validate_account_unobserved(
+    bank_code: &str,
+    agencia: &str,
+    conta: &str,
+) -> ValidationResult<BankAccount> {
+    let bank_code = bank_code.trim();
+    if bank_code.len() != 3 || !bank_code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::BankAccount,
+        });
+    }
+    let rule = Rule::for_bank(bank_code);
+
+    let agencia = normalize(agencia);
+    let branch = agencia
+        .get(..BRANCH_LEN)
+        .ok_or(BrazilianValidationError::InvalidLength {
+            expected: BRANCH_LEN,
+            actual: agencia.len(),
+        })?;
+    let branch_digits = dv::digits(branch).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    let branch_check_digit = rule.branch_check_digit(&branch_digits);
+    let given = &agencia[BRANCH_LEN..];
+    if !given.is_empty() {
+        match &branch_check_digit {
+            Some(expected) if given == expected => {}
+            Some(expected) if given.len() == expected.len() => {
+                return Err(invalid_check_digits());
+            }
+            _ => {
+                return Err(BrazilianValidationError::InvalidLength {
+                    expected: BRANCH_LEN + branch_check_digit.as_ref().map_or(0, String::len),
+                    actual: agencia.len(),
+                })
+            }
+        }
+    }
+
+    let conta = normalize(conta);
+    let (number, check_digit) = match conta.char_indices().last() {
+        Some((last, check_digit)) if last > 0 => (&conta[..last], check_digit),
+        _ => {
+            return Err(BrazilianValidationError::InvalidLength {
+                expected: rule.account_len() + 1,
+                actual: conta.len(),
+            })
+        }
+    };
+    if number.len() > rule.account_len() {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: rule.account_len() + 1,
+            actual: conta.len(),
+        });
+    }
+    let account = match rule {
+        Rule::Generic => number.to_string(),
+        _ => format!("{:0>width$}", number, width = rule.account_len()),
+    };
+    let account_digits = dv::digits(&account).ok_or(BrazilianValidationError::InvalidCharacters)?;
+
+    if rule.account_check_digit(&branch_digits, &account_digits) != check_digit {
+        return Err(invalid_check_digits());
+    }
+
+    Ok(BankAccount {
+        bank_code: bank_code.to_string(),
+        branch: branch.to_string(),
+        branch_check_digit,
+        account,
+        account_check_digit: check_digit,
+    })
+}
+
+/// Validate an account written as `banco agência conta`, separated by
+/// whitespace
+pub(crate) fn validate_formatted(input: &str) -> ValidationResult<BankAccount> {
+    let mut fields = input.split_whitespace();
+    match (fields.next(), fields.next(), fields.next(), fields.next()) {
+        (Some(bank_code), Some(agencia), Some(conta), None) => {
+            validate_account(bank_code, agencia, conta)
+        }
+        _ => Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::BankAccount,
+        }),
+    }
+}
+
+/// Normalize an agência or conta: digits and uppercase letters only
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::bank_account::normalize;
+///
+/// assert_eq!(normalize("1234-x"), "1234X");
+/// ```
+pub fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Modulo 11 with weights 2 to 9 from the right, where 10 is written with
+/// a letter and 11 as 0 (Banco do Brasil, Bradesco)
+fn mod11_symbol(digits: &[u32], ten: char) -> char {
+    let weights = dv::cyclic_weights(digits.len(), 2, 9);
+    let sum: u32 = digits.iter().zip(&weights).map(|(d, w)| d * w).sum();
+    match 11 - sum % 11 {
+        10 => ten,
+        digit => char::from_digit(digit % 11, 10).unwrap_or('0'),
+    }
+}
+
+/// Banrisul agência "NC": a modulo 10 digit, then a modulo 11 digit that
+/// bumps the first one when the remainder is 1
+fn banrisul_branch_check_digits(branch: &[u32]) -> String {
+    let mut first = dv::mod10_alternating(branch).unwrap_or(0);
+    loop {
+        let digits: Vec<u32> = branch.iter().copied().chain([first]).collect();
+        let sum: u32 = digits.iter().zip([6, 5, 4, 3, 2]).map(|(d, w)| d * w).sum();
+        match sum % 11 {
+            0 => return format!("{}0", first),
+            1 => first = (first + 1) % 10,
+            remainder => return format!("{}{}", first, 11 - remainder),
+        }
+    }
+}
+
+/// Wrong agência or account check digit
+fn invalid_check_digits() -> BrazilianValidationError {
+    BrazilianValidationError::InvalidCheckDigits {
+        document_type: DocumentKind::BankAccount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_accounts() {
+        let cases = [
+            ("001", "1584-9", "00210169-6"),
+            ("001", "0001-9", "00210169-6"),
+            ("033", "2006", "13002366-7"),
+            ("041", "0100-81", "358507671-8"),
+            ("104", "2004", "00100000448-6"),
+            ("237", "1234-3", "0238069-2"),
+            ("341", "2545", "02366-1"),
+        ];
+        for (bank, agencia, conta) in cases {
+            let account = validate_account(bank, agencia, conta);
+            assert!(
+                account.is_ok(),
+                "{} {} {}: {:?}",
+                bank,
+                agencia,
+                conta,
+                account
+            );
+        }
+    }
+
+    #[test]
+    fn test_letter_check_digits() {
+        // Banco do Brasil writes 10 as X, Bradesco as P
+        assert_eq!(mod11_symbol(&[0, 0, 0, 0, 0, 0, 0, 6], 'X'), 'X');
+        assert!(validate_account("001", "0001", "6-x").is_ok());
+        assert!(validate_account("237", "0001", "6-P").is_ok());
+        assert!(validate_account("237", "0001", "6-X").is_err());
+    }
+
+    #[test]
+    fn test_wrong_check_digits() {
+        assert_eq!(
+            validate_account("001", "1584-8", "00210169-6"),
+            Err(invalid_check_digits())
+        );
+        assert_eq!(
+            validate_account("104", "2004", "00100000448-7"),
+            Err(invalid_check_digits())
+        );
+        assert_eq!(
+            validate_account("041", "0100-82", "358507671-8"),
+            Err(invalid_check_digits())
+        );
+    }
+
+    #[test]
+    fn test_generic_rule() {
+        // Modulo 11 over the account, weights 2 to 9
+        assert!(validate_account("260", "0001", "1234567-9").is_ok());
+        assert!(validate_account("260", "0001", "1234567-0").is_err());
+        // No agência check digit outside the known banks
+        assert!(validate_account("260", "0001-1", "1234567-9").is_err());
+    }
+
+    #[test]
+    fn test_layout_errors() {
+        assert!(matches!(
+            validate_account("1", "1584", "00210169-6"),
+            Err(BrazilianValidationError::InvalidDocumentFormat { .. })
+        ));
+        assert!(matches!(
+            validate_account("001", "158", "00210169-6"),
+            Err(BrazilianValidationError::InvalidLength { expected: 4, .. })
+        ));
+        assert!(matches!(
+            validate_account("341", "2545", "123456-1"),
+            Err(BrazilianValidationError::InvalidLength { expected: 6, .. })
+        ));
+        assert!(validate_account("341", "2545", "1").is_err());
+    }
+
+    #[test]
+    fn test_formatted_round_trip() {
+        let account = validate_account("041", "0100", "358507671-8").unwrap();
+        assert_eq!(account.to_string(), "041 0100-81 358507671-8");
+        assert_eq!(validate_formatted(&account.to_string()), Ok(account));
+        assert!(validate_formatted("041 0100").is_err());
+    }
+}
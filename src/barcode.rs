@@ -0,0 +1,220 @@
+//! Linear barcode rendering shared by the boleto and NF-e encoders
+//!
+//! Symbologies produce alternating bar and space widths in modules (the
+//! narrowest element); this module lays them out in pixels and draws them
+//! as SVG or grayscale PNG.
+//!
+//! Dimensions come from caller-supplied options, so every computation is
+//! checked and images above [`MAX_PIXELS`] are refused with
+//! [`ImageTooLarge`](BrazilianValidationError::ImageTooLarge).
+
+use crate::error::{BrazilianValidationError, ValidationResult};
+use std::fmt::Write;
+
+/// Largest image drawn, in pixels (64 MiB of grayscale PNG data)
+pub(crate) const MAX_PIXELS: u64 = 1 << 26;
+
+/// Layout of a symbol in pixels
+pub(crate) struct Layout {
+    /// Bars as `(x, width)` pixel runs
+    runs: Vec<(u32, u32)>,
+    /// Image width, quiet zones included
+    width: u32,
+    /// Image height
+    height: u32,
+}
+
+impl Layout {
+    /// Lay out element widths, starting with a bar
+    ///
+    /// `module` is the pixel width of one module and `quiet_zone` the blank
+    /// margin on each side, in modules. Fails if the image would exceed
+    /// [`MAX_PIXELS`].
+    pub(crate) fn new(
+        widths: &[u32],
+        module: u32,
+        height: u32,
+        quiet_zone: u32,
+    ) -> ValidationResult<Self> {
+        let too_large = || BrazilianValidationError::ImageTooLarge;
+        let module = module.max(1);
+        let height = height.max(1);
+        let margin = quiet_zone.checked_mul(module).ok_or_else(too_large)?;
+
+        let mut x = margin;
+        let mut runs = Vec::with_capacity(widths.len() / 2 + 1);
+        for (i, units) in widths.iter().enumerate() {
+            let pixels = units.checked_mul(module).ok_or_else(too_large)?;
+            if i % 2 == 0 {
+                runs.push((x, pixels));
+            }
+            x = x.checked_add(pixels).ok_or_else(too_large)?;
+        }
+        let width = x.checked_add(margin).ok_or_else(too_large)?;
+
+        if u64::from(width) * u64::from(height) > MAX_PIXELS {
+            return Err(too_large());
+        }
+
+        Ok(Layout {
+            runs,
+            width,
+            height,
+        })
+    }
+
+    /// Draw black bars on a white background as an SVG document
+    pub(crate) fn to_svg(&self) -> String {
+        let Layout { width, height, .. } = self;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\
+             <rect width=\"{width}\" height=\"{height}\" fill=\"#fff\"/>"
+        );
+        for (x, bar_width) in &self.runs {
+            let _ = write!(
+                svg,
+                "<rect x=\"{x}\" width=\"{bar_width}\" height=\"{height}\"/>"
+            );
+        }
+        svg.push_str("</svg>");
+
+        svg
+    }
+
+    /// Draw black bars on a white background as a grayscale PNG file
+    pub(crate) fn to_png(&self) -> ValidationResult<Vec<u8>> {
+        // Runs end within the width, checked in `new`
+        let mut row = vec![0xFF_u8; self.width as usize];
+        for &(x, bar_width) in &self.runs {
+            row[x as usize..(x + bar_width) as usize].fill(0);
+        }
+
+        // Writing to memory only fails on dimensions PNG cannot hold
+        let too_large = |_| BrazilianValidationError::ImageTooLarge;
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(too_large)?;
+        writer
+            .write_image_data(&row.repeat(self.height as usize))
+            .map_err(too_large)?;
+        writer.finish().map_err(too_large)?;
+
+        Ok(png)
+    }
+}
+
+/// Code 128 element widths of each symbol value, bar first
+const CODE128_PATTERNS: [&str; 107] = [
+    "212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312", "132212",
+    "221213", "221312", "231212", "112232", "122132", "122231", "113222", "123122", "123221",
+    "223211", "221132", "221231", "213212", "223112", "312131", "311222", "321122", "321221",
+    "312212", "322112", "322211", "212123", "212321", "232121", "111323", "131123", "131321",
+    "112313", "132113", "132311", "211313", "231113", "231311", "112133", "112331", "132131",
+    "113123", "113321", "133121", "313121", "211331", "231131", "213113", "213311", "213131",
+    "311123", "311321", "331121", "312113", "312311", "332111", "314111", "221411", "431111",
+    "111224", "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+    "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111", "111242",
+    "121142", "121241", "114212", "124112", "124211", "411212", "421112", "421211", "212141",
+    "214121", "412121", "111143", "111341", "131141", "114113", "114311", "411113", "411311",
+    "113141", "114131", "311141", "411131", "211412", "211214", "211232", "2331112",
+];
+
+/// Start symbol of code set C
+const CODE128_START_C: usize = 105;
+
+/// Stop symbol
+const CODE128_STOP: usize = 106;
+
+/// Encode an even number of ASCII digits in Code 128 code set C
+///
+/// Each pair of digits is one symbol, followed by the modulo 103 checksum
+/// and the stop pattern. Returns `None` for odd lengths or non-digits.
+pub(crate) fn code128c(digits: &str) -> Option<Vec<u32>> {
+    if !digits.len().is_multiple_of(2) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut values = vec![CODE128_START_C];
+    values.extend(
+        digits
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| usize::from(pair[0] - b'0') * 10 + usize::from(pair[1] - b'0')),
+    );
+    let checksum = values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| i.max(1) * value)
+        .sum::<usize>()
+        % 103;
+    values.extend([checksum, CODE128_STOP]);
+
+    Some(
+        values
+            .into_iter()
+            .flat_map(|value| CODE128_PATTERNS[value].bytes().map(|b| u32::from(b - b'0')))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code128_patterns() {
+        for (value, pattern) in CODE128_PATTERNS.iter().enumerate() {
+            let widths: Vec<u32> = pattern.bytes().map(|b| u32::from(b - b'0')).collect();
+            let modules: u32 = widths.iter().sum();
+            assert_eq!(
+                modules,
+                if value == CODE128_STOP { 13 } else { 11 },
+                "{value}"
+            );
+            assert_eq!(widths.iter().step_by(2).sum::<u32>() % 2, 0, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_code128c() {
+        // Start C, 12, 34, checksum (105 + 12 + 2 * 34) % 103 = 82, stop
+        let widths = code128c("1234").unwrap();
+        let expected: String = ["211232", "112232", "131123", "121241", "2331112"].concat();
+        let expected: Vec<u32> = expected.bytes().map(|b| u32::from(b - b'0')).collect();
+        assert_eq!(widths, expected);
+
+        assert_eq!(code128c("123"), None);
+        assert_eq!(code128c("12a4"), None);
+    }
+
+    #[test]
+    fn test_layout() {
+        let layout = Layout::new(&[1, 2, 3], 2, 0, 1).unwrap();
+        assert_eq!(layout.runs, [(2, 2), (8, 6)]);
+        assert_eq!(layout.width, 16);
+        assert_eq!(layout.height, 1);
+        assert_eq!(layout.to_svg().matches("<rect").count(), 3);
+    }
+
+    #[test]
+    fn test_oversized_layouts_are_refused() {
+        let too_large = Some(BrazilianValidationError::ImageTooLarge);
+        // Overflowing bar, margin and total widths
+        assert_eq!(Layout::new(&[u32::MAX, 1], 2, 1, 0).err(), too_large);
+        assert_eq!(Layout::new(&[1, 1], 2, 1, u32::MAX).err(), too_large);
+        assert_eq!(Layout::new(&[u32::MAX / 2; 3], 1, 1, 0).err(), too_large);
+        // Representable, but above the cap
+        assert_eq!(Layout::new(&[1], 1 << 13, 1 << 14, 0).err(), too_large);
+
+        // Just under the cap
+        let layout = Layout::new(&[1], 1 << 12, 1 << 14, 0).unwrap();
+        assert_eq!(
+            u64::from(layout.width) * u64::from(layout.height),
+            MAX_PIXELS
+        );
+    }
+}
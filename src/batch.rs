@@ -0,0 +1,634 @@
+//! Batch validation reports
+//!
+//! Validates a column of documents from a data-cleansing run and keeps one
+//! row per input, ready to be handed back to the business team as a CSV or
+//! JSON file. Unlike [`iter`](crate::iter), rows keep the original value:
+//! reports are meant for the people fixing the data, not for logs.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::{batch, DocumentKind};
+//!
+//! let report = batch::validate(["123.456.789-09", "I23.456.789-O9", "123"], DocumentKind::Cpf);
+//!
+//! assert_eq!(report.valid_count(), 1);
+//! assert_eq!(
+//!     report.to_csv(),
+//!     "row,original,normalized,status,error_code,suggestion\n\
+//!      0,123.456.789-09,12345678909,valid,,\n\
+//!      1,I23.456.789-O9,,invalid,INVALID_LENGTH,12345678909\n\
+//!      2,123,,invalid,INVALID_LENGTH,\n"
+//! );
+//! ```
+//!
+//! The report also summarizes the run: error counts by code, positions of
+//! the failed rows and the distinct valid documents.
+//!
+//! ```
+//! use pleme_brazilian_validators::batch;
+//!
+//! let report = batch::validate_cpfs(["123.456.789-09", "12345678909", "123", "111.111.111-11"]);
+//!
+//! assert_eq!(report.unique_valid(), vec!["12345678909"]);
+//! assert_eq!(report.failed_indices(), vec![2, 3]);
+//! assert_eq!(report.error_counts()["INVALID_LENGTH"], 1);
+//! ```
+
+#[cfg(feature = "parallel")]
+use crate::config::Config;
+use crate::document::{BrazilianDocument, DocumentKind};
+use crate::error::BrazilianValidationError;
+use crate::{ocr, unicode};
+use std::collections::{BTreeMap, HashSet};
+
+/// Columns of the CSV export, in order
+const CSV_HEADER: [&str; 6] = [
+    "row",
+    "original",
+    "normalized",
+    "status",
+    "error_code",
+    "suggestion",
+];
+
+/// Outcome of a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowStatus {
+    /// The document is valid as given
+    Valid,
+    /// The document was rejected
+    Invalid,
+}
+
+impl RowStatus {
+    /// Stable identifier used in exports (`"valid"`, `"invalid"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RowStatus::Valid => "valid",
+            RowStatus::Invalid => "invalid",
+        }
+    }
+}
+
+/// Validation outcome of one input row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    /// Zero-based position of the row in the input
+    pub index: usize,
+    /// Value as received
+    pub original: String,
+    /// Normalized document, for valid rows
+    pub normalized: Option<String>,
+    /// Error raised, for invalid rows
+    pub error: Option<BrazilianValidationError>,
+    /// Likely intended document, for invalid rows that validate once
+    /// Unicode digits or OCR confusions are corrected
+    pub suggestion: Option<String>,
+}
+
+impl Row {
+    /// Whether the row is valid
+    pub fn status(&self) -> RowStatus {
+        if self.error.is_some() {
+            RowStatus::Invalid
+        } else {
+            RowStatus::Valid
+        }
+    }
+
+    /// Error code of the row (see [`BrazilianValidationError::error_code`])
+    pub fn error_code(&self) -> Option<&'static str> {
+        self.error
+            .as_ref()
+            .map(BrazilianValidationError::error_code)
+    }
+}
+
+/// Per-row outcome of a batch validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    kind: DocumentKind,
+    rows: Vec<Row>,
+}
+
+impl Report {
+    /// Kind of document validated
+    pub fn kind(&self) -> DocumentKind {
+        self.kind
+    }
+
+    /// Rows in input order
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Number of valid rows
+    pub fn valid_count(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| row.status() == RowStatus::Valid)
+            .count()
+    }
+
+    /// Number of invalid rows
+    pub fn invalid_count(&self) -> usize {
+        self.rows.len() - self.valid_count()
+    }
+
+    /// Number of invalid rows per error code
+    pub fn error_counts(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for code in self.rows.iter().filter_map(Row::error_code) {
+            *counts.entry(code).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Positions of the invalid rows, in input order
+    pub fn failed_indices(&self) -> Vec<usize> {
+        self.rows
+            .iter()
+            .filter(|row| row.error.is_some())
+            .map(|row| row.index)
+            .collect()
+    }
+
+    /// Distinct normalized documents of the valid rows, in order of first
+    /// appearance
+    ///
+    /// Differently formatted copies of a document count once.
+    pub fn unique_valid(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.rows
+            .iter()
+            .filter_map(|row| row.normalized.as_deref())
+            .filter(|normalized| seen.insert(*normalized))
+            .collect()
+    }
+
+    /// Export the report as CSV (RFC 4180, with a header row)
+    ///
+    /// Columns: `row`, `original`, `normalized`, `status`, `error_code`
+    /// and `suggestion`. Missing values are left empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = CSV_HEADER.join(",");
+        csv.push('\n');
+
+        for row in &self.rows {
+            let index = row.index.to_string();
+            let fields = [
+                index.as_str(),
+                &row.original,
+                row.normalized.as_deref().unwrap_or_default(),
+                row.status().as_str(),
+                row.error_code().unwrap_or_default(),
+                row.suggestion.as_deref().unwrap_or_default(),
+            ];
+            let line: Vec<_> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&line.join(","));
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Export the report as a JSON array with one object per row
+    ///
+    /// Objects have the same keys as the CSV columns; missing values are
+    /// `null`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::{batch, DocumentKind};
+    ///
+    /// let report = batch::validate(["01310-100"], DocumentKind::Cep);
+    /// assert_eq!(
+    ///     report.to_json(),
+    ///     r#"[{"row":0,"original":"01310-100","normalized":"01310100","status":"valid","error_code":null,"suggestion":null}]"#
+    /// );
+    /// ```
+    #[cfg(feature = "serialization")]
+    pub fn to_json(&self) -> String {
+        // Rows only hold strings and integers, which always serialize
+        serde_json::to_string(&self.rows).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Row {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Row", 6)?;
+        state.serialize_field("row", &self.index)?;
+        state.serialize_field("original", &self.original)?;
+        state.serialize_field("normalized", &self.normalized)?;
+        state.serialize_field("status", self.status().as_str())?;
+        state.serialize_field("error_code", &self.error_code())?;
+        state.serialize_field("suggestion", &self.suggestion)?;
+        state.end()
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Suggest a correction for an invalid value
+///
+/// Unicode digits are tried first, then OCR confusions; a suggestion is
+/// only made when the corrected value validates.
+fn suggest(original: &str, kind: DocumentKind) -> Option<String> {
+    unicode::validate(original, kind)
+        .ok()
+        .filter(|result| result.is_normalized())
+        .map(|result| result.normalized)
+        .or_else(|| {
+            ocr::validate(original, kind)
+                .ok()
+                .filter(|result| result.is_corrected())
+                .map(|result| result.normalized)
+        })
+}
+
+/// Progress of a batch run, reported by [`validate_with_progress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Progress {
+    /// Rows validated so far
+    pub processed: usize,
+    /// Invalid rows so far
+    pub invalid: usize,
+    /// Total number of rows, when the input knows its length
+    pub total: Option<usize>,
+}
+
+impl Progress {
+    /// Valid rows so far
+    pub fn valid(&self) -> usize {
+        self.processed - self.invalid
+    }
+
+    /// Fraction of the rows processed (`0.0..=1.0`), if the total is known
+    pub fn fraction(&self) -> Option<f64> {
+        self.total.map(|total| match total {
+            0 => 1.0,
+            total => self.processed as f64 / total as f64,
+        })
+    }
+}
+
+/// Validate every row as a document of the given kind
+///
+/// # Arguments
+/// * `rows` - Values to validate, in file order
+/// * `kind` - Expected document kind
+///
+/// # Returns
+/// A [`Report`] with one [`Row`] per input value
+pub fn validate<I>(rows: I, kind: DocumentKind) -> Report
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    validate_with_progress(rows, kind, usize::MAX, |_| {})
+}
+
+/// Validate every row as a CPF
+///
+/// Shorthand for [`validate`] with [`DocumentKind::Cpf`].
+pub fn validate_cpfs<I>(rows: I) -> Report
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    validate(rows, DocumentKind::Cpf)
+}
+
+/// Validate every row as a CNPJ
+///
+/// Shorthand for [`validate`] with [`DocumentKind::Cnpj`].
+pub fn validate_cnpjs<I>(rows: I) -> Report
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    validate(rows, DocumentKind::Cnpj)
+}
+
+/// Validate every row as a document of type `D`
+///
+/// For generic code over the validated document types; the rows are
+/// validated as [`D::KIND`](BrazilianDocument::KIND).
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{batch, Cep};
+///
+/// let report = batch::validate_typed::<Cep, _>(["01310-100", "0131"]);
+/// assert_eq!(report.failed_indices(), vec![1]);
+/// ```
+pub fn validate_typed<D, I>(rows: I) -> Report
+where
+    D: BrazilianDocument,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    validate(rows, D::KIND)
+}
+
+/// Parallel version of [`validate`] for very large inputs
+///
+/// Rows are validated on the rayon thread pool with the options in effect
+/// on the calling thread; the report is identical to the one of
+/// [`validate`], rows included in input order. Enabled by the `parallel`
+/// feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{batch, DocumentKind};
+///
+/// let rows: Vec<String> = (0..10_000).map(|i| format!("{:011}", i)).collect();
+/// let report = batch::par_validate(&rows, DocumentKind::Cpf);
+///
+/// assert_eq!(report, batch::validate(&rows, DocumentKind::Cpf));
+/// ```
+#[cfg(feature = "parallel")]
+pub fn par_validate<I>(rows: I, kind: DocumentKind) -> Report
+where
+    I: rayon::iter::IntoParallelIterator,
+    I::Item: AsRef<str>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+{
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+    // Per-thread overrides do not reach the pool's threads on their own
+    let options = Config::current();
+    let rows = rows
+        .into_par_iter()
+        .enumerate()
+        .map(|(index, original)| {
+            Config::with_options(options, || validate_row(index, original.as_ref(), kind))
+        })
+        .collect();
+
+    Report { kind, rows }
+}
+
+/// Parallel version of [`validate_cpfs`]
+#[cfg(feature = "parallel")]
+pub fn par_validate_cpfs<I>(rows: I) -> Report
+where
+    I: rayon::iter::IntoParallelIterator,
+    I::Item: AsRef<str>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+{
+    par_validate(rows, DocumentKind::Cpf)
+}
+
+/// Parallel version of [`validate_cnpjs`]
+#[cfg(feature = "parallel")]
+pub fn par_validate_cnpjs<I>(rows: I) -> Report
+where
+    I: rayon::iter::IntoParallelIterator,
+    I::Item: AsRef<str>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+{
+    par_validate(rows, DocumentKind::Cnpj)
+}
+
+/// Parallel version of [`validate_typed`]
+#[cfg(feature = "parallel")]
+pub fn par_validate_typed<D, I>(rows: I) -> Report
+where
+    D: BrazilianDocument,
+    I: rayon::iter::IntoParallelIterator,
+    I::Item: AsRef<str>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+{
+    par_validate(rows, D::KIND)
+}
+
+/// Validate every row, reporting progress periodically
+///
+/// `on_progress` is called every `every` rows and once more after the last
+/// row, so CLIs and background jobs can drive a progress bar. The total is
+/// known when the input iterator reports an exact size (vectors, slices).
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{batch, DocumentKind};
+///
+/// let rows = vec!["01310-100"; 2_500];
+/// let mut updates = Vec::new();
+/// let report = batch::validate_with_progress(rows, DocumentKind::Cep, 1_000, |progress| {
+///     updates.push(progress.processed);
+/// });
+///
+/// assert_eq!(updates, vec![1_000, 2_000, 2_500]);
+/// assert_eq!(report.valid_count(), 2_500);
+/// ```
+pub fn validate_with_progress<I, F>(
+    rows: I,
+    kind: DocumentKind,
+    every: usize,
+    mut on_progress: F,
+) -> Report
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+    F: FnMut(Progress),
+{
+    let rows = rows.into_iter();
+    let every = every.max(1);
+    let mut progress = Progress {
+        total: match rows.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        },
+        ..Progress::default()
+    };
+
+    let rows = rows
+        .enumerate()
+        .map(|(index, original)| {
+            let row = validate_row(index, original.as_ref(), kind);
+
+            progress.processed += 1;
+            if row.error.is_some() {
+                progress.invalid += 1;
+            }
+            if progress.processed.is_multiple_of(every) {
+                on_progress(progress);
+            }
+            row
+        })
+        .collect();
+
+    if !progress.processed.is_multiple_of(every) {
+        on_progress(progress);
+    }
+
+    Report { kind, rows }
+}
+
+/// Validate one row
+fn validate_row(index: usize, original: &str, kind: DocumentKind) -> Row {
+    match kind.validate(original) {
+        Ok(normalized) => Row {
+            index,
+            original: original.to_string(),
+            normalized: Some(normalized),
+            error: None,
+            suggestion: None,
+        },
+        Err(error) => Row {
+            index,
+            original: original.to_string(),
+            normalized: None,
+            error: Some(error),
+            suggestion: suggest(original, kind),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_and_statuses() {
+        let report = validate(
+            vec![
+                "11.222.333/0001-81".to_string(),
+                "11.222.333/0001-00".to_string(),
+            ],
+            DocumentKind::Cnpj,
+        );
+
+        assert_eq!(report.kind(), DocumentKind::Cnpj);
+        assert_eq!((report.valid_count(), report.invalid_count()), (1, 1));
+        assert_eq!(report.rows()[0].status(), RowStatus::Valid);
+        assert_eq!(report.rows()[1].error_code(), Some("INVALID_CHECK_DIGITS"));
+        assert_eq!(report.rows()[1].suggestion, None);
+    }
+
+    #[test]
+    fn test_summary() {
+        let report = validate_cnpjs([
+            "11.222.333/0001-81",
+            "11222333000181",
+            "11.222.333/0001-00",
+            "12.ABC.345/01DE-35",
+            "123",
+            "11.222.333/0001-01",
+        ]);
+
+        assert_eq!(
+            report.unique_valid(),
+            vec!["11222333000181", "12ABC34501DE35"]
+        );
+        assert_eq!(report.failed_indices(), vec![2, 4, 5]);
+        assert_eq!(
+            report.error_counts().into_iter().collect::<Vec<_>>(),
+            vec![("INVALID_CHECK_DIGITS", 2), ("INVALID_LENGTH", 1)]
+        );
+
+        let report = validate_typed::<crate::Phone, _>(["11987654321", "(11) 98765-4321"]);
+        assert_eq!(report.kind(), DocumentKind::Phone);
+        assert_eq!(report.unique_valid(), vec!["+5511987654321"]);
+        assert!(report.error_counts().is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_validate_matches_sequential() {
+        let rows: Vec<String> = (0..5000)
+            .map(|i| format!("{:03}.{:03}.{:03}-{:02}", i % 1000, i, i / 7, i % 100))
+            .chain(["123.456.789-09".to_string(), "abc".to_string()])
+            .collect();
+
+        assert_eq!(par_validate_cpfs(&rows), validate_cpfs(&rows));
+        assert_eq!(
+            par_validate_typed::<crate::Cnpj, _>(&rows),
+            validate_cnpjs(&rows)
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_validate_uses_caller_options() {
+        let strict = Config::builder().strict(true).build();
+        let rows = vec!["CEP 01310-100"; 100];
+        let report = Config::with_options(strict, || par_validate(&rows, DocumentKind::Cep));
+        assert_eq!(report.invalid_count(), 100);
+    }
+
+    #[test]
+    fn test_unicode_suggestion() {
+        let report = validate(["０１３１０-１００"], DocumentKind::Cep);
+        assert_eq!(report.rows()[0].suggestion.as_deref(), Some("01310100"));
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        let report = validate(["1,2", "say \"hi\"\n"], DocumentKind::Cep);
+        let csv = report.to_csv();
+
+        assert!(csv.ends_with(
+            "0,\"1,2\",,invalid,INVALID_LENGTH,\n\
+             1,\"say \"\"hi\"\"\n\",,invalid,INVALID_LENGTH,\n"
+        ));
+    }
+
+    #[test]
+    fn test_progress_counts() {
+        let rows = ["01310-100", "123", "20040-020", "", "01001-000"];
+        let mut updates = Vec::new();
+        validate_with_progress(rows, DocumentKind::Cep, 2, |progress| {
+            updates.push(progress)
+        });
+
+        let last = updates.last().unwrap();
+        assert_eq!(updates.len(), 3);
+        assert_eq!((last.processed, last.invalid, last.valid()), (5, 2, 3));
+        assert_eq!(updates[0].fraction(), Some(0.4));
+    }
+
+    #[test]
+    fn test_progress_without_known_total() {
+        let rows = ["01310-100", "123"].into_iter().filter(|_| true);
+        let mut updates = Vec::new();
+        validate_with_progress(rows, DocumentKind::Cep, 0, |progress| {
+            updates.push(progress)
+        });
+
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[1].total, None);
+        assert_eq!(updates[1].fraction(), None);
+    }
+
+    #[test]
+    fn test_empty_input_reports_nothing() {
+        let mut calls = 0;
+        validate_with_progress(Vec::<String>::new(), DocumentKind::Cpf, 10, |_| calls += 1);
+        assert_eq!(calls, 0);
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_json_export() {
+        let report = validate(["123.456.789-00"], DocumentKind::Cpf);
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+
+        assert_eq!(json[0]["status"], "invalid");
+        assert_eq!(json[0]["error_code"], "INVALID_CHECK_DIGITS");
+        assert!(json[0]["normalized"].is_null());
+    }
+}
@@ -0,0 +1,66 @@
+//! Compressed embedded tables
+//!
+//! The `dataset` tables live in `data/` as tab-separated text, one record
+//! per line. The build script deflates them, and each [`Table`] inflates
+//! its text on first use, so applications that never look anything up
+//! only pay for the compressed bytes.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::sync::OnceLock;
+
+/// Embed a compressed table of `data/` by file name
+macro_rules! table {
+    ($name:literal) => {
+        $crate::blob::Table::new(include_bytes!(concat!(
+            env!("OUT_DIR"),
+            "/",
+            $name,
+            ".deflate"
+        )))
+    };
+}
+
+pub(crate) use table;
+
+/// Tab-separated table, stored compressed and inflated on first use
+pub(crate) struct Table {
+    compressed: &'static [u8],
+    text: OnceLock<String>,
+}
+
+impl Table {
+    pub(crate) const fn new(compressed: &'static [u8]) -> Self {
+        Table {
+            compressed,
+            text: OnceLock::new(),
+        }
+    }
+
+    /// Decompressed text of the table
+    ///
+    /// The data is compressed by the build script, so it always inflates;
+    /// an empty table is returned rather than panicking if it did not.
+    pub(crate) fn text(&'static self) -> &'static str {
+        self.text.get_or_init(|| {
+            miniz_oxide::inflate::decompress_to_vec(self.compressed)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Records of the table, each split into its fields
+    pub(crate) fn records(&'static self) -> impl Iterator<Item = Vec<&'static str>> {
+        self.text()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split('\t').collect())
+    }
+
+    /// Bytes embedded in the binary
+    #[cfg(test)]
+    pub(crate) fn compressed_len(&self) -> usize {
+        self.compressed.len()
+    }
+}
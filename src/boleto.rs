@@ -0,0 +1,647 @@
+//! Boleto bancário validation
+//!
+//! Bank-collection boletos are identified by a 44-digit barcode: bank code,
+//! currency, general check digit (modulo 11), due-date factor, amount and
+//! a 25-digit free field defined by the issuing bank.
+//!
+//! Payers type the 47-digit linha digitável instead, printed above the
+//! barcode. It holds the same fields in another order, split into five
+//! groups; the first three end in a modulo 10 check digit:
+//!
+//! ```text
+//! 00190.50095 40144.816069 06809.350314 3 37370000000100
+//! AAABC.CCCCX DDDDD.DDDDDY EEEEE.EEEEEZ K FFFFVVVVVVVVVV
+//! ```
+//!
+//! [`parse`] accepts either form, and [`barcode_to_digitable_line`] and
+//! [`digitable_line_to_barcode`] convert between them. Utility and tax slips, whose barcodes
+//! start with `8`, are handled by the [`arrecadacao`] submodule.
+//!
+//! The [`hybrid`] submodule cross-checks boletos that also carry a PIX
+//! QR code ("boleto híbrido"), and `itf` renders barcodes as printable
+//! bars (feature `barcode`).
+
+use crate::document::DocumentKind;
+use crate::dv::{self, Mod11Remainder};
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::bank::{self, Bank};
+use crate::fixed::{self, Cleaned};
+use crate::hooks;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub mod arrecadacao;
+pub mod hybrid;
+#[cfg(feature = "barcode")]
+pub mod itf;
+
+/// Number of digits of a boleto barcode
+pub const BARCODE_LEN: usize = 44;
+
+/// Number of digits of a linha digitável
+pub const DIGITABLE_LINE_LEN: usize = 47;
+
+/// Position of the general check digit in the barcode
+const CHECK_DIGIT_POS: usize = 4;
+
+/// Days from 1970-01-01 to 1997-10-07, the base date of due-date factors
+const FACTOR_BASE_DAY: i64 = 10_141;
+
+/// Days after which due-date factors repeat (they went from 9999 back to
+/// 1000 on 2025-02-22)
+const FACTOR_CYCLE: i64 = 9_000;
+
+/// Days before a reference date within which a factor is resolved
+const FACTOR_WINDOW_BEFORE: i64 = 3_000;
+
+/// Fields of a bank-collection boleto barcode
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Barcode {
+    /// Normalized barcode (44 digits)
+    pub barcode: String,
+    /// COMPE code of the issuing bank (3 digits)
+    pub bank_code: String,
+    /// Currency code (`9` for the real)
+    pub currency_code: u8,
+    /// Days since the FEBRABAN base date; 0 when there is no due date
+    pub due_date_factor: u16,
+    /// Amount in centavos; 0 when the payer informs the amount
+    pub amount_cents: u64,
+    /// Bank-defined free field (25 digits)
+    pub free_field: String,
+}
+
+impl Barcode {
+    /// Issuing bank, if it is in the [`bank`](crate::bank) registry
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::boleto::parse_barcode;
+    ///
+    /// let boleto = parse_barcode("00193373700000001000500940144816060680935031").unwrap();
+    /// assert_eq!(boleto.bank().unwrap().ispb, "00000000");
+    /// ```
+    pub fn bank(&self) -> Option<&'static Bank> {
+        bank::by_compe(&self.bank_code)
+    }
+
+    /// Due date encoded by the factor, `None` when there is no due date
+    ///
+    /// Factors repeat every 9000 days, so a factor is resolved to the date
+    /// within 3000 days before and 6000 days after today (UTC).
+    #[cfg(feature = "std")]
+    pub fn due_date(&self) -> Option<DueDate> {
+        let today = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| (elapsed.as_secs() / 86_400) as i64);
+        self.due_date_near_day(today)
+    }
+
+    /// Due date encoded by the factor, resolved around a reference date
+    ///
+    /// Use this instead of [`due_date`](Self::due_date) to process files
+    /// from the past reproducibly.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::boleto::{parse, DueDate};
+    ///
+    /// let boleto = parse("00190.50095 40144.816069 06809.350314 3 37370000000100").unwrap();
+    /// let issued = DueDate { year: 2007, month: 12, day: 1 };
+    /// assert_eq!(boleto.due_date_near(issued).unwrap().to_string(), "31/12/2007");
+    ///
+    /// let issued = DueDate { year: 2032, month: 1, day: 1 };
+    /// assert_eq!(boleto.due_date_near(issued).unwrap().to_string(), "21/08/2032");
+    /// ```
+    pub fn due_date_near(&self, reference: DueDate) -> Option<DueDate> {
+        self.due_date_near_day(reference.days_since_epoch())
+    }
+
+    /// Resolve the factor around a day counted from 1970-01-01
+    fn due_date_near_day(&self, reference: i64) -> Option<DueDate> {
+        if self.due_date_factor == 0 {
+            return None;
+        }
+        let first = FACTOR_BASE_DAY + i64::from(self.due_date_factor);
+        let cycles = (reference - FACTOR_WINDOW_BEFORE - first + FACTOR_CYCLE - 1)
+            .div_euclid(FACTOR_CYCLE)
+            .max(0);
+        Some(DueDate::from_days_since_epoch(
+            first + cycles * FACTOR_CYCLE,
+        ))
+    }
+}
+
+/// Calendar date of a boleto
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DueDate {
+    /// Year (e.g. `2025`)
+    pub year: u16,
+    /// Month (`1` to `12`)
+    pub month: u8,
+    /// Day of the month (`1` to `31`)
+    pub day: u8,
+}
+
+impl DueDate {
+    /// Days since 1970-01-01 (proleptic Gregorian calendar)
+    fn days_since_epoch(&self) -> i64 {
+        let (month, day) = (i64::from(self.month), i64::from(self.day));
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Date of a day counted from 1970-01-01
+    fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        DueDate {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl fmt::Display for DueDate {
+    /// Brazilian format, `dd/mm/yyyy`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.day, self.month, self.year)
+    }
+}
+
+/// Validate a boleto barcode
+///
+/// # Arguments
+/// * `barcode` - 44-digit barcode (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized barcode (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::validate_barcode;
+///
+/// assert!(validate_barcode("00193373700000001000500940144816060680935031").is_ok());
+/// assert!(validate_barcode("00194373700000001000500940144816060680935031").is_err());
+/// ```
+pub fn validate_barcode(barcode: &str) -> ValidationResult<String> {
+    let result = parse_barcode_unobserved(barcode).map(|parsed| parsed.barcode);
+    hooks::notify(DocumentKind::Boleto, &result);
+    result
+}
+
+/// Validate a boleto barcode and extract its fields
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::parse_barcode;
+///
+/// let boleto = parse_barcode("00193373700000001000500940144816060680935031").unwrap();
+/// assert_eq!(boleto.bank_code, "001");
+/// assert_eq!(boleto.due_date_factor, 3737);
+/// assert_eq!(boleto.amount_cents, 100);
+/// ```
+pub fn parse_barcode(barcode: &str) -> ValidationResult<Barcode> {
+    let result = parse_barcode_unobserved(barcode);
+    hooks::notify(DocumentKind::Boleto, &result);
+    result
+}
+
+/// Validate a linha digitável
+///
+/// Checks the modulo 10 check digit of each of the first three groups and
+/// the general check digit of the barcode they describe.
+///
+/// # Arguments
+/// * `line` - 47-digit linha digitável (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized linha digitável (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::validate_digitable_line;
+///
+/// assert!(validate_digitable_line("00190.50095 40144.816069 06809.350314 3 37370000000100").is_ok());
+/// assert!(validate_digitable_line("00190.50096 40144.816069 06809.350314 3 37370000000100").is_err());
+/// ```
+pub fn validate_digitable_line(line: &str) -> ValidationResult<String> {
+    let result = parse_digitable_line_unobserved(line).map(|_| normalize(line));
+    hooks::notify(DocumentKind::Boleto, &result);
+    result
+}
+
+/// Validate a barcode or linha digitável and extract its fields
+///
+/// The form is told by the number of digits: 44 for the barcode, 47 for
+/// the linha digitável.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::parse;
+///
+/// let line = parse("00190.50095 40144.816069 06809.350314 3 37370000000100").unwrap();
+/// let barcode = parse("00193373700000001000500940144816060680935031").unwrap();
+/// assert_eq!(line, barcode);
+/// assert_eq!(line.amount_cents, 100);
+/// ```
+pub fn parse(boleto: &str) -> ValidationResult<Barcode> {
+    let result = if normalize(boleto).len() == DIGITABLE_LINE_LEN {
+        parse_digitable_line_unobserved(boleto)
+    } else {
+        parse_barcode_unobserved(boleto)
+    };
+    hooks::notify(DocumentKind::Boleto, &result);
+    result
+}
+
+/// Parse a linha digitável without reporting to the validation hook
+fn parse_digitable_line_unobserved(line: &str) -> ValidationResult<Barcode> {
+    let cleaned = normalize(line);
+
+    if cleaned.len() != DIGITABLE_LINE_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: DIGITABLE_LINE_LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let digits = dv::digits(&cleaned).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    let fields_valid = [0..9, 10..20, 21..31]
+        .into_iter()
+        .all(|field| dv::mod10_alternating(&digits[field.clone()]) == Some(digits[field.end]));
+    if !fields_valid {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Boleto,
+        });
+    }
+
+    let barcode = [
+        &cleaned[0..4],
+        &cleaned[32..47],
+        &cleaned[4..9],
+        &cleaned[10..20],
+        &cleaned[21..31],
+    ]
+    .concat();
+    parse_barcode_unobserved(&barcode)
+}
+
+/// Convert a barcode to its linha digitável
+///
+/// Works for bank boletos (47-digit line) and arrecadação slips (48-digit
+/// line). The barcode is validated first; the check digits of the line
+/// fields are computed.
+///
+/// # Returns
+/// * `Ok(String)` - Linha digitável (digits only)
+/// * `Err(BrazilianValidationError)` - The barcode is invalid
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::barcode_to_digitable_line;
+///
+/// assert_eq!(
+///     barcode_to_digitable_line("00193373700000001000500940144816060680935031").unwrap(),
+///     "00190500954014481606906809350314337370000000100"
+/// );
+/// assert_eq!(
+///     barcode_to_digitable_line("84840000000999004560000000000000000000054321").unwrap(),
+///     "848400000003999004560007000000000000000000543217"
+/// );
+/// ```
+pub fn barcode_to_digitable_line(barcode: &str) -> ValidationResult<String> {
+    let cleaned = normalize(barcode);
+
+    if cleaned.len() != BARCODE_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: BARCODE_LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if cleaned.starts_with('8') {
+        let parsed = arrecadacao::parse_unobserved(&cleaned)?;
+        return Ok(arrecadacao::digitable_line(&parsed.barcode));
+    }
+
+    let b = parse_barcode_unobserved(&cleaned)?.barcode;
+    Ok([
+        with_mod10(&[&b[0..4], &b[19..24]].concat()),
+        with_mod10(&b[24..34]),
+        with_mod10(&b[34..44]),
+        b[4..19].to_string(),
+    ]
+    .concat())
+}
+
+/// Convert a linha digitável to its barcode
+///
+/// Works for bank boletos (47-digit line) and arrecadação slips (48-digit
+/// line). Every check digit of the line is verified.
+///
+/// # Returns
+/// * `Ok(String)` - 44-digit barcode
+/// * `Err(BrazilianValidationError)` - The linha digitável is invalid
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::digitable_line_to_barcode;
+///
+/// assert_eq!(
+///     digitable_line_to_barcode("00190.50095 40144.816069 06809.350314 3 37370000000100").unwrap(),
+///     "00193373700000001000500940144816060680935031"
+/// );
+/// ```
+pub fn digitable_line_to_barcode(line: &str) -> ValidationResult<String> {
+    if normalize(line).len() == arrecadacao::DIGITABLE_LINE_LEN {
+        arrecadacao::parse_unobserved(line).map(|parsed| parsed.barcode)
+    } else {
+        parse_digitable_line_unobserved(line).map(|parsed| parsed.barcode)
+    }
+}
+
+/// Length of the longest printed linha digitável, an arrecadação one
+pub const FORMATTED_DIGITABLE_LINE_LEN: usize = 55;
+
+/// Format a linha digitável into a caller-provided buffer, without allocating
+///
+/// Lays the digits out as printed on the slip: five groups for bank
+/// boletos, four blocks with their check digit apart for arrecadação
+/// slips. Check digits are not verified. A buffer of
+/// [`FORMATTED_DIGITABLE_LINE_LEN`] bytes always suffices.
+///
+/// # Returns
+/// `None` if the input has neither 47 nor 48 digits or `buf` is too small.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto;
+///
+/// let mut buf = [0u8; boleto::FORMATTED_DIGITABLE_LINE_LEN];
+/// assert_eq!(
+///     boleto::format_digitable_line_into("00190500954014481606906809350314337370000000100", &mut buf),
+///     Some("00190.50095 40144.816069 06809.350314 3 37370000000100")
+/// );
+/// assert_eq!(
+///     boleto::format_digitable_line_into("826900000017234501232024610170000000000000123455", &mut buf),
+///     Some("82690000001-7 23450123202-4 61017000000-0 00000012345-5")
+/// );
+/// ```
+pub fn format_digitable_line_into<'a>(line: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let cleaned = Cleaned::<{ arrecadacao::DIGITABLE_LINE_LEN }>::new(line, false)?;
+    let pattern = if cleaned.as_str().len() == DIGITABLE_LINE_LEN {
+        "#####.##### #####.###### #####.###### # ##############"
+    } else {
+        "###########-# ###########-# ###########-# ###########-#"
+    };
+    fixed::write_pattern(cleaned.as_str(), pattern, buf)
+}
+
+/// Append the modulo 10 check digit to a field of digits
+fn with_mod10(field: &str) -> String {
+    let digits = dv::digits(field).unwrap_or_default();
+    let check_digit = dv::mod10_alternating(&digits).unwrap_or(0);
+    format!("{}{}", field, check_digit)
+}
+
+/// Parse without reporting to the validation hook (for internal use)
+pub(crate) fn parse_barcode_unobserved(barcode: &str) -> ValidationResult<Barcode> {
+    let cleaned = normalize(barcode);
+
+    if cleaned.len() != BARCODE_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: BARCODE_LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    // Barcodes starting with 8 are arrecadação (utilities and taxes),
+    // see the arrecadacao submodule
+    if cleaned.starts_with('8') {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Boleto,
+        });
+    }
+
+    let digits = dv::digits(&cleaned).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    if compute_check_digit(&digits) != digits[CHECK_DIGIT_POS] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Boleto,
+        });
+    }
+
+    Ok(Barcode {
+        bank_code: cleaned[0..3].to_string(),
+        currency_code: digits[3] as u8,
+        due_date_factor: cleaned[5..9].parse().unwrap_or(0),
+        amount_cents: cleaned[9..19].parse().unwrap_or(0),
+        free_field: cleaned[19..].to_string(),
+        barcode: cleaned,
+    })
+}
+
+/// Compute the general check digit of a 44-digit barcode
+///
+/// Modulo 11 over every digit but the check digit itself, weights 2 to 9
+/// from the right; results 0, 10 and 11 become 1.
+pub(crate) fn compute_check_digit(digits: &[u32]) -> u32 {
+    let payload: Vec<u32> = digits
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != CHECK_DIGIT_POS)
+        .map(|(_, &d)| d)
+        .collect();
+    let weights = dv::cyclic_weights(payload.len(), 2, 9);
+    dv::mod11_with(&payload, &weights, Mod11Remainder::ComplementOrOne).unwrap_or(0)
+}
+
+/// Normalize a boleto barcode by removing non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::normalize;
+///
+/// assert_eq!(normalize("0019 3373 7000"), "001933737000");
+/// ```
+pub fn normalize(barcode: &str) -> String {
+    barcode.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BARCODE: &str = "00193373700000001000500940144816060680935031";
+
+    #[test]
+    fn test_validate_barcode() {
+        assert_eq!(validate_barcode(BARCODE).as_deref(), Ok(BARCODE));
+        assert_eq!(
+            validate_barcode("0019337370000000100050094014481606068093503"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 44,
+                actual: 43
+            })
+        );
+        assert_eq!(
+            validate_barcode("00195373700000001000500940144816060680935031"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Boleto
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_arrecadacao_barcodes() {
+        assert_eq!(
+            validate_barcode("83640000001135000090134600030012904710101111"),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::Boleto
+            })
+        );
+    }
+
+    const LINE: &str = "00190.50095 40144.816069 06809.350314 3 37370000000100";
+
+    #[test]
+    fn test_validate_digitable_line() {
+        assert_eq!(
+            validate_digitable_line(LINE).as_deref(),
+            Ok("00190500954014481606906809350314337370000000100")
+        );
+        // Each field check digit, then the general one
+        for wrong in [
+            "00190.50094 40144.816069 06809.350314 3 37370000000100",
+            "00190.50095 40144.816068 06809.350314 3 37370000000100",
+            "00190.50095 40144.816069 06809.350315 3 37370000000100",
+            "00190.50095 40144.816069 06809.350314 4 37370000000100",
+        ] {
+            assert_eq!(
+                validate_digitable_line(wrong),
+                Err(BrazilianValidationError::InvalidCheckDigits {
+                    document_type: DocumentKind::Boleto
+                })
+            );
+        }
+        assert!(matches!(
+            validate_digitable_line(BARCODE),
+            Err(BrazilianValidationError::InvalidLength { expected: 47, .. })
+        ));
+    }
+
+    #[test]
+    fn test_conversion_round_trips() {
+        let line = barcode_to_digitable_line(BARCODE).unwrap();
+        assert_eq!(line, normalize(LINE));
+        assert_eq!(digitable_line_to_barcode(&line).as_deref(), Ok(BARCODE));
+
+        let arrecadacao = "82690000001234501232026101700000000000012345";
+        let line = barcode_to_digitable_line(arrecadacao).unwrap();
+        assert_eq!(line, "826900000017234501232024610170000000000000123455");
+        assert_eq!(digitable_line_to_barcode(&line).as_deref(), Ok(arrecadacao));
+    }
+
+    #[test]
+    fn test_format_digitable_line_into() {
+        let mut buf = [0u8; FORMATTED_DIGITABLE_LINE_LEN];
+        assert_eq!(format_digitable_line_into(&normalize(LINE), &mut buf), Some(LINE));
+        // Already formatted input is laid out again
+        assert_eq!(format_digitable_line_into(LINE, &mut buf), Some(LINE));
+
+        assert_eq!(format_digitable_line_into(BARCODE, &mut buf), None);
+        assert_eq!(format_digitable_line_into(&"1".repeat(49), &mut buf), None);
+        assert_eq!(format_digitable_line_into(LINE, &mut [0u8; 53]), None);
+    }
+
+    #[test]
+    fn test_conversion_validates_input() {
+        assert!(barcode_to_digitable_line("00195373700000001000500940144816060680935031").is_err());
+        assert!(barcode_to_digitable_line(LINE).is_err());
+        assert!(digitable_line_to_barcode(
+            "00190.50095 40144.816069 06809.350314 4 37370000000100"
+        )
+        .is_err());
+        assert!(digitable_line_to_barcode(BARCODE).is_err());
+    }
+
+    #[test]
+    fn test_parse_either_form() {
+        assert_eq!(parse(LINE), parse_barcode(BARCODE));
+        assert_eq!(parse(BARCODE), parse_barcode(BARCODE));
+        assert!(parse("123").is_err());
+    }
+
+    #[test]
+    fn test_due_date() {
+        let mut boleto = parse(LINE).unwrap();
+        let around = |year| DueDate {
+            year,
+            month: 1,
+            day: 1,
+        };
+        boleto.due_date_factor = 1000;
+        assert_eq!(
+            boleto.due_date_near(around(2000)),
+            Some(DueDate {
+                year: 2000,
+                month: 7,
+                day: 3
+            })
+        );
+        // Factors restarted at 1000 after 9999 (2025-02-21)
+        boleto.due_date_factor = 9999;
+        assert_eq!(
+            boleto.due_date_near(around(2025)),
+            Some(DueDate {
+                year: 2025,
+                month: 2,
+                day: 21
+            })
+        );
+        boleto.due_date_factor = 1000;
+        assert_eq!(
+            boleto.due_date_near(around(2025)),
+            Some(DueDate {
+                year: 2025,
+                month: 2,
+                day: 22
+            })
+        );
+        boleto.due_date_factor = 0;
+        assert_eq!(boleto.due_date_near(around(2025)), None);
+    }
+
+    #[test]
+    fn test_parse_barcode() {
+        let boleto = parse_barcode(BARCODE).unwrap();
+        assert_eq!(boleto.bank_code, "001");
+        assert_eq!(boleto.currency_code, 9);
+        assert_eq!(boleto.due_date_factor, 3737);
+        assert_eq!(boleto.amount_cents, 100);
+        assert_eq!(boleto.free_field, "0500940144816060680935031");
+    }
+}
@@ -0,0 +1,369 @@
+//! Arrecadação boletos (utilities, taxes and fines)
+//!
+//! Collection slips issued by utilities and government bodies ("guias de
+//! arrecadação", "contas de consumo") have a 44-digit barcode starting
+//! with `8`, structured differently from bank boletos:
+//!
+//! ```text
+//! 8 2 6 9 00000012345 0123 2026101700000000000012345
+//! │ │ │ │ value       │    free field
+//! │ │ │ └ general check digit
+//! │ │ └ value indicator: modulo 10 (6, 7) or 11 (8, 9)
+//! │ └ segment
+//! └ product (arrecadação)
+//! ```
+//!
+//! The company is identified by 4 digits, or by the 8 first digits of its
+//! CNPJ in segment 6. The 48-digit linha digitável splits the barcode into
+//! four blocks of 11 digits, each followed by a check digit computed with
+//! the same modulo as the general one.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::boleto::arrecadacao::{self, Segment};
+//!
+//! let guia = arrecadacao::parse("82690000001-7 23450123202-4 61017000000-0 00000012345-5").unwrap();
+//! assert_eq!(guia.segment, Segment::Sanitation);
+//! assert_eq!(guia.amount_cents(), Some(12345));
+//! assert_eq!(guia.company_id, "0123");
+//! ```
+
+use super::normalize;
+use crate::document::DocumentKind;
+use crate::dv::{self, Mod11Remainder};
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Number of digits of an arrecadação barcode
+pub const BARCODE_LEN: usize = 44;
+
+/// Number of digits of an arrecadação linha digitável
+pub const DIGITABLE_LINE_LEN: usize = 48;
+
+/// Position of the general check digit in the barcode
+const CHECK_DIGIT_POS: usize = 3;
+
+/// Digits of a block of the linha digitável, without its check digit
+const BLOCK_LEN: usize = 11;
+
+/// Segment of the issuer (2nd digit of the barcode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// Prefeituras (municipal taxes)
+    Municipalities,
+    /// Saneamento (water and sewage)
+    Sanitation,
+    /// Energia elétrica e gás
+    EnergyAndGas,
+    /// Telecomunicações
+    Telecommunications,
+    /// Órgãos governamentais
+    Government,
+    /// Carnês and other issuers identified by CNPJ
+    CnpjIdentified,
+    /// Multas de trânsito (traffic fines)
+    TrafficFines,
+    /// Reserved for the bank's own use
+    BankExclusive,
+}
+
+impl Segment {
+    /// Segment for its code in the barcode
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Segment::Municipalities),
+            2 => Some(Segment::Sanitation),
+            3 => Some(Segment::EnergyAndGas),
+            4 => Some(Segment::Telecommunications),
+            5 => Some(Segment::Government),
+            6 => Some(Segment::CnpjIdentified),
+            7 => Some(Segment::TrafficFines),
+            9 => Some(Segment::BankExclusive),
+            _ => None,
+        }
+    }
+
+    /// Code of the segment in the barcode
+    pub fn code(&self) -> u8 {
+        match self {
+            Segment::Municipalities => 1,
+            Segment::Sanitation => 2,
+            Segment::EnergyAndGas => 3,
+            Segment::Telecommunications => 4,
+            Segment::Government => 5,
+            Segment::CnpjIdentified => 6,
+            Segment::TrafficFines => 7,
+            Segment::BankExclusive => 9,
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Segment::Municipalities => write!(f, "Prefeituras"),
+            Segment::Sanitation => write!(f, "Saneamento"),
+            Segment::EnergyAndGas => write!(f, "Energia elétrica e gás"),
+            Segment::Telecommunications => write!(f, "Telecomunicações"),
+            Segment::Government => write!(f, "Órgãos governamentais"),
+            Segment::CnpjIdentified => write!(f, "Carnês e assemelhados"),
+            Segment::TrafficFines => write!(f, "Multas de trânsito"),
+            Segment::BankExclusive => write!(f, "Uso exclusivo do banco"),
+        }
+    }
+}
+
+/// Fields of an arrecadação barcode
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrecadacaoBarcode {
+    /// Normalized barcode (44 digits)
+    pub barcode: String,
+    /// Segment of the issuer
+    pub segment: Segment,
+    /// Value indicator (`6` to `9`)
+    pub value_indicator: u8,
+    /// Value field: centavos, or a reference quantity (see
+    /// [`amount_cents`](Self::amount_cents))
+    pub value: u64,
+    /// Issuer identification: 4 digits, or the CNPJ base (8 digits) in
+    /// segment 6
+    pub company_id: String,
+    /// Issuer-defined free field
+    pub free_field: String,
+}
+
+impl ArrecadacaoBarcode {
+    /// Amount in centavos, `None` when the value is a reference quantity
+    /// (indicators 7 and 9, e.g. amounts indexed to a currency unit)
+    pub fn amount_cents(&self) -> Option<u64> {
+        matches!(self.value_indicator, 6 | 8).then_some(self.value)
+    }
+}
+
+/// Validate an arrecadação barcode or linha digitável
+///
+/// # Arguments
+/// * `boleto` - 44-digit barcode or 48-digit linha digitável (non-digit
+///   characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized input (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::arrecadacao::validate;
+///
+/// assert!(validate("84840000000999004560000000000000000000054321").is_ok());
+/// assert!(validate("84840000000999004560000000000000000000054322").is_err());
+/// ```
+pub fn validate(boleto: &str) -> ValidationResult<String> {
+    let result = parse_unobserved(boleto).map(|_| normalize(boleto));
+    hooks::notify(DocumentKind::Boleto, &result);
+    result
+}
+
+/// Validate an arrecadação barcode or linha digitável and extract its
+/// fields
+///
+/// The form is told by the number of digits: 44 for the barcode, 48 for
+/// the linha digitável.
+pub fn parse(boleto: &str) -> ValidationResult<ArrecadacaoBarcode> {
+    let result = parse_unobserved(boleto);
+    hooks::notify(DocumentKind::Boleto, &result);
+    result
+}
+
+/// Parse without reporting to the validation hook (for internal use)
+pub(crate) fn parse_unobserved(boleto: &str) -> ValidationResult<ArrecadacaoBarcode> {
+    let cleaned = normalize(boleto);
+
+    if cleaned.len() == DIGITABLE_LINE_LEN {
+        let digits = dv::digits(&cleaned).ok_or(BrazilianValidationError::InvalidCharacters)?;
+        let check_digit = check_digit_fn(digits[2]).ok_or_else(invalid_format)?;
+        let blocks_valid = digits
+            .chunks(BLOCK_LEN + 1)
+            .all(|block| check_digit(&block[..BLOCK_LEN]) == block[BLOCK_LEN]);
+        if !blocks_valid {
+            return Err(invalid_check_digits());
+        }
+
+        let barcode: String = cleaned
+            .as_bytes()
+            .chunks(BLOCK_LEN + 1)
+            .map(|block| core::str::from_utf8(&block[..BLOCK_LEN]).unwrap_or_default())
+            .collect();
+        return parse_barcode(barcode);
+    }
+
+    if cleaned.len() != BARCODE_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: BARCODE_LEN,
+            actual: cleaned.len(),
+        });
+    }
+    parse_barcode(cleaned)
+}
+
+/// Parse a normalized 44-digit barcode
+fn parse_barcode(barcode: String) -> ValidationResult<ArrecadacaoBarcode> {
+    let digits = dv::digits(&barcode).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    if digits[0] != 8 {
+        return Err(invalid_format());
+    }
+    let segment = Segment::from_code(digits[1] as u8).ok_or_else(invalid_format)?;
+    let check_digit = check_digit_fn(digits[2]).ok_or_else(invalid_format)?;
+
+    let payload: Vec<u32> = digits
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != CHECK_DIGIT_POS)
+        .map(|(_, &d)| d)
+        .collect();
+    if check_digit(&payload) != digits[CHECK_DIGIT_POS] {
+        return Err(invalid_check_digits());
+    }
+
+    let company_end = if segment == Segment::CnpjIdentified {
+        23
+    } else {
+        19
+    };
+    Ok(ArrecadacaoBarcode {
+        segment,
+        value_indicator: digits[2] as u8,
+        value: barcode[4..15].parse().unwrap_or(0),
+        company_id: barcode[15..company_end].to_string(),
+        free_field: barcode[company_end..].to_string(),
+        barcode,
+    })
+}
+
+/// Linha digitável of a validated barcode: four blocks of 11 digits, each
+/// followed by its check digit
+pub(crate) fn digitable_line(barcode: &str) -> String {
+    let digits = dv::digits(barcode).unwrap_or_default();
+    let Some(check_digit) = digits
+        .get(2)
+        .and_then(|&indicator| check_digit_fn(indicator))
+    else {
+        return barcode.to_string();
+    };
+    digits
+        .chunks(BLOCK_LEN)
+        .flat_map(|block| block.iter().copied().chain([check_digit(block)]))
+        .filter_map(|digit| char::from_digit(digit, 10))
+        .collect()
+}
+
+/// Check-digit function selected by the value indicator
+fn check_digit_fn(value_indicator: u32) -> Option<fn(&[u32]) -> u32> {
+    match value_indicator {
+        6 | 7 => Some(mod10),
+        8 | 9 => Some(mod11),
+        _ => None,
+    }
+}
+
+/// Modulo 10 check digit, weights 2 and 1 from the right
+fn mod10(digits: &[u32]) -> u32 {
+    dv::mod10_alternating(digits).unwrap_or(0)
+}
+
+/// Modulo 11 check digit, weights 2 to 9 from the right; remainders 0
+/// and 1 give 0
+fn mod11(digits: &[u32]) -> u32 {
+    let weights = dv::cyclic_weights(digits.len(), 2, 9);
+    dv::mod11_with(digits, &weights, Mod11Remainder::Complement).unwrap_or(0)
+}
+
+/// Input that is not an arrecadação boleto
+fn invalid_format() -> BrazilianValidationError {
+    BrazilianValidationError::InvalidDocumentFormat {
+        document_type: DocumentKind::Boleto,
+    }
+}
+
+/// Wrong general or block check digit
+fn invalid_check_digits() -> BrazilianValidationError {
+    BrazilianValidationError::InvalidCheckDigits {
+        document_type: DocumentKind::Boleto,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sanitation, modulo 10, R$ 123,45
+    const BARCODE: &str = "82690000001234501232026101700000000000012345";
+    const LINE: &str = "82690000001-7 23450123202-4 61017000000-0 00000012345-5";
+    /// Telecommunications, modulo 11, R$ 99,90
+    const BARCODE_MOD11: &str = "84840000000999004560000000000000000000054321";
+    const LINE_MOD11: &str = "84840000000-3 99900456000-7 00000000000-0 00000054321-7";
+    /// Issuer identified by CNPJ, modulo 10, R$ 500,00
+    const BARCODE_CNPJ: &str = "86620000005000011222333000000000000000000001";
+
+    #[test]
+    fn test_parse_barcode_and_line() {
+        let guia = parse(BARCODE).unwrap();
+        assert_eq!(guia.segment, Segment::Sanitation);
+        assert_eq!(guia.value_indicator, 6);
+        assert_eq!(guia.amount_cents(), Some(12345));
+        assert_eq!(guia.company_id, "0123");
+        assert_eq!(guia.free_field, "2026101700000000000012345");
+        assert_eq!(parse(LINE), Ok(guia));
+
+        let guia = parse(LINE_MOD11).unwrap();
+        assert_eq!(guia.barcode, BARCODE_MOD11);
+        assert_eq!(guia.segment, Segment::Telecommunications);
+        assert_eq!(guia.amount_cents(), Some(9990));
+    }
+
+    #[test]
+    fn test_cnpj_segment_company_id() {
+        let guia = parse(BARCODE_CNPJ).unwrap();
+        assert_eq!(guia.segment, Segment::CnpjIdentified);
+        assert_eq!(guia.company_id, "11222333");
+        assert_eq!(guia.free_field.len(), 21);
+    }
+
+    #[test]
+    fn test_check_digits() {
+        // General check digit
+        assert_eq!(
+            validate("82680000001234501232026101700000000000012345"),
+            Err(invalid_check_digits())
+        );
+        // Block check digit
+        assert_eq!(
+            validate("82690000001-7 23450123202-5 61017000000-0 00000012345-5"),
+            Err(invalid_check_digits())
+        );
+        assert_eq!(
+            validate(LINE).as_deref(),
+            Ok("826900000017234501232024610170000000000000123455")
+        );
+    }
+
+    #[test]
+    fn test_layout_errors() {
+        // Bank boleto
+        assert_eq!(
+            validate("00193373700000001000500940144816060680935031"),
+            Err(invalid_format())
+        );
+        // Value indicator 5
+        assert_eq!(
+            validate("82590000001234501232026101700000000000012345"),
+            Err(invalid_format())
+        );
+        assert!(matches!(
+            validate("8269"),
+            Err(BrazilianValidationError::InvalidLength { expected: 44, .. })
+        ));
+    }
+}
@@ -0,0 +1,207 @@
+//! Hybrid boletos ("boleto híbrido")
+//!
+//! A hybrid boleto can be paid either through its barcode or through the
+//! PIX QR code printed next to it. Both must describe the same charge:
+//! [`parse`] validates the barcode, the beneficiary document and the BR
+//! Code, then checks that the amounts agree and that a CPF or CNPJ PIX key
+//! belongs to the beneficiary.
+//!
+//! Only BR Codes carrying the PIX key are supported; dynamic payloads
+//! pointing to a location URL are rejected by [`brcode::parse`].
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::boleto::hybrid;
+//!
+//! let boleto = hybrid::parse(
+//!     "00193373700000001000500940144816060680935031",
+//!     "11.222.333/0001-81",
+//!     "00020126360014br.gov.bcb.pix0114112223330001815204000053039865404\
+//!      1.005802BR5915EMPRESA EXEMPLO6009SAO PAULO62070503***6304A3D9",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(boleto.amount_cents(), 100);
+//! assert_eq!(boleto.beneficiary_document, "11222333000181");
+//! ```
+
+use super::Barcode;
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::pix::brcode::{self, PixPayload};
+use crate::pix::{self, PixKeyType};
+use crate::report::ValidationReport;
+use crate::{cnpj, cpf};
+use alloc::format;
+use alloc::string::String;
+
+/// Boleto and PIX QR code of the same charge, cross-validated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HybridBoleto {
+    /// Barcode fields
+    pub boleto: Barcode,
+    /// Normalized CPF or CNPJ of the beneficiary
+    pub beneficiary_document: String,
+    /// PIX payload
+    pub pix: PixPayload,
+}
+
+impl HybridBoleto {
+    /// Amount of the charge in centavos (0 when informed by the payer)
+    pub fn amount_cents(&self) -> u64 {
+        self.boleto.amount_cents
+    }
+}
+
+/// Validate and cross-check the two halves of a hybrid boleto
+///
+/// # Arguments
+/// * `barcode` - 44-digit boleto barcode
+/// * `beneficiary_document` - CPF or CNPJ of the beneficiary, as registered
+///   with the boleto
+/// * `brcode` - PIX "copia e cola" payload
+///
+/// # Returns
+/// * `Ok(HybridBoleto)` - Both halves are valid and consistent
+/// * `Err(ValidationReport)` - Every problem found, under the fields
+///   `barcode`, `beneficiary_document`, `pix` and `pix.*`
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::hybrid;
+///
+/// // PIX charges R$ 2,00 for a R$ 1,00 boleto
+/// let report = hybrid::parse(
+///     "00193373700000001000500940144816060680935031",
+///     "11222333000181",
+///     "00020126360014br.gov.bcb.pix0114112223330001815204000053039865404\
+///      2.005802BR5915EMPRESA EXEMPLO6009SAO PAULO62070503***630413D7",
+/// )
+/// .unwrap_err();
+///
+/// assert_eq!(report.field_errors("pix.amount").count(), 1);
+/// ```
+pub fn parse(
+    barcode: &str,
+    beneficiary_document: &str,
+    brcode: &str,
+) -> Result<HybridBoleto, ValidationReport> {
+    let mut report = ValidationReport::new();
+
+    let boleto = report.check("barcode", super::parse_barcode_unobserved(barcode));
+    let beneficiary = report.check(
+        "beneficiary_document",
+        validate_document(beneficiary_document),
+    );
+    let pix = report.check("pix", brcode::parse(brcode).map_err(Into::into));
+
+    if let Some(pix) = &pix {
+        for error in pix.validate_semantics().errors() {
+            report.add(format!("pix.{}", error.field), error.error.clone());
+        }
+    }
+
+    if let (Some(boleto), Some(pix)) = (&boleto, &pix) {
+        let pix_amount = pix.amount.as_deref().and_then(amount_cents);
+        if boleto.amount_cents != 0 && pix_amount != Some(boleto.amount_cents) {
+            report.add("pix.amount", inconsistent());
+        }
+    }
+
+    if let (Some(beneficiary), Some(pix)) = (&beneficiary, &pix) {
+        let document_key = matches!(
+            pix::detect_type(&pix.key),
+            Some(PixKeyType::Cpf | PixKeyType::Cnpj)
+        );
+        if document_key && pix::normalize(&pix.key) != *beneficiary {
+            report.add("pix.key", inconsistent());
+        }
+    }
+
+    match (boleto, beneficiary, pix) {
+        (Some(boleto), Some(beneficiary_document), Some(pix)) if report.is_valid() => {
+            Ok(HybridBoleto {
+                boleto,
+                beneficiary_document,
+                pix,
+            })
+        }
+        _ => Err(report),
+    }
+}
+
+/// Validate a CPF or CNPJ, told apart by the number of digits
+fn validate_document(document: &str) -> ValidationResult<String> {
+    if cpf::normalize(document).len() == 11 {
+        cpf::validate_unobserved(document)
+    } else {
+        cnpj::validate_unobserved(document)
+    }
+}
+
+/// Convert a BR Code amount (`"10.5"`) to centavos
+fn amount_cents(amount: &str) -> Option<u64> {
+    let (units, cents) = amount.split_once('.').unwrap_or((amount, ""));
+    if cents.len() > 2 {
+        return None;
+    }
+    let units: u64 = units.parse().ok()?;
+    let cents: u64 = format!("{:0<2}", cents).parse().ok()?;
+    units.checked_mul(100)?.checked_add(cents)
+}
+
+/// Disagreement between the boleto and the PIX payload
+fn inconsistent() -> BrazilianValidationError {
+    BrazilianValidationError::Inconsistent {
+        document_type: DocumentKind::Boleto,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BARCODE: &str = "00193373700000001000500940144816060680935031";
+    const CNPJ: &str = "11222333000181";
+    /// CNPJ key, R$ 1,00
+    const PIX: &str = "00020126360014br.gov.bcb.pix01141122233300018152040000530398654041.005802BR5915EMPRESA EXEMPLO6009SAO PAULO62070503***6304A3D9";
+    /// E-mail key, R$ 1,00
+    const PIX_EMAIL: &str = "00020126380014br.gov.bcb.pix0116user@example.com52040000530398654041.005802BR5915EMPRESA EXEMPLO6009SAO PAULO62070503***6304B21D";
+    /// CPF key, R$ 1,00
+    const PIX_CPF: &str = "00020126330014br.gov.bcb.pix01111114447773552040000530398654041.005802BR5915EMPRESA EXEMPLO6009SAO PAULO62070503***6304DB7D";
+
+    #[test]
+    fn test_consistent_boleto() {
+        let boleto = parse(BARCODE, "11.222.333/0001-81", PIX).unwrap();
+        assert_eq!(boleto.boleto.bank_code, "001");
+        assert_eq!(boleto.pix.key, CNPJ);
+        assert_eq!(boleto.amount_cents(), 100);
+
+        // E-mail keys cannot be matched against the beneficiary
+        assert!(parse(BARCODE, CNPJ, PIX_EMAIL).is_ok());
+    }
+
+    #[test]
+    fn test_key_of_another_document_is_inconsistent() {
+        let report = parse(BARCODE, CNPJ, PIX_CPF).unwrap_err();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.field_errors("pix.key").next(), Some(&inconsistent()));
+    }
+
+    #[test]
+    fn test_reports_every_invalid_part() {
+        let report = parse("0019337370000", "123.456.789-00", "000201").unwrap_err();
+        assert_eq!(report.field_errors("barcode").count(), 1);
+        assert_eq!(report.field_errors("beneficiary_document").count(), 1);
+        assert_eq!(report.field_errors("pix").count(), 1);
+    }
+
+    #[test]
+    fn test_amount_cents() {
+        assert_eq!(amount_cents("1.00"), Some(100));
+        assert_eq!(amount_cents("10.5"), Some(1050));
+        assert_eq!(amount_cents("7"), Some(700));
+        assert_eq!(amount_cents("1.005"), None);
+        assert_eq!(amount_cents("1,00"), None);
+    }
+}
@@ -0,0 +1,212 @@
+//! Interleaved 2 of 5 rendering of boleto barcodes
+//!
+//! Boletos print their 44-digit barcode in ITF (Interleaved 2 of 5): digits
+//! are encoded in pairs, the first digit in the widths of five bars and the
+//! second in the widths of the five spaces between them. The barcode is
+//! validated before rendering, so the printed bars always match a valid
+//! boleto.
+//!
+//! Enabled by the `barcode` feature.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::boleto::itf::{self, ItfOptions};
+//!
+//! let barcode = "00193373700000001000500940144816060680935031";
+//! let svg = itf::to_svg(barcode, &ItfOptions::default()).unwrap();
+//! assert!(svg.starts_with("<svg"));
+//!
+//! let png = itf::to_png(barcode, &ItfOptions::default()).unwrap();
+//! assert_eq!(&png[1..4], b"PNG");
+//! ```
+
+use crate::barcode::Layout;
+use crate::error::ValidationResult;
+
+/// Wide/narrow pattern of each digit (`true` = wide)
+const PATTERNS: [[bool; 5]; 10] = {
+    const N: bool = false;
+    const W: bool = true;
+    [
+        [N, N, W, W, N],
+        [W, N, N, N, W],
+        [N, W, N, N, W],
+        [W, W, N, N, N],
+        [N, N, W, N, W],
+        [W, N, W, N, N],
+        [N, W, W, N, N],
+        [N, N, N, W, W],
+        [W, N, N, W, N],
+        [N, W, N, W, N],
+    ]
+};
+
+/// Rendering dimensions
+///
+/// The defaults follow the FEBRABAN layout at roughly 96 dpi: a 3:1 wide to
+/// narrow ratio, a 13 mm tall symbol and quiet zones of ten narrow bars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItfOptions {
+    /// Width of a narrow bar, in pixels
+    pub narrow_width: u32,
+    /// Width of a wide bar, as a multiple of the narrow width
+    pub wide_ratio: u32,
+    /// Height of the bars, in pixels
+    pub height: u32,
+    /// Blank margin on each side, in narrow bar widths
+    pub quiet_zone: u32,
+}
+
+impl Default for ItfOptions {
+    fn default() -> Self {
+        ItfOptions {
+            narrow_width: 1,
+            wide_ratio: 3,
+            height: 50,
+            quiet_zone: 10,
+        }
+    }
+}
+
+/// Encode a boleto barcode as alternating bar and space widths
+///
+/// The first element is a bar; widths are in narrow bar units, start and
+/// stop patterns included, quiet zones excluded.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::boleto::itf;
+///
+/// let widths = itf::encode("00193373700000001000500940144816060680935031", 3).unwrap();
+/// assert_eq!(&widths[..4], [1, 1, 1, 1]); // start pattern
+/// assert_eq!(widths.len(), 4 + 44 * 5 + 3);
+/// ```
+pub fn encode(barcode: &str, wide_ratio: u32) -> ValidationResult<Vec<u32>> {
+    let parsed = super::parse_barcode_unobserved(barcode)?;
+    let digits: Vec<usize> = parsed
+        .barcode
+        .bytes()
+        .map(|b| (b - b'0') as usize)
+        .collect();
+    let width = |wide: bool| if wide { wide_ratio } else { 1 };
+
+    let mut widths = vec![1, 1, 1, 1];
+    for pair in digits.chunks(2) {
+        let (bars, spaces) = (PATTERNS[pair[0]], PATTERNS[pair[1]]);
+        for (bar, space) in bars.into_iter().zip(spaces) {
+            widths.push(width(bar));
+            widths.push(width(space));
+        }
+    }
+    widths.extend([wide_ratio, 1, 1]);
+
+    Ok(widths)
+}
+
+/// Render a boleto barcode as an SVG image
+///
+/// # Returns
+/// * `Ok(String)` - SVG document, black bars on a white background
+/// * `Err(BrazilianValidationError)` - The barcode is not valid, or the
+///   options would make an image larger than 64 megapixels
+pub fn to_svg(barcode: &str, options: &ItfOptions) -> ValidationResult<String> {
+    Ok(layout(barcode, options)?.to_svg())
+}
+
+/// Render a boleto barcode as a grayscale PNG image
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - PNG file contents
+/// * `Err(BrazilianValidationError)` - The barcode is not valid, or the
+///   options would make an image larger than 64 megapixels
+pub fn to_png(barcode: &str, options: &ItfOptions) -> ValidationResult<Vec<u8>> {
+    layout(barcode, options)?.to_png()
+}
+
+/// Lay out the bars of a barcode in pixels
+fn layout(barcode: &str, options: &ItfOptions) -> ValidationResult<Layout> {
+    let widths = encode(barcode, options.wide_ratio.max(2))?;
+    Layout::new(
+        &widths,
+        options.narrow_width,
+        options.height,
+        options.quiet_zone,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BrazilianValidationError;
+
+    const BARCODE: &str = "00193373700000001000500940144816060680935031";
+
+    #[test]
+    fn test_patterns_have_two_wide_elements() {
+        for pattern in PATTERNS {
+            assert_eq!(pattern.iter().filter(|&&wide| wide).count(), 2);
+        }
+    }
+
+    #[test]
+    fn test_encode_interleaves_pairs() {
+        let widths = encode(BARCODE, 3).unwrap();
+
+        // "00": bars and spaces both N N W W N
+        assert_eq!(&widths[4..14], [1, 1, 1, 1, 3, 3, 3, 3, 1, 1]);
+        // stop pattern: wide bar, narrow space, narrow bar
+        assert_eq!(&widths[widths.len() - 3..], [3, 1, 1]);
+        // every symbol has the same width: 2 wide and 3 narrow per digit
+        assert_eq!(widths.iter().sum::<u32>(), 4 + 44 * (2 * 3 + 3) + 5);
+    }
+
+    #[test]
+    fn test_invalid_barcode_is_not_rendered() {
+        let invalid = "00195373700000001000500940144816060680935031";
+        let expected = BrazilianValidationError::InvalidCheckDigits {
+            document_type: crate::document::DocumentKind::Boleto,
+        };
+        assert_eq!(
+            to_svg(invalid, &ItfOptions::default()),
+            Err(expected.clone())
+        );
+        assert_eq!(to_png(invalid, &ItfOptions::default()), Err(expected));
+    }
+
+    #[test]
+    fn test_svg_dimensions() {
+        let options = ItfOptions {
+            narrow_width: 2,
+            ..ItfOptions::default()
+        };
+        let svg = to_svg(BARCODE, &options).unwrap();
+        let width = (4 + 44 * 9 + 5 + 20) * 2;
+
+        assert!(svg.contains(&format!("width=\"{}\" height=\"50\"", width)));
+        // 2 start bars, 5 bars per digit pair, 2 stop bars, plus the background
+        assert_eq!(svg.matches("<rect").count(), 2 + 22 * 5 + 2 + 1);
+    }
+
+    #[test]
+    fn test_extreme_options_are_refused() {
+        let too_large = Some(BrazilianValidationError::ImageTooLarge);
+        for options in [
+            ItfOptions {
+                narrow_width: u32::MAX / 4,
+                ..ItfOptions::default()
+            },
+            ItfOptions {
+                wide_ratio: u32::MAX,
+                ..ItfOptions::default()
+            },
+            ItfOptions {
+                height: u32::MAX,
+                quiet_zone: u32::MAX,
+                ..ItfOptions::default()
+            },
+        ] {
+            assert_eq!(to_svg(BARCODE, &options).err(), too_large);
+            assert_eq!(to_png(BARCODE, &options).err(), too_large);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! CAEPF (Cadastro de Atividade Econômica da Pessoa Física) validation
+//!
+//! The CAEPF identifies the economic activities of individuals who hire
+//! employees (rural producers, self-employed professionals), replacing
+//! their CEI ([`crate::cei`]). It has 14 digits: the first 9 digits of the
+//! holder's CPF, a 3-digit sequence and two check digits.
+//!
+//! The check digits are computed as for a CNPJ, then 12 is added to the
+//! two-digit result, modulo 100.
+
+use crate::document::DocumentKind;
+use crate::dv;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of digits of a CAEPF
+pub const LEN: usize = 14;
+
+/// Validate a CAEPF number
+///
+/// # Arguments
+/// * `caepf` - CAEPF number (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized CAEPF (14 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::caepf::validate;
+///
+/// assert!(validate("123.456.789/001-00").is_ok());
+/// assert!(validate("123.456.789/001-88").is_err()); // CNPJ check digits, without the 12
+/// ```
+pub fn validate(caepf: &str) -> ValidationResult<String> {
+    let result = check(caepf);
+    hooks::notify(DocumentKind::Caepf, &result);
+    result
+}
+
+fn check(caepf: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(caepf));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if cleaned.bytes().all(|b| b == cleaned.as_bytes()[0]) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Caepf,
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    if compute_check_digits(&digits[..12]) != digits[12] * 10 + digits[13] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Caepf,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute both check digits of the 12 base digits, as a number
+fn compute_check_digits(base: &[u32]) -> u32 {
+    let check1 = dv::mod11(base, &dv::cyclic_weights(12, 2, 9)).unwrap_or_default();
+    let with_check1: Vec<u32> = base.iter().copied().chain([check1]).collect();
+    let check2 = dv::mod11(&with_check1, &dv::cyclic_weights(13, 2, 9)).unwrap_or_default();
+    (check1 * 10 + check2 + 12) % 100
+}
+
+/// Normalize a CAEPF number by removing all non-digit characters
+pub fn normalize(caepf: &str) -> String {
+    caepf.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a CAEPF number (XXX.XXX.XXX/XXX-XX)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::caepf::format;
+///
+/// assert_eq!(format("11144477700154"), "111.444.777/001-54");
+/// assert_eq!(format("123"), "123");
+/// ```
+pub fn format(caepf: &str) -> String {
+    let cleaned = normalize(caepf);
+
+    if cleaned.len() == LEN {
+        format!(
+            "{}.{}.{}/{}-{}",
+            &cleaned[..3],
+            &cleaned[3..6],
+            &cleaned[6..9],
+            &cleaned[9..12],
+            &cleaned[12..]
+        )
+    } else {
+        caepf.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_is_ignored() {
+        for caepf in [
+            "111.444.777/001-54",
+            "111 444 777 001 54",
+            "111444777/00154",
+        ] {
+            assert_eq!(validate(caepf).as_deref(), Ok("11144477700154"));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 14,
+                actual,
+            })
+        };
+        assert_eq!(validate("1114447770015"), invalid_length(13));
+        assert_eq!(validate("111444777001540"), invalid_length(15));
+        // The holder's CPF alone is not a CAEPF
+        assert_eq!(validate("111.444.777-35"), invalid_length(11));
+    }
+
+    #[test]
+    fn test_repeated_digits_are_rejected() {
+        for digit in '0'..='9' {
+            assert_eq!(
+                validate(&digit.to_string().repeat(14)),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Caepf
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_digits() {
+        for caepf in ["12345678900100", "11144477700154", "98765432100234"] {
+            assert_eq!(validate(caepf).as_deref(), Ok(caepf));
+        }
+        let bad_check_digits = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Caepf,
+        });
+        // Each check digit off by one
+        assert_eq!(validate("11144477700164"), bad_check_digits);
+        assert_eq!(validate("11144477700155"), bad_check_digits);
+        // CNPJ check digits, without adding 12
+        assert_eq!(validate("12345678900188"), bad_check_digits);
+        // Another sequence number for the same CPF
+        assert_eq!(validate("11144477700254"), bad_check_digits);
+    }
+}
@@ -0,0 +1,361 @@
+//! Payment card number validation and brand detection
+//!
+//! Card numbers (PAN) end in a Luhn check digit. The brand is told by the
+//! first digits (the BIN): international brands have a few wide ranges,
+//! while the Brazilian Elo and Hipercard use scattered 6-digit ranges,
+//! some of them inside Visa's and Discover's. Brazilian brands are
+//! therefore matched first.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::card::{self, CardBrand};
+//!
+//! assert_eq!(card::detect_brand("6362 9700 0045 7013"), Some(CardBrand::Elo));
+//! assert_eq!(card::detect_brand("4111 1111 1111 1111"), Some(CardBrand::Visa));
+//! assert_eq!(card::mask("4111111111111111"), "**** **** **** 1111");
+//! ```
+
+use crate::document::DocumentKind;
+use crate::dv;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Shortest card number accepted
+pub const MIN_LEN: usize = 12;
+
+/// Longest card number accepted
+pub const MAX_LEN: usize = 19;
+
+/// Elo BIN ranges (6 digits, inclusive)
+const ELO_BINS: [(u32, u32); 23] = [
+    (401178, 401179),
+    (431274, 431274),
+    (438935, 438935),
+    (451416, 451416),
+    (457393, 457393),
+    (457631, 457632),
+    (504175, 504175),
+    (506699, 506778),
+    (509000, 509999),
+    (627780, 627780),
+    (636297, 636297),
+    (636368, 636368),
+    (650031, 650033),
+    (650035, 650051),
+    (650405, 650439),
+    (650485, 650538),
+    (650541, 650598),
+    (650700, 650718),
+    (650720, 650727),
+    (650901, 650978),
+    (651652, 651679),
+    (655000, 655019),
+    (655021, 655058),
+];
+
+/// Hipercard BIN ranges (6 digits, inclusive)
+const HIPERCARD_BINS: [(u32, u32); 7] = [
+    (384100, 384100),
+    (384140, 384140),
+    (384160, 384160),
+    (606282, 606282),
+    (637095, 637095),
+    (637568, 637568),
+    (637599, 637612),
+];
+
+/// Card brand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    /// Visa
+    Visa,
+    /// Mastercard
+    Mastercard,
+    /// American Express
+    Amex,
+    /// Elo (Brazilian)
+    Elo,
+    /// Hipercard (Brazilian)
+    Hipercard,
+    /// Diners Club
+    Diners,
+}
+
+impl CardBrand {
+    /// Numbers of digits issued by the brand
+    pub fn lengths(&self) -> &'static [usize] {
+        match self {
+            CardBrand::Visa => &[13, 16, 19],
+            CardBrand::Mastercard | CardBrand::Elo => &[16],
+            CardBrand::Amex => &[15],
+            CardBrand::Hipercard => &[13, 16, 19],
+            CardBrand::Diners => &[14, 16],
+        }
+    }
+}
+
+impl fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardBrand::Visa => write!(f, "Visa"),
+            CardBrand::Mastercard => write!(f, "Mastercard"),
+            CardBrand::Amex => write!(f, "American Express"),
+            CardBrand::Elo => write!(f, "Elo"),
+            CardBrand::Hipercard => write!(f, "Hipercard"),
+            CardBrand::Diners => write!(f, "Diners Club"),
+        }
+    }
+}
+
+/// Validate a card number
+///
+/// Checks the Luhn check digit and, for known brands, the number of
+/// digits. Numbers of unknown brands are accepted if their check digit is
+/// valid.
+///
+/// # Arguments
+/// * `number` - Card number (spaces and hyphens are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized number (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::card::validate;
+///
+/// assert!(validate("4111 1111 1111 1111").is_ok());
+/// assert!(validate("4111 1111 1111 1112").is_err());
+/// ```
+pub fn validate(number: &str) -> ValidationResult<String> {
+    let result = check(number);
+    hooks::notify(DocumentKind::Card, &result);
+    result
+}
+
+fn check(number: &str) -> ValidationResult<String> {
+    if number
+        .chars()
+        .any(|c| !c.is_ascii_digit() && c != ' ' && c != '-')
+    {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    let cleaned = normalize(number);
+
+    if !(MIN_LEN..=MAX_LEN).contains(&cleaned.len()) {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: 16,
+            actual: cleaned.len(),
+        });
+    }
+
+    if let Some(brand) = detect_brand(&cleaned) {
+        if !brand.lengths().contains(&cleaned.len()) {
+            return Err(BrazilianValidationError::InvalidLength {
+                expected: brand.lengths()[0],
+                actual: cleaned.len(),
+            });
+        }
+    }
+
+    let digits = dv::digits(&cleaned).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    let (payload, check_digit) = digits.split_at(digits.len() - 1);
+    if dv::mod10_alternating(payload) != Some(check_digit[0]) {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Card,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Detect the brand of a card number from its first digits
+///
+/// Works on partial numbers, e.g. as the user types, once enough digits
+/// are known (6 for Elo and Hipercard).
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::card::{detect_brand, CardBrand};
+///
+/// assert_eq!(detect_brand("5067 2300"), Some(CardBrand::Elo));
+/// assert_eq!(detect_brand("6062 8200"), Some(CardBrand::Hipercard));
+/// assert_eq!(detect_brand("3782"), Some(CardBrand::Amex));
+/// assert_eq!(detect_brand("9999"), None);
+/// ```
+pub fn detect_brand(number: &str) -> Option<CardBrand> {
+    let cleaned = normalize(number);
+    let prefix = |len: usize| cleaned.get(..len).and_then(|p| p.parse::<u32>().ok());
+    let in_ranges = |ranges: &[(u32, u32)]| {
+        prefix(6).is_some_and(|bin| {
+            ranges
+                .iter()
+                .any(|&(start, end)| (start..=end).contains(&bin))
+        })
+    };
+
+    if in_ranges(&ELO_BINS) {
+        return Some(CardBrand::Elo);
+    }
+    if in_ranges(&HIPERCARD_BINS) {
+        return Some(CardBrand::Hipercard);
+    }
+
+    match (prefix(1), prefix(2), prefix(3), prefix(4)) {
+        (Some(4), ..) => Some(CardBrand::Visa),
+        (_, Some(34 | 37), ..) => Some(CardBrand::Amex),
+        (_, Some(51..=55), ..) | (.., Some(2221..=2720)) => Some(CardBrand::Mastercard),
+        (_, Some(36 | 38), ..) | (_, _, Some(300..=305), _) => Some(CardBrand::Diners),
+        _ => None,
+    }
+}
+
+/// Normalize a card number by removing non-digit characters
+pub fn normalize(number: &str) -> String {
+    number.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a card number in groups, as embossed (4-6-5 for Amex, groups
+/// of 4 otherwise)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::card::format;
+///
+/// assert_eq!(format("4111111111111111"), "4111 1111 1111 1111");
+/// assert_eq!(format("378282246310005"), "3782 822463 10005");
+/// ```
+pub fn format(number: &str) -> String {
+    let cleaned = normalize(number);
+    if !(MIN_LEN..=MAX_LEN).contains(&cleaned.len()) {
+        return number.to_string();
+    }
+
+    if detect_brand(&cleaned) == Some(CardBrand::Amex) && cleaned.len() == 15 {
+        return format!("{} {} {}", &cleaned[..4], &cleaned[4..10], &cleaned[10..]);
+    }
+    cleaned
+        .as_bytes()
+        .chunks(4)
+        .map(|group| core::str::from_utf8(group).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Mask a card number for display (shows the last 4 digits)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::card::mask;
+///
+/// assert_eq!(mask("4111 1111 1111 1111"), "**** **** **** 1111");
+/// assert_eq!(mask("378282246310005"), "**** ****** *0005");
+/// ```
+pub fn mask(number: &str) -> String {
+    let cleaned = normalize(number);
+    if !(MIN_LEN..=MAX_LEN).contains(&cleaned.len()) {
+        return number.to_string();
+    }
+
+    let visible_from = cleaned.len() - 4;
+    let mut seen = 0;
+    format(&cleaned)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_digit() {
+                seen += 1;
+                if seen <= visible_from {
+                    return '*';
+                }
+            }
+            c
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brands() {
+        let cases = [
+            ("4111111111111111", CardBrand::Visa),
+            ("5555555555554444", CardBrand::Mastercard),
+            ("2223000048400011", CardBrand::Mastercard),
+            ("378282246310005", CardBrand::Amex),
+            ("30569309025904", CardBrand::Diners),
+            ("6362970000457013", CardBrand::Elo),
+            ("4011780000000000", CardBrand::Elo),
+            ("6062825624254001", CardBrand::Hipercard),
+        ];
+        for (number, brand) in cases {
+            assert_eq!(detect_brand(number), Some(brand), "{}", number);
+        }
+        // Elo ranges inside Visa's
+        assert_eq!(detect_brand("4576 32"), Some(CardBrand::Elo));
+        assert_eq!(detect_brand("4576 33"), Some(CardBrand::Visa));
+    }
+
+    #[test]
+    fn test_separators() {
+        for number in [
+            "6362 9700 0045 7013",
+            "6362-9700-0045-7013",
+            "6362970000457013",
+        ] {
+            assert_eq!(validate(number).as_deref(), Ok("6362970000457013"));
+        }
+        // Only spaces and hyphens, as printed on cards and typed in forms
+        for number in [
+            "6362.9700.0045.7013",
+            "6362/9700/0045/7013",
+            "６３６２970000457013",
+        ] {
+            assert_eq!(
+                validate(number),
+                Err(BrazilianValidationError::InvalidCharacters)
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length =
+            |expected, actual| Err(BrazilianValidationError::InvalidLength { expected, actual });
+        // Outside 12 to 19 digits, for any brand
+        assert_eq!(validate("99999999993"), invalid_length(16, 11));
+        assert_eq!(validate(&"9".repeat(20)), invalid_length(16, 20));
+        // Amex numbers have 15 digits, Visa 13, 16 or 19
+        assert_eq!(validate("3782822463100055"), invalid_length(15, 16));
+        assert_eq!(validate("41111111111111"), invalid_length(13, 14));
+    }
+
+    #[test]
+    fn test_luhn_check_digit() {
+        let bad_check_digit = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Card,
+        });
+        // Last digit off, two adjacent digits swapped
+        assert_eq!(validate("6362970000457014"), bad_check_digit);
+        assert_eq!(validate("6362970000475013"), bad_check_digit);
+        // A repeated digit is rejected by Luhn, except for zeros
+        assert_eq!(validate("4444 4444 4444 4444"), bad_check_digit);
+        // Unknown brands only need a valid check digit
+        assert_eq!(
+            validate("9999 9999 9999 9995").as_deref(),
+            Ok("9999999999999995")
+        );
+    }
+
+    #[test]
+    fn test_format_and_mask_passthrough() {
+        assert_eq!(format("1234"), "1234");
+        assert_eq!(mask("1234"), "1234");
+        assert_eq!(mask("6362-9700-0045-7013"), "**** **** **** 7013");
+    }
+}
@@ -0,0 +1,164 @@
+//! CEI (Cadastro Específico do INSS) validation and formatting
+//!
+//! The CEI identified employers without a CNPJ (individuals hiring
+//! employees, construction works) for social security contributions. It
+//! has 12 digits, the last one a check digit. It was replaced by the CAEPF
+//! ([`crate::caepf`]) for individual employers and by the CNO
+//! ([`crate::cno`]) for construction works, but still appears in payroll
+//! history.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of digits of a CEI
+pub const LEN: usize = 12;
+
+/// Weights of the 11 base digits
+const WEIGHTS: [u32; 11] = [7, 4, 1, 8, 5, 2, 1, 6, 3, 7, 4];
+
+/// Validate a CEI number
+///
+/// # Arguments
+/// * `cei` - CEI number (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized CEI (12 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cei::validate;
+///
+/// assert!(validate("11.222.33333/47").is_ok());
+/// assert!(validate("11.222.33333/48").is_err()); // Invalid check digit
+/// ```
+pub fn validate(cei: &str) -> ValidationResult<String> {
+    let result = validate_digits(cei, DocumentKind::Cei);
+    hooks::notify(DocumentKind::Cei, &result);
+    result
+}
+
+/// Validate a CEI-layout number, reporting errors as `kind`
+///
+/// Shared with the CNO, which kept the CEI numbering.
+pub(crate) fn validate_digits(number: &str, kind: DocumentKind) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(number));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if cleaned.bytes().all(|b| b == cleaned.as_bytes()[0]) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: kind,
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    if compute_check_digit(&digits[..LEN - 1]) != digits[LEN - 1] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: kind,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute the check digit of the 11 base digits
+///
+/// The units and tens of the weighted sum are added, and the check digit
+/// is the complement to 10 of the units of the result (0 instead of 10).
+fn compute_check_digit(base: &[u32]) -> u32 {
+    let sum: u32 = base.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum();
+    let folded = sum % 10 + sum / 10 % 10;
+    (10 - folded % 10) % 10
+}
+
+/// Normalize a CEI number by removing all non-digit characters
+pub fn normalize(cei: &str) -> String {
+    cei.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a CEI number (XX.XXX.XXXXX/XX)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cei::format;
+///
+/// assert_eq!(format("112223333347"), "11.222.33333/47");
+/// assert_eq!(format("123"), "123");
+/// ```
+pub fn format(cei: &str) -> String {
+    let cleaned = normalize(cei);
+
+    if cleaned.len() == LEN {
+        format!(
+            "{}.{}.{}/{}",
+            &cleaned[..2],
+            &cleaned[2..5],
+            &cleaned[5..10],
+            &cleaned[10..]
+        )
+    } else {
+        cei.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_is_ignored() {
+        for cei in ["11.222.33333/47", "11 222 33333 47", "11222333334-7"] {
+            assert_eq!(validate(cei).as_deref(), Ok("112223333347"));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 12,
+                actual,
+            })
+        };
+        assert_eq!(validate("12345678901"), invalid_length(11));
+        assert_eq!(validate("1122233333470"), invalid_length(13));
+        // A CNPJ is not a CEI
+        assert_eq!(validate("11.222.333/0001-81"), invalid_length(14));
+    }
+
+    #[test]
+    fn test_repeated_digits_are_rejected() {
+        for digit in '0'..='9' {
+            assert_eq!(
+                validate(&digit.to_string().repeat(12)),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Cei
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_digit() {
+        for cei in ["112223333347", "123456789010", "512012345672"] {
+            assert_eq!(validate(cei).as_deref(), Ok(cei));
+        }
+        let bad_check_digit = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Cei,
+        });
+        assert_eq!(validate("123456789011"), bad_check_digit);
+        assert_eq!(validate("112223333348"), bad_check_digit);
+        // Two adjacent base digits swapped
+        assert_eq!(validate("121223333347"), bad_check_digit);
+    }
+}
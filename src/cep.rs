@@ -1,14 +1,40 @@
 //! CEP (Código de Endereçamento Postal) validation and formatting
 //!
 //! Brazilian postal code with 8 digits.
+//!
+//! With the `dataset` feature, `search_prefix` suggests the states and
+//! localities a partially typed CEP may belong to, without network access,
+//! `is_cep_geral` spots single-CEP municipalities and `get_subregion_name`
+//! names metropolitan areas and interior zones.
+//!
+//! # Dataset size
+//!
+//! The `dataset` tables (these, the IBGE municipality table and the CNAE
+//! divisions) are kept as text in `data/`, deflated by the build script and
+//! inflated on first use. Their compressed size must stay under 128 KiB in
+//! total, which a test enforces, so that enabling the feature does not
+//! bloat WASM bundles.
 
+use crate::document::DocumentKind;
 use crate::error::{BrazilianValidationError, ValidationResult};
-use regex::Regex;
-use std::sync::LazyLock;
+use crate::fixed::{self, Cleaned};
+use crate::uf::Uf;
+use crate::{ascii, config, denylist, hooks, shape, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "dataset")]
+mod ranges;
+
+#[cfg(feature = "dataset")]
+mod zones;
 
-/// Regex for CEP format (with or without hyphen)
-static CEP_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\d{5}-?\d{3}$").unwrap());
+#[cfg(feature = "dataset")]
+pub use ranges::{is_cep_geral, search_prefix, CepRange};
+
+#[cfg(feature = "dataset")]
+pub use zones::get_subregion_name;
 
 /// Validate a Brazilian CEP (postal code)
 ///
@@ -28,23 +54,55 @@ static CEP_REGEX: LazyLock<Regex> =
 /// assert!(validate("12345").is_err()); // Too short
 /// ```
 pub fn validate(cep: &str) -> ValidationResult<String> {
-    let cleaned = normalize(cep);
+    let result = validate_unobserved(cep)
+        .and_then(|cep| denylist::check(DocumentKind::Cep, &cep).map(|()| cep));
+    hooks::notify(DocumentKind::Cep, &result);
+    result
+}
 
-    // Check length
-    if cleaned.len() != 8 {
-        return Err(BrazilianValidationError::InvalidLength {
-            expected: 8,
-            actual: cleaned.len(),
+/// Validate without reporting to the validation hook (for internal lookups)
+pub(crate) fn validate_unobserved(cep: &str) -> ValidationResult<String> {
+    check(cep).map(|cleaned| cleaned.as_str().to_string())
+}
+
+/// Check whether a CEP is valid, without allocating
+///
+/// Gives the same answer as `validate(cep).is_ok()`, but works over the
+/// input bytes and never builds the normalized `String`, for bulk
+/// pipelines that only filter. Validation hooks are not notified.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep::is_valid;
+///
+/// assert!(is_valid("01310-100"));
+/// assert!(!is_valid("0131-010"));
+/// ```
+pub fn is_valid(cep: &str) -> bool {
+    check(cep).is_ok_and(|cleaned| denylist::check(DocumentKind::Cep, cleaned.as_str()).is_ok())
+}
+
+/// Validate into a stack buffer (the allocation-free core of [`validate`])
+fn check(cep: &str) -> ValidationResult<Cleaned<8>> {
+    let cep = &*unicode::normalize_if_enabled(cep);
+
+    // Strict mode only accepts the canonical formats
+    if config::Config::current().strict && !is_cep_format(cep) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cep,
         });
     }
 
-    // Ensure all characters are digits
-    if !cleaned.chars().all(|c| c.is_ascii_digit()) {
-        return Err(BrazilianValidationError::InvalidCharacters);
-    }
+    // Check length
+    let cleaned = Cleaned::<8>::new(cep, false)
+        .filter(|cleaned| cleaned.as_str().len() == 8)
+        .ok_or_else(|| BrazilianValidationError::InvalidLength {
+            expected: 8,
+            actual: cep.bytes().filter(u8::is_ascii_digit).count(),
+        })?;
 
     // Check for invalid CEPs (all zeros is invalid)
-    if cleaned == "00000000" {
+    if cleaned.as_str() == "00000000" {
         return Err(BrazilianValidationError::invalid_cep("CEP inválido"));
     }
 
@@ -89,6 +147,29 @@ pub fn format(cep: &str) -> String {
     }
 }
 
+/// Length of a CEP formatted as `XXXXX-XXX`
+pub const FORMATTED_LEN: usize = 9;
+
+/// Format a CEP into a caller-provided buffer, without allocating
+///
+/// Writes the same output as [`format`](format()) at the start of `buf` and returns
+/// it as a `&str`. A buffer of [`FORMATTED_LEN`] bytes always suffices.
+///
+/// # Returns
+/// `None` if the input does not have 8 digits or `buf` is too small.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep;
+///
+/// let mut buf = [0u8; cep::FORMATTED_LEN];
+/// assert_eq!(cep::format_into("01310100", &mut buf), Some("01310-100"));
+/// ```
+pub fn format_into<'a>(cep: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let cleaned = Cleaned::<8>::new(cep, false)?;
+    fixed::write_pattern(cleaned.as_str(), "#####-###", buf)
+}
+
 /// Check if a string matches CEP format (does not validate if CEP exists)
 ///
 /// # Examples
@@ -100,7 +181,7 @@ pub fn format(cep: &str) -> String {
 /// assert!(!is_cep_format("12345")); // 5 digits
 /// ```
 pub fn is_cep_format(cep: &str) -> bool {
-    CEP_REGEX.is_match(cep)
+    shape::is_cep(cep)
 }
 
 /// Extract the region code (first digit) from CEP
@@ -127,7 +208,7 @@ pub fn is_cep_format(cep: &str) -> bool {
 pub fn extract_region(cep: &str) -> Option<u8> {
     let cleaned = normalize(cep);
 
-    if cleaned.len() >= 1 {
+    if !cleaned.is_empty() {
         cleaned.chars().next()?.to_digit(10).map(|d| d as u8)
     } else {
         None
@@ -136,6 +217,10 @@ pub fn extract_region(cep: &str) -> Option<u8> {
 
 /// Get region name from CEP
 ///
+/// Names the postal region of the first digit. With the `dataset` feature,
+/// `get_subregion_name` gives finer-grained zones. Both are accent-free when
+/// [`ascii_names`](crate::config::ValidationOptions::ascii_names) is enabled.
+///
 /// # Examples
 /// ```
 /// use pleme_brazilian_validators::cep::get_region_name;
@@ -144,7 +229,7 @@ pub fn extract_region(cep: &str) -> Option<u8> {
 /// assert_eq!(get_region_name("20040-020"), Some("Rio de Janeiro e Espírito Santo"));
 /// ```
 pub fn get_region_name(cep: &str) -> Option<&'static str> {
-    extract_region(cep).map(|region| match region {
+    extract_region(cep).map(|region| ascii::name(match region {
         0 => "Grande São Paulo",
         1 => "Interior de São Paulo",
         2 => "Rio de Janeiro e Espírito Santo",
@@ -156,7 +241,68 @@ pub fn get_region_name(cep: &str) -> Option<&'static str> {
         8 => "Paraná e Santa Catarina",
         9 => "Rio Grande do Sul",
         _ => "Região desconhecida",
-    })
+    }))
+}
+
+/// CEP ranges of each federative unit, by 5-digit prefix (inclusive)
+const UF_RANGES: [(u32, u32, Uf); 30] = [
+    (1000, 19999, Uf::SP),
+    (20000, 28999, Uf::RJ),
+    (29000, 29999, Uf::ES),
+    (30000, 39999, Uf::MG),
+    (40000, 48999, Uf::BA),
+    (49000, 49999, Uf::SE),
+    (50000, 56999, Uf::PE),
+    (57000, 57999, Uf::AL),
+    (58000, 58999, Uf::PB),
+    (59000, 59999, Uf::RN),
+    (60000, 63999, Uf::CE),
+    (64000, 64999, Uf::PI),
+    (65000, 65999, Uf::MA),
+    (66000, 68899, Uf::PA),
+    (68900, 68999, Uf::AP),
+    (69000, 69299, Uf::AM),
+    (69300, 69399, Uf::RR),
+    (69400, 69899, Uf::AM),
+    (69900, 69999, Uf::AC),
+    (70000, 72799, Uf::DF),
+    (72800, 72999, Uf::GO),
+    (73000, 73699, Uf::DF),
+    (73700, 76799, Uf::GO),
+    (76800, 76999, Uf::RO),
+    (77000, 77999, Uf::TO),
+    (78000, 78899, Uf::MT),
+    (79000, 79999, Uf::MS),
+    (80000, 87999, Uf::PR),
+    (88000, 89999, Uf::SC),
+    (90000, 99999, Uf::RS),
+];
+
+/// Get the federative unit of a CEP
+///
+/// Typed counterpart of [`get_region_name`], from the Correios ranges of
+/// each state. The CEP must have 8 digits; it is not otherwise validated.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep::uf_for_cep;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(uf_for_cep("01310-100"), Some(Uf::SP));
+/// assert_eq!(uf_for_cep("69301-000"), Some(Uf::RR));
+/// assert_eq!(uf_for_cep("01310"), None);
+/// ```
+pub fn uf_for_cep(cep: &str) -> Option<Uf> {
+    let cleaned = normalize(cep);
+    if cleaned.len() != 8 {
+        return None;
+    }
+    let prefix: u32 = cleaned[..5].parse().ok()?;
+
+    UF_RANGES
+        .iter()
+        .find(|&&(first, last, _)| (first..=last).contains(&prefix))
+        .map(|&(_, _, uf)| uf)
 }
 
 /// Extract the sub-region code (first 2 digits) from CEP
@@ -195,10 +341,221 @@ pub fn extract_sector(cep: &str) -> Option<String> {
     }
 }
 
+/// Everything known about a valid CEP, see [`inspect`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct CepInfo {
+    /// 8 digits
+    pub normalized: String,
+    /// `XXXXX-XXX`
+    pub formatted: String,
+    /// `XXXXX-***`
+    pub masked: String,
+    /// Postal region (first digit)
+    pub region: u8,
+    /// Name of the postal region
+    pub region_name: &'static str,
+}
+
+/// Validate a CEP and derive its formatted, masked and region forms
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep;
+///
+/// let info = cep::inspect("20040020").unwrap();
+/// assert_eq!(info.formatted, "20040-020");
+/// assert_eq!(info.region_name, "Rio de Janeiro e Espírito Santo");
+/// ```
+pub fn inspect(cep: &str) -> ValidationResult<CepInfo> {
+    let normalized = validate(cep)?;
+
+    Ok(CepInfo {
+        formatted: format(&normalized),
+        masked: mask(&normalized),
+        region: extract_region(&normalized).unwrap_or_default(),
+        region_name: get_region_name(&normalized).unwrap_or_default(),
+        normalized,
+    })
+}
+
+/// Mask a CEP for display (shows only the sector, first 5 digits)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep::mask;
+///
+/// assert_eq!(mask("01310100"), "01310-***");
+/// ```
+pub fn mask(cep: &str) -> String {
+    let cleaned = normalize(cep);
+
+    if cleaned.len() == 8 {
+        format!("{}-***", &cleaned[0..5])
+    } else {
+        cep.to_string()
+    }
+}
+
+/// Validated CEP
+///
+/// Can only be built through [`validate`], so a `Cep` carried through a
+/// domain model never needs to be checked again. `Display` prints the
+/// formatted form; `AsRef<str>` and serde use the 8 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep::Cep;
+///
+/// let cep: Cep = "01310-100".parse().unwrap();
+/// assert_eq!(cep.digits(), "01310100");
+/// assert_eq!(cep.to_string(), "01310-100");
+/// assert_eq!(cep.masked(), "01310-***");
+///
+/// assert!("0131-010".parse::<Cep>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cep([u8; 8]);
+
+impl Cep {
+    /// Validate a CEP in any accepted format
+    pub fn parse(cep: &str) -> ValidationResult<Self> {
+        let normalized = validate(cep)?;
+        let digits = normalized.as_bytes().try_into().map_err(|_| {
+            BrazilianValidationError::InvalidLength {
+                expected: 8,
+                actual: normalized.len(),
+            }
+        })?;
+        Ok(Cep(digits))
+    }
+
+    /// The 8 digits
+    pub fn digits(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// `XXXXX-XXX`
+    pub fn formatted(&self) -> String {
+        format(self.digits())
+    }
+
+    /// `XXXXX-***`
+    pub fn masked(&self) -> String {
+        mask(self.digits())
+    }
+
+    /// Compare with another CEP in constant time
+    ///
+    /// Unlike `==`, the comparison of the digits does not short-circuit.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.0[..].ct_eq(&other.0[..]).into()
+    }
+}
+
+impl core::fmt::Debug for Cep {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Cep").field(&self.digits()).finish()
+    }
+}
+
+impl core::fmt::Display for Cep {
+    /// Formats as `XXXXX-XXX`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.formatted())
+    }
+}
+
+impl core::str::FromStr for Cep {
+    type Err = BrazilianValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Cep {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl AsRef<str> for Cep {
+    fn as_ref(&self) -> &str {
+        self.digits()
+    }
+}
+
+/// Wipes the stored digits, leaving a value that no longer holds a CEP
+///
+/// `Cep` is `Copy`, so it cannot wipe itself on drop: wrap it in
+/// [`Zeroizing`](zeroize::Zeroizing) for that.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Cep {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl crate::document::BrazilianDocument for Cep {
+    const KIND: DocumentKind = DocumentKind::Cep;
+
+    fn validate(input: &str) -> ValidationResult<Self> {
+        Cep::parse(input)
+    }
+
+    fn formatted(&self) -> String {
+        Cep::formatted(self)
+    }
+
+    fn normalized(&self) -> &str {
+        self.digits()
+    }
+
+    fn masked(&self) -> String {
+        Cep::masked(self)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Cep {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.digits())
+    }
+}
+
+/// Deserializing validates, accepting any format `validate` does; errors
+/// carry the error code and the localized message
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for Cep {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).map_err(BrazilianValidationError::into_de_error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "dataset")]
+    #[test]
+    fn test_dataset_size_budget() {
+        let size = ranges::embedded_size()
+            + zones::embedded_size()
+            + crate::ibge::embedded_size()
+            + crate::cnae::embedded_size();
+        assert!(size <= 128 * 1024, "{} bytes", size);
+    }
+
     #[test]
     fn test_validate_valid_cep() {
         assert!(validate("12345-678").is_ok());
@@ -216,6 +573,30 @@ mod tests {
         assert!(validate("00000000").is_err());
     }
 
+    #[test]
+    fn test_uf_for_cep() {
+        assert!(UF_RANGES.windows(2).all(|pair| pair[0].1 < pair[1].0));
+        for (cep, uf) in [
+            ("01001-000", Uf::SP),
+            ("20040-020", Uf::RJ),
+            ("70040-010", Uf::DF),
+            ("74003-010", Uf::GO),
+            ("69900-062", Uf::AC),
+            ("90010-150", Uf::RS),
+        ] {
+            assert_eq!(uf_for_cep(cep), Some(uf), "{}", cep);
+        }
+        assert_eq!(uf_for_cep("00999-999"), None);
+    }
+
+    #[test]
+    fn test_is_valid_matches_validate() {
+        let inputs = ["01310-100", "01310100", "00000-000", "0131-010", "013101000", ""];
+        for input in inputs {
+            assert_eq!(is_valid(input), validate(input).is_ok(), "{}", input);
+        }
+    }
+
     #[test]
     fn test_normalize() {
         assert_eq!(normalize("12345-678"), "12345678");
@@ -231,6 +612,16 @@ mod tests {
         assert_eq!(format("12345"), "12345");
     }
 
+    #[test]
+    fn test_format_into_matches_format() {
+        let mut buf = [0u8; FORMATTED_LEN];
+        for input in ["12345678", "12345-678"] {
+            assert_eq!(format_into(input, &mut buf), Some(format(input).as_str()));
+        }
+        assert_eq!(format_into("12345", &mut buf), None);
+        assert_eq!(format_into("12345678", &mut [0u8; 8]), None);
+    }
+
     #[test]
     fn test_is_cep_format() {
         assert!(is_cep_format("12345-678"));
@@ -259,4 +650,47 @@ mod tests {
     fn test_extract_sector() {
         assert_eq!(extract_sector("01310-100"), Some("01310".to_string()));
     }
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(mask("01310100"), "01310-***");
+        assert_eq!(mask("01310-100"), "01310-***");
+        assert_eq!(mask("123"), "123");
+    }
+
+    #[test]
+    fn test_cep_type() {
+        let cep = Cep::parse("01310100").unwrap();
+        assert_eq!(cep.formatted(), "01310-100");
+        assert_eq!(cep.as_ref(), "01310100");
+        assert!(Cep::try_from("00000-000").is_err());
+    }
+
+    #[test]
+    fn test_cep_type_ct_eq() {
+        let cep = Cep::parse("01310-100").unwrap();
+        assert!(cep.ct_eq(&Cep::parse("01310100").unwrap()));
+        assert!(!cep.ct_eq(&Cep::parse("20040-020").unwrap()));
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_cep_type_serde() {
+        let cep: Cep = serde_json::from_str(r#""01310-100""#).unwrap();
+        assert_eq!(serde_json::to_string(&cep).unwrap(), r#""01310100""#);
+        assert!(serde_json::from_str::<Cep>(r#""0131""#).is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_wipes_digits() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut value = Cep::parse("01310-100").unwrap();
+        value.zeroize();
+        assert!(value.digits().bytes().all(|b| b == 0));
+
+        let wiped_on_drop = Zeroizing::new(Cep::parse("01310-100").unwrap());
+        assert_eq!(wiped_on_drop.to_string(), "01310-100");
+    }
 }
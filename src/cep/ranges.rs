@@ -0,0 +1,234 @@
+//! Offline CEP range table for prefix search
+//!
+//! CEP ranges (faixas) allocated by Correios to each state and to the state
+//! capitals. Ranges are approximate at the edges and do not cover every
+//! municipality: they are meant for as-you-type suggestions, not for
+//! authoritative address resolution (use the `lookup` feature for that).
+//!
+//! Locality ranges are also what tells [`is_cep_geral`] apart from a
+//! street-level CEP.
+
+use super::{format, normalize, validate_unobserved};
+use crate::blob::{self, Table};
+use crate::uf::Uf;
+use alloc::vec::Vec;
+use core::fmt;
+use std::sync::LazyLock;
+
+/// CEP range allocated to a state or locality
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CepRange {
+    /// First CEP of the range (8 digits)
+    pub start: &'static str,
+    /// Last CEP of the range (8 digits)
+    pub end: &'static str,
+    /// Federative unit
+    pub state: Uf,
+    /// Municipality, for locality ranges; `None` for whole-state ranges
+    pub locality: Option<&'static str>,
+}
+
+impl CepRange {
+    /// Check whether a CEP falls within the range
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::cep;
+    ///
+    /// let range = cep::search_prefix("0131")[0];
+    /// assert!(range.contains("01310-100"));
+    /// assert!(!range.contains("20040-020"));
+    /// ```
+    pub fn contains(&self, cep: &str) -> bool {
+        let cep = normalize(cep);
+        cep.len() == 8 && self.start <= cep.as_str() && cep.as_str() <= self.end
+    }
+
+    /// Check whether any CEP starting with `prefix` falls within the range
+    ///
+    /// Equal-length digit strings compare like numbers, so comparing the
+    /// prefix against the truncated bounds is enough.
+    fn overlaps_prefix(&self, prefix: &str) -> bool {
+        let len = prefix.len();
+        &self.start[..len] <= prefix && prefix <= &self.end[..len]
+    }
+
+    /// Span of the range; narrower ranges are more specific
+    fn width(&self) -> u32 {
+        let start: u32 = self.start.parse().unwrap_or(0);
+        let end: u32 = self.end.parse().unwrap_or(0);
+        end.saturating_sub(start)
+    }
+}
+
+impl fmt::Display for CepRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} a {} (", format(self.start), format(self.end))?;
+        match self.locality {
+            Some(locality) => write!(f, "{}/{})", locality, self.state),
+            None => write!(f, "{})", self.state),
+        }
+    }
+}
+
+/// Ranges, one `start<TAB>end<TAB>UF[<TAB>locality]` record per line
+static TABLE: Table = blob::table!("cep_ranges.tsv");
+
+/// Known ranges, sorted by start
+static CEP_RANGES: LazyLock<Vec<CepRange>> = LazyLock::new(|| {
+    TABLE
+        .records()
+        .filter_map(|record| {
+            let (start, end, state, locality) = match record[..] {
+                [start, end, state] => (start, end, state, None),
+                [start, end, state, locality] => (start, end, state, Some(locality)),
+                _ => return None,
+            };
+            Some(CepRange {
+                start,
+                end,
+                state: state.parse().ok()?,
+                locality,
+            })
+        })
+        .collect()
+});
+
+/// Find the CEP ranges that CEPs starting with a prefix may belong to
+///
+/// Non-digit characters are ignored, so partially typed formatted CEPs
+/// (`"01310-1"`) work. Results are ordered most specific first (localities
+/// before their state). An empty prefix matches every range; a prefix
+/// longer than 8 digits matches none.
+///
+/// Enabled by the `dataset` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{cep, uf::Uf};
+///
+/// let suggestions = cep::search_prefix("0131");
+/// assert_eq!(suggestions[0].locality, Some("São Paulo"));
+/// assert_eq!(suggestions[1].state, Uf::SP);
+///
+/// let states: Vec<_> = cep::search_prefix("69").iter().map(|r| r.state).collect();
+/// assert!(states.contains(&Uf::AM) && states.contains(&Uf::RR) && states.contains(&Uf::AC));
+/// ```
+pub fn search_prefix(prefix: &str) -> Vec<&'static CepRange> {
+    let prefix = normalize(prefix);
+    if prefix.len() > 8 {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<&'static CepRange> = CEP_RANGES
+        .iter()
+        .filter(|range| range.overlaps_prefix(&prefix))
+        .collect();
+
+    matches.sort_by_key(|range| (range.width(), range.start));
+    matches
+}
+
+/// Check whether a CEP is the single "CEP geral" of a municipality
+///
+/// Municipalities without street-level codes share one CEP ending in
+/// `-000`, and address forms should not ask for a street-specific CEP
+/// there. A CEP is reported as CEP geral when it is valid, ends in `-000`
+/// and is outside every street-coded locality of the bundled table.
+///
+/// The table only knows the state capitals, so the `-000` codes of large
+/// non-capital cities with street-level CEPs (Campinas, Santos, ...) are
+/// reported as CEP geral too. Confirm with a `lookup` provider when that
+/// matters.
+///
+/// Enabled by the `dataset` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep;
+///
+/// assert!(cep::is_cep_geral("37890-000"));
+/// // Street-level codes of a capital, even when ending in -000
+/// assert!(!cep::is_cep_geral("01001-000"));
+/// assert!(!cep::is_cep_geral("37890-123"));
+/// ```
+pub fn is_cep_geral(cep: &str) -> bool {
+    let Ok(cep) = validate_unobserved(cep) else {
+        return false;
+    };
+
+    cep.ends_with("000")
+        && !CEP_RANGES
+            .iter()
+            .any(|range| range.locality.is_some() && range.contains(&cep))
+}
+
+/// Bytes embedded in the binary by the compressed table
+#[cfg(test)]
+pub(super) fn embedded_size() -> usize {
+    TABLE.compressed_len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_well_formed() {
+        for range in CEP_RANGES.iter() {
+            assert_eq!(range.start.len(), 8, "{}", range);
+            assert_eq!(range.end.len(), 8, "{}", range);
+            assert!(range.start <= range.end, "{}", range);
+        }
+        assert!(CEP_RANGES.windows(2).all(|w| w[0].start <= w[1].start));
+    }
+
+    #[test]
+    fn test_state_ranges_cover_every_cep() {
+        let states: Vec<_> = CEP_RANGES.iter().filter(|r| r.locality.is_none()).collect();
+        assert_eq!(states[0].start, "01000000");
+        assert_eq!(states.last().unwrap().end, "99999999");
+
+        for pair in states.windows(2) {
+            let end: u32 = pair[0].end.parse().unwrap();
+            let next: u32 = pair[1].start.parse().unwrap();
+            assert_eq!(end + 1, next, "gap between {} and {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_search_prefix_orders_by_specificity() {
+        let ranges = search_prefix("90");
+        assert_eq!(ranges[0].locality, Some("Porto Alegre"));
+        assert_eq!(ranges[1].state, Uf::RS);
+        assert_eq!(ranges.len(), 2);
+    }
+
+    #[test]
+    fn test_search_prefix_edge_cases() {
+        assert_eq!(search_prefix("").len(), CEP_RANGES.len());
+        assert_eq!(search_prefix("01310-100"), search_prefix("01310100"));
+        assert!(search_prefix("013101000").is_empty());
+        // CEPs never start with 00
+        assert!(search_prefix("00").is_empty());
+        assert_eq!(search_prefix("7").len(), 16);
+    }
+
+    #[test]
+    fn test_is_cep_geral() {
+        assert!(is_cep_geral("78890000"));
+        assert!(is_cep_geral("99990-000"));
+        for cep in ["70040-000", "90010-000", "20040-020", "12345-678", "123", ""] {
+            assert!(!is_cep_geral(cep), "{}", cep);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            search_prefix("0131")[0].to_string(),
+            "01000-000 a 05999-999 (São Paulo/SP)"
+        );
+        assert_eq!(search_prefix("29500")[0].to_string(), "29000-000 a 29999-999 (ES)");
+    }
+}
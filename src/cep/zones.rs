@@ -0,0 +1,97 @@
+//! Offline CEP sub-region table
+//!
+//! Metropolitan areas and interior zones keyed by the first three digits of
+//! the CEP, for logistics zone pricing. Like the range table, boundaries are
+//! approximate and only the largest metropolitan areas are split further
+//! than the state.
+
+use super::normalize;
+use crate::ascii;
+use crate::blob::{self, Table};
+use alloc::vec::Vec;
+use std::sync::LazyLock;
+
+/// Sub-region covering CEPs whose 3-digit prefix is in `first..=last`
+struct Subregion {
+    first: &'static str,
+    last: &'static str,
+    name: &'static str,
+}
+
+/// Sub-regions, one `first<TAB>last<TAB>name` record per line
+static TABLE: Table = blob::table!("cep_subregions.tsv");
+
+/// Known sub-regions, sorted and non-overlapping
+static SUBREGIONS: LazyLock<Vec<Subregion>> = LazyLock::new(|| {
+    TABLE
+        .records()
+        .filter_map(|record| match record[..] {
+            [first, last, name] => Some(Subregion { first, last, name }),
+            _ => None,
+        })
+        .collect()
+});
+
+/// Get the metropolitan area or interior zone of a CEP
+///
+/// Finer-grained counterpart of [`get_region_name`](super::get_region_name),
+/// keyed by the first 3 digits. Returns `None` for prefixes outside the
+/// table; fall back to `get_region_name` when any name will do.
+///
+/// Enabled by the `dataset` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cep;
+///
+/// assert_eq!(cep::get_subregion_name("01310-100"), Some("São Paulo Centro"));
+/// assert_eq!(cep::get_subregion_name("13083-970"), Some("Região de Campinas"));
+///
+/// let name = cep::get_subregion_name("69005-040").or_else(|| cep::get_region_name("69005-040"));
+/// assert_eq!(name, Some("Ceará, Piauí, Maranhão, Pará, Amazonas, Acre, Amapá e Roraima"));
+/// ```
+pub fn get_subregion_name(cep: &str) -> Option<&'static str> {
+    let cleaned = normalize(cep);
+    let prefix = cleaned.get(..3)?;
+
+    SUBREGIONS
+        .iter()
+        .find(|zone| zone.first <= prefix && prefix <= zone.last)
+        .map(|zone| ascii::name(zone.name))
+}
+
+/// Bytes embedded in the binary by the compressed table
+#[cfg(test)]
+pub(super) fn embedded_size() -> usize {
+    TABLE.compressed_len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted_and_disjoint() {
+        for zone in SUBREGIONS.iter() {
+            assert_eq!(zone.first.len(), 3, "{}", zone.name);
+            assert!(zone.first <= zone.last, "{}", zone.name);
+        }
+        assert!(SUBREGIONS.windows(2).all(|w| w[0].last < w[1].first));
+    }
+
+    #[test]
+    fn test_get_subregion_name() {
+        assert_eq!(
+            get_subregion_name("22041-001"),
+            Some("Rio de Janeiro Zona Sul")
+        );
+        assert_eq!(get_subregion_name("09015-000"), Some("Grande ABC"));
+        assert_eq!(
+            get_subregion_name("08210-000"),
+            Some("São Paulo Zona Leste")
+        );
+        assert_eq!(get_subregion_name("016"), None);
+        assert_eq!(get_subregion_name("01"), None);
+        assert_eq!(get_subregion_name(""), None);
+    }
+}
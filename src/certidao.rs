@@ -0,0 +1,322 @@
+//! Certidão (civil registry certificate) matrícula validation
+//!
+//! Since 2010, birth, marriage and death certificates carry a 32-digit
+//! national matrícula identifying the registry entry, ending in two
+//! modulo 11 check digits:
+//!
+//! ```text
+//! 104539 01 55 2013 1 00012 021 0012345 14
+//! │      │  │  │    │ │     │   │       └ check digits
+//! │      │  │  │    │ │     │   └ term (termo)
+//! │      │  │  │    │ │     └ page (folha)
+//! │      │  │  │    │ └ book number (livro)
+//! │      │  │  │    └ certificate type (book type)
+//! │      │  │  └ year of registration
+//! │      │  └ service (55: civil registry of natural persons)
+//! │      └ collection (acervo): 01 own, 02 incorporated
+//! └ cartório CNS code
+//! ```
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::certidao::{self, CertidaoKind};
+//!
+//! let certidao = certidao::parse("104539 01 55 2013 1 00012 021 0012345 14").unwrap();
+//! assert_eq!(certidao.cartorio, "104539");
+//! assert_eq!(certidao.kind, CertidaoKind::Nascimento);
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Number of digits of a matrícula
+pub const LEN: usize = 32;
+
+/// Certificate type, from the book the entry was registered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CertidaoKind {
+    /// Birth, book A (1)
+    Nascimento,
+    /// Civil marriage, book B (2)
+    Casamento,
+    /// Religious marriage with civil effect, book B auxiliar (3)
+    CasamentoReligioso,
+    /// Death, book C (4)
+    Obito,
+    /// Stillbirth, book C auxiliar (5)
+    Natimorto,
+    /// Marriage banns, book D (6)
+    Proclamas,
+    /// Other acts (emancipation, interdiction, ...), book E (7)
+    LivroE,
+}
+
+impl CertidaoKind {
+    /// Type code as written in the matrícula
+    pub fn code(&self) -> u8 {
+        match self {
+            CertidaoKind::Nascimento => 1,
+            CertidaoKind::Casamento => 2,
+            CertidaoKind::CasamentoReligioso => 3,
+            CertidaoKind::Obito => 4,
+            CertidaoKind::Natimorto => 5,
+            CertidaoKind::Proclamas => 6,
+            CertidaoKind::LivroE => 7,
+        }
+    }
+
+    /// Type for a code
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(CertidaoKind::Nascimento),
+            2 => Some(CertidaoKind::Casamento),
+            3 => Some(CertidaoKind::CasamentoReligioso),
+            4 => Some(CertidaoKind::Obito),
+            5 => Some(CertidaoKind::Natimorto),
+            6 => Some(CertidaoKind::Proclamas),
+            7 => Some(CertidaoKind::LivroE),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CertidaoKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertidaoKind::Nascimento => write!(f, "Nascimento"),
+            CertidaoKind::Casamento => write!(f, "Casamento"),
+            CertidaoKind::CasamentoReligioso => write!(f, "Casamento religioso com efeito civil"),
+            CertidaoKind::Obito => write!(f, "Óbito"),
+            CertidaoKind::Natimorto => write!(f, "Natimorto"),
+            CertidaoKind::Proclamas => write!(f, "Proclamas"),
+            CertidaoKind::LivroE => write!(f, "Livro E"),
+        }
+    }
+}
+
+/// Fields of a validated matrícula
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Certidao {
+    /// Normalized matrícula (32 digits)
+    pub matricula: String,
+    /// CNS code of the cartório (6 digits)
+    pub cartorio: String,
+    /// Collection (acervo): `1` own, `2` incorporated from another cartório
+    pub acervo: u8,
+    /// Service code (`55` for the civil registry of natural persons)
+    pub service: u8,
+    /// Year of registration
+    pub year: u16,
+    /// Certificate type
+    pub kind: CertidaoKind,
+    /// Book number (livro)
+    pub book: u32,
+    /// Page (folha)
+    pub page: u16,
+    /// Term (termo)
+    pub term: u32,
+}
+
+/// Validate a certidão matrícula
+///
+/// # Arguments
+/// * `matricula` - 32-digit matrícula (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized matrícula (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::certidao::validate;
+///
+/// assert!(validate("10453901552013100012021001234514").is_ok());
+/// assert!(validate("10453901552013100012021001234515").is_err());
+/// ```
+pub fn validate(matricula: &str) -> ValidationResult<String> {
+    let result = parse_unobserved(matricula).map(|parsed| parsed.matricula);
+    hooks::notify(DocumentKind::Certidao, &result);
+    result
+}
+
+/// Validate a matrícula and decompose its fields
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::certidao::{parse, CertidaoKind};
+///
+/// let certidao = parse("122044 01 55 2020 4 00045 012 0023456 02").unwrap();
+/// assert_eq!(certidao.year, 2020);
+/// assert_eq!(certidao.kind, CertidaoKind::Obito);
+/// assert_eq!((certidao.book, certidao.page, certidao.term), (45, 12, 23456));
+/// ```
+pub fn parse(matricula: &str) -> ValidationResult<Certidao> {
+    let result = parse_unobserved(matricula);
+    hooks::notify(DocumentKind::Certidao, &result);
+    result
+}
+
+/// Parse without reporting to the validation hook
+fn parse_unobserved(matricula: &str) -> ValidationResult<Certidao> {
+    let cleaned = normalize(matricula);
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    let check1 = compute_check_digit(&digits[..30]);
+    let check2 = compute_check_digit(&digits[..31]);
+    if [check1, check2] != [digits[30], digits[31]] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Certidao,
+        });
+    }
+
+    // Slices of a normalized matrícula are ASCII digits, so they always parse
+    let field = |range: core::ops::Range<usize>| cleaned[range].parse::<u32>().unwrap_or_default();
+
+    let kind = CertidaoKind::from_code(field(14..15) as u8).ok_or(
+        BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Certidao,
+        },
+    )?;
+
+    Ok(Certidao {
+        cartorio: cleaned[..6].to_string(),
+        acervo: field(6..8) as u8,
+        service: field(8..10) as u8,
+        year: field(10..14) as u16,
+        kind,
+        book: field(15..20),
+        page: field(20..23) as u16,
+        term: field(23..30),
+        matricula: cleaned,
+    })
+}
+
+/// Compute a check digit of the matrícula
+///
+/// Weights grow by one from the left, wrapping from 10 back to 0, and
+/// start at 2 for the first check digit (30 digits) and at 1 for the
+/// second (31 digits). The check digit is the remainder by 11, 10
+/// becoming 1.
+fn compute_check_digit(digits: &[u32]) -> u32 {
+    let first_weight = LEN - digits.len();
+    let sum: u32 = digits
+        .iter()
+        .zip((0..11).cycle().skip(first_weight))
+        .map(|(d, w)| d * w)
+        .sum();
+    match sum % 11 {
+        10 => 1,
+        remainder => remainder,
+    }
+}
+
+/// Normalize a matrícula by removing non-digit characters
+pub fn normalize(matricula: &str) -> String {
+    matricula.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a matrícula in groups, as printed on certificates
+///
+/// Returns input unchanged if it does not have 32 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::certidao::format;
+///
+/// assert_eq!(
+///     format("10453901552013100012021001234514"),
+///     "104539 01 55 2013 1 00012 021 0012345 14"
+/// );
+/// ```
+pub fn format(matricula: &str) -> String {
+    let cleaned = normalize(matricula);
+    if cleaned.len() != LEN {
+        return matricula.to_string();
+    }
+
+    format!(
+        "{} {} {} {} {} {} {} {} {}",
+        &cleaned[..6],
+        &cleaned[6..8],
+        &cleaned[8..10],
+        &cleaned[10..14],
+        &cleaned[14..15],
+        &cleaned[15..20],
+        &cleaned[20..23],
+        &cleaned[23..30],
+        &cleaned[30..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let certidao = parse("10453901552013100012021001234514").unwrap();
+        assert_eq!(certidao.cartorio, "104539");
+        assert_eq!(certidao.acervo, 1);
+        assert_eq!(certidao.service, 55);
+        assert_eq!(certidao.year, 2013);
+        assert_eq!(certidao.kind, CertidaoKind::Nascimento);
+        assert_eq!(certidao.book, 12);
+        assert_eq!(certidao.page, 21);
+        assert_eq!(certidao.term, 12345);
+    }
+
+    #[test]
+    fn test_check_digit_ten_becomes_one() {
+        let digits: Vec<u32> = "608377835337406812415868344978"
+            .bytes()
+            .map(|b| u32::from(b - b'0'))
+            .collect();
+        assert_eq!(compute_check_digit(&digits), 1);
+        let certidao = parse("60837783533740681241586834497813").unwrap();
+        assert_eq!(certidao.kind, CertidaoKind::Proclamas);
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        // Book type 8 does not exist
+        assert_eq!(
+            validate("00000001551999800001001000000141"),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::Certidao
+            })
+        );
+        assert_eq!(
+            validate("10453901552013100012021001234504"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Certidao
+            })
+        );
+        assert_eq!(
+            validate("1045390155201310001202100123451"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 32,
+                actual: 31
+            })
+        );
+    }
+
+    #[test]
+    fn test_kind_codes_round_trip() {
+        for code in 1..=7 {
+            assert_eq!(CertidaoKind::from_code(code).map(|k| k.code()), Some(code));
+        }
+        assert_eq!(CertidaoKind::from_code(0), None);
+    }
+}
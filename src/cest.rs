@@ -0,0 +1,163 @@
+//! CEST (Código Especificador da Substituição Tributária) validation
+//!
+//! The CEST identifies goods subject to ICMS tax substitution in NF-e
+//! product lines, alongside their NCM ([`crate::ncm`]). It has 7 digits,
+//! written `XX.XXX.XX`: the segment (01 to 28, per Convênio ICMS
+//! 142/2018), the item within the segment and the specification of the
+//! item.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::cest;
+//!
+//! assert_eq!(cest::validate("03.021.00").as_deref(), Ok("0302100"));
+//! assert_eq!(cest::format("0302100"), "03.021.00");
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Number of digits of a CEST code
+pub const LEN: usize = 7;
+
+/// Highest segment of the Convênio ICMS 142/2018 annexes
+const LAST_SEGMENT: u8 = 28;
+
+/// Validate a CEST code
+///
+/// # Arguments
+/// * `cest` - 7-digit code (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized code (7 digits)
+/// * `Err(BrazilianValidationError)` - Wrong length, nonexistent segment or
+///   zero item
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cest::validate;
+///
+/// assert!(validate("01.001.00").is_ok());
+/// assert!(validate("29.001.00").is_err()); // Segment 29 does not exist
+/// ```
+pub fn validate(cest: &str) -> ValidationResult<String> {
+    let result = check(cest);
+    hooks::notify(DocumentKind::Cest, &result);
+    result
+}
+
+fn check(cest: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(cest));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    // Items are numbered from 001 within each segment
+    if segment(&cleaned).is_none() || &cleaned[2..5] == "000" {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cest,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Segment of a CEST code (its first two digits), if it exists
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cest::segment;
+///
+/// assert_eq!(segment("28.060.00"), Some(28));
+/// assert_eq!(segment("00.001.00"), None);
+/// ```
+pub fn segment(cest: &str) -> Option<u8> {
+    let segment: u8 = normalize(cest).get(..2)?.parse().ok()?;
+    (1..=LAST_SEGMENT).contains(&segment).then_some(segment)
+}
+
+/// Normalize a CEST code by removing all non-digit characters
+pub fn normalize(cest: &str) -> String {
+    cest.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a CEST code (XX.XXX.XX)
+///
+/// Returns input unchanged if it does not have 7 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cest::format;
+///
+/// assert_eq!(format("2106400"), "21.064.00");
+/// assert_eq!(format("21064"), "21064");
+/// ```
+pub fn format(cest: &str) -> String {
+    let cleaned = normalize(cest);
+
+    if cleaned.len() == LEN {
+        format!("{}.{}.{}", &cleaned[..2], &cleaned[2..5], &cleaned[5..])
+    } else {
+        cest.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_is_ignored() {
+        for cest in ["03.021.00", "03 021 00", "03-021-00", "0302100"] {
+            assert_eq!(validate(cest).as_deref(), Ok("0302100"));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 7,
+                actual,
+            })
+        };
+        assert_eq!(validate("03.021"), invalid_length(5));
+        assert_eq!(validate("03.021.0"), invalid_length(6));
+        assert_eq!(validate("03.021.000"), invalid_length(8));
+        // An NCM is not a CEST
+        assert_eq!(validate("8471.30.12"), invalid_length(8));
+    }
+
+    #[test]
+    fn test_segment_and_item() {
+        for cest in ["0100100", "0302100", "1700100", "2106400", "2806000"] {
+            assert_eq!(validate(cest).as_deref(), Ok(cest));
+        }
+        // Segments 01 to 28, items from 001
+        for cest in ["0000100", "2900100", "9900100", "0300000", "0300099"] {
+            assert_eq!(
+                validate(cest),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Cest
+                }),
+                "{}",
+                cest
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeated_digits() {
+        // Without a check digit, only the segment and item can rule them out
+        assert_eq!(validate("1111111").as_deref(), Ok("1111111"));
+        assert!(validate("0000000").is_err());
+        assert!(validate("3333333").is_err());
+    }
+}
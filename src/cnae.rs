@@ -0,0 +1,315 @@
+//! CNAE (Classificação Nacional de Atividades Econômicas) validation
+//!
+//! CNAE subclasses classify the activities of companies in the CNPJ
+//! registry, NFS-e and tax regime eligibility (Simples Nacional, MEI).
+//! A subclass has 7 digits, written `XXXX-X/XX`:
+//!
+//! ```text
+//! 62 0 1 - 5 / 01
+//! │  │ │   │   └ subclass
+//! │  │ │   └ check digit of the class
+//! │  │ └ class
+//! │  └ group
+//! └ division, which determines the section (A to U)
+//! ```
+//!
+//! With the `dataset` feature, `division_name` returns the description of
+//! a division without network access.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::cnae;
+//!
+//! assert_eq!(cnae::validate("6201-5/01").as_deref(), Ok("6201501"));
+//! assert_eq!(cnae::section("6201501").map(|s| s.letter), Some('J'));
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "dataset")]
+mod divisions;
+
+#[cfg(feature = "dataset")]
+pub use divisions::division_name;
+
+/// Number of digits of a CNAE subclass
+pub const LEN: usize = 7;
+
+/// Section of the classification, grouping divisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Section {
+    /// Section letter (`A` to `U`)
+    pub letter: char,
+    /// Official description
+    pub name: &'static str,
+}
+
+/// Sections with the first and last of their divisions
+///
+/// Divisions are numbered contiguously within a section; numbers between
+/// sections (04, 34, 40, ...) are unassigned.
+const SECTIONS: [(Section, u8, u8); 21] = [
+    entry(
+        'A',
+        1,
+        3,
+        "Agricultura, pecuária, produção florestal, pesca e aquicultura",
+    ),
+    entry('B', 5, 9, "Indústrias extrativas"),
+    entry('C', 10, 33, "Indústrias de transformação"),
+    entry('D', 35, 35, "Eletricidade e gás"),
+    entry(
+        'E',
+        36,
+        39,
+        "Água, esgoto, atividades de gestão de resíduos e descontaminação",
+    ),
+    entry('F', 41, 43, "Construção"),
+    entry(
+        'G',
+        45,
+        47,
+        "Comércio; reparação de veículos automotores e motocicletas",
+    ),
+    entry('H', 49, 53, "Transporte, armazenagem e correio"),
+    entry('I', 55, 56, "Alojamento e alimentação"),
+    entry('J', 58, 63, "Informação e comunicação"),
+    entry(
+        'K',
+        64,
+        66,
+        "Atividades financeiras, de seguros e serviços relacionados",
+    ),
+    entry('L', 68, 68, "Atividades imobiliárias"),
+    entry(
+        'M',
+        69,
+        75,
+        "Atividades profissionais, científicas e técnicas",
+    ),
+    entry(
+        'N',
+        77,
+        82,
+        "Atividades administrativas e serviços complementares",
+    ),
+    entry(
+        'O',
+        84,
+        84,
+        "Administração pública, defesa e seguridade social",
+    ),
+    entry('P', 85, 85, "Educação"),
+    entry('Q', 86, 88, "Saúde humana e serviços sociais"),
+    entry('R', 90, 93, "Artes, cultura, esporte e recreação"),
+    entry('S', 94, 96, "Outras atividades de serviços"),
+    entry('T', 97, 97, "Serviços domésticos"),
+    entry(
+        'U',
+        99,
+        99,
+        "Organismos internacionais e outras instituições extraterritoriais",
+    ),
+];
+
+/// Shorthand for a table entry
+const fn entry(letter: char, first: u8, last: u8, name: &'static str) -> (Section, u8, u8) {
+    (Section { letter, name }, first, last)
+}
+
+/// Validate a CNAE subclass
+///
+/// # Arguments
+/// * `code` - 7-digit subclass (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized subclass (7 digits)
+/// * `Err(BrazilianValidationError)` - Wrong length, unassigned division or
+///   check digit
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnae::validate;
+///
+/// assert!(validate("4711-3/02").is_ok());
+/// assert!(validate("4711-4/02").is_err()); // Invalid check digit
+/// assert!(validate("4011-1/00").is_err()); // Division 40 does not exist
+/// ```
+pub fn validate(code: &str) -> ValidationResult<String> {
+    let result = check(code);
+    hooks::notify(DocumentKind::Cnae, &result);
+    result
+}
+
+fn check(code: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(code));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if section(&cleaned).is_none() {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cnae,
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    if compute_check_digit(&digits[..4]) != digits[4] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Cnae,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute the check digit of the 4-digit class
+///
+/// The class digits are weighted 6 to 9; the check digit is the units
+/// digit of the remainder by 11, plus one.
+fn compute_check_digit(class: &[u32]) -> u32 {
+    let sum: u32 = class.iter().zip(6..).map(|(d, w)| d * w).sum();
+    (sum % 11 + 1) % 10
+}
+
+/// Bytes embedded in the binary by the division table
+#[cfg(all(test, feature = "dataset"))]
+pub(crate) fn embedded_size() -> usize {
+    divisions::embedded_size()
+}
+
+/// Section of a CNAE code, from its first two digits
+///
+/// Accepts a subclass, class, group or bare division; the check digit is
+/// not verified.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnae::section;
+///
+/// let saude = section("8630-5/03").unwrap();
+/// assert_eq!(saude.letter, 'Q');
+/// assert_eq!(saude.name, "Saúde humana e serviços sociais");
+/// assert_eq!(section("40"), None);
+/// ```
+pub fn section(code: &str) -> Option<Section> {
+    let division: u8 = normalize(code).get(..2)?.parse().ok()?;
+    SECTIONS
+        .iter()
+        .find(|(_, first, last)| (*first..=*last).contains(&division))
+        .map(|(section, _, _)| *section)
+}
+
+/// Normalize a CNAE code by removing all non-digit characters
+pub fn normalize(code: &str) -> String {
+    code.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a CNAE subclass (XXXX-X/XX)
+///
+/// Returns input unchanged if it does not have 7 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnae::format;
+///
+/// assert_eq!(format("6201501"), "6201-5/01");
+/// assert_eq!(format("6201"), "6201");
+/// ```
+pub fn format(code: &str) -> String {
+    let cleaned = normalize(code);
+
+    if cleaned.len() == LEN {
+        format!("{}-{}/{}", &cleaned[..4], &cleaned[4..5], &cleaned[5..])
+    } else {
+        code.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_subclasses() {
+        for code in [
+            "0111301", "0151201", "1091102", "4120400", "4520001", "4711302", "4721102", "4930202",
+            "5611201", "5620104", "6201501", "6204000", "6209100", "6462000", "7319002", "8211300",
+            "8630503", "9602501", "9700500",
+        ] {
+            assert_eq!(validate(code).as_deref(), Ok(code));
+        }
+    }
+
+    #[test]
+    fn test_punctuation_is_ignored() {
+        for code in ["6201-5/01", "6201 5 01", "62.01-5-01", "6201501"] {
+            assert_eq!(validate(code).as_deref(), Ok("6201501"));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 7,
+                actual,
+            })
+        };
+        assert_eq!(validate("620150"), invalid_length(6));
+        assert_eq!(validate("62015010"), invalid_length(8));
+        // A class with its check digit, but no subclass
+        assert_eq!(validate("6201-5"), invalid_length(5));
+    }
+
+    #[test]
+    fn test_unassigned_divisions() {
+        for code in ["0000000", "0411100", "3411100", "4011100", "9811100"] {
+            assert_eq!(
+                validate(code),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Cnae
+                }),
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_class_check_digit() {
+        let bad_check_digit = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Cnae,
+        });
+        for code in ["6201601", "6201401", "6210501", "1111111", "2222222"] {
+            assert_eq!(validate(code), bad_check_digit, "{}", code);
+        }
+        // A remainder of 9 wraps to 0
+        assert_eq!(compute_check_digit(&[6, 2, 0, 4]), 0);
+        assert_eq!(validate("6204000").as_deref(), Ok("6204000"));
+    }
+
+    #[test]
+    fn test_sections_cover_divisions_in_order() {
+        let letters: String = SECTIONS
+            .iter()
+            .map(|(section, _, _)| section.letter)
+            .collect();
+        assert_eq!(letters, "ABCDEFGHIJKLMNOPQRSTU");
+        assert!(SECTIONS.windows(2).all(|pair| pair[0].2 < pair[1].1));
+
+        let assigned = (0..100)
+            .filter(|division| section(&format!("{:02}", division)).is_some())
+            .count();
+        assert_eq!(assigned, 87);
+    }
+}
@@ -0,0 +1,63 @@
+//! Offline CNAE division descriptions
+//!
+//! Official descriptions of the 87 divisions, for display next to a
+//! company's activities, from `data/cnae_divisions.tsv`. The table is
+//! embedded compressed and inflated on the first lookup.
+
+use super::normalize;
+use crate::blob::{self, Table};
+
+/// Division descriptions, one `NN<TAB>description` record per line
+static DIVISIONS: Table = blob::table!("cnae_divisions.tsv");
+
+/// Description of the division of a CNAE code
+///
+/// Accepts a subclass, class, group or bare division (first two digits);
+/// the check digit is not verified.
+///
+/// Enabled by the `dataset` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnae;
+///
+/// assert_eq!(
+///     cnae::division_name("6201-5/01"),
+///     Some("Atividades dos serviços de tecnologia da informação")
+/// );
+/// assert_eq!(cnae::division_name("47"), Some("Comércio varejista"));
+/// assert_eq!(cnae::division_name("40"), None);
+/// ```
+pub fn division_name(code: &str) -> Option<&'static str> {
+    let cleaned = normalize(code);
+    let division = cleaned.get(..2)?;
+    DIVISIONS
+        .text()
+        .lines()
+        .find_map(|line| line.strip_prefix(division)?.strip_prefix('\t'))
+}
+
+/// Bytes embedded in the binary by the compressed table
+#[cfg(test)]
+pub(super) fn embedded_size() -> usize {
+    DIVISIONS.compressed_len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cnae::section;
+
+    #[test]
+    fn test_every_assigned_division_has_a_name() {
+        for division in 0..100 {
+            let code = format!("{:02}", division);
+            assert_eq!(
+                division_name(&code).is_some(),
+                section(&code).is_some(),
+                "division {}",
+                code
+            );
+        }
+    }
+}
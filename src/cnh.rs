@@ -0,0 +1,204 @@
+//! CNH (Carteira Nacional de Habilitação) validation and formatting
+//!
+//! The número de registro of a Brazilian driver's license has 11 digits:
+//! 9 base digits and two modulo 11 check digits. When the first check
+//! digit overflows to 0, the second is shifted down by 2 (the DSC rule of
+//! the DENATRAN algorithm).
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of digits of a CNH registration number
+pub const LEN: usize = 11;
+
+/// Validate a CNH registration number
+///
+/// Validates length, check digits, and rejects sequences of a repeated
+/// digit.
+///
+/// # Arguments
+/// * `cnh` - CNH number (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized CNH (11 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnh::validate;
+///
+/// assert!(validate("02650306461").is_ok());
+/// assert!(validate("026503064-61").is_ok());
+/// assert!(validate("02650306462").is_err()); // Invalid check digits
+/// assert!(validate("11111111111").is_err()); // All same digits
+/// ```
+pub fn validate(cnh: &str) -> ValidationResult<String> {
+    let result = check(cnh);
+    hooks::notify(DocumentKind::Cnh, &result);
+    result
+}
+
+fn check(cnh: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(cnh));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if cleaned.bytes().all(|b| b == cleaned.as_bytes()[0]) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cnh,
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    if compute_check_digits(&digits[..9]) != [digits[9], digits[10]] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Cnh,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute both CNH check digits for the 9 base digits
+pub(crate) fn compute_check_digits(base: &[u32]) -> [u32; 2] {
+    // First digit: weights 9 down to 1; a remainder of 10 becomes 0 and
+    // shifts the second digit (DSC)
+    let sum: u32 = base.iter().zip((1..=9).rev()).map(|(d, w)| d * w).sum();
+    let (check1, dsc) = match sum % 11 {
+        remainder @ 0..=9 => (remainder, 0),
+        _ => (0, 2),
+    };
+
+    // Second digit: weights 1 up to 9, minus the shift
+    let sum: u32 = base.iter().zip(1..=9).map(|(d, w)| d * w).sum();
+    let check2 = match (sum % 11 + 11 - dsc) % 11 {
+        remainder @ 0..=9 => remainder,
+        _ => 0,
+    };
+
+    [check1, check2]
+}
+
+/// Normalize a CNH number by removing all non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnh::normalize;
+///
+/// assert_eq!(normalize("026503064-61"), "02650306461");
+/// ```
+pub fn normalize(cnh: &str) -> String {
+    cnh.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a CNH number with its check digits apart (XXXXXXXXX-XX)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnh::format;
+///
+/// assert_eq!(format("02650306461"), "026503064-61");
+/// assert_eq!(format("123"), "123");
+/// ```
+pub fn format(cnh: &str) -> String {
+    let cleaned = normalize(cnh);
+
+    if cleaned.len() == LEN {
+        format!("{}-{}", &cleaned[..9], &cleaned[9..])
+    } else {
+        cnh.to_string()
+    }
+}
+
+/// Mask a CNH number for display (shows the last 4 digits)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnh::mask;
+///
+/// assert_eq!(mask("02650306461"), "*******64-61");
+/// ```
+pub fn mask(cnh: &str) -> String {
+    let cleaned = normalize(cnh);
+
+    if cleaned.len() == LEN {
+        format!("*******{}-{}", &cleaned[7..9], &cleaned[9..])
+    } else {
+        cnh.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_and_spacing_are_ignored() {
+        for cnh in ["026.503.064-61", "026503064 61", " 02650306461\n", "0265 0306 461"] {
+            assert_eq!(validate(cnh).as_deref(), Ok("02650306461"), "{cnh:?}");
+        }
+    }
+
+    #[test]
+    fn test_dsc_shift() {
+        // First remainder is 10: check digit 0, second shifted from 10 to 8
+        assert_eq!(compute_check_digits(&[1, 0, 0, 0, 0, 0, 0, 0, 1]), [0, 8]);
+        assert!(validate("10000000108").is_ok());
+        assert!(validate("10000000100").is_err());
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        // A leading zero dropped by a spreadsheet, and one digit too many
+        for (cnh, actual) in [("2650306461", 10), ("026503064610", 12), ("", 0)] {
+            assert_eq!(
+                validate(cnh),
+                Err(BrazilianValidationError::InvalidLength {
+                    expected: 11,
+                    actual
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeated_digits_are_rejected() {
+        // Some of them have matching check digits, e.g. 11111111111
+        for digit in '0'..='9' {
+            assert_eq!(
+                validate(&digit.to_string().repeat(11)),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Cnh
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_each_check_digit_is_verified() {
+        // First check digit off, second off, and both swapped
+        for cnh in ["02650306471", "02650306462", "02650306416"] {
+            assert_eq!(
+                validate(cnh),
+                Err(BrazilianValidationError::InvalidCheckDigits {
+                    document_type: DocumentKind::Cnh
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_and_mask_passthrough() {
+        assert_eq!(format("026503064-61"), "026503064-61");
+        assert_eq!(mask("abc"), "abc");
+    }
+}
@@ -0,0 +1,71 @@
+//! CNO (Cadastro Nacional de Obras) validation and formatting
+//!
+//! The CNO registers construction works for social security purposes,
+//! replacing the CEI of works since 2019. Works migrated from the CEI
+//! kept their number, so CNO numbers share the CEI layout and check digit
+//! ([`crate::cei`]).
+
+use crate::cei;
+use crate::document::DocumentKind;
+use crate::error::ValidationResult;
+use crate::hooks;
+use alloc::string::String;
+
+/// Number of digits of a CNO
+pub const LEN: usize = cei::LEN;
+
+/// Validate a CNO number
+///
+/// # Arguments
+/// * `cno` - CNO number (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized CNO (12 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cno::validate;
+///
+/// assert!(validate("90.010.01234/55").is_ok());
+/// assert!(validate("90.010.01234/56").is_err()); // Invalid check digit
+/// ```
+pub fn validate(cno: &str) -> ValidationResult<String> {
+    let result = cei::validate_digits(cno, DocumentKind::Cno);
+    hooks::notify(DocumentKind::Cno, &result);
+    result
+}
+
+/// Normalize a CNO number by removing all non-digit characters
+pub fn normalize(cno: &str) -> String {
+    cei::normalize(cno)
+}
+
+/// Format a CNO number (XX.XXX.XXXXX/XX)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cno::format;
+///
+/// assert_eq!(format("900100123455"), "90.010.01234/55");
+/// ```
+pub fn format(cno: &str) -> String {
+    cei::format(cno)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BrazilianValidationError;
+
+    #[test]
+    fn test_errors_name_the_cno() {
+        assert_eq!(validate("900100123455").as_deref(), Ok("900100123455"));
+        assert_eq!(
+            validate("900100123456"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Cno
+            })
+        );
+    }
+}
@@ -1,15 +1,35 @@
 //! CNPJ (Cadastro Nacional da Pessoa Jurídica) validation and formatting
 //!
-//! Brazilian business taxpayer identification number with 14 digits
+//! Brazilian business taxpayer identification number with 14 characters
 //! and two check digits calculated using weighted modulo 11.
+//!
+//! From July 2026 the Receita Federal also issues alphanumeric CNPJs: the
+//! 8-character root and the 4-character branch may contain uppercase
+//! letters, while the check digits stay numeric. Every function in this
+//! module accepts both formats; letters count as their ASCII code minus 48
+//! in the check-digit sums, so numeric CNPJs keep their digits.
 
+use crate::document::DocumentKind;
 use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::fixed::{self, Cleaned};
+use crate::masking::MaskOptions;
+use crate::{config, denylist, dv, hooks, shape, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "std")]
+use crate::patterns;
+#[cfg(feature = "std")]
 use regex::Regex;
+#[cfg(feature = "std")]
 use std::sync::LazyLock;
 
 /// Regex for CNPJ format (with or without punctuation)
+#[cfg(feature = "std")]
 pub static CNPJ_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\d{2}\.?\d{3}\.?\d{3}/?\d{4}-?\d{2}$").unwrap());
+    LazyLock::new(|| Regex::new(patterns::CNPJ).unwrap());
 
 /// Known invalid CNPJs (all same digits)
 const INVALID_CNPJS: [&str; 10] = [
@@ -33,7 +53,7 @@ const INVALID_CNPJS: [&str; 10] = [
 /// * `cnpj` - CNPJ string (with or without punctuation)
 ///
 /// # Returns
-/// * `Ok(String)` - Normalized CNPJ (14 digits only)
+/// * `Ok(String)` - Normalized CNPJ (14 characters, letters uppercased)
 /// * `Err(BrazilianValidationError)` - Validation error
 ///
 /// # Examples
@@ -42,21 +62,61 @@ const INVALID_CNPJS: [&str; 10] = [
 ///
 /// assert!(validate("11.222.333/0001-81").is_ok());
 /// assert!(validate("11222333000181").is_ok());
+/// assert_eq!(validate("12.ABC.345/01DE-35").as_deref(), Ok("12ABC34501DE35"));
 /// assert!(validate("11.111.111/1111-11").is_err()); // All same digits
 /// ```
 pub fn validate(cnpj: &str) -> ValidationResult<String> {
-    let cleaned = normalize(cnpj);
+    let result = validate_unobserved(cnpj)
+        .and_then(|cnpj| denylist::check(DocumentKind::Cnpj, &cnpj).map(|()| cnpj));
+    hooks::notify(DocumentKind::Cnpj, &result);
+    result
+}
 
-    // Check length
-    if cleaned.len() != 14 {
-        return Err(BrazilianValidationError::InvalidLength {
-            expected: 14,
-            actual: cleaned.len(),
+/// Validate without reporting to the validation hook (for internal lookups)
+pub(crate) fn validate_unobserved(cnpj: &str) -> ValidationResult<String> {
+    check(cnpj).map(|cleaned| cleaned.as_str().to_string())
+}
+
+/// Check whether a CNPJ is valid, without allocating
+///
+/// Gives the same answer as `validate(cnpj).is_ok()`, but works over the
+/// input bytes and never builds the normalized `String`, for bulk
+/// pipelines that only filter. Validation hooks are not notified.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj::is_valid;
+///
+/// assert!(is_valid("11.222.333/0001-81"));
+/// assert!(is_valid("12.ABC.345/01DE-35"));
+/// assert!(!is_valid("11.222.333/0001-00"));
+/// ```
+pub fn is_valid(cnpj: &str) -> bool {
+    check(cnpj).is_ok_and(|cleaned| denylist::check(DocumentKind::Cnpj, cleaned.as_str()).is_ok())
+}
+
+/// Validate into a stack buffer (the allocation-free core of [`validate`])
+fn check(cnpj: &str) -> ValidationResult<Cleaned<14>> {
+    let cnpj = &*unicode::normalize_if_enabled(cnpj);
+
+    // Strict mode only accepts the canonical formats
+    if config::Config::current().strict && !is_cnpj_format(cnpj) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cnpj,
         });
     }
 
-    // Ensure all characters are digits
-    if !cleaned.chars().all(|c| c.is_ascii_digit()) {
+    // Check length
+    let chars = cnpj_chars(cnpj);
+    let cleaned = Cleaned::<14>::alphanumeric(chars)
+        .filter(|cleaned| cleaned.as_str().len() == 14)
+        .ok_or_else(|| BrazilianValidationError::InvalidLength {
+            expected: 14,
+            actual: chars.bytes().filter(u8::is_ascii_alphanumeric).count(),
+        })?;
+
+    // Letters are only allowed in the root and branch
+    if !cleaned.as_str()[12..].bytes().all(|b| b.is_ascii_digit()) {
         return Err(BrazilianValidationError::InvalidCharacters);
     }
 
@@ -68,16 +128,19 @@ pub fn validate(cnpj: &str) -> ValidationResult<String> {
     }
 
     // Validate check digits
-    if !validate_check_digits(&cleaned) {
+    if !validate_check_digits(cleaned.as_str()) {
         return Err(BrazilianValidationError::InvalidCheckDigits {
-            document_type: "CNPJ".to_string(),
+            document_type: DocumentKind::Cnpj,
         });
     }
 
     Ok(cleaned)
 }
 
-/// Normalize a CNPJ string by removing all non-digit characters
+/// Normalize a CNPJ string by removing punctuation and uppercasing letters
+///
+/// Letters can only be part of the 12-character base, so letters around
+/// the CNPJ, such as a `CNPJ:` label, are removed too.
 ///
 /// # Examples
 /// ```
@@ -85,9 +148,74 @@ pub fn validate(cnpj: &str) -> ValidationResult<String> {
 ///
 /// assert_eq!(normalize("11.222.333/0001-81"), "11222333000181");
 /// assert_eq!(normalize("11222333000181"), "11222333000181");
+/// assert_eq!(normalize("12.abc.345/01de-35"), "12ABC34501DE35");
+/// assert_eq!(normalize("CNPJ: 11.222.333/0001-81"), "11222333000181");
 /// ```
 pub fn normalize(cnpj: &str) -> String {
-    cnpj.chars().filter(|c| c.is_ascii_digit()).collect()
+    cnpj_chars(cnpj)
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Part of the input that can hold the CNPJ
+///
+/// Letters only belong to a CNPJ in its 12-character base, so when there
+/// are more than 14 characters, letters before the first digit and after
+/// the last one (labels such as `CNPJ:`) are left out. Digits are always
+/// kept, so extra digits still make the length wrong.
+fn cnpj_chars(cnpj: &str) -> &str {
+    let (start, end) = span(cnpj.as_bytes());
+    &cnpj[start..end]
+}
+
+/// Byte range of [`cnpj_chars`], usable in `const` contexts
+///
+/// Both ends fall next to ASCII bytes, hence on character boundaries.
+const fn span(bytes: &[u8]) -> (usize, usize) {
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_alphanumeric() {
+            count += 1;
+        }
+        i += 1;
+    }
+
+    let (mut start, mut end) = (0, bytes.len());
+    while count > 14 && end > start && !bytes[end - 1].is_ascii_digit() {
+        if bytes[end - 1].is_ascii_alphabetic() {
+            count -= 1;
+        }
+        end -= 1;
+    }
+    while count > 14 && start < end && !bytes[start].is_ascii_digit() {
+        if bytes[start].is_ascii_alphabetic() {
+            count -= 1;
+        }
+        start += 1;
+    }
+
+    (start, end)
+}
+
+/// Check if a CNPJ uses the alphanumeric format (letters in the root or
+/// branch); does not validate check digits
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj::is_alphanumeric_cnpj;
+///
+/// assert!(is_alphanumeric_cnpj("12.ABC.345/01DE-35"));
+/// assert!(!is_alphanumeric_cnpj("11.222.333/0001-81"));
+/// assert!(!is_alphanumeric_cnpj("ABC"));
+/// ```
+pub fn is_alphanumeric_cnpj(cnpj: &str) -> bool {
+    let cleaned = normalize(cnpj);
+    cleaned.len() == 14
+        && cleaned[12..].bytes().all(|b| b.is_ascii_digit())
+        && cleaned.bytes().any(|b| b.is_ascii_alphabetic())
 }
 
 /// Format a CNPJ string with standard punctuation (XX.XXX.XXX/XXXX-XX)
@@ -96,7 +224,7 @@ pub fn normalize(cnpj: &str) -> String {
 /// * `cnpj` - CNPJ string (normalized or formatted)
 ///
 /// # Returns
-/// Formatted CNPJ string. Returns input unchanged if not 14 digits.
+/// Formatted CNPJ string. Returns input unchanged if not 14 characters.
 ///
 /// # Examples
 /// ```
@@ -104,6 +232,7 @@ pub fn normalize(cnpj: &str) -> String {
 ///
 /// assert_eq!(format("11222333000181"), "11.222.333/0001-81");
 /// assert_eq!(format("11.222.333/0001-81"), "11.222.333/0001-81");
+/// assert_eq!(format("12ABC34501DE35"), "12.ABC.345/01DE-35");
 /// ```
 pub fn format(cnpj: &str) -> String {
     let cleaned = normalize(cnpj);
@@ -122,6 +251,29 @@ pub fn format(cnpj: &str) -> String {
     }
 }
 
+/// Length of a CNPJ formatted as `XX.XXX.XXX/XXXX-XX`
+pub const FORMATTED_LEN: usize = 18;
+
+/// Format a CNPJ into a caller-provided buffer, without allocating
+///
+/// Writes the same output as [`format`](format()) at the start of `buf` and returns
+/// it as a `&str`. A buffer of [`FORMATTED_LEN`] bytes always suffices.
+///
+/// # Returns
+/// `None` if the input does not have 14 characters or `buf` is too small.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj;
+///
+/// let mut buf = [0u8; cnpj::FORMATTED_LEN];
+/// assert_eq!(cnpj::format_into("11222333000181", &mut buf), Some("11.222.333/0001-81"));
+/// ```
+pub fn format_into<'a>(cnpj: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let cleaned = Cleaned::<14>::alphanumeric(cnpj_chars(cnpj))?;
+    fixed::write_pattern(cleaned.as_str(), "##.###.###/####-##", buf)
+}
+
 /// Check if a string matches CNPJ format (does not validate check digits)
 ///
 /// # Examples
@@ -133,45 +285,50 @@ pub fn format(cnpj: &str) -> String {
 /// assert!(!is_cnpj_format("1122233300018")); // 13 digits
 /// ```
 pub fn is_cnpj_format(cnpj: &str) -> bool {
-    CNPJ_REGEX.is_match(cnpj)
+    shape::is_cnpj(cnpj)
 }
 
 /// Validate CNPJ check digits using weighted modulo 11 algorithm
 fn validate_check_digits(cnpj: &str) -> bool {
-    let digits: Vec<u32> = cnpj
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .collect();
-
-    if digits.len() != 14 {
+    let Ok(bytes) = <[u8; 14]>::try_from(cnpj.as_bytes()) else {
         return false;
+    };
+    let mut values = [0; 14];
+    for (value, byte) in values.iter_mut().zip(bytes) {
+        match char_value(byte) {
+            Some(v) => *value = v,
+            None => return false,
+        }
     }
 
-    // Weights for first check digit
-    let weights1 = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
-
-    // Calculate first check digit
-    let mut sum = 0;
-    for i in 0..12 {
-        sum += digits[i] * weights1[i];
-    }
-    let check1 = if sum % 11 < 2 { 0 } else { 11 - (sum % 11) };
+    compute_check_digits(&values[..12]) == [values[12], values[13]]
+}
 
-    if check1 != digits[12] {
-        return false;
-    }
+/// Values of the characters of a normalized CNPJ in the check-digit sums
+pub(crate) fn values(normalized: &str) -> Option<Vec<u32>> {
+    normalized.bytes().map(char_value).collect()
+}
 
-    // Weights for second check digit
-    let weights2 = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+/// Value of a character in the check-digit sums: the ASCII code minus 48
+/// (`0`-`9` → 0-9, `A`-`Z` → 17-42)
+fn char_value(b: u8) -> Option<u32> {
+    (b.is_ascii_digit() || b.is_ascii_uppercase()).then(|| u32::from(b - b'0'))
+}
 
-    // Calculate second check digit
-    sum = 0;
-    for i in 0..13 {
-        sum += digits[i] * weights2[i];
+/// Compute both CNPJ check digits for the 12 base values using weighted modulo 11
+pub(crate) fn compute_check_digits(base: &[u32]) -> [u32; 2] {
+    // Weights cycle from 2 to 9, right to left (5432 98765432 for the first digit).
+    // Letter values go up to 42, past what dv::mod11 accepts, so the sum is
+    // computed here.
+    fn check_digit(values: impl DoubleEndedIterator<Item = u32>) -> u32 {
+        let sum: u32 = values.rev().zip((2..=9).cycle()).map(|(v, w)| v * w).sum();
+        dv::Mod11Remainder::Complement.check_digit(sum % 11)
     }
-    let check2 = if sum % 11 < 2 { 0 } else { 11 - (sum % 11) };
 
-    check2 == digits[13]
+    let check1 = check_digit(base.iter().copied());
+    let check2 = check_digit(base.iter().copied().chain([check1]));
+
+    [check1, check2]
 }
 
 /// Mask a CNPJ for display (shows first 2 and last 2 digits)
@@ -197,7 +354,28 @@ pub fn mask(cnpj: &str) -> String {
     }
 }
 
-/// Extract the base CNPJ (first 8 digits - company identifier)
+/// Mask a CNPJ according to a masking policy
+///
+/// The formatted CNPJ (XX.XXX.XXX/XXXX-XX) is masked; input without 14
+/// characters is returned unchanged, as by [`mask`].
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj::mask_with;
+/// use pleme_brazilian_validators::masking::MaskOptions;
+///
+/// let options = MaskOptions { visible_leading: 8, ..MaskOptions::default() };
+/// assert_eq!(mask_with("11222333000181", &options), "11.222.333/****-81");
+/// ```
+pub fn mask_with(cnpj: &str, options: &MaskOptions) -> String {
+    if normalize(cnpj).len() == 14 {
+        options.apply(&format(cnpj))
+    } else {
+        cnpj.to_string()
+    }
+}
+
+/// Extract the base CNPJ (first 8 characters - company identifier)
 ///
 /// # Examples
 /// ```
@@ -215,7 +393,7 @@ pub fn extract_base(cnpj: &str) -> Option<String> {
     }
 }
 
-/// Extract the branch number (digits 9-12)
+/// Extract the branch number (characters 9-12)
 ///
 /// # Examples
 /// ```
@@ -243,12 +421,447 @@ pub fn extract_branch(cnpj: &str) -> Option<String> {
 /// assert!(!is_main_branch("11222333000281"));
 /// ```
 pub fn is_main_branch(cnpj: &str) -> bool {
-    extract_branch(cnpj).map_or(false, |branch| branch == "0001")
+    extract_branch(cnpj).is_some_and(|branch| branch == "0001")
+}
+
+/// Everything known about a valid CNPJ, see [`inspect`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct CnpjInfo {
+    /// 14 characters
+    pub normalized: String,
+    /// `XX.XXX.XXX/XXXX-XX`
+    pub formatted: String,
+    /// `XX.***.***/**XX-XX`
+    pub masked: String,
+    /// Company base (first 8 characters), shared by every branch
+    pub base: String,
+    /// Branch number (4 digits, `0001` for the headquarters)
+    pub branch: String,
+    /// Whether this is the headquarters (matriz)
+    pub is_main_branch: bool,
+}
+
+/// Validate a CNPJ and derive its formatted, masked, base and branch forms
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj;
+///
+/// let info = cnpj::inspect("11222333000181").unwrap();
+/// assert_eq!(info.formatted, "11.222.333/0001-81");
+/// assert_eq!((info.base.as_str(), info.branch.as_str()), ("11222333", "0001"));
+/// assert!(info.is_main_branch);
+/// ```
+pub fn inspect(cnpj: &str) -> ValidationResult<CnpjInfo> {
+    let normalized = validate(cnpj)?;
+
+    Ok(CnpjInfo {
+        formatted: format(&normalized),
+        masked: mask(&normalized),
+        base: normalized[..8].to_string(),
+        branch: normalized[8..12].to_string(),
+        is_main_branch: is_main_branch(&normalized),
+        normalized,
+    })
+}
+
+/// Compare two CNPJs in constant time
+///
+/// Both values are normalized first, so formatting differences do not matter.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj::ct_eq;
+///
+/// assert!(ct_eq("11.222.333/0001-81", "11222333000181"));
+/// assert!(!ct_eq("11.222.333/0001-81", "11222333000262"));
+/// ```
+pub fn ct_eq(a: &str, b: &str) -> bool {
+    normalize(a).as_bytes().ct_eq(normalize(b).as_bytes()).into()
+}
+
+/// CNPJ root (raiz): the first 8 characters, shared by every branch of a company
+///
+/// Group-level rules (credit limits, KYC, deduplication) apply to the root
+/// rather than to each establishment.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj::CnpjBase;
+///
+/// let base: CnpjBase = "11.222.333".parse().unwrap();
+/// assert_eq!(base.to_string(), "11.222.333");
+/// assert!(base.matches("11.222.333/0001-81"));
+/// assert_eq!(base.headquarters(), "11222333000181");
+///
+/// let from_branch = CnpjBase::from_cnpj("11.222.333/0002-62").unwrap();
+/// assert_eq!(from_branch, base);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CnpjBase([u8; 8]);
+
+impl CnpjBase {
+    /// Parse a CNPJ root (`12.345.678`, `12345678` or alphanumeric `12.ABC.345`)
+    ///
+    /// # Returns
+    /// * `Ok(CnpjBase)` - Root with exactly 8 characters
+    /// * `Err(BrazilianValidationError)` - Wrong length or invalid characters
+    pub fn parse(base: &str) -> ValidationResult<Self> {
+        let base = base.trim();
+        if !base.chars().all(|c| c.is_ascii_alphanumeric() || c == '.') {
+            return Err(BrazilianValidationError::InvalidCharacters);
+        }
+
+        let cleaned: String = base
+            .chars()
+            .filter(|&c| c != '.')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let digits: [u8; 8] = cleaned.as_bytes().try_into().map_err(|_| {
+            BrazilianValidationError::InvalidLength {
+                expected: 8,
+                actual: cleaned.len(),
+            }
+        })?;
+
+        Ok(CnpjBase(digits))
+    }
+
+    /// Get the root of a valid full CNPJ
+    pub fn from_cnpj(cnpj: &str) -> ValidationResult<Self> {
+        let normalized = validate(cnpj)?;
+        Self::parse(&normalized[..8])
+    }
+
+    /// Root characters (8 digits or uppercase letters)
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Check whether a CNPJ is valid and belongs to this root
+    pub fn matches(&self, cnpj: &str) -> bool {
+        validate_unobserved(cnpj).is_ok_and(|normalized| normalized.as_bytes()[..8] == self.0)
+    }
+
+    /// Full CNPJ of a branch (ordem), with computed check digits
+    ///
+    /// # Returns
+    /// * `Some(String)` - Normalized CNPJ (14 characters)
+    /// * `None` - Branch 0 or above 9999, or the result would be a
+    ///   repeated-digit sequence
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::cnpj::CnpjBase;
+    ///
+    /// let base = CnpjBase::parse("11222333").unwrap();
+    /// assert_eq!(base.branch(2).as_deref(), Some("11222333000262"));
+    /// assert_eq!(base.branch(0), None);
+    /// ```
+    pub fn branch(&self, number: u16) -> Option<String> {
+        if !(1..=9999).contains(&number) {
+            return None;
+        }
+
+        let base12 = format!("{}{:04}", self.as_str(), number);
+        let [dv1, dv2] = compute_check_digits(&values(&base12)?);
+        let cnpj = format!("{}{}{}", base12, dv1, dv2);
+
+        validate_unobserved(&cnpj).ok()
+    }
+
+    /// Headquarters CNPJ (branch 0001)
+    pub fn headquarters(&self) -> String {
+        self.branch(1).unwrap_or_default()
+    }
+
+    /// Iterate the CNPJs of branches 0001 through 9999, in order
+    pub fn branches(&self) -> impl Iterator<Item = String> + '_ {
+        (1..=9999).filter_map(move |number| self.branch(number))
+    }
+}
+
+impl core::fmt::Display for CnpjBase {
+    /// Formats as `12.345.678`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let base = self.as_str();
+        write!(f, "{}.{}.{}", &base[0..2], &base[2..5], &base[5..8])
+    }
+}
+
+impl core::str::FromStr for CnpjBase {
+    type Err = BrazilianValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for CnpjBase {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+/// Validated CNPJ
+///
+/// Can only be built through [`validate`], so a `Cnpj` carried through a
+/// domain model never needs to be checked again. `Display` prints the
+/// formatted form; `AsRef<str>` and serde use the 14 normalized characters.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cnpj::Cnpj;
+///
+/// let cnpj: Cnpj = "11.222.333/0001-81".parse().unwrap();
+/// assert_eq!(cnpj.digits(), "11222333000181");
+/// assert_eq!(cnpj.to_string(), "11.222.333/0001-81");
+/// assert_eq!(cnpj.masked(), "11.***.***/**01-81");
+///
+/// assert!("11.222.333/0001-00".parse::<Cnpj>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cnpj([u8; 14]);
+
+impl Cnpj {
+    /// Validate a CNPJ in any accepted format
+    pub fn parse(cnpj: &str) -> ValidationResult<Self> {
+        let normalized = validate(cnpj)?;
+        let digits = normalized.as_bytes().try_into().map_err(|_| {
+            BrazilianValidationError::InvalidLength {
+                expected: 14,
+                actual: normalized.len(),
+            }
+        })?;
+        Ok(Cnpj(digits))
+    }
+
+    /// Validate a CNPJ in a `const` context
+    ///
+    /// Punctuation is ignored and letters are uppercased, as by
+    /// [`normalize`]. The configuration (strict mode, Unicode digits,
+    /// denylist) is not read and validation hooks are not notified. Backs
+    /// the [`cnpj!`](crate::cnpj!) macro.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::Cnpj;
+    ///
+    /// const FIXTURE: Option<Cnpj> = Cnpj::parse_const("12.abc.345/01de-35");
+    /// assert_eq!(FIXTURE.map(|cnpj| cnpj.to_string()).as_deref(), Some("12.ABC.345/01DE-35"));
+    /// assert!(Cnpj::parse_const("11.222.333/0001-00").is_none());
+    /// ```
+    pub const fn parse_const(cnpj: &str) -> Option<Self> {
+        let bytes = cnpj.as_bytes();
+        let (mut i, end) = span(bytes);
+        let mut chars = [0u8; 14];
+        let mut len = 0;
+        while i < end {
+            if bytes[i].is_ascii_alphanumeric() {
+                if len == chars.len() {
+                    return None;
+                }
+                chars[len] = bytes[i].to_ascii_uppercase();
+                len += 1;
+            }
+            i += 1;
+        }
+
+        // Letters are only allowed in the root and branch
+        if len != chars.len() || !chars[12].is_ascii_digit() || !chars[13].is_ascii_digit() {
+            return None;
+        }
+        if is_repeated(&chars) || !const_check_digits(&chars) {
+            return None;
+        }
+        Some(Cnpj(chars))
+    }
+
+    /// The 14 normalized characters (uppercase letters in alphanumeric CNPJs)
+    pub fn digits(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// `XX.XXX.XXX/XXXX-XX`
+    pub fn formatted(&self) -> String {
+        format(self.digits())
+    }
+
+    /// `XX.***.***/**XX-XX`
+    pub fn masked(&self) -> String {
+        mask(self.digits())
+    }
+
+    /// Compare with another CNPJ in constant time, as by [`ct_eq`]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.0[..].ct_eq(&other.0[..]).into()
+    }
+}
+
+/// Whether every character is the same (`const` version of the
+/// [`INVALID_CNPJS`] check)
+const fn is_repeated(chars: &[u8; 14]) -> bool {
+    let mut i = 1;
+    while i < chars.len() {
+        if chars[i] != chars[0] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `const` version of [`validate_check_digits`] over normalized characters
+const fn const_check_digits(chars: &[u8; 14]) -> bool {
+    let mut position = 12;
+    while position < chars.len() {
+        // Weights cycle from 2 to 9, right to left
+        let mut sum = 0;
+        let mut i = 0;
+        while i < position {
+            sum += (chars[i] - b'0') as usize * (2 + (position - 1 - i) % 8);
+            i += 1;
+        }
+        let check = match sum % 11 {
+            remainder @ 2.. => 11 - remainder,
+            _ => 0,
+        };
+        if (chars[position] - b'0') as usize != check {
+            return false;
+        }
+        position += 1;
+    }
+    true
+}
+
+/// Validated [`Cnpj`] from a literal, checked at compile time
+///
+/// An invalid literal fails the build, so test fixtures and sandbox
+/// credentials embedded in code are always valid. Alphanumeric CNPJs are
+/// accepted. See [`Cnpj::parse_const`] for the rules applied.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{cnpj, Cnpj};
+///
+/// const SANDBOX_CNPJ: Cnpj = cnpj!("11.222.333/0001-81");
+/// assert_eq!(SANDBOX_CNPJ.digits(), "11222333000181");
+/// ```
+///
+/// ```compile_fail
+/// use pleme_brazilian_validators::cnpj;
+///
+/// let cnpj = cnpj!("11.222.333/0001-00"); // Wrong check digits
+/// ```
+#[macro_export]
+macro_rules! cnpj {
+    ($cnpj:literal) => {{
+        const CNPJ: $crate::cnpj::Cnpj = match $crate::cnpj::Cnpj::parse_const($cnpj) {
+            ::core::option::Option::Some(cnpj) => cnpj,
+            ::core::option::Option::None => {
+                ::core::panic!(::core::concat!("CNPJ inválido: ", $cnpj))
+            }
+        };
+        CNPJ
+    }};
+}
+
+impl core::fmt::Debug for Cnpj {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Cnpj").field(&self.digits()).finish()
+    }
+}
+
+impl core::fmt::Display for Cnpj {
+    /// Formats as `XX.XXX.XXX/XXXX-XX`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.formatted())
+    }
+}
+
+impl core::str::FromStr for Cnpj {
+    type Err = BrazilianValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Cnpj {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl AsRef<str> for Cnpj {
+    fn as_ref(&self) -> &str {
+        self.digits()
+    }
+}
+
+/// Wipes the stored digits, leaving a value that no longer holds a CNPJ
+///
+/// `Cnpj` is `Copy`, so it cannot wipe itself on drop: wrap it in
+/// [`Zeroizing`](zeroize::Zeroizing) for that.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Cnpj {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl crate::document::BrazilianDocument for Cnpj {
+    const KIND: DocumentKind = DocumentKind::Cnpj;
+
+    fn validate(input: &str) -> ValidationResult<Self> {
+        Cnpj::parse(input)
+    }
+
+    fn formatted(&self) -> String {
+        Cnpj::formatted(self)
+    }
+
+    fn normalized(&self) -> &str {
+        self.digits()
+    }
+
+    fn masked(&self) -> String {
+        Cnpj::masked(self)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Cnpj {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.digits())
+    }
+}
+
+/// Deserializing validates, accepting any format `validate` does; errors
+/// carry the error code and the localized message
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for Cnpj {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).map_err(BrazilianValidationError::into_de_error)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_validate_valid_cnpj() {
@@ -272,6 +885,56 @@ mod tests {
         assert!(validate("112223330001812").is_err());
     }
 
+    #[test]
+    fn test_is_valid_matches_validate() {
+        let inputs = [
+            "11.222.333/0001-81",
+            "12.abc.345/01de-35",
+            "11.222.333/0001-00",
+            "00.000.000/0000-00",
+            "12.ABC.345/01DE-3A",
+            "1122233300018",
+            "112223330001811",
+            "",
+        ];
+        for input in inputs {
+            assert_eq!(is_valid(input), validate(input).is_ok(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_labelled_input() {
+        let labelled = "CNPJ: 11.222.333/0001-81";
+        assert_eq!(validate(labelled).as_deref(), Ok("11222333000181"));
+        assert!(is_valid(labelled));
+        assert_eq!(format(labelled), "11.222.333/0001-81");
+        assert_eq!(mask(labelled), "11.***.***/**01-81");
+        assert_eq!(
+            validate("cnpj 12.ABC.345/01DE-35 (matriz)").as_deref(),
+            Ok("12ABC34501DE35")
+        );
+        assert_eq!(Cnpj::parse_const(labelled), Cnpj::parse(labelled).ok());
+
+        // Extra digits are never dropped
+        assert_eq!(
+            validate("CNPJ: 111.222.333/0001-81"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 14,
+                actual: 15
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_strict_mode_accepts_lowercase_letters() {
+        let strict = config::Config::builder().strict(true).build();
+        config::Config::with_options(strict, || {
+            assert!(validate("12.abc.345/01de-35").is_ok());
+            assert!(validate("CNPJ: 11.222.333/0001-81").is_err());
+        });
+    }
+
     #[test]
     fn test_normalize() {
         assert_eq!(normalize("11.222.333/0001-81"), "11222333000181");
@@ -286,6 +949,16 @@ mod tests {
         assert_eq!(format("123"), "123");
     }
 
+    #[test]
+    fn test_format_into_matches_format() {
+        let mut buf = [0u8; FORMATTED_LEN];
+        for input in ["11222333000181", "11.222.333/0001-81"] {
+            assert_eq!(format_into(input, &mut buf), Some(format(input).as_str()));
+        }
+        assert_eq!(format_into("1122233300018", &mut buf), None);
+        assert_eq!(format_into("11222333000181", &mut [0u8; 17]), None);
+    }
+
     #[test]
     fn test_is_cnpj_format() {
         assert!(is_cnpj_format("11.222.333/0001-81"));
@@ -315,4 +988,143 @@ mod tests {
         assert!(is_main_branch("11222333000181"));
         assert!(!is_main_branch("11222333000281"));
     }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq("11.222.333/0001-81", "11222333000181"));
+        assert!(!ct_eq("11222333000181", "11222333000100"));
+
+        let cnpj = Cnpj::parse("11.222.333/0001-81").unwrap();
+        assert!(cnpj.ct_eq(&Cnpj::parse("11222333000181").unwrap()));
+        assert!(!cnpj.ct_eq(&Cnpj::parse("12.ABC.345/01DE-35").unwrap()));
+    }
+
+    #[test]
+    fn test_cnpj_base_parse() {
+        assert_eq!(CnpjBase::parse("11222333").unwrap().as_str(), "11222333");
+        assert_eq!(CnpjBase::parse(" 11.222.333 ").unwrap().as_str(), "11222333");
+        assert!(matches!(
+            CnpjBase::parse("1122233"),
+            Err(BrazilianValidationError::InvalidLength { expected: 8, actual: 7 })
+        ));
+        assert_eq!(
+            CnpjBase::parse("11.222.333/0001"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+        assert!(CnpjBase::from_cnpj("11.222.333/0001-00").is_err());
+    }
+
+    #[test]
+    fn test_cnpj_base_branches() {
+        let base = CnpjBase::parse("11222333").unwrap();
+        let branches: Vec<_> = base.branches().take(3).collect();
+        assert_eq!(branches[0], "11222333000181");
+        assert_eq!(branches[1], "11222333000262");
+        assert!(branches.iter().all(|cnpj| base.matches(cnpj)));
+        assert_eq!(base.branch(9999).map(|c| c[8..12].to_string()).as_deref(), Some("9999"));
+        assert_eq!(base.branch(10000), None);
+    }
+
+    #[test]
+    fn test_cnpj_base_skips_repeated_digit_cnpjs() {
+        let ones = CnpjBase::parse("11111111").unwrap();
+        assert!(ones.branches().all(|cnpj| cnpj != "11111111111111"));
+    }
+
+    #[test]
+    fn test_cnpj_base_matches() {
+        let base = CnpjBase::parse("11222333").unwrap();
+        assert!(!base.matches("11.222.333/0001-82")); // Invalid check digits
+        assert!(!base.matches("00.000.000/0001-91")); // Other root
+    }
+
+    #[test]
+    fn test_parse_const_matches_parse() {
+        for cnpj in [
+            "11.222.333/0001-81",
+            "12.abc.345/01de-35",
+            "12ABC34501DE35",
+            "11.222.333/0001-00",
+            "00.000.000/0000-00",
+            "12.ABC.345/01DE-3A",
+            "1122233300018",
+            "112223330001811",
+        ] {
+            assert_eq!(Cnpj::parse_const(cnpj), Cnpj::parse(cnpj).ok(), "{}", cnpj);
+        }
+        assert_eq!(
+            crate::cnpj!("12.ABC.345/01DE-35"),
+            Cnpj::parse("12ABC34501DE35").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cnpj_type() {
+        let cnpj = Cnpj::parse("11222333000181").unwrap();
+        assert_eq!(cnpj.formatted(), "11.222.333/0001-81");
+        assert_eq!(cnpj.to_string(), "11.222.333/0001-81");
+        assert_eq!(cnpj.as_ref(), "11222333000181");
+        assert!(Cnpj::try_from("11.222.333/0001-00").is_err());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_cnpj_type_serde() {
+        let cnpj: Cnpj = serde_json::from_str(r#""11.222.333/0001-81""#).unwrap();
+        assert_eq!(serde_json::to_string(&cnpj).unwrap(), r#""11222333000181""#);
+        assert!(serde_json::from_str::<Cnpj>(r#""11222333000100""#).is_err());
+    }
+
+    #[test]
+    fn test_alphanumeric_cnpj() {
+        // Example published by the Receita Federal
+        assert_eq!(validate("12.ABC.345/01DE-35").as_deref(), Ok("12ABC34501DE35"));
+        assert_eq!(validate("12abc34501de35").as_deref(), Ok("12ABC34501DE35"));
+        assert!(validate("12.ABC.345/01DE-36").is_err());
+        assert_eq!(
+            validate("12.ABC.345/01DE-3A"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+
+        assert_eq!(format("12abc34501de35"), "12.ABC.345/01DE-35");
+        assert_eq!(mask("12ABC34501DE35"), "12.***.***/**DE-35");
+        assert_eq!(extract_branch("12ABC34501DE35").as_deref(), Some("01DE"));
+        assert!(is_cnpj_format("12.ABC.345/01DE-35"));
+        assert!(is_alphanumeric_cnpj("12ABC34501DE35"));
+
+        let mut buf = [0u8; FORMATTED_LEN];
+        assert_eq!(format_into("12abc34501de35", &mut buf), Some("12.ABC.345/01DE-35"));
+    }
+
+    #[test]
+    fn test_alphanumeric_cnpj_base() {
+        let base = CnpjBase::parse("12.ABC.345").unwrap();
+        assert_eq!(base.as_str(), "12ABC345");
+        assert!(base.matches("12.ABC.345/01DE-35"));
+        let headquarters = base.headquarters();
+        assert!(validate(&headquarters).is_ok());
+        assert!(headquarters.starts_with("12ABC3450001"));
+        assert_eq!(Cnpj::parse("12.ABC.345/01DE-35").unwrap().to_string(), "12.ABC.345/01DE-35");
+    }
+
+    #[test]
+    fn test_numeric_values_unchanged() {
+        // ASCII minus 48 leaves digits as they are
+        assert_eq!(values("1122233300"), Some(vec![1, 1, 2, 2, 2, 3, 3, 3, 0, 0]));
+        assert_eq!(values("AZ"), Some(vec![17, 42]));
+        assert_eq!(values("a"), None);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_wipes_digits() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut value = Cnpj::parse("11.222.333/0001-81").unwrap();
+        value.zeroize();
+        assert!(value.digits().bytes().all(|b| b == 0));
+
+        let wiped_on_drop = Zeroizing::new(Cnpj::parse("11.222.333/0001-81").unwrap());
+        assert_eq!(wiped_on_drop.to_string(), "11.222.333/0001-81");
+    }
 }
@@ -0,0 +1,220 @@
+//! CNS (Cartão Nacional de Saúde) validation and formatting
+//!
+//! The SUS health card number has 15 digits. Definitive numbers start
+//! with 1 or 2 and are derived from the holder's PIS/NIS: 11 digits, then
+//! `000` or `001` and a check digit. Provisional numbers start with 7, 8
+//! or 9. In both cases, the sum of the digits weighted 15 down to 1 is a
+//! multiple of 11.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of digits of a CNS
+pub const LEN: usize = 15;
+
+/// Validate a CNS number
+///
+/// # Arguments
+/// * `cns` - CNS number (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized CNS (15 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cns::validate;
+///
+/// assert!(validate("298 7654 3210 0018").is_ok()); // Definitive
+/// assert!(validate("898765432100008").is_ok()); // Provisional
+/// assert!(validate("298765432100019").is_err()); // Invalid check digit
+/// assert!(validate("398765432100018").is_err()); // Unknown first digit
+/// ```
+pub fn validate(cns: &str) -> ValidationResult<String> {
+    let result = check(cns);
+    hooks::notify(DocumentKind::Cns, &result);
+    result
+}
+
+fn check(cns: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(cns));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    let well_formed = match digits[0] {
+        // PIS/NIS, then 000 or 001 (the latter when the check digit
+        // would have been 10)
+        1 | 2 => digits[11] == 0 && digits[12] == 0 && digits[13] <= 1,
+        7..=9 => true,
+        _ => false,
+    };
+    if !well_formed {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cns,
+        });
+    }
+
+    let sum: u32 = digits.iter().zip((1..=15).rev()).map(|(d, w)| d * w).sum();
+    if !sum.is_multiple_of(11) {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Cns,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Whether a CNS is provisional (starts with 7, 8 or 9)
+///
+/// Provisional numbers are issued when the holder's PIS/NIS is unknown,
+/// e.g. on an emergency admission. Only the first digit is looked at.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cns::is_provisional;
+///
+/// assert!(is_provisional("898765432100008"));
+/// assert!(!is_provisional("298765432100018"));
+/// ```
+pub fn is_provisional(cns: &str) -> bool {
+    matches!(normalize(cns).as_bytes().first(), Some(b'7'..=b'9'))
+}
+
+/// Normalize a CNS number by removing all non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cns::normalize;
+///
+/// assert_eq!(normalize("298 7654 3210 0018"), "298765432100018");
+/// ```
+pub fn normalize(cns: &str) -> String {
+    cns.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a CNS number as printed on the card (XXX XXXX XXXX XXXX)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cns::format;
+///
+/// assert_eq!(format("298765432100018"), "298 7654 3210 0018");
+/// assert_eq!(format("123"), "123");
+/// ```
+pub fn format(cns: &str) -> String {
+    let cleaned = normalize(cns);
+
+    if cleaned.len() == LEN {
+        format!(
+            "{} {} {} {}",
+            &cleaned[..3],
+            &cleaned[3..7],
+            &cleaned[7..11],
+            &cleaned[11..]
+        )
+    } else {
+        cns.to_string()
+    }
+}
+
+/// Mask a CNS number for display (shows the last 4 digits)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cns::mask;
+///
+/// assert_eq!(mask("298765432100018"), "*** **** **** 0018");
+/// ```
+pub fn mask(cns: &str) -> String {
+    let cleaned = normalize(cns);
+
+    if cleaned.len() == LEN {
+        format!("*** **** **** {}", &cleaned[11..])
+    } else {
+        cns.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_printed_layouts_are_accepted() {
+        for cns in [
+            "298 7654 3210 0018",
+            "298.7654.3210.0018",
+            "2987654-3210-0018",
+        ] {
+            assert_eq!(validate(cns).as_deref(), Ok("298765432100018"));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 15,
+                actual,
+            })
+        };
+        assert_eq!(validate("29876543210001"), invalid_length(14));
+        assert_eq!(validate("2987654321000180"), invalid_length(16));
+        // The PIS/NIS alone is not a CNS
+        assert_eq!(validate("123.45678.90-1"), invalid_length(11));
+    }
+
+    #[test]
+    fn test_first_digit_and_suffix() {
+        let bad_format = Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cns,
+        });
+        for first in ['0', '3', '4', '5', '6'] {
+            assert_eq!(validate(&format!("{}98765432100018", first)), bad_format);
+        }
+        // Definitive numbers must have 000 or 001 before the check digit
+        assert_eq!(validate("123456789010100"), bad_format);
+        assert_eq!(validate("123456789010020"), bad_format);
+        assert_eq!(validate("111111111111111"), bad_format);
+        assert_eq!(validate("000000000000000"), bad_format);
+        // Provisional numbers have no fixed suffix
+        assert_eq!(
+            validate("800123456789009").as_deref(),
+            Ok("800123456789009")
+        );
+    }
+
+    #[test]
+    fn test_weighted_sum_is_verified() {
+        let bad_check_digit = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Cns,
+        });
+        assert_eq!(validate("123456789010001"), bad_check_digit);
+        assert_eq!(validate("298765432100019"), bad_check_digit);
+        // Two adjacent digits swapped
+        assert_eq!(validate("800213456789009"), bad_check_digit);
+        // Repeated digits only pass when their sum happens to be a multiple of 11
+        assert_eq!(validate("777777777777777"), bad_check_digit);
+        assert_eq!(validate("999999999999999"), bad_check_digit);
+        // Check digit overflow: 001 instead of 000
+        assert_eq!(
+            validate("298765432100018").as_deref(),
+            Ok("298765432100018")
+        );
+    }
+
+    #[test]
+    fn test_mask_passthrough() {
+        assert_eq!(mask("123"), "123");
+    }
+}
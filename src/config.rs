@@ -0,0 +1,266 @@
+//! Process-wide validation defaults
+//!
+//! Large codebases rarely want to thread options through every call site.
+//! [`Config::builder`] installs defaults once at startup; the plain
+//! `validate`/`mask` APIs read them from there. [`Config::with_options`]
+//! overrides them for the current thread only, e.g. in tests.
+//!
+//! Both need the `std` feature: without it there is no process-wide state
+//! and the plain APIs always use the library defaults.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::config::Config;
+//! use pleme_brazilian_validators::{cpf, Locale};
+//!
+//! Config::builder().strict(true).locale(Locale::En).install();
+//!
+//! // Strict mode only accepts the canonical formats
+//! assert!(cpf::validate("123.456.789-09").is_ok());
+//! assert!(cpf::validate("CPF: 123.456.789-09").is_err());
+//!
+//! Config::reset();
+//! assert!(cpf::validate("CPF: 123.456.789-09").is_ok());
+//! ```
+
+use crate::locale::Locale;
+use crate::scan::MaskStyle;
+#[cfg(feature = "std")]
+use std::cell::Cell;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// Options consumed by the plain validation and masking functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ValidationOptions {
+    /// Reject CPF, CNPJ, CEP and phone inputs that do not match the
+    /// canonical formats of [`patterns`](crate::patterns), instead of
+    /// discarding stray characters
+    pub strict: bool,
+    /// Locale of [`BrazilianValidationError::message`](crate::BrazilianValidationError::message)
+    pub locale: Locale,
+    /// Masking used by [`Secret`](crate::secret::Secret) and other wrappers
+    pub mask_style: MaskStyle,
+    /// Reject documents on the [`denylist`](crate::denylist), such as the
+    /// well-known example CPF `123.456.789-09`
+    pub reject_denylisted: bool,
+    /// Map full-width, Arabic-Indic and other Unicode digits to ASCII
+    /// before validating CPF, CNPJ, CEP and phone inputs (see
+    /// [`unicode`](crate::unicode))
+    pub unicode_digits: bool,
+    /// Return geographic names without accents (`"Sao Paulo"`), see
+    /// [`ascii`](crate::ascii)
+    pub ascii_names: bool,
+    /// Accept toll-free, shared-cost and short corporate phone numbers
+    /// (`0800`, `0300`, `4004`, ...) that have no DDD, see
+    /// [`PhoneKind`](crate::phone::PhoneKind)
+    pub non_geographic_phones: bool,
+}
+
+/// Defaults installed for the whole process
+#[cfg(feature = "std")]
+static GLOBAL_OPTIONS: RwLock<ValidationOptions> = RwLock::new(ValidationOptions {
+    strict: false,
+    locale: Locale::PtBr,
+    mask_style: MaskStyle::Partial,
+    reject_denylisted: false,
+    unicode_digits: false,
+    ascii_names: false,
+    non_geographic_phones: false,
+});
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// Per-thread override set by [`Config::with_options`]
+    static THREAD_OPTIONS: Cell<Option<ValidationOptions>> = const { Cell::new(None) };
+}
+
+/// Entry point to the process-wide configuration
+pub struct Config;
+
+impl Config {
+    /// Start building options from the library defaults
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// Options in effect for the current thread
+    #[cfg(feature = "std")]
+    pub fn current() -> ValidationOptions {
+        THREAD_OPTIONS
+            .with(Cell::get)
+            .or_else(|| GLOBAL_OPTIONS.read().ok().map(|options| *options))
+            .unwrap_or_default()
+    }
+
+    /// Options in effect: the library defaults, without `std`
+    #[cfg(not(feature = "std"))]
+    pub fn current() -> ValidationOptions {
+        ValidationOptions::default()
+    }
+
+    /// Restore the library defaults for the whole process
+    #[cfg(feature = "std")]
+    pub fn reset() {
+        ConfigBuilder::default().install();
+    }
+
+    /// Run a closure with options overridden for the current thread only
+    ///
+    /// The previous options are restored when the closure returns, even if
+    /// it panics.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::config::{Config, ValidationOptions};
+    /// use pleme_brazilian_validators::cep;
+    ///
+    /// let strict = ValidationOptions { strict: true, ..Default::default() };
+    /// Config::with_options(strict, || assert!(cep::validate("CEP 01310-100").is_err()));
+    /// assert!(cep::validate("CEP 01310-100").is_ok());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_options<R>(options: ValidationOptions, f: impl FnOnce() -> R) -> R {
+        /// Restores the previous override on drop
+        struct Restore(Option<ValidationOptions>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                THREAD_OPTIONS.with(|current| current.set(self.0));
+            }
+        }
+
+        let _restore = Restore(THREAD_OPTIONS.with(|current| current.replace(Some(options))));
+        f()
+    }
+}
+
+/// Builder for [`ValidationOptions`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfigBuilder {
+    options: ValidationOptions,
+}
+
+impl ConfigBuilder {
+    /// Only accept canonical formats (default: `false`)
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.options.strict = strict;
+        self
+    }
+
+    /// Locale for error messages (default: [`Locale::PtBr`])
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.options.locale = locale;
+        self
+    }
+
+    /// Masking style (default: [`MaskStyle::Partial`])
+    pub fn mask_style(mut self, mask_style: MaskStyle) -> Self {
+        self.options.mask_style = mask_style;
+        self
+    }
+
+    /// Reject test and reserved documents (default: `false`)
+    pub fn reject_denylisted(mut self, reject: bool) -> Self {
+        self.options.reject_denylisted = reject;
+        self
+    }
+
+    /// Map Unicode digits to ASCII before validating (default: `false`)
+    pub fn unicode_digits(mut self, enabled: bool) -> Self {
+        self.options.unicode_digits = enabled;
+        self
+    }
+
+    /// Return accent-free geographic names (default: `false`)
+    pub fn ascii_names(mut self, enabled: bool) -> Self {
+        self.options.ascii_names = enabled;
+        self
+    }
+
+    /// Accept phone numbers without a DDD (default: `false`)
+    pub fn non_geographic_phones(mut self, enabled: bool) -> Self {
+        self.options.non_geographic_phones = enabled;
+        self
+    }
+
+    /// Get the options without installing them
+    pub fn build(self) -> ValidationOptions {
+        self.options
+    }
+
+    /// Install the options as process-wide defaults
+    ///
+    /// Threads inside [`Config::with_options`] keep their override.
+    #[cfg(feature = "std")]
+    pub fn install(self) {
+        if let Ok(mut global) = GLOBAL_OPTIONS.write() {
+            *global = self.options;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::{cep, cnpj, cpf, phone};
+
+    #[cfg(feature = "std")]
+    fn strict() -> ValidationOptions {
+        Config::builder().strict(true).build()
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        assert_eq!(Config::builder().build(), ValidationOptions::default());
+        assert_eq!(
+            Config::builder().locale(Locale::En).mask_style(MaskStyle::Label).build(),
+            ValidationOptions {
+                strict: false,
+                locale: Locale::En,
+                mask_style: MaskStyle::Label,
+                reject_denylisted: false,
+                unicode_digits: false,
+                ascii_names: false,
+                non_geographic_phones: false,
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_strict_mode_accepts_canonical_formats() {
+        Config::with_options(strict(), || {
+            assert!(cpf::validate("12345678909").is_ok());
+            assert!(cnpj::validate("11.222.333/0001-81").is_ok());
+            assert!(cep::validate("01310-100").is_ok());
+            assert!(phone::validate("+55 (11) 98765-4321").is_ok());
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_strict_mode_rejects_stray_characters() {
+        Config::with_options(strict(), || {
+            assert!(matches!(
+                cpf::validate(" 123.456.789-09"),
+                Err(crate::BrazilianValidationError::InvalidDocumentFormat { .. })
+            ));
+            assert!(cnpj::validate("11-222-333-0001-81").is_err());
+            assert!(cep::validate("01.310-100").is_err());
+            assert!(phone::validate("tel: 11987654321").is_err());
+        });
+        assert!(cpf::validate(" 123.456.789-09").is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_options_restores_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            Config::with_options(strict(), || panic!("boom"));
+        });
+        assert!(result.is_err());
+        assert!(!Config::current().strict);
+    }
+}
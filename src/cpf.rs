@@ -3,13 +3,41 @@
 //! Brazilian individual taxpayer identification number with 11 digits
 //! and two check digits calculated using modulo 11.
 
+use crate::document::DocumentKind;
 use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::fixed::{self, Cleaned};
+use crate::masking::MaskOptions;
+use crate::uf::Uf;
+use crate::{config, denylist, dv, hooks, shape, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "std")]
+use crate::patterns;
+#[cfg(feature = "std")]
 use regex::Regex;
+#[cfg(feature = "std")]
 use std::sync::LazyLock;
 
 /// Regex for CPF format (with or without punctuation)
+#[cfg(feature = "std")]
 pub static CPF_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\d{3}\.?\d{3}\.?\d{3}-?\d{2}$").unwrap());
+    LazyLock::new(|| Regex::new(patterns::CPF).unwrap());
+
+/// Federative units of each fiscal region (9th digit of the CPF)
+const FISCAL_REGIONS: [&[Uf]; 10] = [
+    &[Uf::RS],
+    &[Uf::DF, Uf::GO, Uf::MS, Uf::MT, Uf::TO],
+    &[Uf::AC, Uf::AM, Uf::AP, Uf::PA, Uf::RO, Uf::RR],
+    &[Uf::CE, Uf::MA, Uf::PI],
+    &[Uf::AL, Uf::PB, Uf::PE, Uf::RN],
+    &[Uf::BA, Uf::SE],
+    &[Uf::MG],
+    &[Uf::ES, Uf::RJ],
+    &[Uf::SP],
+    &[Uf::PR, Uf::SC],
+];
 
 /// Known invalid CPFs (all same digits)
 const INVALID_CPFS: [&str; 10] = [
@@ -46,20 +74,52 @@ const INVALID_CPFS: [&str; 10] = [
 /// assert!(validate("123.456.789-00").is_err()); // Invalid check digits
 /// ```
 pub fn validate(cpf: &str) -> ValidationResult<String> {
-    let cleaned = normalize(cpf);
+    let result = validate_unobserved(cpf)
+        .and_then(|cpf| denylist::check(DocumentKind::Cpf, &cpf).map(|()| cpf));
+    hooks::notify(DocumentKind::Cpf, &result);
+    result
+}
 
-    // Check length
-    if cleaned.len() != 11 {
-        return Err(BrazilianValidationError::InvalidLength {
-            expected: 11,
-            actual: cleaned.len(),
+/// Validate without reporting to the validation hook (for internal lookups)
+pub(crate) fn validate_unobserved(cpf: &str) -> ValidationResult<String> {
+    check(cpf).map(|cleaned| cleaned.as_str().to_string())
+}
+
+/// Check whether a CPF is valid, without allocating
+///
+/// Gives the same answer as `validate(cpf).is_ok()`, but works over the
+/// input bytes and never builds the normalized `String`, for bulk
+/// pipelines that only filter. Validation hooks are not notified.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf::is_valid;
+///
+/// assert!(is_valid("123.456.789-09"));
+/// assert!(!is_valid("123.456.789-00"));
+/// ```
+pub fn is_valid(cpf: &str) -> bool {
+    check(cpf).is_ok_and(|cleaned| denylist::check(DocumentKind::Cpf, cleaned.as_str()).is_ok())
+}
+
+/// Validate into a stack buffer (the allocation-free core of [`validate`])
+fn check(cpf: &str) -> ValidationResult<Cleaned<11>> {
+    let cpf = &*unicode::normalize_if_enabled(cpf);
+
+    // Strict mode only accepts the canonical formats
+    if config::Config::current().strict && !is_cpf_format(cpf) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Cpf,
         });
     }
 
-    // Ensure all characters are digits
-    if !cleaned.chars().all(|c| c.is_ascii_digit()) {
-        return Err(BrazilianValidationError::InvalidCharacters);
-    }
+    // Check length
+    let cleaned = Cleaned::<11>::new(cpf, false)
+        .filter(|cleaned| cleaned.as_str().len() == 11)
+        .ok_or_else(|| BrazilianValidationError::InvalidLength {
+            expected: 11,
+            actual: cpf.bytes().filter(u8::is_ascii_digit).count(),
+        })?;
 
     // Check for known invalid CPFs
     if INVALID_CPFS.contains(&cleaned.as_str()) {
@@ -69,9 +129,9 @@ pub fn validate(cpf: &str) -> ValidationResult<String> {
     }
 
     // Validate check digits
-    if !validate_check_digits(&cleaned) {
+    if !validate_check_digits(cleaned.as_str()) {
         return Err(BrazilianValidationError::InvalidCheckDigits {
-            document_type: "CPF".to_string(),
+            document_type: DocumentKind::Cpf,
         });
     }
 
@@ -122,6 +182,30 @@ pub fn format(cpf: &str) -> String {
     }
 }
 
+/// Length of a CPF formatted as `XXX.XXX.XXX-XX`
+pub const FORMATTED_LEN: usize = 14;
+
+/// Format a CPF into a caller-provided buffer, without allocating
+///
+/// Writes the same output as [`format`](format()) at the start of `buf` and returns
+/// it as a `&str`. A buffer of [`FORMATTED_LEN`] bytes always suffices.
+///
+/// # Returns
+/// `None` if the input does not have 11 digits or `buf` is too small.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf;
+///
+/// let mut buf = [0u8; cpf::FORMATTED_LEN];
+/// assert_eq!(cpf::format_into("12345678909", &mut buf), Some("123.456.789-09"));
+/// assert_eq!(cpf::format_into("1234567890", &mut buf), None);
+/// ```
+pub fn format_into<'a>(cpf: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let cleaned = Cleaned::<11>::new(cpf, false)?;
+    fixed::write_pattern(cleaned.as_str(), "###.###.###-##", buf)
+}
+
 /// Check if a string matches CPF format (does not validate check digits)
 ///
 /// # Examples
@@ -133,39 +217,35 @@ pub fn format(cpf: &str) -> String {
 /// assert!(!is_cpf_format("1234567890")); // 10 digits
 /// ```
 pub fn is_cpf_format(cpf: &str) -> bool {
-    CPF_REGEX.is_match(cpf)
+    shape::is_cpf(cpf)
 }
 
 /// Validate CPF check digits using modulo 11 algorithm
 fn validate_check_digits(cpf: &str) -> bool {
-    let digits: Vec<u32> = cpf
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .collect();
-
-    if digits.len() != 11 {
+    let Ok(bytes) = <[u8; 11]>::try_from(cpf.as_bytes()) else {
+        return false;
+    };
+    if !bytes.iter().all(u8::is_ascii_digit) {
         return false;
     }
 
-    // Calculate first check digit
-    let mut sum = 0;
-    for i in 0..9 {
-        sum += digits[i] * (10 - i as u32);
-    }
-    let check1 = if sum % 11 < 2 { 0 } else { 11 - (sum % 11) };
+    let digits = bytes.map(|b| u32::from(b - b'0'));
+    compute_check_digits(&digits[..9]) == [digits[9], digits[10]]
+}
 
-    if check1 != digits[9] {
-        return false;
-    }
+/// Compute both CPF check digits for the 9 base digits using modulo 11
+pub(crate) fn compute_check_digits(base: &[u32]) -> [u32; 2] {
+    // Weights run from 10 (first check digit) or 11 (second) down to 2
+    let check1 = check_digit(base.iter().copied());
+    let check2 = check_digit(base.iter().copied().chain([check1]));
 
-    // Calculate second check digit
-    sum = 0;
-    for i in 0..10 {
-        sum += digits[i] * (11 - i as u32);
-    }
-    let check2 = if sum % 11 < 2 { 0 } else { 11 - (sum % 11) };
+    [check1, check2]
+}
 
-    check2 == digits[10]
+/// Modulo 11 check digit, weighting the digits 2, 3, ... from the right
+fn check_digit(digits: impl DoubleEndedIterator<Item = u32>) -> u32 {
+    let sum: u32 = digits.rev().zip(2..).map(|(digit, weight)| digit * weight).sum();
+    dv::Mod11Remainder::Complement.check_digit(sum % 11)
 }
 
 /// Mask a CPF for display (shows first 3 and last 2 digits)
@@ -186,9 +266,371 @@ pub fn mask(cpf: &str) -> String {
     }
 }
 
+/// Mask a CPF according to a masking policy
+///
+/// The formatted CPF (XXX.XXX.XXX-XX) is masked; input without 11 digits
+/// is returned unchanged, as by [`mask`].
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf::mask_with;
+/// use pleme_brazilian_validators::masking::MaskOptions;
+///
+/// let options = MaskOptions { visible_leading: 0, ..MaskOptions::default() };
+/// assert_eq!(mask_with("12345678909", &options), "***.***.***-09");
+/// ```
+pub fn mask_with(cpf: &str, options: &MaskOptions) -> String {
+    if normalize(cpf).len() == 11 {
+        options.apply(&format(cpf))
+    } else {
+        cpf.to_string()
+    }
+}
+
+/// Get the fiscal region of a CPF (its 9th digit)
+///
+/// The Receita Federal region that issued the CPF; see
+/// [`fiscal_region_ufs`] for the states it covers. The digit is read
+/// without validating the CPF.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf::fiscal_region;
+///
+/// assert_eq!(fiscal_region("123.456.789-09"), Some(9));
+/// assert_eq!(fiscal_region("123"), None);
+/// ```
+pub fn fiscal_region(cpf: &str) -> Option<u8> {
+    let cleaned = normalize(cpf);
+    if cleaned.len() != 11 {
+        return None;
+    }
+    cleaned[8..9].parse().ok()
+}
+
+/// Get the federative units covered by a fiscal region
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf::fiscal_region_ufs;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(fiscal_region_ufs(8), &[Uf::SP]);
+/// assert!(fiscal_region_ufs(10).is_empty());
+/// ```
+pub fn fiscal_region_ufs(region: u8) -> &'static [Uf] {
+    FISCAL_REGIONS.get(usize::from(region)).copied().unwrap_or_default()
+}
+
+/// Everything known about a valid CPF, see [`inspect`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct CpfInfo {
+    /// 11 digits
+    pub normalized: String,
+    /// `XXX.XXX.XXX-XX`
+    pub formatted: String,
+    /// `XXX.***.***-XX`
+    pub masked: String,
+    /// Fiscal region (9th digit)
+    pub fiscal_region: u8,
+    /// Federative units of the fiscal region
+    pub fiscal_region_ufs: &'static [Uf],
+}
+
+/// Validate a CPF and derive its formatted, masked and fiscal-region forms
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// let info = cpf::inspect("52998224725").unwrap();
+/// assert_eq!(info.formatted, "529.982.247-25");
+/// assert_eq!(info.masked, "529.***.***-25");
+/// assert_eq!(info.fiscal_region_ufs, &[Uf::ES, Uf::RJ]);
+/// ```
+pub fn inspect(cpf: &str) -> ValidationResult<CpfInfo> {
+    let normalized = validate(cpf)?;
+    let fiscal_region = fiscal_region(&normalized).unwrap_or_default();
+
+    Ok(CpfInfo {
+        formatted: format(&normalized),
+        masked: mask(&normalized),
+        fiscal_region,
+        fiscal_region_ufs: fiscal_region_ufs(fiscal_region),
+        normalized,
+    })
+}
+
+/// Compare two CPFs in constant time
+///
+/// Both values are normalized first, so formatting differences do not matter.
+/// The comparison of the digits does not short-circuit, which avoids timing
+/// side channels when checking user input against a stored CPF.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf::ct_eq;
+///
+/// assert!(ct_eq("123.456.789-09", "12345678909"));
+/// assert!(!ct_eq("123.456.789-09", "98765432100"));
+/// ```
+pub fn ct_eq(a: &str, b: &str) -> bool {
+    normalize(a).as_bytes().ct_eq(normalize(b).as_bytes()).into()
+}
+
+/// Validated CPF
+///
+/// Can only be built through [`validate`], so a `Cpf` carried through a
+/// domain model never needs to be checked again. `Display` prints the
+/// formatted form; `AsRef<str>` and serde use the 11 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::cpf::Cpf;
+///
+/// let cpf: Cpf = "123.456.789-09".parse().unwrap();
+/// assert_eq!(cpf.digits(), "12345678909");
+/// assert_eq!(cpf.to_string(), "123.456.789-09");
+/// assert_eq!(cpf.masked(), "123.***.***-09");
+///
+/// assert!("123.456.789-00".parse::<Cpf>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cpf([u8; 11]);
+
+impl Cpf {
+    /// Validate a CPF in any accepted format
+    pub fn parse(cpf: &str) -> ValidationResult<Self> {
+        let normalized = validate(cpf)?;
+        let digits = normalized.as_bytes().try_into().map_err(|_| {
+            BrazilianValidationError::InvalidLength {
+                expected: 11,
+                actual: normalized.len(),
+            }
+        })?;
+        Ok(Cpf(digits))
+    }
+
+    /// Validate a CPF in a `const` context
+    ///
+    /// Non-digit characters are ignored, as by [`normalize`]. The
+    /// configuration (strict mode, Unicode digits, denylist) is not read and
+    /// validation hooks are not notified. Backs the [`cpf!`](crate::cpf!)
+    /// macro.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::Cpf;
+    ///
+    /// const FIXTURE: Option<Cpf> = Cpf::parse_const("123.456.789-09");
+    /// assert_eq!(FIXTURE.map(|cpf| cpf.masked()).as_deref(), Some("123.***.***-09"));
+    /// assert!(Cpf::parse_const("111.111.111-11").is_none());
+    /// ```
+    pub const fn parse_const(cpf: &str) -> Option<Self> {
+        let bytes = cpf.as_bytes();
+        let mut digits = [0u8; 11];
+        let mut len = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i].is_ascii_digit() {
+                if len == digits.len() {
+                    return None;
+                }
+                digits[len] = bytes[i];
+                len += 1;
+            }
+            i += 1;
+        }
+
+        if len != digits.len() || is_repeated(&digits) || !const_check_digits(&digits) {
+            return None;
+        }
+        Some(Cpf(digits))
+    }
+
+    /// The 11 digits
+    pub fn digits(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// `XXX.XXX.XXX-XX`
+    pub fn formatted(&self) -> String {
+        format(self.digits())
+    }
+
+    /// `XXX.***.***-XX`
+    pub fn masked(&self) -> String {
+        mask(self.digits())
+    }
+
+    /// Compare with another CPF in constant time, as by [`ct_eq`]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.0[..].ct_eq(&other.0[..]).into()
+    }
+}
+
+/// Whether every digit is the same (`const` version of the [`INVALID_CPFS`] check)
+const fn is_repeated(digits: &[u8; 11]) -> bool {
+    let mut i = 1;
+    while i < digits.len() {
+        if digits[i] != digits[0] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `const` version of [`validate_check_digits`] over ASCII digits
+const fn const_check_digits(digits: &[u8; 11]) -> bool {
+    let mut position = 9;
+    while position < digits.len() {
+        // Weights run from 10 (first check digit) or 11 (second) down to 2
+        let mut sum = 0;
+        let mut i = 0;
+        while i < position {
+            sum += (digits[i] - b'0') as usize * (position + 1 - i);
+            i += 1;
+        }
+        let check = match sum % 11 {
+            remainder @ 2.. => 11 - remainder,
+            _ => 0,
+        };
+        if (digits[position] - b'0') as usize != check {
+            return false;
+        }
+        position += 1;
+    }
+    true
+}
+
+/// Validated [`Cpf`] from a literal, checked at compile time
+///
+/// An invalid literal fails the build, so test fixtures and sandbox
+/// credentials embedded in code are always valid. See
+/// [`Cpf::parse_const`] for the rules applied.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{cpf, Cpf};
+///
+/// const SANDBOX_CPF: Cpf = cpf!("123.456.789-09");
+/// assert_eq!(SANDBOX_CPF.digits(), "12345678909");
+/// ```
+///
+/// ```compile_fail
+/// use pleme_brazilian_validators::cpf;
+///
+/// let cpf = cpf!("123.456.789-00"); // Wrong check digits
+/// ```
+#[macro_export]
+macro_rules! cpf {
+    ($cpf:literal) => {{
+        const CPF: $crate::cpf::Cpf = match $crate::cpf::Cpf::parse_const($cpf) {
+            ::core::option::Option::Some(cpf) => cpf,
+            ::core::option::Option::None => {
+                ::core::panic!(::core::concat!("CPF inválido: ", $cpf))
+            }
+        };
+        CPF
+    }};
+}
+
+impl core::fmt::Debug for Cpf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Cpf").field(&self.digits()).finish()
+    }
+}
+
+impl core::fmt::Display for Cpf {
+    /// Formats as `XXX.XXX.XXX-XX`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.formatted())
+    }
+}
+
+impl core::str::FromStr for Cpf {
+    type Err = BrazilianValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Cpf {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl AsRef<str> for Cpf {
+    fn as_ref(&self) -> &str {
+        self.digits()
+    }
+}
+
+/// Wipes the stored digits, leaving a value that no longer holds a CPF
+///
+/// `Cpf` is `Copy`, so it cannot wipe itself on drop: wrap it in
+/// [`Zeroizing`](zeroize::Zeroizing) for that.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Cpf {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl crate::document::BrazilianDocument for Cpf {
+    const KIND: DocumentKind = DocumentKind::Cpf;
+
+    fn validate(input: &str) -> ValidationResult<Self> {
+        Cpf::parse(input)
+    }
+
+    fn formatted(&self) -> String {
+        Cpf::formatted(self)
+    }
+
+    fn normalized(&self) -> &str {
+        self.digits()
+    }
+
+    fn masked(&self) -> String {
+        Cpf::masked(self)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Cpf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.digits())
+    }
+}
+
+/// Deserializing validates, accepting any format `validate` does; errors
+/// carry the error code and the localized message
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for Cpf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).map_err(BrazilianValidationError::into_de_error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_validate_valid_cpf() {
@@ -212,6 +654,32 @@ mod tests {
         assert!(validate("123456789012").is_err());
     }
 
+    #[test]
+    fn test_is_valid_matches_validate() {
+        let inputs = [
+            "123.456.789-09",
+            "12345678909",
+            "123.456.789-00",
+            "111.111.111-11",
+            "1234567890",
+            "123456789091",
+            "cpf: 529.982.247-25",
+            "",
+        ];
+        for input in inputs {
+            assert_eq!(is_valid(input), validate(input).is_ok(), "{}", input);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let rejecting = config::Config::builder().reject_denylisted(true).build();
+            config::Config::with_options(rejecting, || {
+                assert!(!is_valid("123.456.789-09"));
+                assert!(is_valid("529.982.247-25"));
+            });
+        }
+    }
+
     #[test]
     fn test_normalize() {
         assert_eq!(normalize("123.456.789-09"), "12345678909");
@@ -227,6 +695,16 @@ mod tests {
         assert_eq!(format("123"), "123");
     }
 
+    #[test]
+    fn test_format_into_matches_format() {
+        let mut buf = [0u8; FORMATTED_LEN];
+        for input in ["12345678909", "123.456.789-09", " 123 456 789 09 "] {
+            assert_eq!(format_into(input, &mut buf), Some(format(input).as_str()));
+        }
+        assert_eq!(format_into("123456789091", &mut buf), None);
+        assert_eq!(format_into("12345678909", &mut [0u8; 13]), None);
+    }
+
     #[test]
     fn test_is_cpf_format() {
         assert!(is_cpf_format("123.456.789-09"));
@@ -240,4 +718,97 @@ mod tests {
         assert_eq!(mask("12345678909"), "123.***.***-09");
         assert_eq!(mask("123.456.789-09"), "123.***.***-09");
     }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq("123.456.789-09", "12345678909"));
+        assert!(!ct_eq("12345678909", "12345678900"));
+        assert!(!ct_eq("12345678909", "1234567890"));
+
+        let cpf = Cpf::parse("123.456.789-09").unwrap();
+        assert!(cpf.ct_eq(&Cpf::parse("12345678909").unwrap()));
+        assert!(!cpf.ct_eq(&Cpf::parse("987.654.321-00").unwrap()));
+    }
+
+    #[test]
+    fn test_fiscal_regions_cover_every_uf_once() {
+        let mut ufs: Vec<Uf> = (0..10).flat_map(|r| fiscal_region_ufs(r).iter().copied()).collect();
+        ufs.sort();
+        assert_eq!(ufs, Uf::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_inspect() {
+        let info = inspect("123.456.789-09").unwrap();
+        assert_eq!(info.normalized, "12345678909");
+        assert_eq!(info.fiscal_region, 9);
+        assert_eq!(info.fiscal_region_ufs, &[Uf::PR, Uf::SC]);
+        assert!(inspect("123.456.789-00").is_err());
+    }
+
+    #[test]
+    fn test_parse_const_matches_parse() {
+        for cpf in [
+            "123.456.789-09",
+            "529.982.247-25",
+            "cpf 52998224725",
+            "123.456.789-00",
+            "000.000.000-00",
+            "1234567890",
+            "123456789091",
+        ] {
+            assert_eq!(Cpf::parse_const(cpf), Cpf::parse(cpf).ok(), "{}", cpf);
+        }
+        assert_eq!(crate::cpf!("529.982.247-25"), Cpf::parse("52998224725").unwrap());
+    }
+
+    #[test]
+    fn test_cpf_type() {
+        let cpf = Cpf::parse("123.456.789-09").unwrap();
+        assert_eq!(cpf, "12345678909".parse().unwrap());
+        assert_eq!(cpf.as_ref(), "12345678909");
+        assert_eq!(cpf.formatted(), "123.456.789-09");
+        assert_eq!(format!("{:?}", cpf), "Cpf(\"12345678909\")");
+        assert!(Cpf::try_from("111.111.111-11").is_err());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_cpf_type_serde() {
+        let cpf: Cpf = serde_json::from_str(r#""123.456.789-09""#).unwrap();
+        assert_eq!(serde_json::to_string(&cpf).unwrap(), r#""12345678909""#);
+        let error = serde_json::from_str::<Cpf>(r#""123.456.789-00""#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("INVALID_CHECK_DIGITS: Dígitos verificadores inválidos para CPF"),
+            "{}",
+            error
+        );
+
+        let english = config::Config::builder().locale(crate::Locale::En).build();
+        let error = config::Config::with_options(english, || {
+            serde_json::from_str::<Cpf>(r#""123""#).unwrap_err()
+        });
+        assert!(
+            error
+                .to_string()
+                .starts_with("INVALID_LENGTH: Invalid length: expected 11, got 3"),
+            "{}",
+            error
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_wipes_digits() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut value = Cpf::parse("123.456.789-09").unwrap();
+        value.zeroize();
+        assert!(value.digits().bytes().all(|b| b == 0));
+
+        let wiped_on_drop = Zeroizing::new(Cpf::parse("123.456.789-09").unwrap());
+        assert_eq!(wiped_on_drop.to_string(), "123.456.789-09");
+    }
 }
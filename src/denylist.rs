@@ -0,0 +1,237 @@
+//! Denylist of test and reserved documents
+//!
+//! Some documents are perfectly valid yet never belong to a real customer:
+//! the CPF printed in every tutorial, the all-nines mobile number typed to
+//! get past a form. With
+//! [`reject_denylisted`](crate::config::ValidationOptions::reject_denylisted)
+//! enabled, the plain `validate` functions of CPF, CNPJ, CEP, phone and PIX
+//! keys reject the documents on this list with
+//! [`BrazilianValidationError::Denylisted`].
+//!
+//! The list starts with [`DEFAULT_ENTRIES`] and can be extended at startup
+//! with known fraud or QA documents (feature `std`). PIX keys are screened
+//! against the entries of the CPF, CNPJ or phone number they are made of.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::config::Config;
+//! use pleme_brazilian_validators::{cpf, denylist, DocumentKind};
+//!
+//! denylist::add(DocumentKind::Cpf, "529.982.247-25").unwrap();
+//!
+//! let options = Config::builder().reject_denylisted(true).build();
+//! Config::with_options(options, || {
+//!     assert!(cpf::validate("123.456.789-09").is_err());
+//!     assert!(cpf::validate("52998224725").is_err());
+//!     assert!(cpf::validate("111.444.777-35").is_ok());
+//! });
+//!
+//! // Disabled by default
+//! assert!(cpf::validate("123.456.789-09").is_ok());
+//! ```
+
+use crate::config::Config;
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::pix::PixKeyType;
+use crate::{cep, cnpj, cpf, phone, pix};
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::sync::{LazyLock, RwLock};
+
+/// Well-known test documents denylisted out of the box
+pub const DEFAULT_ENTRIES: [(DocumentKind, &str); 3] = [
+    (DocumentKind::Cpf, "12345678909"),
+    (DocumentKind::Cnpj, "00000000000191"),
+    (DocumentKind::Phone, "+5511999999999"),
+];
+
+/// Denylisted documents by kind, in the normalized form returned by
+/// `validate`
+#[cfg(feature = "std")]
+static ENTRIES: LazyLock<RwLock<HashMap<DocumentKind, HashSet<String>>>> =
+    LazyLock::new(|| RwLock::new(default_entries()));
+
+#[cfg(feature = "std")]
+fn default_entries() -> HashMap<DocumentKind, HashSet<String>> {
+    let mut entries: HashMap<DocumentKind, HashSet<String>> = HashMap::new();
+    for (kind, document) in DEFAULT_ENTRIES {
+        entries
+            .entry(kind)
+            .or_default()
+            .insert(document.to_string());
+    }
+    entries
+}
+
+/// Normalize a document the way the kind's `validate` function does
+fn canonical(kind: DocumentKind, document: &str) -> ValidationResult<String> {
+    match kind {
+        DocumentKind::Cpf => cpf::validate_unobserved(document),
+        DocumentKind::Cnpj => cnpj::validate_unobserved(document),
+        DocumentKind::Cep => cep::validate_unobserved(document),
+        DocumentKind::Phone => phone::validate_unobserved(document),
+        DocumentKind::PixKey => pix::validate_with_type_unobserved(document).map(|(_, key)| key),
+        // Not screened by any validator; accepted as typed
+        _ => Ok(document.trim().to_string()),
+    }
+}
+
+/// Add a document to the denylist
+///
+/// # Returns
+/// * `Ok(())` - Document added (or already present)
+/// * `Err(BrazilianValidationError)` - The document is not valid; invalid
+///   documents are rejected anyway and need no entry
+#[cfg(feature = "std")]
+pub fn add(kind: DocumentKind, document: &str) -> ValidationResult<()> {
+    let document = canonical(kind, document)?;
+    if let Ok(mut entries) = ENTRIES.write() {
+        entries.entry(kind).or_default().insert(document);
+    }
+    Ok(())
+}
+
+/// Remove a document from the denylist, returning whether it was present
+///
+/// Removing a default entry is how an application opts back into it.
+#[cfg(feature = "std")]
+pub fn remove(kind: DocumentKind, document: &str) -> bool {
+    let Ok(document) = canonical(kind, document) else {
+        return false;
+    };
+    ENTRIES
+        .write()
+        .map(|mut entries| {
+            entries
+                .get_mut(&kind)
+                .is_some_and(|docs| docs.remove(&document))
+        })
+        .unwrap_or(false)
+}
+
+/// Check whether a document is denylisted, in any of its accepted formats
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{denylist, DocumentKind};
+///
+/// assert!(denylist::contains(DocumentKind::Phone, "(11) 99999-9999"));
+/// assert!(!denylist::contains(DocumentKind::Phone, "(11) 98765-4321"));
+/// ```
+pub fn contains(kind: DocumentKind, document: &str) -> bool {
+    canonical(kind, document).is_ok_and(|document| is_listed(kind, &document))
+}
+
+/// Restore the list to [`DEFAULT_ENTRIES`]
+#[cfg(feature = "std")]
+pub fn reset() {
+    if let Ok(mut entries) = ENTRIES.write() {
+        *entries = default_entries();
+    }
+}
+
+/// Check whether a normalized document is on the list
+///
+/// PIX keys made of a CPF, CNPJ or phone number also match the entries of
+/// that document kind.
+fn is_listed(kind: DocumentKind, normalized: &str) -> bool {
+    let underlying = match (kind, pix::detect_type(normalized)) {
+        (DocumentKind::PixKey, Some(PixKeyType::Cpf)) => Some(DocumentKind::Cpf),
+        (DocumentKind::PixKey, Some(PixKeyType::Cnpj)) => Some(DocumentKind::Cnpj),
+        (DocumentKind::PixKey, Some(PixKeyType::Phone)) => Some(DocumentKind::Phone),
+        _ => None,
+    };
+
+    let listed = |kind| has_entry(kind, normalized);
+    listed(kind) || underlying.is_some_and(listed)
+}
+
+/// Check the list for an exact entry, without allocating
+#[cfg(feature = "std")]
+fn has_entry(kind: DocumentKind, normalized: &str) -> bool {
+    ENTRIES.read().is_ok_and(|entries| {
+        entries
+            .get(&kind)
+            .is_some_and(|docs| docs.contains(normalized))
+    })
+}
+
+/// Check the list for an exact entry: only the defaults, without `std`
+#[cfg(not(feature = "std"))]
+fn has_entry(kind: DocumentKind, normalized: &str) -> bool {
+    DEFAULT_ENTRIES.contains(&(kind, normalized))
+}
+
+/// Reject a validated document if denylisting is enabled and it is listed
+pub(crate) fn check(kind: DocumentKind, normalized: &str) -> ValidationResult<()> {
+    if Config::current().reject_denylisted && is_listed(kind, normalized) {
+        return Err(BrazilianValidationError::Denylisted {
+            document_type: kind,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    fn rejecting() -> crate::config::ValidationOptions {
+        Config::builder().reject_denylisted(true).build()
+    }
+
+    #[test]
+    fn test_default_entries_are_valid_and_normalized() {
+        for (kind, document) in DEFAULT_ENTRIES {
+            assert_eq!(canonical(kind, document).as_deref(), Ok(document));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_default_entries_are_rejected_when_enabled() {
+        Config::with_options(rejecting(), || {
+            assert_eq!(
+                cpf::validate("123.456.789-09"),
+                Err(BrazilianValidationError::Denylisted {
+                    document_type: DocumentKind::Cpf
+                })
+            );
+            assert!(cnpj::validate("00.000.000/0001-91").is_err());
+            assert!(phone::validate("+55 (11) 99999-9999").is_err());
+            assert!(pix::validate("+5511999999999").is_err());
+            assert!(pix::validate_with_type("123.456.789-09").is_err());
+            assert!(cpf::validate("111.444.777-35").is_ok());
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_add_and_remove() {
+        assert!(add(DocumentKind::Cep, "99999-999").is_ok());
+        assert!(contains(DocumentKind::Cep, "99999999"));
+        Config::with_options(rejecting(), || {
+            assert!(cep::validate("99999-999").is_err());
+        });
+
+        assert!(remove(DocumentKind::Cep, "99999999"));
+        assert!(!remove(DocumentKind::Cep, "99999999"));
+        assert!(!contains(DocumentKind::Cep, "99999-999"));
+
+        assert!(add(DocumentKind::Cpf, "123.456.789-00").is_err());
+        assert!(!remove(DocumentKind::Cpf, "not a cpf"));
+    }
+
+    #[test]
+    fn test_error_code() {
+        let err = BrazilianValidationError::Denylisted {
+            document_type: DocumentKind::Phone,
+        };
+        assert_eq!(err.error_code(), "DENYLISTED_DOCUMENT");
+        assert_eq!(err.document_type(), Some(DocumentKind::Phone));
+    }
+}
@@ -0,0 +1,328 @@
+//! Verbose check-digit diagnostics
+//!
+//! The plain `validate` functions only say that the check digits are wrong.
+//! Support teams resolving a rejected registration usually need to know
+//! more: which digits the document should end with and whether the first,
+//! the second or both check digits failed, which tells a typo in the check
+//! digits apart from a typo in the base number.
+//!
+//! Diagnostics expose the correct check digits of the document, so keep
+//! them in back-office tools and logs rather than end-user responses.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::diagnostics::{self, FailedCheckDigit};
+//! use pleme_brazilian_validators::DocumentKind;
+//!
+//! let diagnostic = diagnostics::validate("123.456.789-08", DocumentKind::Cpf).unwrap_err();
+//! let mismatch = diagnostic.check_digits.unwrap();
+//!
+//! assert_eq!(mismatch.expected, "09");
+//! assert_eq!(mismatch.received, "08");
+//! assert_eq!(mismatch.failed, FailedCheckDigit::Second);
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::BrazilianValidationError;
+use crate::locale::Locale;
+use crate::{cnpj, cpf, ibge};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Which check digit of a document failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FailedCheckDigit {
+    /// Only the first check digit is wrong
+    First,
+    /// Only the second check digit is wrong
+    Second,
+    /// Both check digits are wrong
+    Both,
+}
+
+/// Expected vs received check digits of a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckDigitMismatch {
+    /// Kind of document checked
+    pub document_type: DocumentKind,
+    /// Check digits computed from the base number
+    pub expected: String,
+    /// Check digits found in the input
+    pub received: String,
+    /// Which check digit differs
+    ///
+    /// Always [`First`](FailedCheckDigit::First) for documents with a
+    /// single check digit, such as IBGE codes.
+    pub failed: FailedCheckDigit,
+}
+
+impl CheckDigitMismatch {
+    /// Get the diagnostic message in the given locale
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::{diagnostics, DocumentKind, Locale};
+    ///
+    /// let mismatch = diagnostics::check_digits("11.222.333/0001-91", DocumentKind::Cnpj).unwrap();
+    /// assert_eq!(mismatch.localized(Locale::PtBr), "esperado 81, recebido 91 (primeiro dígito)");
+    /// assert_eq!(mismatch.localized(Locale::En), "expected 81, got 91 (first digit)");
+    /// ```
+    pub fn localized(&self, locale: Locale) -> String {
+        let (expected, received) = match locale {
+            Locale::PtBr => ("esperado", "recebido"),
+            Locale::En => ("expected", "got"),
+        };
+        let message = format!(
+            "{} {}, {} {}",
+            expected, self.expected, received, self.received
+        );
+
+        // Documents with a single check digit have no position to report
+        if self.expected.len() == 1 {
+            return message;
+        }
+
+        let failed = match (locale, self.failed) {
+            (Locale::PtBr, FailedCheckDigit::First) => "primeiro dígito",
+            (Locale::PtBr, FailedCheckDigit::Second) => "segundo dígito",
+            (Locale::PtBr, FailedCheckDigit::Both) => "ambos os dígitos",
+            (Locale::En, FailedCheckDigit::First) => "first digit",
+            (Locale::En, FailedCheckDigit::Second) => "second digit",
+            (Locale::En, FailedCheckDigit::Both) => "both digits",
+        };
+        format!("{} ({})", message, failed)
+    }
+}
+
+impl fmt::Display for CheckDigitMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.localized(Locale::PtBr))
+    }
+}
+
+/// Validation error with diagnostic details
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Error returned by the plain `validate` function
+    pub error: BrazilianValidationError,
+    /// Check-digit details, for [`InvalidCheckDigits`](BrazilianValidationError::InvalidCheckDigits) errors
+    pub check_digits: Option<CheckDigitMismatch>,
+}
+
+impl Diagnostic {
+    /// Get the error message, with check-digit details, in the given locale
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::{diagnostics, DocumentKind, Locale};
+    ///
+    /// let diagnostic = diagnostics::validate("123.456.789-18", DocumentKind::Cpf).unwrap_err();
+    /// assert_eq!(
+    ///     diagnostic.localized(Locale::PtBr),
+    ///     "Dígitos verificadores inválidos para CPF: esperado 09, recebido 18 (ambos os dígitos)"
+    /// );
+    /// ```
+    pub fn localized(&self, locale: Locale) -> String {
+        match &self.check_digits {
+            Some(mismatch) => format!(
+                "{}: {}",
+                self.error.localized(locale),
+                mismatch.localized(locale)
+            ),
+            None => self.error.localized(locale),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.localized(Locale::PtBr))
+    }
+}
+
+impl core::error::Error for Diagnostic {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<BrazilianValidationError> for Diagnostic {
+    fn from(error: BrazilianValidationError) -> Self {
+        Diagnostic {
+            error,
+            check_digits: None,
+        }
+    }
+}
+
+/// Compare the check digits of a document with the expected ones
+///
+/// Only CPF, CNPJ and IBGE codes are checked; other kinds have no check
+/// digits and return `None`.
+///
+/// # Returns
+/// * `Some(CheckDigitMismatch)` - The input has the right number of digits
+///   but its check digits are wrong
+/// * `None` - Check digits are correct, or the input is not a candidate
+///   (wrong length, other document kind)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::diagnostics::{check_digits, FailedCheckDigit};
+/// use pleme_brazilian_validators::DocumentKind;
+///
+/// let mismatch = check_digits("123.456.789-19", DocumentKind::Cpf).unwrap();
+/// assert_eq!(mismatch.failed, FailedCheckDigit::First);
+///
+/// assert!(check_digits("123.456.789-09", DocumentKind::Cpf).is_none());
+/// assert!(check_digits("123.456", DocumentKind::Cpf).is_none());
+/// ```
+pub fn check_digits(input: &str, kind: DocumentKind) -> Option<CheckDigitMismatch> {
+    let (digits, expected) = match kind {
+        DocumentKind::Cpf => {
+            let digits = digits_of(&cpf::normalize(input), 11)?;
+            let expected = cpf::compute_check_digits(&digits[..9]).to_vec();
+            (digits, expected)
+        }
+        DocumentKind::Cnpj => {
+            // Letters of alphanumeric CNPJs only appear before the check digits
+            let digits = cnpj::values(&cnpj::normalize(input)).filter(|v| v.len() == 14)?;
+            let expected = cnpj::compute_check_digits(&digits[..12]).to_vec();
+            (digits, expected)
+        }
+        DocumentKind::IbgeCode => {
+            let digits = digits_of(&ibge::normalize(input), 7)?;
+            let expected = vec![ibge::compute_check_digit(&digits[..6])];
+            (digits, expected)
+        }
+        _ => return None,
+    };
+
+    let received = &digits[digits.len() - expected.len()..];
+    let failed = match (
+        received[0] != expected[0],
+        received.get(1) != expected.get(1),
+    ) {
+        (true, true) => FailedCheckDigit::Both,
+        (true, false) => FailedCheckDigit::First,
+        (false, true) => FailedCheckDigit::Second,
+        (false, false) => return None,
+    };
+
+    Some(CheckDigitMismatch {
+        document_type: kind,
+        expected: to_string(&expected),
+        received: to_string(received),
+        failed,
+    })
+}
+
+/// Validate a document, explaining check-digit failures
+///
+/// Same outcome as the kind's plain `validate` function; on error, the
+/// [`Diagnostic`] carries the expected and received check digits when the
+/// check digits are what failed.
+///
+/// # Arguments
+/// * `input` - Document string
+/// * `kind` - Expected document kind
+///
+/// # Returns
+/// * `Ok(String)` - Normalized document
+/// * `Err(Diagnostic)` - Validation error and check-digit details
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{diagnostics, DocumentKind};
+///
+/// assert_eq!(diagnostics::validate("3550308", DocumentKind::IbgeCode).as_deref(), Ok("3550308"));
+///
+/// let diagnostic = diagnostics::validate("3550309", DocumentKind::IbgeCode).unwrap_err();
+/// assert_eq!(diagnostic.check_digits.unwrap().to_string(), "esperado 8, recebido 9");
+///
+/// let diagnostic = diagnostics::validate("123", DocumentKind::Cpf).unwrap_err();
+/// assert!(diagnostic.check_digits.is_none());
+/// ```
+pub fn validate(input: &str, kind: DocumentKind) -> Result<String, Diagnostic> {
+    kind.validate(input).map_err(|error| {
+        let check_digits = match error {
+            BrazilianValidationError::InvalidCheckDigits { document_type } => {
+                check_digits(input, document_type)
+            }
+            _ => None,
+        };
+        Diagnostic {
+            error,
+            check_digits,
+        }
+    })
+}
+
+/// Parse exactly `len` ASCII digits
+fn digits_of(normalized: &str, len: usize) -> Option<Vec<u32>> {
+    let digits: Vec<u32> = normalized.chars().filter_map(|c| c.to_digit(10)).collect();
+    (digits.len() == len && normalized.len() == len).then_some(digits)
+}
+
+fn to_string(digits: &[u32]) -> String {
+    digits.iter().map(|d| d.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpf_failed_digit() {
+        let failed = |cpf| check_digits(cpf, DocumentKind::Cpf).map(|m| m.failed);
+        assert_eq!(failed("123.456.789-19"), Some(FailedCheckDigit::First));
+        assert_eq!(failed("123.456.789-08"), Some(FailedCheckDigit::Second));
+        assert_eq!(failed("123.456.789-18"), Some(FailedCheckDigit::Both));
+        assert_eq!(failed("123.456.789-09"), None);
+    }
+
+    #[test]
+    fn test_cnpj_mismatch() {
+        assert_eq!(
+            check_digits("11222333000182", DocumentKind::Cnpj),
+            Some(CheckDigitMismatch {
+                document_type: DocumentKind::Cnpj,
+                expected: "81".to_string(),
+                received: "82".to_string(),
+                failed: FailedCheckDigit::Second,
+            })
+        );
+    }
+
+    #[test]
+    fn test_kinds_without_check_digits() {
+        assert_eq!(check_digits("01310-100", DocumentKind::Cep), None);
+        assert_eq!(check_digits("12345678900", DocumentKind::PixKey), None);
+    }
+
+    #[test]
+    fn test_validate_attaches_details_only_to_check_digit_errors() {
+        let diagnostic = validate("111.111.111-11", DocumentKind::Cpf).unwrap_err();
+        assert!(diagnostic.check_digits.is_none());
+
+        let diagnostic = validate("11.222.333/0001-00", DocumentKind::Cnpj).unwrap_err();
+        assert_eq!(
+            diagnostic.error,
+            BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Cnpj
+            }
+        );
+        assert_eq!(
+            diagnostic.localized(Locale::En),
+            "Invalid check digits for CNPJ: expected 81, got 00 (both digits)"
+        );
+
+        assert_eq!(
+            validate("11.222.333/0001-81", DocumentKind::Cnpj),
+            Ok("11222333000181".to_string())
+        );
+    }
+}
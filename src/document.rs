@@ -0,0 +1,568 @@
+//! Document kinds shared across the crate
+//!
+//! Typed vocabulary for the kinds of Brazilian documents and formats
+//! handled by the validators. Errors, validation hooks and the scanner all
+//! report a [`DocumentKind`], so metrics and error handling can match on it
+//! instead of comparing display strings. [`Document`] holds a validated
+//! document of any kind, and [`detect`] finds out which kind an input is;
+//! [`BrazilianDocument`] abstracts over the validated document types.
+
+use crate::error::ValidationResult;
+use crate::pix::PixKey;
+use crate::uf::Uf;
+use crate::{Cep, Cnpj, Cpf, Phone};
+use crate::{bank_account, boleto, caepf, card, cei, cep, certidao, cest, cnae, cnh, cno, cnpj, cns, cpf, gtin, iban, ibge, ie, ncm, nfe, passaporte, phone, pis, pix, placa, processo_cnj, renavam, rg, titulo_eleitor};
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+/// Kind of Brazilian document or format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentKind {
+    /// CPF (individual taxpayer ID)
+    Cpf,
+    /// CNPJ (business taxpayer ID)
+    Cnpj,
+    /// CEP (postal code)
+    Cep,
+    /// Phone number
+    Phone,
+    /// PIX key (email, phone or random key)
+    PixKey,
+    /// PIX BR Code ("copia e cola" payload)
+    BrCode,
+    /// Federative unit (state abbreviation or name)
+    Uf,
+    /// IBGE municipality code
+    IbgeCode,
+    /// Boleto barcode
+    Boleto,
+    /// NF-e access key (chave de acesso)
+    NfeKey,
+    /// CNH (driver's license registration number)
+    Cnh,
+    /// Título de eleitor (voter registration number)
+    TituloEleitor,
+    /// Vehicle license plate
+    Placa,
+    /// Inscrição estadual (state tax registration)
+    InscricaoEstadual,
+    /// Bank account (agência and conta)
+    BankAccount,
+    /// Brazilian IBAN
+    Iban,
+    /// Payment card number
+    Card,
+    /// Processo judicial number (numeração única CNJ)
+    ProcessoCnj,
+    /// Cartão Nacional de Saúde (SUS health card)
+    Cns,
+    /// RG (Registro Geral, state identity card)
+    Rg,
+    /// Brazilian passport number
+    Passaporte,
+    /// Civil registry certificate matrícula (birth, marriage, death)
+    Certidao,
+    /// CEI (Cadastro Específico do INSS)
+    Cei,
+    /// CAEPF (individual employer registration)
+    Caepf,
+    /// CNO (Cadastro Nacional de Obras)
+    Cno,
+    /// GTIN (EAN/UPC) product barcode
+    Gtin,
+    /// CNAE economic activity subclass
+    Cnae,
+    /// NCM (Mercosul nomenclature) goods code
+    Ncm,
+    /// CEST tax substitution code
+    Cest,
+    /// PIS/PASEP (NIS) worker registration number
+    Pis,
+    /// RENAVAM vehicle registry code
+    Renavam,
+}
+
+impl DocumentKind {
+    /// Every document kind, in declaration order
+    pub const ALL: [DocumentKind; 31] = [
+        DocumentKind::Cpf,
+        DocumentKind::Cnpj,
+        DocumentKind::Cep,
+        DocumentKind::Phone,
+        DocumentKind::PixKey,
+        DocumentKind::BrCode,
+        DocumentKind::Uf,
+        DocumentKind::IbgeCode,
+        DocumentKind::Boleto,
+        DocumentKind::NfeKey,
+        DocumentKind::Cnh,
+        DocumentKind::TituloEleitor,
+        DocumentKind::Placa,
+        DocumentKind::InscricaoEstadual,
+        DocumentKind::BankAccount,
+        DocumentKind::Iban,
+        DocumentKind::Card,
+        DocumentKind::ProcessoCnj,
+        DocumentKind::Cns,
+        DocumentKind::Rg,
+        DocumentKind::Passaporte,
+        DocumentKind::Certidao,
+        DocumentKind::Cei,
+        DocumentKind::Caepf,
+        DocumentKind::Cno,
+        DocumentKind::Gtin,
+        DocumentKind::Cnae,
+        DocumentKind::Ncm,
+        DocumentKind::Cest,
+        DocumentKind::Pis,
+        DocumentKind::Renavam,
+    ];
+
+    /// Iterate over every document kind
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::DocumentKind;
+    ///
+    /// let options: Vec<_> = DocumentKind::iter().map(|kind| (kind.as_str(), kind.to_string())).collect();
+    /// assert_eq!(options[0], ("cpf", "CPF".to_string()));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = DocumentKind> {
+        DocumentKind::ALL.into_iter()
+    }
+
+    /// Stable machine-readable identifier (e.g. `"pix_key"`)
+    ///
+    /// Unlike `Display`, which is a Portuguese label, identifiers never
+    /// change between releases: use them for metrics labels and API
+    /// payloads. This is also the serde representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::DocumentKind;
+    ///
+    /// assert_eq!(DocumentKind::PixKey.as_str(), "pix_key");
+    /// assert_eq!("pix_key".parse(), Ok(DocumentKind::PixKey));
+    /// assert_eq!(DocumentKind::PixKey.to_string(), "Chave PIX");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentKind::Cpf => "cpf",
+            DocumentKind::Cnpj => "cnpj",
+            DocumentKind::Cep => "cep",
+            DocumentKind::Phone => "phone",
+            DocumentKind::PixKey => "pix_key",
+            DocumentKind::BrCode => "br_code",
+            DocumentKind::Uf => "uf",
+            DocumentKind::IbgeCode => "ibge_code",
+            DocumentKind::Boleto => "boleto",
+            DocumentKind::NfeKey => "nfe_key",
+            DocumentKind::Cnh => "cnh",
+            DocumentKind::TituloEleitor => "titulo_eleitor",
+            DocumentKind::Placa => "placa",
+            DocumentKind::InscricaoEstadual => "inscricao_estadual",
+            DocumentKind::BankAccount => "bank_account",
+            DocumentKind::Iban => "iban",
+            DocumentKind::Card => "card",
+            DocumentKind::ProcessoCnj => "processo_cnj",
+            DocumentKind::Cns => "cns",
+            DocumentKind::Rg => "rg",
+            DocumentKind::Passaporte => "passaporte",
+            DocumentKind::Certidao => "certidao",
+            DocumentKind::Cei => "cei",
+            DocumentKind::Caepf => "caepf",
+            DocumentKind::Cno => "cno",
+            DocumentKind::Gtin => "gtin",
+            DocumentKind::Cnae => "cnae",
+            DocumentKind::Ncm => "ncm",
+            DocumentKind::Cest => "cest",
+            DocumentKind::Pis => "pis",
+            DocumentKind::Renavam => "renavam",
+        }
+    }
+}
+
+impl DocumentKind {
+    /// Validate a document of this kind with its module's `validate`
+    ///
+    /// Returns the normalized document; BR Codes, which have no normalized
+    /// form, are returned trimmed.
+    pub(crate) fn validate(self, input: &str) -> ValidationResult<String> {
+        match self {
+            DocumentKind::Cpf => cpf::validate(input),
+            DocumentKind::Cnpj => cnpj::validate(input),
+            DocumentKind::Cep => cep::validate(input),
+            DocumentKind::Phone => phone::validate(input),
+            DocumentKind::PixKey => pix::validate_with_type(input).map(|(_, key)| key),
+            DocumentKind::BrCode => {
+                pix::brcode::parse(input)?;
+                Ok(input.trim().to_string())
+            }
+            DocumentKind::Uf => Ok(input.parse::<Uf>()?.abbreviation().to_string()),
+            DocumentKind::IbgeCode => Ok(ibge::validate_municipio(input)?.code),
+            DocumentKind::Boleto => boleto::validate_barcode(input),
+            DocumentKind::NfeKey => nfe::validate_key(input),
+            DocumentKind::Cnh => cnh::validate(input),
+            DocumentKind::TituloEleitor => titulo_eleitor::validate(input),
+            DocumentKind::Placa => placa::validate(input),
+            // Without a state, any state whose algorithm accepts it
+            DocumentKind::InscricaoEstadual => ie::validate_any_state(input),
+            // `banco agência conta`, separated by whitespace
+            DocumentKind::BankAccount => {
+                Ok(bank_account::validate_formatted(input)?.to_string())
+            }
+            DocumentKind::Iban => iban::validate_br(input),
+            DocumentKind::Card => card::validate(input),
+            DocumentKind::ProcessoCnj => processo_cnj::validate(input),
+            DocumentKind::Cns => cns::validate(input),
+            DocumentKind::Rg => rg::validate(input, None),
+            DocumentKind::Passaporte => passaporte::validate(input),
+            DocumentKind::Certidao => certidao::validate(input),
+            DocumentKind::Cei => cei::validate(input),
+            DocumentKind::Caepf => caepf::validate(input),
+            DocumentKind::Cno => cno::validate(input),
+            DocumentKind::Gtin => gtin::validate(input),
+            DocumentKind::Cnae => cnae::validate(input),
+            DocumentKind::Ncm => ncm::validate(input),
+            DocumentKind::Cest => cest::validate(input),
+            DocumentKind::Pis => pis::validate(input),
+            DocumentKind::Renavam => renavam::validate(input),
+        }
+    }
+}
+
+impl fmt::Display for DocumentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentKind::Cpf => write!(f, "CPF"),
+            DocumentKind::Cnpj => write!(f, "CNPJ"),
+            DocumentKind::Cep => write!(f, "CEP"),
+            DocumentKind::Phone => write!(f, "Telefone"),
+            DocumentKind::PixKey => write!(f, "Chave PIX"),
+            DocumentKind::BrCode => write!(f, "BR Code"),
+            DocumentKind::Uf => write!(f, "UF"),
+            DocumentKind::IbgeCode => write!(f, "Código IBGE"),
+            DocumentKind::Boleto => write!(f, "Boleto"),
+            DocumentKind::NfeKey => write!(f, "Chave de acesso NF-e"),
+            DocumentKind::Cnh => write!(f, "CNH"),
+            DocumentKind::TituloEleitor => write!(f, "Título de Eleitor"),
+            DocumentKind::Placa => write!(f, "Placa"),
+            DocumentKind::InscricaoEstadual => write!(f, "Inscrição Estadual"),
+            DocumentKind::BankAccount => write!(f, "Conta bancária"),
+            DocumentKind::Iban => write!(f, "IBAN"),
+            DocumentKind::Card => write!(f, "Cartão"),
+            DocumentKind::ProcessoCnj => write!(f, "Processo CNJ"),
+            DocumentKind::Cns => write!(f, "CNS"),
+            DocumentKind::Rg => write!(f, "RG"),
+            DocumentKind::Passaporte => write!(f, "Passaporte"),
+            DocumentKind::Certidao => write!(f, "Certidão"),
+            DocumentKind::Cei => write!(f, "CEI"),
+            DocumentKind::Caepf => write!(f, "CAEPF"),
+            DocumentKind::Cno => write!(f, "CNO"),
+            DocumentKind::Gtin => write!(f, "GTIN"),
+            DocumentKind::Cnae => write!(f, "CNAE"),
+            DocumentKind::Ncm => write!(f, "NCM"),
+            DocumentKind::Cest => write!(f, "CEST"),
+            DocumentKind::Pis => write!(f, "PIS/PASEP"),
+            DocumentKind::Renavam => write!(f, "RENAVAM"),
+        }
+    }
+}
+
+/// Common interface of the validated document types
+///
+/// Implemented by [`Cpf`], [`Cnpj`], [`Cep`], [`Phone`] and [`PixKey`], so
+/// generic code (form frameworks, CSV cleaners) can be written once over
+/// any of them.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::document::BrazilianDocument;
+/// use pleme_brazilian_validators::{Cep, Cpf};
+///
+/// /// Formatted column values, `None` for invalid cells
+/// fn clean_column<D: BrazilianDocument>(cells: &[&str]) -> Vec<Option<String>> {
+///     cells.iter().map(|cell| D::validate(cell).ok().map(|d| d.formatted())).collect()
+/// }
+///
+/// assert_eq!(
+///     clean_column::<Cpf>(&["12345678909", "123"]),
+///     vec![Some("123.456.789-09".to_string()), None]
+/// );
+/// assert_eq!(clean_column::<Cep>(&["01310100"]), vec![Some("01310-100".to_string())]);
+/// ```
+pub trait BrazilianDocument: Sized {
+    /// Kind of the document
+    const KIND: DocumentKind;
+
+    /// Validate a document in any accepted format
+    fn validate(input: &str) -> ValidationResult<Self>;
+
+    /// Display format, with the usual punctuation
+    fn formatted(&self) -> String;
+
+    /// Normalized form, as returned by the module's `validate`
+    fn normalized(&self) -> &str;
+
+    /// Masked form, safe for logs and receipts
+    fn masked(&self) -> String;
+}
+
+/// Validated document of any kind, holding its normalized value
+///
+/// Returned by [`detect`] and [`Document::validate_as`], for fields that
+/// accept more than one kind of document ("CPF ou CNPJ"). The common kinds
+/// wrap their validated types; the others keep the normalized string
+/// returned by their module's `validate`.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::document::{self, Document};
+///
+/// match document::detect("11.222.333/0001-81") {
+///     Some(Document::Cnpj(cnpj)) => assert_eq!(cnpj.digits(), "11222333000181"),
+///     other => panic!("expected a CNPJ, got {:?}", other),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Document {
+    /// CPF
+    Cpf(Cpf),
+    /// CNPJ, numeric or alphanumeric
+    Cnpj(Cnpj),
+    /// CEP
+    Cep(Cep),
+    /// Phone number
+    Phone(Phone),
+    /// PIX key
+    PixKey(PixKey),
+    /// Any other kind, with the normalized value
+    Other(DocumentKind, String),
+}
+
+impl Document {
+    /// Validate the input as a document of the given kind
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::document::Document;
+    /// use pleme_brazilian_validators::DocumentKind;
+    ///
+    /// let cep = Document::validate_as(DocumentKind::Cep, "01310-100").unwrap();
+    /// assert_eq!(cep.kind(), DocumentKind::Cep);
+    /// assert_eq!(cep.to_string(), "01310-100");
+    ///
+    /// // An 11-digit mobile number is not a CPF
+    /// assert!(Document::validate_as(DocumentKind::Cpf, "11987654321").is_err());
+    /// ```
+    pub fn validate_as(kind: DocumentKind, input: &str) -> ValidationResult<Document> {
+        match kind {
+            DocumentKind::Cpf => Cpf::parse(input).map(Document::Cpf),
+            DocumentKind::Cnpj => Cnpj::parse(input).map(Document::Cnpj),
+            DocumentKind::Cep => Cep::parse(input).map(Document::Cep),
+            DocumentKind::Phone => Phone::parse(input).map(Document::Phone),
+            DocumentKind::PixKey => PixKey::parse(input).map(Document::PixKey),
+            _ => kind.validate(input).map(|value| Document::Other(kind, value)),
+        }
+    }
+
+    /// Kind of the document
+    pub fn kind(&self) -> DocumentKind {
+        match self {
+            Document::Cpf(_) => DocumentKind::Cpf,
+            Document::Cnpj(_) => DocumentKind::Cnpj,
+            Document::Cep(_) => DocumentKind::Cep,
+            Document::Phone(_) => DocumentKind::Phone,
+            Document::PixKey(_) => DocumentKind::PixKey,
+            Document::Other(kind, _) => *kind,
+        }
+    }
+}
+
+impl fmt::Display for Document {
+    /// Formats CPFs, CNPJs, CEPs and phones with their usual punctuation;
+    /// other documents are printed normalized
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Document::Cpf(cpf) => cpf.fmt(f),
+            Document::Cnpj(cnpj) => cnpj.fmt(f),
+            Document::Cep(cep) => cep.fmt(f),
+            Document::Phone(phone) => phone.fmt(f),
+            Document::PixKey(key) => key.fmt(f),
+            Document::Other(_, value) => f.write_str(value),
+        }
+    }
+}
+
+/// Kinds tried by [`detect`], in order of precedence
+const DETECTED_KINDS: [DocumentKind; 5] = [
+    DocumentKind::Cnpj,
+    DocumentKind::Cpf,
+    DocumentKind::Phone,
+    DocumentKind::Cep,
+    DocumentKind::PixKey,
+];
+
+/// Detect and validate a CPF, CNPJ, CEP, phone number or PIX key
+///
+/// Kinds are tried in the order CNPJ, CPF, phone, CEP, PIX key; the first
+/// one that validates wins. An 11-digit number that is both a valid CPF and
+/// a valid mobile number is therefore a CPF: use
+/// [`Document::validate_as`] when the kind is known. Only the detected
+/// kind is reported to the validation hook.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::document::{detect, Document};
+/// use pleme_brazilian_validators::DocumentKind;
+///
+/// assert!(matches!(detect("123.456.789-09"), Some(Document::Cpf(_))));
+/// assert_eq!(detect("(11) 98765-4321").map(|d| d.kind()), Some(DocumentKind::Phone));
+/// assert_eq!(detect("user@example.com").map(|d| d.kind()), Some(DocumentKind::PixKey));
+/// assert_eq!(detect("123.456.789-00"), None);
+/// ```
+pub fn detect(input: &str) -> Option<Document> {
+    let kind = DETECTED_KINDS.into_iter().find(|kind| match kind {
+        DocumentKind::Cnpj => cnpj::validate_unobserved(input).is_ok(),
+        DocumentKind::Cpf => cpf::validate_unobserved(input).is_ok(),
+        DocumentKind::Phone => phone::validate_unobserved(input).is_ok(),
+        DocumentKind::Cep => cep::validate_unobserved(input).is_ok(),
+        _ => pix::validate_unobserved(input).is_ok(),
+    })?;
+    Document::validate_as(kind, input).ok()
+}
+
+/// Error returned when parsing an unknown [`DocumentKind`] identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownDocumentKind(pub String);
+
+impl fmt::Display for UnknownDocumentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tipo de documento desconhecido: {}", self.0)
+    }
+}
+
+impl core::error::Error for UnknownDocumentKind {}
+
+impl FromStr for DocumentKind {
+    type Err = UnknownDocumentKind;
+
+    /// Parse a stable identifier as returned by [`DocumentKind::as_str`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DocumentKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| UnknownDocumentKind(s.to_string()))
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for DocumentKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for DocumentKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifiers_round_trip() {
+        for kind in DocumentKind::iter() {
+            assert_eq!(kind.as_str().parse(), Ok(kind));
+        }
+        assert_eq!(
+            "CPF".parse::<DocumentKind>(),
+            Err(UnknownDocumentKind("CPF".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            detect("11222333000181"),
+            Some(Document::Cnpj(Cnpj::parse("11222333000181").unwrap()))
+        );
+        assert_eq!(
+            detect("12.ABC.345/01DE-35").map(|d| d.kind()),
+            Some(DocumentKind::Cnpj)
+        );
+        assert_eq!(detect("52998224725").map(|d| d.kind()), Some(DocumentKind::Cpf));
+        assert_eq!(
+            detect("+55 21 3456-7890").map(|d| d.to_string()),
+            Some("+55 (21) 3456-7890".to_string())
+        );
+        assert_eq!(detect("01310-100").map(|d| d.kind()), Some(DocumentKind::Cep));
+        assert_eq!(
+            detect("123e4567-e89b-12d3-a456-426614174000").map(|d| d.kind()),
+            Some(DocumentKind::PixKey)
+        );
+        assert_eq!(detect(""), None);
+        assert_eq!(detect("pedido 42"), None);
+    }
+
+    #[test]
+    fn test_brazilian_document_impls() {
+        fn check<D: BrazilianDocument>(input: &str) -> (DocumentKind, String, String, String) {
+            let document = D::validate(input).unwrap();
+            let normalized = document.normalized().to_string();
+            assert_eq!(D::KIND.validate(input), Ok(normalized.clone()));
+            (D::KIND, normalized, document.formatted(), document.masked())
+        }
+
+        assert_eq!(
+            check::<Cnpj>("11.222.333/0001-81"),
+            (
+                DocumentKind::Cnpj,
+                "11222333000181".to_string(),
+                "11.222.333/0001-81".to_string(),
+                "11.***.***/**01-81".to_string()
+            )
+        );
+        assert_eq!(check::<Phone>("11987654321").1, "+5511987654321");
+        assert_eq!(check::<Cep>("01310-100").0, DocumentKind::Cep);
+        assert_eq!(check::<Cpf>("123.456.789-09").3, "123.***.***-09");
+        assert_eq!(check::<PixKey>("User@Example.com").1, "user@example.com");
+        assert!(Cpf::validate("123.456.789-00").is_err());
+    }
+
+    #[test]
+    fn test_validate_as() {
+        assert_eq!(
+            Document::validate_as(DocumentKind::PixKey, "52998224725"),
+            Ok(Document::PixKey(PixKey::parse("52998224725").unwrap()))
+        );
+        let plate = Document::validate_as(DocumentKind::Placa, "ABC1D23").unwrap();
+        assert_eq!(plate.kind(), DocumentKind::Placa);
+        assert!(Document::validate_as(DocumentKind::Cnpj, "52998224725").is_err());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_serde_uses_identifiers() {
+        assert_eq!(
+            serde_json::to_string(&DocumentKind::Cnpj).unwrap(),
+            r#""cnpj""#
+        );
+        assert_eq!(
+            serde_json::from_str::<DocumentKind>(r#""ibge_code""#).unwrap(),
+            DocumentKind::IbgeCode
+        );
+    }
+}
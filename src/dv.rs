@@ -0,0 +1,238 @@
+//! Check-digit (DV) building blocks
+//!
+//! The modulo 11 and modulo 10 routines behind the CPF, CNPJ and IBGE
+//! validators, exposed so that documents the crate does not cover yet
+//! (bank-specific fields, state registrations, municipal codes) can be
+//! checked with the same tested primitives instead of ad-hoc copies.
+//!
+//! Digits are passed as numeric values (`0..=9`), most significant first,
+//! as returned by [`digits`]. The functions return `None` rather than a
+//! wrong digit when the input is malformed.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::dv;
+//!
+//! // CPF first check digit: weights 10 down to 2
+//! let base = dv::digits("123456789").unwrap();
+//! assert_eq!(dv::mod11(&base, &dv::cyclic_weights(9, 2, 11)), Some(0));
+//!
+//! // FEBRABAN modulo 10: first field of a boleto line, 00190.5009(5)
+//! assert_eq!(dv::mod10_alternating(&dv::digits("001905009").unwrap()), Some(5));
+//! ```
+
+use alloc::vec::Vec;
+
+/// How a modulo 11 remainder becomes a check digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Mod11Remainder {
+    /// `11 - remainder`, or 0 when the remainder is 0 or 1 (CPF, CNPJ)
+    #[default]
+    Complement,
+    /// `11 - remainder`, or 1 when that would be 0, 10 or 11 (FEBRABAN
+    /// boleto general check digit)
+    ComplementOrOne,
+    /// `11 - remainder`, or 0 when that would be 10 or 11 (NF-e access key)
+    ComplementOrZero,
+    /// The remainder itself, or 0 when it is 10 (título de eleitor)
+    Remainder,
+}
+
+impl Mod11Remainder {
+    /// Turn a remainder (`0..11`) into a check digit
+    pub fn check_digit(&self, remainder: u32) -> u32 {
+        match self {
+            Mod11Remainder::Complement if remainder < 2 => 0,
+            Mod11Remainder::ComplementOrOne if remainder < 2 => 1,
+            Mod11Remainder::ComplementOrOne if remainder == 10 => 1,
+            Mod11Remainder::ComplementOrZero if remainder < 2 => 0,
+            Mod11Remainder::Remainder if remainder == 10 => 0,
+            Mod11Remainder::Remainder => remainder,
+            _ => 11 - remainder,
+        }
+    }
+}
+
+/// Parse a string of ASCII digits
+///
+/// Returns `None` if any character is not an ASCII digit; strip
+/// punctuation first (e.g. with the module's `normalize`).
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::dv::digits;
+///
+/// assert_eq!(digits("1203"), Some(vec![1, 2, 0, 3]));
+/// assert_eq!(digits("12-3"), None);
+/// ```
+pub fn digits(s: &str) -> Option<Vec<u32>> {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_digit() {
+                c.to_digit(10)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Weights cycling from `min` to `max`, applied right to left
+///
+/// Returns `len` weights in digit order (most significant first) where the
+/// rightmost digit gets `min`, the next one `min + 1`, and so on, wrapping
+/// back to `min` after `max`. This is the weighting of most Brazilian
+/// modulo 11 schemes.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::dv::cyclic_weights;
+///
+/// assert_eq!(cyclic_weights(4, 2, 9), vec![5, 4, 3, 2]);
+/// assert_eq!(cyclic_weights(12, 2, 9), vec![5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]);
+/// ```
+pub fn cyclic_weights(len: usize, min: u32, max: u32) -> Vec<u32> {
+    let span = max.saturating_sub(min) as usize + 1;
+    (0..len)
+        .rev()
+        .map(|position| min + (position % span) as u32)
+        .collect()
+}
+
+/// Weighted sum of digits, or `None` on mismatched lengths or non-digits
+fn weighted_sum(digits: &[u32], weights: &[u32]) -> Option<u32> {
+    if digits.len() != weights.len() || digits.iter().any(|&d| d > 9) {
+        return None;
+    }
+    digits
+        .iter()
+        .zip(weights)
+        .try_fold(0u32, |sum, (d, w)| sum.checked_add(d.checked_mul(*w)?))
+}
+
+/// Modulo 11 check digit with the CPF/CNPJ remainder rule
+///
+/// Shorthand for [`mod11_with`] and [`Mod11Remainder::Complement`].
+///
+/// # Arguments
+/// * `digits` - Digits covered by the check digit
+/// * `weights` - One weight per digit, in the same order
+///
+/// # Returns
+/// * `Some(u32)` - Check digit
+/// * `None` - `digits` and `weights` differ in length, or a digit is above 9
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::dv;
+///
+/// let base = dv::digits("112223330001").unwrap();
+/// assert_eq!(dv::mod11(&base, &dv::cyclic_weights(12, 2, 9)), Some(8));
+/// assert_eq!(dv::mod11(&base, &[2, 3]), None);
+/// ```
+pub fn mod11(digits: &[u32], weights: &[u32]) -> Option<u32> {
+    mod11_with(digits, weights, Mod11Remainder::Complement)
+}
+
+/// Modulo 11 check digit with a configurable remainder rule
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::dv::{self, Mod11Remainder};
+///
+/// let digits = [0, 0, 0, 0];
+/// let weights = [5, 4, 3, 2];
+/// assert_eq!(dv::mod11_with(&digits, &weights, Mod11Remainder::Complement), Some(0));
+/// assert_eq!(dv::mod11_with(&digits, &weights, Mod11Remainder::ComplementOrOne), Some(1));
+/// ```
+pub fn mod11_with(digits: &[u32], weights: &[u32], remainder: Mod11Remainder) -> Option<u32> {
+    weighted_sum(digits, weights).map(|sum| remainder.check_digit(sum % 11))
+}
+
+/// Modulo 10 check digit with alternating 2-1 weights
+///
+/// The rightmost digit is weighted 2, the next one 1, and so on; products
+/// above 9 contribute the sum of their digits, and the check digit
+/// completes the total to the next multiple of 10. This is the FEBRABAN
+/// modulo 10 used in boleto fields, the IBGE municipality code rule and
+/// the Luhn algorithm.
+///
+/// # Returns
+/// * `Some(u32)` - Check digit
+/// * `None` - A digit is above 9
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::dv;
+///
+/// assert_eq!(dv::mod10_alternating(&[3, 5, 5, 0, 3, 0]), Some(8)); // São Paulo
+/// assert_eq!(dv::mod10_alternating(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1]), Some(3)); // Luhn
+/// ```
+pub fn mod10_alternating(digits: &[u32]) -> Option<u32> {
+    let weights: Vec<u32> = (0..digits.len())
+        .rev()
+        .map(|position| if position % 2 == 0 { 2 } else { 1 })
+        .collect();
+
+    if digits.iter().any(|&d| d > 9) {
+        return None;
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .zip(weights)
+        .map(|(d, w)| {
+            let product = d * w;
+            if product > 9 {
+                product - 9
+            } else {
+                product
+            }
+        })
+        .sum();
+
+    Some((10 - sum % 10) % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_remainder_rules() {
+        let rules = [
+            Mod11Remainder::Complement,
+            Mod11Remainder::ComplementOrOne,
+            Mod11Remainder::ComplementOrZero,
+            Mod11Remainder::Remainder,
+        ];
+        let table: Vec<[u32; 4]> = [0, 1, 2, 10]
+            .iter()
+            .map(|&r| rules.map(|rule| rule.check_digit(r)))
+            .collect();
+
+        assert_eq!(table[0], [0, 1, 0, 0]);
+        assert_eq!(table[1], [0, 1, 0, 1]);
+        assert_eq!(table[2], [9, 9, 9, 2]);
+        assert_eq!(table[3], [1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_cyclic_weights() {
+        assert_eq!(
+            cyclic_weights(10, 2, 11),
+            vec![11, 10, 9, 8, 7, 6, 5, 4, 3, 2]
+        );
+        assert_eq!(cyclic_weights(3, 2, 2), vec![2, 2, 2]);
+        assert!(cyclic_weights(0, 2, 9).is_empty());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(mod11(&[1, 10], &[2, 3]), None);
+        assert_eq!(mod11(&[2, 2], &[u32::MAX, u32::MAX]), None);
+        assert_eq!(mod10_alternating(&[12]), None);
+        assert_eq!(mod10_alternating(&[]), Some(0));
+    }
+}
@@ -0,0 +1,308 @@
+//! Postal address formatting for Correios shipping labels
+//!
+//! [`format_label`] lays out a validated address the way Correios sorting
+//! expects it on a label: street line, neighborhood, city and UF, then the
+//! CEP alone on the last line. Lines longer than [`MAX_LINE_LEN`] are
+//! shortened with the standard abbreviations (`Avenida` → `Av.`); a
+//! complement that still does not fit moves to its own line, and other
+//! lines are truncated as a last resort.
+//!
+//! Names are written accent-free when
+//! [`ascii_names`](crate::config::ValidationOptions::ascii_names) is enabled,
+//! for label printers limited to ASCII.
+
+use crate::ascii;
+use crate::cep;
+use crate::config::Config;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::uf::Uf;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Maximum characters per label line
+pub const MAX_LINE_LEN: usize = 40;
+
+/// Written on the street line when the address has no number
+const NO_NUMBER: &str = "S/N";
+
+/// Standard abbreviations, keyed by the accent-free lowercase word
+const ABBREVIATIONS: [(&str, &str); 29] = [
+    ("alameda", "Al."),
+    ("andar", "And."),
+    ("apartamento", "Apto."),
+    ("avenida", "Av."),
+    ("bloco", "Bl."),
+    ("conjunto", "Cj."),
+    ("coronel", "Cel."),
+    ("doutor", "Dr."),
+    ("edificio", "Ed."),
+    ("engenheiro", "Eng."),
+    ("estrada", "Estr."),
+    ("general", "Gen."),
+    ("governador", "Gov."),
+    ("jardim", "Jd."),
+    ("largo", "Lgo."),
+    ("lote", "Lt."),
+    ("marechal", "Mal."),
+    ("parque", "Pq."),
+    ("praca", "Pç."),
+    ("presidente", "Pres."),
+    ("professor", "Prof."),
+    ("quadra", "Qd."),
+    ("residencial", "Res."),
+    ("rodovia", "Rod."),
+    ("rua", "R."),
+    ("sala", "Sl."),
+    ("santa", "Sta."),
+    ("santo", "Sto."),
+    ("travessa", "Tv."),
+];
+
+/// Address to print on a shipping label
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PostalAddress {
+    /// Street (logradouro), including its type (`Avenida Paulista`)
+    pub street: String,
+    /// Building number; `None` is printed as `S/N`
+    pub number: Option<String>,
+    /// Complement (`Apartamento 12`, `Bloco B`)
+    pub complement: Option<String>,
+    /// Neighborhood (bairro)
+    pub neighborhood: String,
+    /// Municipality
+    pub city: String,
+    /// Federative unit
+    pub uf: Uf,
+    /// CEP, in any accepted format
+    pub cep: String,
+}
+
+/// Render an address in the Correios label layout
+///
+/// # Arguments
+/// * `address` - Address to print
+///
+/// # Returns
+/// * `Ok(String)` - Label lines separated by `\n`, each at most
+///   [`MAX_LINE_LEN`] characters
+/// * `Err(BrazilianValidationError)` - Invalid CEP, or blank street or city
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::endereco::{format_label, PostalAddress};
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// let address = PostalAddress {
+///     street: "Avenida Presidente Juscelino Kubitschek".to_string(),
+///     number: Some("1909".to_string()),
+///     complement: Some("Conjunto 12".to_string()),
+///     neighborhood: "Vila Nova Conceição".to_string(),
+///     city: "São Paulo".to_string(),
+///     uf: Uf::SP,
+///     cep: "04543907".to_string(),
+/// };
+///
+/// assert_eq!(
+///     format_label(&address).unwrap(),
+///     "Av. Pres. Juscelino Kubitschek, 1909\n\
+///      Conjunto 12\n\
+///      Vila Nova Conceição\n\
+///      São Paulo - SP\n\
+///      04543-907"
+/// );
+/// ```
+pub fn format_label(address: &PostalAddress) -> ValidationResult<String> {
+    let cep = cep::validate_unobserved(&address.cep)?;
+    require_text(&address.street)?;
+    require_text(&address.city)?;
+
+    let number = address
+        .number
+        .as_deref()
+        .map(str::trim)
+        .filter(|number| !number.is_empty())
+        .unwrap_or(NO_NUMBER);
+    let street = format!("{}, {}", address.street.trim(), number);
+    let complement = address
+        .complement
+        .as_deref()
+        .map(str::trim)
+        .filter(|complement| !complement.is_empty());
+
+    let mut lines = Vec::new();
+    match complement {
+        Some(complement) => {
+            let combined = shorten(&format!("{} - {}", street, complement));
+            if combined.chars().count() <= MAX_LINE_LEN {
+                lines.push(combined);
+            } else {
+                lines.push(fit(&street));
+                lines.push(fit(complement));
+            }
+        }
+        None => lines.push(fit(&street)),
+    }
+    if !address.neighborhood.trim().is_empty() {
+        lines.push(fit(address.neighborhood.trim()));
+    }
+    lines.push(fit(&format!(
+        "{} - {}",
+        address.city.trim(),
+        address.uf.abbreviation()
+    )));
+    lines.push(cep::format(&cep));
+
+    Ok(lines.join("\n"))
+}
+
+/// Reject blank mandatory fields
+fn require_text(value: &str) -> ValidationResult<()> {
+    if value.trim().is_empty() {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: MAX_LINE_LEN,
+            actual: 0,
+        });
+    }
+    Ok(())
+}
+
+/// Make a line fit the label, truncating it if abbreviations are not enough
+fn fit(line: &str) -> String {
+    truncate(&shorten(line))
+}
+
+/// Fold accents per config and abbreviate the line if it is too long
+fn shorten(line: &str) -> String {
+    let ascii_names = Config::current().ascii_names;
+    let line = if ascii_names {
+        ascii::to_ascii(line).into_owned()
+    } else {
+        line.to_string()
+    };
+    if line.chars().count() <= MAX_LINE_LEN {
+        return line;
+    }
+
+    let abbreviated = abbreviate(&line);
+    if ascii_names {
+        // Abbreviations may bring accents back (Pç.)
+        ascii::to_ascii(&abbreviated).into_owned()
+    } else {
+        abbreviated
+    }
+}
+
+/// Replace every word with a standard abbreviation
+fn abbreviate(line: &str) -> String {
+    line.split(' ')
+        .map(|word| {
+            let core = word.trim_end_matches(',');
+            let key = ascii::to_ascii(core).to_lowercase();
+            match ABBREVIATIONS.binary_search_by_key(&key.as_str(), |&(word, _)| word) {
+                Ok(i) => format!("{}{}", ABBREVIATIONS[i].1, &word[core.len()..]),
+                Err(_) => word.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Cut a line at [`MAX_LINE_LEN`] characters
+fn truncate(line: &str) -> String {
+    line.chars()
+        .take(MAX_LINE_LEN)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address() -> PostalAddress {
+        PostalAddress {
+            street: "Rua Augusta".to_string(),
+            number: Some("1500".to_string()),
+            complement: None,
+            neighborhood: "Consolação".to_string(),
+            city: "São Paulo".to_string(),
+            uf: Uf::SP,
+            cep: "01304-001".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_abbreviations_are_sorted() {
+        assert!(ABBREVIATIONS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_short_lines_are_kept() {
+        assert_eq!(
+            format_label(&address()).unwrap(),
+            "Rua Augusta, 1500\nConsolação\nSão Paulo - SP\n01304-001"
+        );
+    }
+
+    #[test]
+    fn test_missing_number_and_neighborhood() {
+        let mut address = address();
+        address.number = Some(" ".to_string());
+        address.neighborhood = String::new();
+        assert_eq!(
+            format_label(&address).unwrap(),
+            "Rua Augusta, S/N\nSão Paulo - SP\n01304-001"
+        );
+    }
+
+    #[test]
+    fn test_long_lines_are_abbreviated_then_truncated() {
+        let mut address = address();
+        address.street = "Travessa Engenheiro Marechal".to_string();
+        address.complement = Some("Edifício Central Bloco B".to_string());
+        assert_eq!(
+            format_label(&address).unwrap().lines().next(),
+            Some("Tv. Eng. Mal., 1500 - Ed. Central Bl. B")
+        );
+
+        // The complement moves to its own line, truncated if needed
+        address.complement = Some("X".repeat(60));
+        let label = format_label(&address).unwrap();
+        let lines: Vec<_> = label.lines().collect();
+        assert_eq!(lines[0], "Travessa Engenheiro Marechal, 1500");
+        assert_eq!(lines[1], "X".repeat(MAX_LINE_LEN));
+
+        address.street = "Rua ".repeat(15);
+        let label = format_label(&address).unwrap();
+        assert_eq!(label.lines().next().unwrap().chars().count(), MAX_LINE_LEN);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ascii_names() {
+        let options = Config::builder().ascii_names(true).build();
+        Config::with_options(options, || {
+            let mut address = address();
+            address.street = "Praça Doutor João Mendes Júnior Filho".to_string();
+            address.number = Some("100".to_string());
+            let label = format_label(&address).unwrap();
+            assert_eq!(
+                label,
+                "Pc. Dr. Joao Mendes Junior Filho, 100\nConsolacao\nSao Paulo - SP\n01304-001"
+            );
+        });
+    }
+
+    #[test]
+    fn test_invalid_address() {
+        let mut address = address();
+        address.cep = "123".to_string();
+        assert!(format_label(&address).is_err());
+
+        let mut address = self::address();
+        address.city = " ".to_string();
+        assert!(format_label(&address).is_err());
+    }
+}
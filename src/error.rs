@@ -1,5 +1,12 @@
 //! Error types for Brazilian validators
 
+use crate::config::Config;
+use crate::document::DocumentKind;
+use crate::locale::Locale;
+use alloc::format;
+use alloc::string::{String, ToString};
+#[cfg(feature = "graphql")]
+use async_graphql::ErrorExtensions;
 use thiserror::Error;
 
 /// Result type alias for Brazilian validation operations
@@ -30,11 +37,11 @@ pub enum BrazilianValidationError {
 
     /// Invalid document format (generic)
     #[error("Formato de documento inválido: {document_type}")]
-    InvalidDocumentFormat { document_type: String },
+    InvalidDocumentFormat { document_type: DocumentKind },
 
     /// Document has invalid check digits
     #[error("Dígitos verificadores inválidos para {document_type}")]
-    InvalidCheckDigits { document_type: String },
+    InvalidCheckDigits { document_type: DocumentKind },
 
     /// Document contains invalid characters
     #[error("Caracteres inválidos no documento")]
@@ -43,6 +50,19 @@ pub enum BrazilianValidationError {
     /// Document has invalid length
     #[error("Tamanho inválido: esperado {expected}, recebido {actual}")]
     InvalidLength { expected: usize, actual: usize },
+
+    /// Document is valid but reserved for tests or examples
+    #[error("Documento de teste ou reservado: {document_type}")]
+    Denylisted { document_type: DocumentKind },
+
+    /// Documents that must agree with each other do not (e.g. the amount
+    /// of a boleto and of its PIX QR code)
+    #[error("Dados inconsistentes: {document_type}")]
+    Inconsistent { document_type: DocumentKind },
+
+    /// Barcode rendering options would produce an oversized image
+    #[error("Imagem grande demais para ser gerada")]
+    ImageTooLarge,
 }
 
 impl BrazilianValidationError {
@@ -83,23 +103,103 @@ impl BrazilianValidationError {
             Self::InvalidCheckDigits { .. } => "INVALID_CHECK_DIGITS",
             Self::InvalidCharacters => "INVALID_CHARACTERS",
             Self::InvalidLength { .. } => "INVALID_LENGTH",
+            Self::Denylisted { .. } => "DENYLISTED_DOCUMENT",
+            Self::Inconsistent { .. } => "INCONSISTENT_DATA",
+            Self::ImageTooLarge => "IMAGE_TOO_LARGE",
         }
     }
 
-    /// Get document type that failed validation
-    pub fn document_type(&self) -> &str {
+    /// Get the kind of document that failed validation
+    ///
+    /// `None` for errors raised before the document could be identified
+    /// ([`InvalidCharacters`](Self::InvalidCharacters),
+    /// [`InvalidLength`](Self::InvalidLength)).
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::{cpf, DocumentKind};
+    ///
+    /// let err = cpf::validate("123.456.789-00").unwrap_err();
+    /// assert_eq!(err.document_type(), Some(DocumentKind::Cpf));
+    /// ```
+    pub fn document_type(&self) -> Option<DocumentKind> {
         match self {
-            Self::InvalidCpf(_) => "CPF",
-            Self::InvalidCnpj(_) => "CNPJ",
-            Self::InvalidCep(_) => "CEP",
-            Self::InvalidPhone(_) => "phone",
-            Self::InvalidPixKey(_) => "PIX key",
-            Self::InvalidDocumentFormat { document_type } => document_type,
-            Self::InvalidCheckDigits { document_type } => document_type,
-            Self::InvalidCharacters => "document",
-            Self::InvalidLength { .. } => "document",
+            Self::InvalidCpf(_) => Some(DocumentKind::Cpf),
+            Self::InvalidCnpj(_) => Some(DocumentKind::Cnpj),
+            Self::InvalidCep(_) => Some(DocumentKind::Cep),
+            Self::InvalidPhone(_) => Some(DocumentKind::Phone),
+            Self::InvalidPixKey(_) => Some(DocumentKind::PixKey),
+            Self::InvalidDocumentFormat { document_type }
+            | Self::InvalidCheckDigits { document_type }
+            | Self::Denylisted { document_type }
+            | Self::Inconsistent { document_type } => Some(*document_type),
+            Self::InvalidCharacters | Self::InvalidLength { .. } | Self::ImageTooLarge => None,
         }
     }
+
+    /// Get the error message in the given locale
+    ///
+    /// [`Locale::PtBr`] returns the `Display` message. Other locales
+    /// translate the error kind; free-text details passed to constructors
+    /// such as [`invalid_cpf`](Self::invalid_cpf) are Portuguese and are
+    /// therefore omitted.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::{BrazilianValidationError, DocumentKind, Locale};
+    ///
+    /// let err = BrazilianValidationError::InvalidCheckDigits { document_type: DocumentKind::Cpf };
+    /// assert_eq!(err.localized(Locale::PtBr), "Dígitos verificadores inválidos para CPF");
+    /// assert_eq!(err.localized(Locale::En), "Invalid check digits for CPF");
+    /// ```
+    pub fn localized(&self, locale: Locale) -> String {
+        match locale {
+            Locale::PtBr => self.to_string(),
+            Locale::En => match self {
+                Self::InvalidCpf(_) => "Invalid CPF".to_string(),
+                Self::InvalidCnpj(_) => "Invalid CNPJ".to_string(),
+                Self::InvalidCep(_) => "Invalid CEP".to_string(),
+                Self::InvalidPhone(_) => "Invalid phone number".to_string(),
+                Self::InvalidPixKey(_) => "Invalid PIX key".to_string(),
+                Self::InvalidDocumentFormat { document_type } => {
+                    format!("Invalid document format: {}", document_type)
+                }
+                Self::InvalidCheckDigits { document_type } => {
+                    format!("Invalid check digits for {}", document_type)
+                }
+                Self::InvalidCharacters => "Document contains invalid characters".to_string(),
+                Self::InvalidLength { expected, actual } => {
+                    format!("Invalid length: expected {}, got {}", expected, actual)
+                }
+                Self::Denylisted { document_type } => {
+                    format!("Test or reserved document: {}", document_type)
+                }
+                Self::Inconsistent { document_type } => {
+                    format!("Inconsistent data: {}", document_type)
+                }
+                Self::ImageTooLarge => "Image too large to render".to_string(),
+            },
+        }
+    }
+
+    /// Get the error message in the configured locale
+    ///
+    /// Uses the locale installed with [`Config`](crate::config::Config),
+    /// Brazilian Portuguese by default.
+    pub fn message(&self) -> String {
+        self.localized(Config::current().locale)
+    }
+
+    /// Convert into a deserialization error
+    ///
+    /// The serde message is the error code followed by the message in the
+    /// configured locale (`INVALID_CHECK_DIGITS: Dígitos verificadores
+    /// inválidos para CPF`), so API layers can report structured errors
+    /// from a failed request body.
+    #[cfg(feature = "serialization")]
+    pub(crate) fn into_de_error<E: serde::de::Error>(self) -> E {
+        E::custom(format_args!("{}: {}", self.error_code(), self.message()))
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -111,19 +211,46 @@ impl serde::Serialize for BrazilianValidationError {
         use serde::ser::SerializeStruct;
         let mut state = serializer.serialize_struct("BrazilianValidationError", 3)?;
         state.serialize_field("code", self.error_code())?;
-        state.serialize_field("document_type", self.document_type())?;
+        state.serialize_field("document_type", &self.document_type().map(|k| k.as_str()))?;
         state.serialize_field("message", &self.to_string())?;
         state.end()
     }
 }
 
+/// GraphQL error with the error code and document type as extensions
+///
+/// `?` already converts validation errors into `async_graphql::Error`
+/// through its `Display` implementation, keeping only the message; call
+/// `.extend()` to also report `code` and `document_type`.
 #[cfg(feature = "graphql")]
-impl From<BrazilianValidationError> for async_graphql::Error {
-    fn from(err: BrazilianValidationError) -> Self {
-        async_graphql::Error::new(err.to_string())
-            .extend_with(|_, e| {
-                e.set("code", err.error_code());
-                e.set("document_type", err.document_type());
-            })
+impl ErrorExtensions for BrazilianValidationError {
+    fn extend(&self) -> async_graphql::Error {
+        async_graphql::Error::new(self.to_string()).extend_with(|_, e| {
+            e.set("code", self.error_code());
+            if let Some(kind) = self.document_type() {
+                e.set("document_type", kind.as_str());
+            }
+        })
+    }
+}
+
+#[cfg(all(test, feature = "graphql"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graphql_extensions() {
+        let error = BrazilianValidationError::InvalidCpf("dígitos".to_string()).extend();
+        let extensions = error.extensions.unwrap();
+
+        assert_eq!(error.message, "CPF inválido: dígitos");
+        assert_eq!(
+            extensions.get("code"),
+            Some(&async_graphql::Value::from("INVALID_CPF"))
+        );
+        assert_eq!(
+            extensions.get("document_type"),
+            Some(&async_graphql::Value::from("cpf"))
+        );
     }
 }
@@ -0,0 +1,155 @@
+//! Extraction of documents from free text
+//!
+//! Where [`scan::redact`](crate::scan::redact) hides the documents of a
+//! text, [`scan`] reports them: invoices, e-mails and OCR output can be
+//! searched for CPFs, CNPJs, CEPs, phone numbers and PIX keys, for
+//! data-loss prevention or to feed a document ingestion pipeline.
+//!
+//! Detection follows the same rules as redaction: formatted documents are
+//! found by shape alone, so a typo in a formatted CPF is still reported
+//! (with [`FoundDocument::valid`] set to `false`), while bare digit runs
+//! are only reported when they pass full validation.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::extract;
+//! use pleme_brazilian_validators::DocumentKind;
+//!
+//! let text = "Tomador: CNPJ 11.222.333/0001-81, CEP 01310-100";
+//! let found = extract::scan(text);
+//!
+//! assert_eq!(found[0].kind, DocumentKind::Cnpj);
+//! assert_eq!(found[0].raw, "11.222.333/0001-81");
+//! assert_eq!(&text[found[1].span.clone()], "01310-100");
+//! ```
+
+use crate::document::DocumentKind;
+use crate::{cep, cnpj, cpf, phone, pix, scan as detection};
+use std::ops::Range;
+
+/// Document found in a text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundDocument<'a> {
+    /// Detected kind of document
+    pub kind: DocumentKind,
+    /// Byte range of the match in the scanned text
+    pub span: Range<usize>,
+    /// Matched text, as written
+    pub raw: &'a str,
+    /// Whether the match passes full validation (check digits included)
+    pub valid: bool,
+}
+
+impl FoundDocument<'_> {
+    /// Normalized document, if the match is valid
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::extract::scan;
+    ///
+    /// let found = scan("ligue (11) 98765-4321");
+    /// assert_eq!(found[0].normalized().as_deref(), Some("+5511987654321"));
+    /// ```
+    pub fn normalized(&self) -> Option<String> {
+        normalize(self.kind, self.raw)
+    }
+}
+
+/// Find every CPF, CNPJ, CEP, phone number and PIX key in a text
+///
+/// Documents are returned in order of appearance and never overlap.
+/// Validation hooks are not notified: scanning a text is not a validation
+/// attempt.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::extract::scan;
+/// use pleme_brazilian_validators::DocumentKind;
+///
+/// let found = scan("CPF 111.111.111-11 ou 123.456.789-09, pix user@example.com");
+///
+/// assert_eq!(found.len(), 3);
+/// assert!(!found[0].valid); // Repeated digits
+/// assert!(found[1].valid);
+/// assert_eq!(found[2].kind, DocumentKind::PixKey);
+/// ```
+pub fn scan(text: &str) -> Vec<FoundDocument<'_>> {
+    detection::detect(text)
+        .into_iter()
+        .map(|found| {
+            let raw = &text[found.start..found.end];
+            FoundDocument {
+                kind: found.kind,
+                span: found.start..found.end,
+                raw,
+                valid: normalize(found.kind, raw).is_some(),
+            }
+        })
+        .collect()
+}
+
+/// Validate a match with its module's validator, without notifying hooks
+fn normalize(kind: DocumentKind, raw: &str) -> Option<String> {
+    match kind {
+        DocumentKind::Cpf => cpf::validate_unobserved(raw).ok(),
+        DocumentKind::Cnpj => cnpj::validate_unobserved(raw).ok(),
+        DocumentKind::Cep => cep::validate_unobserved(raw).ok(),
+        DocumentKind::Phone => phone::validate_unobserved(raw).ok(),
+        DocumentKind::PixKey => pix::validate_with_type_unobserved(raw)
+            .ok()
+            .map(|(_, key)| key),
+        // The scanner only detects the kinds above
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_spans_and_validity() {
+        let text = "NF emitida para 12.345.678/0001-00 (CEP 01310-100), tel 11987654321.";
+        let found = scan(text);
+
+        assert_eq!(found.len(), 3);
+        for document in &found {
+            assert_eq!(&text[document.span.clone()], document.raw);
+        }
+        assert_eq!(found[0].kind, DocumentKind::Cnpj);
+        assert!(!found[0].valid);
+        assert_eq!(found[0].normalized(), None);
+        assert_eq!(found[1].kind, DocumentKind::Cep);
+        assert!(found[1].valid);
+        assert_eq!(found[2].kind, DocumentKind::Phone);
+        assert_eq!(found[2].normalized().as_deref(), Some("+5511987654321"));
+    }
+
+    #[test]
+    fn test_scan_pix_keys() {
+        let found = scan("chaves: Pix@Example.com e 123e4567-e89b-12d3-a456-426614174000");
+        assert_eq!(found.len(), 2);
+        assert!(found
+            .iter()
+            .all(|d| d.kind == DocumentKind::PixKey && d.valid));
+    }
+
+    #[test]
+    fn test_scan_alphanumeric_cnpj() {
+        for cnpj in ["12ABC34501DE35", "12abc34501de35", "12.abc.345/01de-35"] {
+            let text = format!("fornecedor {cnpj}, ok");
+            let found = scan(&text);
+
+            assert_eq!(found.len(), 1, "{cnpj}");
+            assert_eq!(found[0].kind, DocumentKind::Cnpj);
+            assert_eq!(found[0].raw, cnpj);
+            assert_eq!(found[0].normalized().as_deref(), Some("12ABC34501DE35"));
+        }
+    }
+
+    #[test]
+    fn test_scan_without_documents() {
+        assert!(scan("pedido 12345678900 entregue em 2024-01-15").is_empty());
+        assert!(scan("").is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+//! Allocation-free formatting primitives
+//!
+//! Backs the `format_into` functions, which write into a caller-provided
+//! buffer instead of returning a `String`. Nothing here touches the heap,
+//! so the formatters can run on targets without an allocator.
+
+/// Placeholder for a digit in a [`write_pattern`] layout
+const SLOT: u8 = b'#';
+
+/// ASCII digits (and `+` signs or letters, when kept) of an input, on the stack
+pub(crate) struct Cleaned<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Cleaned<N> {
+    /// Collect the digits of `input`, plus `+` signs when `keep_plus` is set
+    ///
+    /// Returns `None` if more than `N` characters are kept.
+    pub(crate) fn new(input: &str, keep_plus: bool) -> Option<Self> {
+        Self::collect(input, |byte| {
+            byte.is_ascii_digit() || (keep_plus && byte == b'+')
+        })
+    }
+
+    /// Collect the ASCII letters and digits of `input`, letters uppercased
+    ///
+    /// Returns `None` if there are more than `N` of them.
+    pub(crate) fn alphanumeric(input: &str) -> Option<Self> {
+        Self::collect(input, |byte| byte.is_ascii_alphanumeric())
+    }
+
+    fn collect(input: &str, keep: impl Fn(u8) -> bool) -> Option<Self> {
+        let mut cleaned = Cleaned {
+            bytes: [0; N],
+            len: 0,
+        };
+        for byte in input.bytes().filter(|&byte| keep(byte)) {
+            *cleaned.bytes.get_mut(cleaned.len)? = byte.to_ascii_uppercase();
+            cleaned.len += 1;
+        }
+        Some(cleaned)
+    }
+
+    /// The collected characters
+    pub(crate) fn as_str(&self) -> &str {
+        // Only ASCII letters, digits and '+' are ever stored
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+/// Lay out `digits` in `pattern`, writing the result at the start of `buf`
+///
+/// Each `#` of the pattern takes the next digit (or letter, for
+/// alphanumeric documents); any other byte is copied as is. Returns `None`
+/// if the number of characters does not match the number of slots or the
+/// pattern does not fit in `buf`.
+pub(crate) fn write_pattern<'a>(digits: &str, pattern: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let slots = pattern.bytes().filter(|&byte| byte == SLOT).count();
+    if digits.len() != slots || !digits.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let out = buf.get_mut(..pattern.len())?;
+
+    let mut digits = digits.bytes();
+    for (slot, byte) in out.iter_mut().zip(pattern.bytes()) {
+        *slot = if byte == SLOT { digits.next()? } else { byte };
+    }
+    core::str::from_utf8(out).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cleaned_keeps_digits() {
+        let cleaned = Cleaned::<8>::new("+55 (11)", true).unwrap();
+        assert_eq!(cleaned.as_str(), "+5511");
+        let cleaned = Cleaned::<8>::new("+55 (11)", false).unwrap();
+        assert_eq!(cleaned.as_str(), "5511");
+        assert!(Cleaned::<3>::new("1234", false).is_none());
+        let cleaned = Cleaned::<8>::alphanumeric("12.abc-3").unwrap();
+        assert_eq!(cleaned.as_str(), "12ABC3");
+    }
+
+    #[test]
+    fn test_write_pattern() {
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            write_pattern("12345678", "#####-###", &mut buf),
+            Some("12345-678")
+        );
+        assert_eq!(write_pattern("1234567", "#####-###", &mut buf), None);
+        assert_eq!(write_pattern("1234567+", "#####-###", &mut buf), None);
+
+        let mut small = [0u8; 8];
+        assert_eq!(write_pattern("12345678", "#####-###", &mut small), None);
+    }
+}
@@ -0,0 +1,382 @@
+//! Random valid documents for test fixtures and staging data
+//!
+//! [`Generator`] fabricates documents with correct check digits: CPFs
+//! (optionally for a fiscal region), CNPJs (optionally for a given branch,
+//! or in the alphanumeric format), títulos de eleitor, CNHs, RENAVAM
+//! codes, PIS/PASEP and CNS numbers, NF-e access keys and boleto barcodes.
+//! Seeded generators are reproducible, so fixtures can be regenerated
+//! identically; the sequence for a seed is stable across releases.
+//!
+//! The documents are valid but random: they may belong to real people or
+//! companies. Use them for tests, never as placeholders in production data.
+//!
+//! Enabled by the `generate` feature.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::generator::Generator;
+//! use pleme_brazilian_validators::{cnpj, cpf};
+//!
+//! let mut generator = Generator::seeded(42);
+//! let customer = generator.cpf();
+//! let supplier = generator.cnpj_with_branch(1).unwrap();
+//!
+//! assert!(cpf::validate(&customer).is_ok());
+//! assert!(cnpj::is_main_branch(&supplier));
+//! assert_eq!(Generator::seeded(42).cpf(), customer);
+//! ```
+
+use crate::cnpj::{self, CnpjBase};
+use crate::uf::Uf;
+use crate::{boleto, cnh, cpf, nfe, pis, titulo_eleitor};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// Characters of the root and branch of an alphanumeric CNPJ
+const CNPJ_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generator of random valid documents
+///
+/// Uses SplitMix64, a small non-cryptographic generator: good enough for
+/// test data, unsuitable for anything secret.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Create a generator with a random seed
+    pub fn new() -> Self {
+        Self::seeded(RandomState::new().build_hasher().finish())
+    }
+
+    /// Create a reproducible generator
+    pub fn seeded(seed: u64) -> Self {
+        Generator { state: seed }
+    }
+
+    /// Generate a normalized CPF (11 digits)
+    pub fn cpf(&mut self) -> String {
+        let region = self.below(10) as u8;
+        self.cpf_in_region(region).unwrap_or_default()
+    }
+
+    /// Generate a CPF issued by a fiscal region (its 9th digit)
+    ///
+    /// # Returns
+    /// `None` if `region` is above 9.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::cpf;
+    /// use pleme_brazilian_validators::generator::Generator;
+    ///
+    /// let sao_paulo = Generator::new().cpf_in_region(8).unwrap();
+    /// assert_eq!(cpf::fiscal_region(&sao_paulo), Some(8));
+    /// ```
+    pub fn cpf_in_region(&mut self, region: u8) -> Option<String> {
+        if region > 9 {
+            return None;
+        }
+
+        loop {
+            let mut base: Vec<u32> = (0..8).map(|_| self.digit()).collect();
+            base.push(u32::from(region));
+            let check_digits = cpf::compute_check_digits(&base);
+
+            let cpf = to_string(base.iter().chain(&check_digits));
+            // Retries the (unlikely) repeated-digit sequences
+            if cpf::validate_unobserved(&cpf).is_ok() {
+                return Some(cpf);
+            }
+        }
+    }
+
+    /// Generate a normalized numeric CNPJ (14 digits), of any branch
+    pub fn cnpj(&mut self) -> String {
+        let branch = 1 + self.below(9999) as u16;
+        self.cnpj_with_branch(branch).unwrap_or_default()
+    }
+
+    /// Generate a numeric CNPJ for a branch (`1` for the headquarters)
+    ///
+    /// # Returns
+    /// `None` if `branch` is 0 or above 9999.
+    pub fn cnpj_with_branch(&mut self, branch: u16) -> Option<String> {
+        if !(1..=9999).contains(&branch) {
+            return None;
+        }
+
+        loop {
+            let root = to_string((0..8).map(|_| self.digit()).collect::<Vec<_>>().iter());
+            if let Some(cnpj) = CnpjBase::parse(&root).ok()?.branch(branch) {
+                return Some(cnpj);
+            }
+        }
+    }
+
+    /// Generate a CNPJ in the alphanumeric format introduced in 2026
+    ///
+    /// The root and branch mix digits and uppercase letters, with at least
+    /// one letter.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::cnpj;
+    /// use pleme_brazilian_validators::generator::Generator;
+    ///
+    /// let cnpj = Generator::new().alphanumeric_cnpj();
+    /// assert!(cnpj::is_alphanumeric_cnpj(&cnpj));
+    /// assert!(cnpj::validate(&cnpj).is_ok());
+    /// ```
+    pub fn alphanumeric_cnpj(&mut self) -> String {
+        loop {
+            let base: String = (0..12)
+                .map(|_| char::from(CNPJ_ALPHABET[self.below(36) as usize]))
+                .collect();
+            let Some(values) = cnpj::values(&base) else {
+                continue;
+            };
+            let check_digits = cnpj::compute_check_digits(&values);
+
+            let cnpj = format!("{}{}", base, to_string(check_digits.iter()));
+            if cnpj::is_alphanumeric_cnpj(&cnpj) && cnpj::validate_unobserved(&cnpj).is_ok() {
+                return cnpj;
+            }
+        }
+    }
+
+    /// Generate a título de eleitor (12 digits)
+    ///
+    /// The state code is one of the 27 states or `28` (abroad).
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::generator::Generator;
+    /// use pleme_brazilian_validators::titulo_eleitor;
+    ///
+    /// let titulo = Generator::new().titulo_eleitor();
+    /// assert!(titulo_eleitor::validate(&titulo).is_ok());
+    /// ```
+    pub fn titulo_eleitor(&mut self) -> String {
+        let mut base: Vec<u32> = (0..8).map(|_| self.digit()).collect();
+        let state = 1 + self.below(u64::from(titulo_eleitor::ABROAD)) as u32;
+        base.extend([state / 10, state % 10]);
+        let check_digits = titulo_eleitor::compute_check_digits(&base);
+        to_string(base.iter().chain(&check_digits))
+    }
+
+    /// Generate a CNH registration number (11 digits)
+    pub fn cnh(&mut self) -> String {
+        loop {
+            let base: Vec<u32> = (0..9).map(|_| self.digit()).collect();
+            let check_digits = cnh::compute_check_digits(&base);
+            let cnh = to_string(base.iter().chain(&check_digits));
+            // Retries the (unlikely) repeated-digit sequences
+            if !is_repeated(&cnh) {
+                return cnh;
+            }
+        }
+    }
+
+    /// Generate a RENAVAM code (11 digits)
+    pub fn renavam(&mut self) -> String {
+        // Same check digit as the PIS
+        self.pis()
+    }
+
+    /// Generate a PIS/PASEP number (11 digits)
+    pub fn pis(&mut self) -> String {
+        loop {
+            let base: Vec<u32> = (0..10).map(|_| self.digit()).collect();
+            let check_digit = pis::compute_check_digit(&base);
+            let pis = to_string(base.iter().chain([&check_digit]));
+            if !is_repeated(&pis) {
+                return pis;
+            }
+        }
+    }
+
+    /// Generate a CNS number (15 digits), definitive or provisional
+    ///
+    /// Definitive numbers (starting with 1 or 2) embed a random PIS/NIS;
+    /// provisional ones start with 7, 8 or 9.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::cns;
+    /// use pleme_brazilian_validators::generator::Generator;
+    ///
+    /// let cns = Generator::new().cns();
+    /// assert!(cns::validate(&cns).is_ok());
+    /// ```
+    pub fn cns(&mut self) -> String {
+        const FIRST_DIGITS: [u32; 5] = [1, 2, 7, 8, 9];
+
+        loop {
+            let first = FIRST_DIGITS[self.below(5) as usize];
+            let definitive = first <= 2;
+            let mut digits = vec![first];
+            digits.extend((0..13).map(|_| self.digit()));
+            if definitive {
+                // The PIS/NIS is followed by `000`
+                digits[11..].fill(0);
+            }
+
+            // The digits weighted 15 down to 1 must sum to a multiple of 11
+            let weighted = |digits: &[u32]| -> u32 {
+                digits.iter().zip((2..=15).rev()).map(|(d, w)| d * w).sum()
+            };
+            let mut check_digit = (11 - weighted(&digits) % 11) % 11;
+            if check_digit == 10 && definitive {
+                // `001` instead of `000`: the 14th digit weighs 2
+                digits[13] = 1;
+                check_digit = (11 - weighted(&digits) % 11) % 11;
+            }
+            if check_digit < 10 {
+                digits.push(check_digit);
+                return to_string(digits.iter());
+            }
+        }
+    }
+
+    /// Generate an NF-e access key (44 digits)
+    ///
+    /// The key is laid out like a real one: IBGE code of a state, issue
+    /// year and month, issuer CNPJ, model 55, series, invoice number,
+    /// normal emission and random numeric code.
+    pub fn nfe_key(&mut self) -> String {
+        let uf = Uf::ALL[self.below(27) as usize];
+        let year = 10 + self.below(20);
+        let month = 1 + self.below(12);
+        let issuer = self.cnpj();
+        let series = self.below(1000);
+        let number = 1 + self.below(999_999_999);
+        let code = self.below(100_000_000);
+
+        let payload = format!(
+            "{:02}{:02}{:02}{}55{:03}{:09}1{:08}",
+            uf.ibge_code(),
+            year,
+            month,
+            issuer,
+            series,
+            number,
+            code
+        );
+        let digits: Vec<u32> = payload.bytes().map(|b| u32::from(b - b'0')).collect();
+        format!("{}{}", payload, nfe::compute_check_digit(&digits))
+    }
+
+    /// Generate a bank-collection boleto barcode (44 digits)
+    ///
+    /// Random bank code, real currency, due-date factor, amount up to
+    /// R$ 1,000,000.00 and free field.
+    pub fn boleto_barcode(&mut self) -> String {
+        // Barcodes starting with 8 are arrecadação, not bank collection
+        let bank = loop {
+            let bank = self.below(1000);
+            if bank / 100 != 8 {
+                break bank;
+            }
+        };
+        let factor = 1000 + self.below(9000);
+        let amount = self.below(100_000_001);
+        let free_field = to_string((0..25).map(|_| self.digit()).collect::<Vec<_>>().iter());
+
+        let mut barcode = format!("{:03}90{:04}{:010}{}", bank, factor, amount, free_field);
+        let digits: Vec<u32> = barcode.bytes().map(|b| u32::from(b - b'0')).collect();
+        let check_digit = boleto::compute_check_digit(&digits);
+        barcode.replace_range(4..5, &check_digit.to_string());
+        barcode
+    }
+
+    /// Next SplitMix64 output
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in `0..n`
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Random decimal digit
+    fn digit(&mut self) -> u32 {
+        self.below(10) as u32
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether every digit is the same
+fn is_repeated(digits: &str) -> bool {
+    digits.bytes().all(|b| b == digits.as_bytes()[0])
+}
+
+/// Concatenate decimal digits
+fn to_string<'a>(digits: impl Iterator<Item = &'a u32>) -> String {
+    digits.filter_map(|&d| char::from_digit(d, 10)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cns, renavam};
+
+    #[test]
+    fn test_generated_documents_are_valid() {
+        let mut generator = Generator::new();
+        for _ in 0..200 {
+            assert!(cpf::validate(&generator.cpf()).is_ok());
+            assert!(cnpj::validate(&generator.cnpj()).is_ok());
+            assert!(cnpj::validate(&generator.alphanumeric_cnpj()).is_ok());
+            assert!(nfe::validate_key(&generator.nfe_key()).is_ok());
+            assert!(boleto::validate_barcode(&generator.boleto_barcode()).is_ok());
+            assert!(titulo_eleitor::validate(&generator.titulo_eleitor()).is_ok());
+            assert!(cnh::validate(&generator.cnh()).is_ok());
+            assert!(renavam::validate(&generator.renavam()).is_ok());
+            assert!(pis::validate(&generator.pis()).is_ok());
+            assert!(cns::validate(&generator.cns()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_seeded_generators_are_reproducible() {
+        let mut a = Generator::seeded(7);
+        let mut b = Generator::seeded(7);
+        let first: Vec<String> = (0..5).map(|_| a.cnpj()).collect();
+        let second: Vec<String> = (0..5).map(|_| b.cnpj()).collect();
+        assert_eq!(first, second);
+        assert_ne!(Generator::seeded(8).cnpj(), first[0]);
+
+        // Pinned so a change to the sequence is noticed
+        assert_eq!(Generator::seeded(0).next_u64(), 0xE220_A839_7B1D_CDAF);
+    }
+
+    #[test]
+    fn test_constraints() {
+        let mut generator = Generator::seeded(1);
+        for region in 0..=9 {
+            let cpf = generator.cpf_in_region(region).unwrap();
+            assert_eq!(cpf::fiscal_region(&cpf), Some(region));
+        }
+        assert_eq!(generator.cpf_in_region(10), None);
+
+        let headquarters = generator.cnpj_with_branch(1).unwrap();
+        assert_eq!(cnpj::extract_branch(&headquarters).as_deref(), Some("0001"));
+        assert_eq!(
+            cnpj::extract_branch(&generator.cnpj_with_branch(42).unwrap()).as_deref(),
+            Some("0042")
+        );
+        assert_eq!(generator.cnpj_with_branch(0), None);
+    }
+}
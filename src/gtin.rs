@@ -0,0 +1,213 @@
+//! GTIN (EAN/UPC) product barcode validation
+//!
+//! GTINs identify trade items in NF-e product lines (`cEAN`) and
+//! e-commerce catalogs. They come in four lengths (GTIN-8, GTIN-12/UPC-A,
+//! GTIN-13/EAN-13 and GTIN-14) and end in a modulo 10 check digit, the
+//! digits being weighted 3 and 1 alternately from the right.
+//!
+//! GS1 Brasil numbers its members' products with the `789` and `790`
+//! prefixes.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::gtin;
+//!
+//! assert!(gtin::validate("7894900011517").is_ok());
+//! assert!(gtin::is_brazilian_prefix("7894900011517"));
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Numbers of digits of the GTIN formats
+pub const LENGTHS: [usize; 4] = [8, 12, 13, 14];
+
+/// GS1 Brasil prefixes
+const BRAZILIAN_PREFIXES: [&str; 2] = ["789", "790"];
+
+/// Validate a GTIN
+///
+/// # Arguments
+/// * `gtin` - GTIN-8, GTIN-12, GTIN-13 or GTIN-14 (spaces and hyphens
+///   are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized GTIN (digits only, length preserved)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::gtin::validate;
+///
+/// assert!(validate("789 1000 31550 7").is_ok());
+/// assert!(validate("96385074").is_ok()); // GTIN-8
+/// assert!(validate("7891000315508").is_err()); // Invalid check digit
+/// ```
+pub fn validate(gtin: &str) -> ValidationResult<String> {
+    let result = check(gtin);
+    hooks::notify(DocumentKind::Gtin, &result);
+    result
+}
+
+fn check(gtin: &str) -> ValidationResult<String> {
+    if gtin
+        .chars()
+        .any(|c| !c.is_ascii_digit() && c != ' ' && c != '-')
+    {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    let cleaned = normalize(gtin);
+
+    if !LENGTHS.contains(&cleaned.len()) {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: 13,
+            actual: cleaned.len(),
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    let (payload, check_digit) = digits.split_at(digits.len() - 1);
+    if compute_check_digit(payload) != check_digit[0] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Gtin,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute the check digit of the digits before it
+///
+/// Weights 3 and 1 alternate from the rightmost digit; the check digit
+/// completes the sum to a multiple of 10.
+fn compute_check_digit(payload: &[u32]) -> u32 {
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .zip([3, 1].into_iter().cycle())
+        .map(|(d, w)| d * w)
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Whether a GTIN was issued by GS1 Brasil (prefix `789` or `790`)
+///
+/// GTIN-13s are checked on their first three digits and GTIN-14s on the
+/// three following the packaging indicator. GTIN-8 and GTIN-12 numbers
+/// are never considered Brazilian. The check digit is not verified.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::gtin::is_brazilian_prefix;
+///
+/// assert!(is_brazilian_prefix("7908123456786"));
+/// assert!(is_brazilian_prefix("17894900011514")); // Carton of a Brazilian product
+/// assert!(!is_brazilian_prefix("4006381333931"));
+/// ```
+pub fn is_brazilian_prefix(gtin: &str) -> bool {
+    let cleaned = normalize(gtin);
+    let company_prefix = match cleaned.len() {
+        13 => &cleaned[..3],
+        14 => &cleaned[1..4],
+        _ => return false,
+    };
+    BRAZILIAN_PREFIXES.contains(&company_prefix)
+}
+
+/// Normalize a GTIN by removing non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::gtin::normalize;
+///
+/// assert_eq!(normalize("789-1000-31550-7"), "7891000315507");
+/// ```
+pub fn normalize(gtin: &str) -> String {
+    gtin.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_length_is_accepted() {
+        for gtin in [
+            "96385074",
+            "036000291452",
+            "7891000315507",
+            "4006381333931",
+            "17894900011514",
+            // A UPC-A padded to 13 digits keeps its check digit
+            "0036000291452",
+        ] {
+            assert_eq!(validate(gtin).as_deref(), Ok(gtin));
+        }
+    }
+
+    #[test]
+    fn test_separators() {
+        for gtin in ["789 1000 31550 7", "789-1000-31550-7", " 7891000315507 "] {
+            assert_eq!(validate(gtin).as_deref(), Ok("7891000315507"));
+        }
+        // NF-e uses this literal for products without a GTIN
+        for gtin in [
+            "SEM GTIN",
+            "789.1000.31550.7",
+            "789/1000315507",
+            "789１000315507",
+        ] {
+            assert_eq!(
+                validate(gtin),
+                Err(BrazilianValidationError::InvalidCharacters),
+                "{}",
+                gtin
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        for len in [0, 7, 9, 10, 11, 15] {
+            assert_eq!(
+                validate(&"7891000315507".repeat(2)[..len]),
+                Err(BrazilianValidationError::InvalidLength {
+                    expected: 13,
+                    actual: len
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_digit() {
+        let bad_check_digit = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Gtin,
+        });
+        for gtin in [
+            "96385075",
+            "036000291453",
+            "7894900011518",
+            "17894900011515",
+            // GTIN-13 with its check digit dropped, read as a GTIN-12
+            "789490001151",
+            // Two adjacent digits swapped
+            "7981000315507",
+            // Repeated digits, only valid when the weighted sum allows it
+            "1111111111111",
+        ] {
+            assert_eq!(validate(gtin), bad_check_digit, "{}", gtin);
+        }
+    }
+
+    #[test]
+    fn test_brazilian_prefix() {
+        assert!(is_brazilian_prefix("7891000315507"));
+        assert!(!is_brazilian_prefix("0789100031550"));
+        assert!(!is_brazilian_prefix("78900001"));
+        assert!(!is_brazilian_prefix(""));
+    }
+}
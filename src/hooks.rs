@@ -0,0 +1,128 @@
+//! Validation observer hooks
+//!
+//! A process-wide callback invoked after every document validation, for
+//! dashboards tracking the data quality of inbound documents (counters per
+//! document kind and error code).
+//!
+//! Only validations requested by callers are reported: lookups performed
+//! internally (e.g. while scanning free text for documents) are not.
+//!
+//! Hooks need the `std` feature; without it validations are not reported.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// Callback receiving the kind of document validated and the outcome
+pub type ValidationHook = fn(DocumentKind, Result<(), &BrazilianValidationError>);
+
+/// Currently installed hook
+#[cfg(feature = "std")]
+static VALIDATION_HOOK: RwLock<Option<ValidationHook>> = RwLock::new(None);
+
+/// Install a hook called after every validation, replacing any previous one
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::hooks::{clear_validation_hook, set_validation_hook};
+/// use pleme_brazilian_validators::{cpf, DocumentKind};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static INVALID_CPFS: AtomicUsize = AtomicUsize::new(0);
+///
+/// set_validation_hook(|kind, result| {
+///     if kind == DocumentKind::Cpf && result.is_err() {
+///         INVALID_CPFS.fetch_add(1, Ordering::Relaxed);
+///     }
+/// });
+///
+/// let _ = cpf::validate("123.456.789-00");
+/// assert_eq!(INVALID_CPFS.load(Ordering::Relaxed), 1);
+///
+/// clear_validation_hook();
+/// ```
+#[cfg(feature = "std")]
+pub fn set_validation_hook(hook: ValidationHook) {
+    if let Ok(mut current) = VALIDATION_HOOK.write() {
+        *current = Some(hook);
+    }
+}
+
+/// Remove the installed hook, if any
+#[cfg(feature = "std")]
+pub fn clear_validation_hook() {
+    if let Ok(mut current) = VALIDATION_HOOK.write() {
+        *current = None;
+    }
+}
+
+/// Report a validation outcome to the installed hook
+#[cfg(feature = "std")]
+pub(crate) fn notify<T>(kind: DocumentKind, result: &ValidationResult<T>) {
+    let hook = VALIDATION_HOOK.read().ok().and_then(|current| *current);
+
+    if let Some(hook) = hook {
+        hook(kind, result.as_ref().map(|_| ()));
+    }
+}
+
+/// Report a validation outcome: no hook can be installed without `std`
+#[cfg(not(feature = "std"))]
+pub(crate) fn notify<T>(_kind: DocumentKind, _result: &ValidationResult<T>) {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{cep, cnpj, cpf, phone, pix, scan};
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    type Event = (ThreadId, DocumentKind, Option<&'static str>);
+
+    static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+
+    fn record(kind: DocumentKind, result: Result<(), &BrazilianValidationError>) {
+        EVENTS.lock().unwrap().push((
+            thread::current().id(),
+            kind,
+            result.err().map(|e| e.error_code()),
+        ));
+    }
+
+    // Single test: the hook is process-wide, so events from tests running
+    // concurrently on other threads are filtered out
+    #[test]
+    fn test_validation_hook() {
+        set_validation_hook(record);
+
+        let _ = cpf::validate("123.456.789-09");
+        let _ = cnpj::validate("11.222.333/0001-00");
+        let _ = cep::validate("01310-100");
+        let _ = phone::validate("12345");
+        let _ = pix::validate("11.222.333/0001-81");
+        let _ = scan::redact("cpf 12345678909", scan::MaskStyle::Partial);
+
+        clear_validation_hook();
+        let _ = cpf::validate("123.456.789-09");
+
+        let this_thread = thread::current().id();
+        let events: Vec<_> = EVENTS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(thread, _, _)| *thread == this_thread)
+            .map(|&(_, kind, code)| (kind, code))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (DocumentKind::Cpf, None),
+                (DocumentKind::Cnpj, Some("INVALID_CHECK_DIGITS")),
+                (DocumentKind::Cep, None),
+                (DocumentKind::Phone, Some("INVALID_LENGTH")),
+                (DocumentKind::PixKey, None),
+            ]
+        );
+    }
+}
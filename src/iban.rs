@@ -0,0 +1,314 @@
+//! Brazilian IBAN validation and generation
+//!
+//! Brazil adopted IBANs for international transfers in 2013. They have 29
+//! characters:
+//!
+//! ```text
+//! BR 18 00360305 00001 0009795493 C 1
+//! │  │  ISPB     │     account    │ └ owner (1 = first holder)
+//! │  │           branch           └ account type (C checking, P savings)
+//! │  └ check digits (ISO 7064 modulo 97)
+//! └ country
+//! ```
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::iban;
+//!
+//! let iban = iban::build("00360305", "1", "9795493", 'C', '1').unwrap();
+//! assert_eq!(iban, "BR1800360305000010009795493C1");
+//! assert!(iban::validate_br("BR18 0036 0305 0000 1000 9795 493C 1").is_ok());
+//! ```
+
+use crate::bank;
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of characters of a Brazilian IBAN
+pub const LEN: usize = 29;
+
+/// Digits of the branch field
+const BRANCH_LEN: usize = 5;
+
+/// Digits of the account field
+const ACCOUNT_LEN: usize = 10;
+
+/// Fields of a validated Brazilian IBAN
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BrIban {
+    /// Normalized IBAN (29 uppercase characters)
+    pub iban: String,
+    /// ISPB of the institution holding the account (8 digits)
+    pub ispb: String,
+    /// Branch (agência), zero-padded to 5 digits
+    pub branch: String,
+    /// Account number with its check digit, zero-padded to 10 digits
+    pub account: String,
+    /// Account type (`C` checking, `P` savings, ...)
+    pub account_type: char,
+    /// Account owner (`1` for the first holder, `2` for the second, ...)
+    pub owner: char,
+}
+
+/// Validate a Brazilian IBAN
+///
+/// # Arguments
+/// * `iban` - IBAN (spaces are ignored, case-insensitive)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized IBAN (29 uppercase characters)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::iban::validate_br;
+///
+/// assert_eq!(
+///     validate_br("br97 0036 0305 0000 1000 9795 493p 1").unwrap(),
+///     "BR9700360305000010009795493P1"
+/// );
+/// assert!(validate_br("BR9800360305000010009795493P1").is_err());
+/// ```
+pub fn validate_br(iban: &str) -> ValidationResult<String> {
+    let result = parse_unobserved(iban).map(|parsed| parsed.iban);
+    hooks::notify(DocumentKind::Iban, &result);
+    result
+}
+
+/// Validate a Brazilian IBAN and extract its fields
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{bank, iban};
+///
+/// let iban = iban::parse_br("BR1800360305000010009795493C1").unwrap();
+/// assert_eq!(iban.branch, "00001");
+/// assert_eq!(bank::by_ispb(&iban.ispb).unwrap().compe, "104");
+/// ```
+pub fn parse_br(iban: &str) -> ValidationResult<BrIban> {
+    let result = parse_unobserved(iban);
+    hooks::notify(DocumentKind::Iban, &result);
+    result
+}
+
+/// Parse without reporting to the validation hook
+fn parse_unobserved(iban: &str) -> ValidationResult<BrIban> {
+    let cleaned = normalize(iban);
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let bytes = cleaned.as_bytes();
+    let layout_valid = cleaned.starts_with("BR")
+        && bytes[2..27].iter().all(u8::is_ascii_digit)
+        && bytes[27].is_ascii_uppercase()
+        && bytes[28].is_ascii_alphanumeric();
+    if !layout_valid {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Iban,
+        });
+    }
+
+    if check_digits(&cleaned[4..]) != cleaned[2..4] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Iban,
+        });
+    }
+
+    Ok(BrIban {
+        ispb: cleaned[4..12].to_string(),
+        branch: cleaned[12..17].to_string(),
+        account: cleaned[17..27].to_string(),
+        account_type: char::from(bytes[27]),
+        owner: char::from(bytes[28]),
+        iban: cleaned,
+    })
+}
+
+/// Build a Brazilian IBAN from account components
+///
+/// # Arguments
+/// * `ispb` - ISPB of the institution (8 digits)
+/// * `branch` - Agência, up to 5 digits (without check digit)
+/// * `account` - Account number and its check digit, up to 10 digits
+/// * `account_type` - `C` for checking, `P` for savings
+/// * `owner` - `1` for the first holder, `2` for the second, ...
+///
+/// # Returns
+/// * `Ok(String)` - IBAN (29 characters, no spaces)
+/// * `Err(BrazilianValidationError)` - A component does not fit
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::iban::build;
+///
+/// assert_eq!(
+///     build("60701190", "2545", "02366-1", 'c', '1').unwrap(),
+///     "BR1060701190025450000023661C1"
+/// );
+/// assert!(build("60701190", "2545", "02366-X", 'C', '1').is_err());
+/// ```
+pub fn build(
+    ispb: &str,
+    branch: &str,
+    account: &str,
+    account_type: char,
+    owner: char,
+) -> ValidationResult<String> {
+    let ispb = bank::validate_ispb(ispb)?;
+    let branch = pad_digits(branch, BRANCH_LEN)?;
+    let account = pad_digits(account, ACCOUNT_LEN)?;
+
+    let account_type = account_type.to_ascii_uppercase();
+    let owner = owner.to_ascii_uppercase();
+    if !account_type.is_ascii_uppercase() || !owner.is_ascii_alphanumeric() {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+
+    let bban = format!("{}{}{}{}{}", ispb, branch, account, account_type, owner);
+    Ok(format!("BR{}{}", check_digits(&bban), bban))
+}
+
+/// Normalize an IBAN: alphanumeric characters only, uppercase
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::iban::normalize;
+///
+/// assert_eq!(normalize("br18 0036 0305"), "BR1800360305");
+/// ```
+pub fn normalize(iban: &str) -> String {
+    iban.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Format an IBAN in groups of four characters, as printed
+///
+/// Returns input unchanged if it does not have 29 characters.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::iban::format;
+///
+/// assert_eq!(
+///     format("BR1800360305000010009795493C1"),
+///     "BR18 0036 0305 0000 1000 9795 493C 1"
+/// );
+/// ```
+pub fn format(iban: &str) -> String {
+    let cleaned = normalize(iban);
+    if cleaned.len() != LEN {
+        return iban.to_string();
+    }
+
+    cleaned
+        .as_bytes()
+        .chunks(4)
+        .map(|group| core::str::from_utf8(group).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Zero-pad the digits of a field, punctuation ignored
+fn pad_digits(value: &str, len: usize) -> ValidationResult<String> {
+    let digits: String = value
+        .chars()
+        .filter(|c| !matches!(c, '-' | '.' | ' '))
+        .collect();
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    if digits.is_empty() || digits.len() > len {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: len,
+            actual: digits.len(),
+        });
+    }
+    Ok(format!("{:0>width$}", digits, width = len))
+}
+
+/// ISO 7064 MOD 97-10 check digits of a Brazilian BBAN
+///
+/// Letters count as two digits (`A` = 10, ..., `Z` = 35), and the country
+/// code and `00` are appended before taking the remainder.
+fn check_digits(bban: &str) -> String {
+    let remainder = bban
+        .chars()
+        .chain("BR00".chars())
+        .filter_map(|c| c.to_digit(36))
+        .fold(0, |remainder, value| {
+            let shift = if value < 10 { 10 } else { 100 };
+            (remainder * shift + value) % 97
+        });
+    format!("{:02}", 98 - remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IBAN: &str = "BR1800360305000010009795493C1";
+
+    #[test]
+    fn test_parse_br() {
+        let iban = parse_br(IBAN).unwrap();
+        assert_eq!(iban.iban, IBAN);
+        assert_eq!(iban.ispb, "00360305");
+        assert_eq!(iban.branch, "00001");
+        assert_eq!(iban.account, "0009795493");
+        assert_eq!(iban.account_type, 'C');
+        assert_eq!(iban.owner, '1');
+    }
+
+    #[test]
+    fn test_build_round_trips() {
+        let built = build("00360305", "00001", "000979549-3", 'C', '1').unwrap();
+        assert_eq!(built, IBAN);
+        assert_eq!(validate_br(&format(&built)).as_deref(), Ok(IBAN));
+        assert!(validate_br(&build("18236120", "1", "12345678", 'P', 'A').unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_br_errors() {
+        assert_eq!(
+            validate_br("BR1900360305000010009795493C1"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Iban
+            })
+        );
+        // German IBAN
+        assert_eq!(
+            validate_br("DE89370400440532013000"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 29,
+                actual: 22
+            })
+        );
+        assert_eq!(
+            validate_br("PT1800360305000010009795493C1"),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::Iban
+            })
+        );
+        assert!(validate_br("BR18003603050000100097954931C").is_err());
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert!(build("0036030", "1", "1", 'C', '1').is_err());
+        assert!(build("00360305", "123456", "1", 'C', '1').is_err());
+        assert!(build("00360305", "1", "12345678901", 'C', '1').is_err());
+        assert!(build("00360305", "1", "1", '1', '1').is_err());
+        assert!(build("00360305", "1", "", 'C', '1').is_err());
+    }
+}
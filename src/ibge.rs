@@ -0,0 +1,182 @@
+//! IBGE code validation
+//!
+//! Municipality codes (código de município) have 7 digits: the 2-digit UF
+//! code, a 4-digit sequence and a check digit. They are mandatory in NF-e,
+//! NFS-e and most government datasets, where corrupted codes are common.
+//!
+//! With the `dataset` feature, `municipality_name` and
+//! `municipality_code` translate between codes and names of the state
+//! capitals and other major municipalities, using an embedded table without
+//! network access.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::dv;
+use crate::uf::Uf;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "dataset")]
+mod municipios;
+
+#[cfg(feature = "dataset")]
+pub use municipios::{municipality_code, municipality_name};
+
+/// Municipalities whose official codes do not satisfy the check digit
+///
+/// Codes assigned before the check-digit rule was enforced; IBGE kept them
+/// for compatibility, so they must be accepted as-is.
+const CHECK_DIGIT_EXCEPTIONS: [&str; 9] = [
+    "2201919", // Bom Princípio do Piauí (PI)
+    "2201988", // Brejo do Piauí (PI)
+    "2202251", // Canavieira (PI)
+    "2611533", // Quixaba (PE)
+    "3117836", // Cônego Marinho (MG)
+    "3152131", // Ponto Chique (MG)
+    "4305871", // Coronel Barros (RS)
+    "5203939", // Buriti de Goiás (GO)
+    "5203962", // Buritinópolis (GO)
+];
+
+/// Validated municipality code
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MunicipioCode {
+    /// Normalized code (7 digits)
+    pub code: String,
+    /// Federative unit encoded in the first two digits
+    pub uf: Uf,
+}
+
+/// Validate an IBGE municipality code
+///
+/// # Arguments
+/// * `code` - 7-digit code (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(MunicipioCode)` - Normalized code and its UF
+/// * `Err(BrazilianValidationError)` - Wrong length, unknown UF or check digit
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ibge::validate_municipio;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// let sao_paulo = validate_municipio("3550308").unwrap();
+/// assert_eq!(sao_paulo.uf, Uf::SP);
+///
+/// assert!(validate_municipio("3550309").is_err()); // Wrong check digit
+/// assert!(validate_municipio("9950308").is_err()); // Unknown UF
+/// ```
+pub fn validate_municipio(code: &str) -> ValidationResult<MunicipioCode> {
+    let cleaned = normalize(code);
+
+    if cleaned.len() != 7 {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: 7,
+            actual: cleaned.len(),
+        });
+    }
+
+    let uf = uf_from_municipio(&cleaned).ok_or(BrazilianValidationError::InvalidDocumentFormat {
+        document_type: DocumentKind::IbgeCode,
+    })?;
+
+    let digits: Vec<u32> = cleaned.chars().filter_map(|c| c.to_digit(10)).collect();
+    let valid = CHECK_DIGIT_EXCEPTIONS.contains(&cleaned.as_str())
+        || compute_check_digit(&digits[..6]) == digits[6];
+
+    if !valid {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::IbgeCode,
+        });
+    }
+
+    Ok(MunicipioCode { code: cleaned, uf })
+}
+
+/// Normalize a code by removing all non-digit characters
+pub fn normalize(code: &str) -> String {
+    code.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Extract the federative unit from a municipality code (no check digit
+/// verification)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ibge::uf_from_municipio;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(uf_from_municipio("3304557"), Some(Uf::RJ));
+/// assert_eq!(uf_from_municipio("0"), None);
+/// ```
+pub fn uf_from_municipio(code: &str) -> Option<Uf> {
+    let cleaned = normalize(code);
+    let uf_code = cleaned.get(..2)?.parse().ok()?;
+    Uf::from_ibge_code(uf_code)
+}
+
+/// Bytes embedded in the binary by the municipality table, strings included
+#[cfg(all(test, feature = "dataset"))]
+pub(crate) fn embedded_size() -> usize {
+    municipios::embedded_size()
+}
+
+/// Compute the check digit of the first 6 digits
+///
+/// Digits are weighted alternately 1 and 2 (modulo 10, see
+/// [`dv::mod10_alternating`]).
+pub(crate) fn compute_check_digit(base: &[u32]) -> u32 {
+    dv::mod10_alternating(base).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_capitals() {
+        for (code, uf) in [
+            ("3550308", Uf::SP),
+            ("3304557", Uf::RJ),
+            ("3106200", Uf::MG),
+            ("5300108", Uf::DF),
+            ("1302603", Uf::AM),
+            ("4314902", Uf::RS),
+        ] {
+            let municipio = validate_municipio(code).unwrap();
+            assert_eq!(municipio.uf, uf, "{}", code);
+            assert_eq!(municipio.code, code);
+        }
+    }
+
+    #[test]
+    fn test_formatted_input_is_normalized() {
+        assert_eq!(validate_municipio("35.50308").unwrap().code, "3550308");
+    }
+
+    #[test]
+    fn test_invalid_codes() {
+        assert!(matches!(
+            validate_municipio("355030"),
+            Err(BrazilianValidationError::InvalidLength { expected: 7, actual: 6 })
+        ));
+        assert!(matches!(
+            validate_municipio("3450308"),
+            Err(BrazilianValidationError::InvalidDocumentFormat { .. })
+        ));
+        assert!(matches!(
+            validate_municipio("3550300"),
+            Err(BrazilianValidationError::InvalidCheckDigits { .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_digit_exceptions_are_accepted() {
+        for code in CHECK_DIGIT_EXCEPTIONS {
+            let digits: Vec<u32> = code.chars().filter_map(|c| c.to_digit(10)).collect();
+            assert_ne!(compute_check_digit(&digits[..6]), digits[6], "{}", code);
+            assert!(validate_municipio(code).is_ok(), "{}", code);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Offline municipality table
+//!
+//! Names of the state capitals and other major municipalities keyed by IBGE
+//! code, from `data/municipios.tsv`. This is a subset of the 5570
+//! municipalities; `scripts/update-municipios.sh` replaces it with the full
+//! table from the IBGE localidades API. The table is embedded compressed and
+//! inflated on the first lookup.
+
+use super::normalize;
+use crate::ascii;
+use crate::blob::{self, Table};
+use crate::uf::Uf;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::sync::LazyLock;
+
+/// Municipality with its IBGE code
+struct Municipio {
+    code: &'static str,
+    name: &'static str,
+}
+
+/// Municipalities by IBGE code, one `code<TAB>name` record per line
+static TABLE: Table = blob::table!("municipios.tsv");
+
+/// Known municipalities, sorted by code
+static MUNICIPIOS: LazyLock<Vec<Municipio>> = LazyLock::new(|| {
+    TABLE
+        .records()
+        .filter_map(|record| match record[..] {
+            [code, name] => Some(Municipio { code, name }),
+            _ => None,
+        })
+        .collect()
+});
+
+/// Codes by UF prefix and name key, sorted for binary search
+static BY_NAME: LazyLock<Vec<(&'static str, String, &'static str)>> = LazyLock::new(|| {
+    let mut index: Vec<_> = MUNICIPIOS
+        .iter()
+        .map(|municipio| {
+            (
+                &municipio.code[..2],
+                name_key(municipio.name),
+                municipio.code,
+            )
+        })
+        .collect();
+    index.sort_unstable();
+    index
+});
+
+/// Get the name of a municipality from its IBGE code
+///
+/// Returns `None` for codes missing from the embedded table, which only
+/// lists capitals and major municipalities. The check digit is not verified.
+///
+/// Enabled by the `dataset` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ibge;
+///
+/// assert_eq!(ibge::municipality_name("3550308"), Some("São Paulo"));
+/// assert_eq!(ibge::municipality_name("2927408"), Some("Salvador"));
+/// ```
+pub fn municipality_name(code: &str) -> Option<&'static str> {
+    let cleaned = normalize(code);
+    MUNICIPIOS
+        .binary_search_by(|municipio| municipio.code.cmp(cleaned.as_str()))
+        .ok()
+        .map(|index| ascii::name(MUNICIPIOS[index].name))
+}
+
+/// Find the IBGE code of a municipality by name and state
+///
+/// Names are compared without accents, case or repeated whitespace, so
+/// `"sao  jose dos campos"` finds São José dos Campos. Returns `None` for
+/// municipalities missing from the embedded table.
+///
+/// Enabled by the `dataset` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ibge;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(ibge::municipality_code("sao paulo", Uf::SP), Some("3550308"));
+/// assert_eq!(ibge::municipality_code("Belém", Uf::PA), Some("1501402"));
+/// assert_eq!(ibge::municipality_code("São Paulo", Uf::RJ), None);
+/// ```
+pub fn municipality_code(name: &str, uf: Uf) -> Option<&'static str> {
+    let key = name_key(name);
+    let uf_prefix = format!("{:02}", uf.ibge_code());
+    BY_NAME
+        .binary_search_by(|(prefix, name, _)| {
+            (*prefix, name.as_str()).cmp(&(uf_prefix.as_str(), key.as_str()))
+        })
+        .ok()
+        .map(|index| BY_NAME[index].2)
+}
+
+/// Comparison key of a name: accent-free, lowercase, single spaces
+fn name_key(name: &str) -> String {
+    ascii::to_ascii(name)
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Bytes embedded in the binary by the compressed table
+#[cfg(test)]
+pub(super) fn embedded_size() -> usize {
+    TABLE.compressed_len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ibge::validate_municipio;
+
+    #[test]
+    fn test_table_is_sorted_and_valid() {
+        for pair in MUNICIPIOS.windows(2) {
+            assert!(pair[0].code < pair[1].code, "{}", pair[1].code);
+        }
+        for municipio in MUNICIPIOS.iter() {
+            assert!(
+                validate_municipio(municipio.code).is_ok(),
+                "{}",
+                municipio.code
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_state_is_listed() {
+        for uf in Uf::iter() {
+            let listed = MUNICIPIOS
+                .iter()
+                .filter(|municipio| {
+                    municipio
+                        .code
+                        .starts_with(&format!("{:02}", uf.ibge_code()))
+                })
+                .count();
+            assert!(listed > 0, "{}", uf.abbreviation());
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for municipio in MUNICIPIOS.iter() {
+            let uf = crate::ibge::uf_from_municipio(municipio.code).unwrap();
+            assert_eq!(municipality_code(municipio.name, uf), Some(municipio.code));
+        }
+        assert_eq!(municipality_name("3550309"), None);
+    }
+}
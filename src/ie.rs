@@ -0,0 +1,503 @@
+//! Inscrição Estadual (state tax registration) validation and formatting
+//!
+//! Every state assigns its own registration numbers to the businesses that
+//! collect ICMS, with its own length, prefix and check-digit algorithm
+//! (the SINTEGRA specifications). [`validate`] applies the algorithm of
+//! the given state; [`possible_ufs`] runs a number against all 27 when
+//! the state is unknown.
+//!
+//! Supported layouts are the ones in use: 14-digit Rondônia numbers (not
+//! the pre-2000 9-digit ones), 9-digit eFisco numbers in Pernambuco, and
+//! both the 9- and 11-digit Tocantins numbers. São Paulo rural producer
+//! registrations (`P-01100424.3/002`) keep their leading `P`.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::ie;
+//! use pleme_brazilian_validators::uf::Uf;
+//!
+//! assert_eq!(ie::validate("110.042.490.114", Uf::SP).unwrap(), "110042490114");
+//! assert!(ie::validate("110.042.490.114", Uf::RJ).is_err());
+//! assert_eq!(ie::format("0623079040081", Uf::MG), "062.307.904/0081");
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::uf::Uf;
+use crate::{dv, fixed, hooks};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// São Paulo check-digit weights: first digit, then second digit
+const SP_WEIGHTS: ([u32; 8], [u32; 11]) = (
+    [1, 3, 4, 5, 6, 7, 8, 10],
+    [3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2],
+);
+
+/// Minas Gerais second check-digit weights
+const MG_WEIGHTS: [u32; 12] = [3, 2, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Validate a state registration against the algorithm of its state
+///
+/// # Arguments
+/// * `ie` - Inscrição estadual (punctuation is ignored)
+/// * `uf` - State that issued it
+///
+/// # Returns
+/// * `Ok(String)` - Normalized registration (digits, with the `P` of São
+///   Paulo rural producers)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ie::validate;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert!(validate("224/3658792", Uf::RS).is_ok());
+/// assert!(validate("P-01100424.3/002", Uf::SP).is_ok());
+/// assert!(validate("224/3658793", Uf::RS).is_err());
+/// ```
+pub fn validate(ie: &str, uf: Uf) -> ValidationResult<String> {
+    let result = validate_unobserved(ie, uf);
+    hooks::notify(DocumentKind::InscricaoEstadual, &result);
+    result
+}
+
+/// Validate without reporting to the validation hook (for internal use)
+pub(crate) fn validate_unobserved(ie: &str, uf: Uf) -> ValidationResult<String> {
+    let cleaned = normalize(ie);
+    let rural = cleaned.starts_with('P');
+    if rural && uf != Uf::SP {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+
+    let digits: Vec<u32> = cleaned
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|b| u32::from(b - b'0'))
+        .collect();
+
+    let lengths = if rural { &[12][..] } else { lengths(uf) };
+    if !lengths.contains(&digits.len()) {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: lengths[0],
+            actual: digits.len(),
+        });
+    }
+
+    let valid = if rural {
+        check_sp_rural(&digits)
+    } else {
+        check(uf, &digits)
+    };
+    match valid {
+        Some(true) => Ok(cleaned),
+        Some(false) => Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::InscricaoEstadual,
+        }),
+        None => Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::InscricaoEstadual,
+        }),
+    }
+}
+
+/// Validate a registration valid in some state, for callers without one
+pub(crate) fn validate_any_state(ie: &str) -> ValidationResult<String> {
+    let result = Uf::iter()
+        .find_map(|uf| validate_unobserved(ie, uf).ok())
+        .ok_or(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::InscricaoEstadual,
+        });
+    hooks::notify(DocumentKind::InscricaoEstadual, &result);
+    result
+}
+
+/// States whose algorithm accepts a registration
+///
+/// Useful when the state of a registration was lost, e.g. in legacy ERP
+/// exports. Many states share lengths and algorithms, so a number is often
+/// valid in several of them: the result narrows the candidates down, it
+/// does not identify the state. States are listed by IBGE code.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ie::possible_ufs;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(possible_ufs("062.307.904/0081"), vec![Uf::MG]);
+/// assert!(possible_ufs("240000048").contains(&Uf::AL));
+/// assert!(possible_ufs("123").is_empty());
+/// ```
+pub fn possible_ufs(ie: &str) -> Vec<Uf> {
+    Uf::iter()
+        .filter(|&uf| validate_unobserved(ie, uf).is_ok())
+        .collect()
+}
+
+/// Normalize a registration: digits only, keeping a leading `P` (São
+/// Paulo rural producer)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ie::normalize;
+///
+/// assert_eq!(normalize("110.042.490.114"), "110042490114");
+/// assert_eq!(normalize("p-01100424.3/002"), "P011004243002");
+/// ```
+pub fn normalize(ie: &str) -> String {
+    let digits: String = ie.chars().filter(|c| c.is_ascii_digit()).collect();
+    if ie.trim_start().starts_with(['P', 'p']) {
+        format!("P{}", digits)
+    } else {
+        digits
+    }
+}
+
+/// Format a registration with the punctuation used by its state
+///
+/// Returns the input unchanged if its length does not fit the state.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ie::format;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(format("110042490114", Uf::SP), "110.042.490.114");
+/// assert_eq!(format("P011004243002", Uf::SP), "P-01100424.3/002");
+/// assert_eq!(format("99999993", Uf::RJ), "99.999.99-3");
+/// assert_eq!(format("123", Uf::RJ), "123");
+/// ```
+pub fn format(ie: &str, uf: Uf) -> String {
+    let cleaned = normalize(ie);
+    let (digits, pattern) = match cleaned.strip_prefix('P') {
+        Some(digits) if uf == Uf::SP => (digits, "P-########.#/###"),
+        Some(_) => return ie.to_string(),
+        None => (cleaned.as_str(), pattern(uf, cleaned.len())),
+    };
+
+    let mut buf = [0u8; 20];
+    fixed::write_pattern(digits, pattern, &mut buf)
+        .map(str::to_string)
+        .unwrap_or_else(|| ie.to_string())
+}
+
+/// Numbers of digits accepted by a state, the current one first
+fn lengths(uf: Uf) -> &'static [usize] {
+    match uf {
+        Uf::AC | Uf::DF | Uf::MG => &[13],
+        Uf::BA => &[9, 8],
+        Uf::MT => &[11],
+        Uf::TO => &[9, 11],
+        Uf::PR | Uf::RS => &[10],
+        Uf::RJ => &[8],
+        Uf::RN => &[9, 10],
+        Uf::RO => &[14],
+        Uf::SP => &[12],
+        _ => &[9],
+    }
+}
+
+/// Formatting pattern of a state for a number of digits
+fn pattern(uf: Uf, len: usize) -> &'static str {
+    match (uf, len) {
+        (Uf::AC, _) => "##.###.###/###-##",
+        (Uf::AM | Uf::GO, _) => "##.###.###-#",
+        (Uf::BA, 8) => "######-##",
+        (Uf::BA, _) => "#######-##",
+        (Uf::CE | Uf::PB | Uf::RR | Uf::SE, _) => "########-#",
+        (Uf::DF, _) => "###########-##",
+        (Uf::ES, _) => "###.###.##-#",
+        (Uf::MG, _) => "###.###.###/####",
+        (Uf::MT, _) => "##########-#",
+        (Uf::PA, _) => "##-######-#",
+        (Uf::PE, _) => "#######-##",
+        (Uf::PR, _) => "########-##",
+        (Uf::RJ, _) => "##.###.##-#",
+        (Uf::RN, 10) => "##.#.###.###-#",
+        (Uf::RN, _) => "##.###.###-#",
+        (Uf::RO, _) => "#############-#",
+        (Uf::RS, _) => "###/#######",
+        (Uf::SC, _) => "###.###.###",
+        (Uf::SP, _) => "###.###.###.###",
+        (Uf::TO, 11) => "###########",
+        _ => "#########",
+    }
+}
+
+/// Check the digits of a registration of the right length
+///
+/// `None` if the number does not have the state's layout (prefix, type
+/// digit), `Some(false)` if a check digit is wrong.
+fn check(uf: Uf, d: &[u32]) -> Option<bool> {
+    let n = d.len();
+    let prefix = d[0] * 10 + d[1];
+
+    let valid = match uf {
+        Uf::AC => {
+            require(prefix == 1)?;
+            two_mod11(d, 9)
+        }
+        Uf::DF => {
+            require(prefix == 7)?;
+            two_mod11(d, 9)
+        }
+        Uf::AL => {
+            require(prefix == 24 && [0, 3, 5, 7, 8].contains(&d[2]))?;
+            d[8] == times_ten_mod11(&d[..8])
+        }
+        Uf::AP => {
+            require(prefix == 3)?;
+            check_ap(d)
+        }
+        Uf::BA => check_ba(d),
+        Uf::GO => {
+            require(matches!(prefix, 10 | 11 | 15 | 20..=29))?;
+            check_go(d)
+        }
+        Uf::MA => {
+            require(prefix == 12)?;
+            one_mod11(d)
+        }
+        Uf::MS => {
+            require(prefix == 28 || prefix == 50)?;
+            one_mod11(d)
+        }
+        Uf::PA => {
+            require(prefix == 15)?;
+            one_mod11(d)
+        }
+        Uf::MG => check_mg(d),
+        Uf::PE | Uf::PR => two_mod11(d, if uf == Uf::PR { 7 } else { 9 }),
+        Uf::RJ => d[7] == mod11(&d[..7], 7),
+        Uf::RN => {
+            require(prefix == 20)?;
+            d[n - 1] == times_ten_mod11(&d[..n - 1])
+        }
+        Uf::RO => {
+            // 10 and 11 become 0 and 1
+            let remainder = weighted(&d[..13], &dv::cyclic_weights(13, 2, 9)) % 11;
+            d[13] == (11 - remainder) % 10
+        }
+        Uf::RR => {
+            require(prefix == 24)?;
+            d[8] == weighted(&d[..8], &[1, 2, 3, 4, 5, 6, 7, 8]) % 9
+        }
+        Uf::SP => {
+            d[8] == weighted(&d[..8], &SP_WEIGHTS.0) % 11 % 10
+                && d[11] == weighted(&d[..11], &SP_WEIGHTS.1) % 11 % 10
+        }
+        Uf::TO if n == 11 => {
+            // Old layout: the type digits (3rd and 4th) are left out
+            require(matches!(d[2] * 10 + d[3], 1 | 2 | 3 | 99))?;
+            let base: Vec<u32> = d[..2].iter().chain(&d[4..10]).copied().collect();
+            d[10] == mod11(&base, 9)
+        }
+        Uf::AM | Uf::CE | Uf::ES | Uf::PB | Uf::PI | Uf::RS | Uf::SC | Uf::SE | Uf::MT | Uf::TO => {
+            one_mod11(d)
+        }
+    };
+    Some(valid)
+}
+
+/// São Paulo rural producer: a single check digit, 9th of the 12 digits
+fn check_sp_rural(d: &[u32]) -> Option<bool> {
+    Some(d[8] == weighted(&d[..8], &SP_WEIGHTS.0) % 11 % 10)
+}
+
+/// Amapá: the sum starts from a constant that depends on the range
+fn check_ap(d: &[u32]) -> bool {
+    let base = d[..8].iter().fold(0, |number, &digit| number * 10 + digit);
+    let (start, eleven) = match base {
+        3_000_001..=3_017_000 => (5, 0),
+        3_017_001..=3_019_022 => (9, 1),
+        _ => (0, 0),
+    };
+    let check_digit = match 11 - (start + weighted(&d[..8], &dv::cyclic_weights(8, 2, 9))) % 11 {
+        10 => 0,
+        11 => eleven,
+        digit => digit,
+    };
+    d[8] == check_digit
+}
+
+/// Bahia: the last digit is computed first, and the module (10 or 11)
+/// depends on the first digit (second for 9-digit numbers)
+fn check_ba(d: &[u32]) -> bool {
+    let n = d.len();
+    let modulo10 = !matches!(d[n - 8], 6 | 7 | 9);
+    let check_digit = |digits: &[u32]| {
+        let sum = weighted(digits, &dv::cyclic_weights(digits.len(), 2, 9));
+        if modulo10 {
+            (10 - sum % 10) % 10
+        } else {
+            dv::Mod11Remainder::Complement.check_digit(sum % 11)
+        }
+    };
+
+    let base = &d[..n - 2];
+    let second: Vec<u32> = base.iter().copied().chain([d[n - 1]]).collect();
+    d[n - 1] == check_digit(base) && d[n - 2] == check_digit(&second)
+}
+
+/// Goiás: remainder 1 gives 1 in a range of older registrations
+fn check_go(d: &[u32]) -> bool {
+    let base = d[..8].iter().fold(0, |number, &digit| number * 10 + digit);
+    let check_digit = match weighted(&d[..8], &dv::cyclic_weights(8, 2, 9)) % 11 {
+        0 => 0,
+        1 if (10_103_105..=10_119_997).contains(&base) => 1,
+        1 => 0,
+        remainder => 11 - remainder,
+    };
+    d[8] == check_digit
+}
+
+/// Minas Gerais: modulo 10 over the number with a 0 after the
+/// municipality code, then modulo 11
+fn check_mg(d: &[u32]) -> bool {
+    let padded: Vec<u32> = d[..3].iter().chain(&[0]).chain(&d[3..11]).copied().collect();
+    let first = dv::mod10_alternating(&padded);
+    let second = dv::mod11(&d[..12], &MG_WEIGHTS);
+    first == Some(d[11]) && second == Some(d[12])
+}
+
+/// Fail with `None` if the number does not have the state's layout
+fn require(condition: bool) -> Option<()> {
+    condition.then_some(())
+}
+
+/// Weighted sum of digits
+fn weighted(digits: &[u32], weights: &[u32]) -> u32 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+/// Modulo 11 check digit, weights cycling from 2 up to `max`
+fn mod11(digits: &[u32], max: u32) -> u32 {
+    dv::mod11(digits, &dv::cyclic_weights(digits.len(), 2, max)).unwrap_or(10)
+}
+
+/// Last digit is the modulo 11 check digit of the others, weights 2 to 9
+fn one_mod11(d: &[u32]) -> bool {
+    let n = d.len();
+    d[n - 1] == mod11(&d[..n - 1], 9)
+}
+
+/// Last two digits are modulo 11 check digits, weights 2 up to `max`
+fn two_mod11(d: &[u32], max: u32) -> bool {
+    let n = d.len();
+    d[n - 2] == mod11(&d[..n - 2], max) && d[n - 1] == mod11(&d[..n - 1], max)
+}
+
+/// Remainder of ten times the weighted sum (Alagoas, Rio Grande do Norte)
+fn times_ten_mod11(digits: &[u32]) -> u32 {
+    let sum = weighted(digits, &dv::cyclic_weights(digits.len(), 2, 10));
+    sum * 10 % 11 % 10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// A valid registration for every state, most from the SINTEGRA
+    /// specifications
+    const VALID: [(Uf, &str); 33] = [
+        (Uf::AC, "01.004.823/001-12"),
+        (Uf::AL, "240000048"),
+        (Uf::AP, "030123459"),
+        (Uf::AM, "99.999.999-0"),
+        (Uf::BA, "123456-63"),
+        (Uf::BA, "612345-57"),
+        (Uf::BA, "1000003-06"),
+        (Uf::CE, "06000001-5"),
+        (Uf::DF, "07300001001-09"),
+        (Uf::ES, "999.999.99-0"),
+        (Uf::GO, "10.987.654-7"),
+        (Uf::MA, "120000385"),
+        (Uf::MT, "0013000001-9"),
+        (Uf::MS, "283115947"),
+        (Uf::MG, "062.307.904/0081"),
+        (Uf::PA, "15-999999-5"),
+        (Uf::PB, "06000001-5"),
+        (Uf::PR, "12345678-50"),
+        (Uf::PE, "0321418-40"),
+        (Uf::PI, "012345679"),
+        (Uf::PI, "190000015"),
+        (Uf::RJ, "99.999.99-3"),
+        (Uf::RN, "20.040.040-1"),
+        (Uf::RN, "20.0.040.040-0"),
+        (Uf::RS, "224/3658792"),
+        (Uf::RO, "0000000062521-3"),
+        (Uf::RR, "24006153-6"),
+        (Uf::SC, "251.040.852"),
+        (Uf::SP, "110.042.490.114"),
+        (Uf::SP, "P-01100424.3/002"),
+        (Uf::SE, "27123456-3"),
+        (Uf::TO, "29010227836"),
+        (Uf::TO, "290227836"),
+    ];
+
+    #[test]
+    fn test_valid_registrations() {
+        for (uf, ie) in VALID {
+            let normalized = validate(ie, uf);
+            assert!(normalized.is_ok(), "{} {}: {:?}", uf.abbreviation(), ie, normalized);
+        }
+    }
+
+    #[test]
+    fn test_wrong_check_digit_is_rejected() {
+        // The check digit of rural producers is not the last one
+        for (uf, ie) in VALID.into_iter().filter(|(_, ie)| !ie.starts_with('P')) {
+            let mut wrong = normalize(ie);
+            let last = wrong.pop().unwrap().to_digit(10).unwrap();
+            wrong.push(char::from_digit((last + 1) % 10, 10).unwrap());
+            assert!(validate(&wrong, uf).is_err(), "{} {}", uf.abbreviation(), wrong);
+        }
+        assert!(validate("P-01100424.4/002", Uf::SP).is_err());
+    }
+
+    #[test]
+    fn test_layout_errors() {
+        assert_eq!(
+            validate("12345", Uf::SP),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 12,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            validate("P011004243002", Uf::MG),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+        // Valid modulo 11, but Pará numbers start with 15
+        assert_eq!(
+            validate("999999990", Uf::PA),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::InscricaoEstadual
+            })
+        );
+        // Alagoas type digit must be 0, 3, 5, 7 or 8
+        assert!(validate("241000048", Uf::AL).is_err());
+    }
+
+    #[test]
+    fn test_possible_ufs() {
+        assert_eq!(possible_ufs("110.042.490.114"), vec![Uf::SP]);
+        assert_eq!(possible_ufs("P-01100424.3/002"), vec![Uf::SP]);
+        assert_eq!(possible_ufs("01.004.823/001-12"), vec![Uf::AC]);
+        for (uf, ie) in VALID {
+            assert!(possible_ufs(ie).contains(&uf), "{} {}", uf.abbreviation(), ie);
+        }
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        for (uf, ie) in VALID {
+            assert_eq!(normalize(&format(ie, uf)), normalize(ie));
+        }
+        assert_eq!(format("0100482300112", Uf::AC), "01.004.823/001-12");
+        assert_eq!(format("2000400400", Uf::RN), "20.0.040.040-0");
+        assert_eq!(format("P011004243002", Uf::RJ), "P011004243002");
+    }
+}
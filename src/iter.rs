@@ -0,0 +1,206 @@
+//! Iterator adapters for validating streams of documents
+//!
+//! [`ValidateExt`] adds validation steps to any iterator of `&str` or
+//! `String`, so ETL code reads like a normal iterator chain. Failures keep
+//! the position of the offending record instead of its value, so error
+//! reports can be logged without leaking documents.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::iter::ValidateExt;
+//!
+//! let rows = ["123.456.789-09", "111.111.111-11", "529.982.247-25"];
+//! let (valid, errors) = rows.iter().validate_cpf().partition_valid();
+//!
+//! assert_eq!(valid, vec!["12345678909", "52998224725"]);
+//! assert_eq!(errors[0].index, 1);
+//! ```
+
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::pipeline::Validator;
+use crate::{cep, cnpj, cpf, phone};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Signature of the module validators used by the shorthand adapters
+type ValidateFn = fn(&str) -> ValidationResult<String>;
+
+/// Validation error tagged with the position of the record in the stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedError {
+    /// Zero-based position of the record
+    pub index: usize,
+    /// Error raised for the record
+    pub error: BrazilianValidationError,
+}
+
+impl fmt::Display for IndexedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "registro {}: {}", self.index, self.error)
+    }
+}
+
+impl core::error::Error for IndexedError {}
+
+/// Iterator adapter validating each item. See [`ValidateExt`].
+#[derive(Debug, Clone)]
+pub struct Validated<I, V> {
+    inner: I,
+    validator: V,
+    index: usize,
+}
+
+impl<I, V> Iterator for Validated<I, V>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    V: Validator,
+{
+    type Item = Result<String, IndexedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        let index = self.index;
+        self.index += 1;
+
+        Some(
+            self.validator
+                .validate(item.as_ref())
+                .map_err(|error| IndexedError { index, error }),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, V> Validated<I, V>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    V: Validator,
+{
+    /// Keep only the normalized valid documents, dropping failures
+    pub fn valid_only(self) -> impl Iterator<Item = String> {
+        self.filter_map(Result::ok)
+    }
+
+    /// Split the stream into normalized valid documents and indexed errors
+    pub fn partition_valid(self) -> (Vec<String>, Vec<IndexedError>) {
+        let mut valid = Vec::new();
+        let mut errors = Vec::new();
+
+        for result in self {
+            match result {
+                Ok(document) => valid.push(document),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        (valid, errors)
+    }
+}
+
+/// Validation adapters for iterators of `&str` or `String`
+pub trait ValidateExt: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Validate each item with any [`Validator`]
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::iter::ValidateExt;
+    /// use pleme_brazilian_validators::pipeline::any_of;
+    /// use pleme_brazilian_validators::{cnpj, cpf};
+    ///
+    /// let taxpayers = vec!["12345678909".to_string(), "11222333000181".to_string()];
+    /// let valid: Vec<_> = taxpayers
+    ///     .into_iter()
+    ///     .validate_with(any_of(cpf::validate, cnpj::validate))
+    ///     .valid_only()
+    ///     .collect();
+    /// assert_eq!(valid.len(), 2);
+    /// ```
+    fn validate_with<V: Validator>(self, validator: V) -> Validated<Self, V> {
+        Validated {
+            inner: self,
+            validator,
+            index: 0,
+        }
+    }
+
+    /// Validate each item as a CPF
+    fn validate_cpf(self) -> Validated<Self, ValidateFn> {
+        self.validate_with(cpf::validate as ValidateFn)
+    }
+
+    /// Validate each item as a CNPJ
+    fn validate_cnpj(self) -> Validated<Self, ValidateFn> {
+        self.validate_with(cnpj::validate as ValidateFn)
+    }
+
+    /// Validate each item as a CEP
+    fn validate_cep(self) -> Validated<Self, ValidateFn> {
+        self.validate_with(cep::validate as ValidateFn)
+    }
+
+    /// Validate each item as a phone number
+    fn validate_phone(self) -> Validated<Self, ValidateFn> {
+        self.validate_with(phone::validate as ValidateFn)
+    }
+}
+
+impl<I> ValidateExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn test_errors_keep_stream_position() {
+        let results: Vec<_> = ["01310-100", "123", "20040-020", ""]
+            .into_iter()
+            .validate_cep()
+            .collect();
+
+        assert!(results[0].is_ok() && results[2].is_ok());
+        assert_eq!(results[1].as_ref().unwrap_err().index, 1);
+        assert_eq!(results[3].as_ref().unwrap_err().index, 3);
+    }
+
+    #[test]
+    fn test_partition_valid() {
+        let rows = [
+            "11.222.333/0001-81".to_string(),
+            "11.222.333/0001-00".to_string(),
+        ];
+        let (valid, errors) = rows.into_iter().validate_cnpj().partition_valid();
+
+        assert_eq!(valid, vec!["11222333000181"]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().starts_with("registro 1: "));
+    }
+
+    #[test]
+    fn test_valid_only() {
+        let phones = ["(11) 98765-4321", "(00) 1234-5678", "21 3456-7890"];
+        let valid: Vec<_> = phones.iter().validate_phone().valid_only().collect();
+        assert_eq!(valid, vec!["+5511987654321", "+552134567890"]);
+    }
+
+    #[test]
+    fn test_size_hint_is_forwarded() {
+        let iter = ["a", "b", "c"].iter().validate_cpf();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+    }
+}
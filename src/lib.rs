@@ -5,11 +5,60 @@
 //!
 //! # Features
 //!
-//! - **CPF**: Brazilian individual taxpayer ID (Cadastro de Pessoas Físicas)
-//! - **CNPJ**: Brazilian business taxpayer ID (Cadastro Nacional de Pessoa Jurídica)
+//! - **CPF**: Brazilian individual taxpayer ID (Cadastro de Pessoas Físicas), `cpf!` compile-time literals
+//! - **CNPJ**: Brazilian business taxpayer ID (Cadastro Nacional de Pessoa Jurídica), `cnpj!` compile-time literals
 //! - **CEP**: Brazilian postal code (Código de Endereçamento Postal)
 //! - **Phone**: Brazilian phone numbers with regional codes
-//! - **PIX**: Brazilian instant payment system keys
+//! - **PIX**: Brazilian instant payment system keys, BR Code parsing and encoding
+//! - **ASCII**: Accent-free geographic names for legacy file formats
+//! - **Audit**: LGPD audit records of document handling, never holding raw values
+//! - **Bank**: COMPE and ISPB registry of financial institutions
+//! - **Bank account**: Agência and conta check digits per bank
+//! - **Batch**: Per-row validation reports exported as CSV or JSON
+//! - **Boleto**: Bank and arrecadação barcodes, linhas digitáveis and hybrid boleto/PIX payments
+//! - **Card**: Payment card Luhn validation and brand detection, Elo and Hipercard included
+//! - **CEI/CAEPF/CNO**: INSS employer and construction work registrations
+//! - **Certidão**: Birth, marriage and death certificate matrículas
+//! - **CNAE**: Economic activity subclasses and their sections, division names (feature `dataset`)
+//! - **CNH**: Driver's license registration numbers (número de registro)
+//! - **CNS**: SUS health card numbers (Cartão Nacional de Saúde)
+//! - **Config**: Process-wide defaults (strictness, locale, mask style)
+//! - **Denylist**: Well-known test documents, rejected on demand
+//! - **Diagnostics**: Expected vs received check digits for support tooling
+//! - **Document**: Validated document of any kind, auto-detection for "CPF or CNPJ" fields, `BrazilianDocument` trait
+//! - **DV**: Modulo 11 / modulo 10 check-digit primitives
+//! - **Endereço**: Correios shipping-label address layout
+//! - **Extract**: Location, kind and validity of documents found in free text
+//! - **Generator**: Random valid test documents, optionally seeded (feature `generate`)
+//! - **GTIN**: EAN/UPC product barcodes, GS1 Brasil prefixes recognized
+//! - **Hooks**: Validation observer callbacks for data-quality metrics
+//! - **IBAN**: Brazilian IBAN validation and generation
+//! - **IBGE**: Municipality code check-digit validation, names of major municipalities (feature `dataset`)
+//! - **IE**: Inscrição Estadual validation and formatting for all 27 states
+//! - **Iter**: Iterator adapters validating streams of documents
+//! - **Lookup**: Async CEP, CNPJ, CPF status and bank lookups (feature `lookup`, or `lookup-core` with your own HTTP client)
+//! - **Masking**: Configurable mask character and revealed digits for CPF, CNPJ, phone and PIX keys
+//! - **Matching**: Fuzzy document matching for record deduplication
+//! - **NCM/CEST**: Mercosul goods nomenclature and tax substitution codes
+//! - **NF-e**: Access key validation and parsing, DANFE formatting and Code 128C barcodes
+//! - **OCR**: OCR-tolerant validation of scanned documents
+//! - **Passaporte**: Passport numbers issued by the Polícia Federal
+//! - **Patterns**: Canonical regular expressions for frontends and databases
+//! - **PIS/PASEP**: Worker registration numbers (NIS)
+//! - **Pipeline**: Combinators composing validators into reusable rules
+//! - **Placa**: Vehicle license plates, legacy and Mercosul formats
+//! - **Processo CNJ**: Unified lawsuit numbers, check digits and court fields
+//! - **RG**: Identity card numbers, with the São Paulo check digit
+//! - **RENAVAM**: Vehicle registry codes, legacy 9-digit codes included
+//! - **Redact**: `Sensitive` wrapper and `tracing` field formatter masking logged documents (feature `redact`)
+//! - **Report**: Multi-field error reports mapped to form fields, localized
+//! - **Scan**: Detection and LGPD redaction of documents in free text
+//! - **Secret**: Wrapper that keeps documents masked in `Debug`/`Display`
+//! - **Serde**: PII-safe serialization helpers and validating field adapters (feature `serialization`)
+//! - **Título de Eleitor**: Voter registration numbers and their issuing state
+//! - **Tokenize**: Format-preserving CPF/CNPJ tokenization (feature `tokenize`)
+//! - **UF**: Federative units with IBGE codes and regions
+//! - **Unicode**: Full-width and Arabic-Indic digits mapped to ASCII
 //!
 //! # Example
 //!
@@ -25,15 +74,119 @@
 //! // Normalize (remove formatting)
 //! assert_eq!(cpf::normalize("123.456.789-09"), "12345678909");
 //! ```
+//!
+//! # Panics
+//!
+//! No public function panics on arbitrary input, including multi-byte
+//! Unicode, embedded NULs and very large strings. Invalid input is reported
+//! through [`ValidationResult`] or returned unchanged by the formatters.
+//! This contract is exercised by the cargo-fuzz targets in `fuzz/`.
+//!
+//! # `no_std`
+//!
+//! The default `std` feature can be disabled for `no_std` environments with
+//! an allocator. The validators, formatters and document types keep working
+//! with the default [`ValidationOptions`](config::ValidationOptions);
+//! process-wide configuration, hooks, free-text scanning and the other
+//! modules built on them need `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod cpf;
 pub mod cnpj;
 pub mod cep;
 pub mod phone;
 pub mod pix;
+pub mod ascii;
+#[cfg(feature = "std")]
+pub mod audit;
+pub mod bank;
+pub mod bank_account;
+#[cfg(feature = "std")]
+pub mod batch;
+pub mod boleto;
+pub mod caepf;
+pub mod card;
+pub mod cei;
+pub mod certidao;
+pub mod cest;
+pub mod cnae;
+pub mod cnh;
+pub mod cno;
+pub mod cns;
+pub mod config;
+pub mod denylist;
+pub mod diagnostics;
+pub mod dv;
+pub mod document;
+pub mod endereco;
+#[cfg(feature = "std")]
+pub mod extract;
+pub mod gtin;
+pub mod hooks;
+pub mod iban;
+pub mod ibge;
+pub mod ie;
+pub mod iter;
+pub mod locale;
+pub mod masking;
+pub mod matching;
+pub mod ncm;
+pub mod nfe;
+pub mod ocr;
+pub mod passaporte;
+pub mod patterns;
+pub mod pis;
+pub mod pipeline;
+pub mod placa;
+pub mod processo_cnj;
+pub mod renavam;
+pub mod rg;
+pub mod report;
+pub mod scan;
+#[cfg(feature = "std")]
+pub mod secret;
+pub mod titulo_eleitor;
+pub mod uf;
+pub mod unicode;
 pub mod error;
 
+mod fixed;
+mod shape;
+
+#[cfg(feature = "dataset")]
+mod blob;
+
+#[cfg(feature = "barcode")]
+mod barcode;
+
+#[cfg(feature = "generate")]
+pub mod generator;
+
+#[cfg(feature = "lookup-core")]
+pub mod lookup;
+
+#[cfg(feature = "redact")]
+pub mod redact;
+
+#[cfg(feature = "serialization")]
+pub mod serde;
+
+#[cfg(feature = "tokenize")]
+pub mod tokenize;
+
+pub use document::{BrazilianDocument, Document, DocumentKind};
 pub use error::{BrazilianValidationError, ValidationResult};
+pub use locale::Locale;
+
+// Validated document types
+pub use cep::Cep;
+pub use cnpj::Cnpj;
+pub use cpf::Cpf;
+pub use phone::Phone;
+pub use pix::PixKey;
 
 // Re-export main functions for convenience
 pub use cpf::{validate, validate as validate_cpf, format as format_cpf, normalize as normalize_cpf};
@@ -41,3 +194,77 @@ pub use cnpj::{validate as validate_cnpj, format as format_cnpj, normalize as no
 pub use cep::{validate as validate_cep, format as format_cep, normalize as normalize_cep};
 pub use phone::{validate as validate_phone, format as format_phone, normalize as normalize_phone};
 pub use pix::validate_pix_key;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// Inputs that break naive byte-indexed string handling
+    fn adversarial_inputs() -> Vec<String> {
+        vec![
+            String::new(),
+            "\0".repeat(14),
+            "é".repeat(11),
+            "١٢٣.٤٥٦.٧٨٩-٠٩".to_string(),
+            "１２３.４５６.７８９-０９".to_string(),
+            "+55\u{200B}(11)\u{0}98765-4321".to_string(),
+            "ü@é.com".to_string(),
+            "+55ééééééééééé".to_string(),
+            "9".repeat(1_000_000),
+            "🇧🇷".repeat(10_000),
+        ]
+    }
+
+    #[test]
+    fn test_public_api_does_not_panic() {
+        for input in adversarial_inputs() {
+            let input = input.as_str();
+            let _ = (cpf::validate(input), cpf::format(input), cpf::mask(input));
+            let _ = (cnpj::validate(input), cnpj::format(input), cnpj::mask(input));
+            let _ = (cnpj::extract_base(input), cnpj::extract_branch(input));
+            let _ = (cep::validate(input), cep::format(input), cep::mask(input));
+            let _ = (cep::extract_region(input), cep::extract_sector(input));
+            let _ = (phone::validate(input), phone::format(input), phone::mask(input));
+            let _ = (phone::extract_ddd(input), phone::is_mobile(input));
+            let _ = (pix::validate_with_type(input), pix::normalize(input), pix::mask(input));
+            let _ = pix::brcode::parse(input).map(|payload| payload.validate_semantics());
+            let _ = document::detect(input);
+            #[cfg(feature = "std")]
+            {
+                let _ = scan::redact(input, scan::MaskStyle::Partial);
+                let _ = extract::scan(input);
+                let _ = secret::Secret::new(input).to_string();
+            }
+            let _ = (ibge::validate_municipio(input), input.parse::<uf::Uf>());
+            let _ = (nfe::parse(input), boleto::parse(input));
+            let _ = boleto::arrecadacao::parse(input);
+            let _ = bank_account::validate_account(input, input, input);
+            let _ = (bank::by_compe(input), bank::by_ispb(input));
+            let _ = (iban::validate_br(input), iban::format(input));
+            let _ = (card::validate(input), card::mask(input), card::detect_brand(input));
+            let _ = (processo_cnj::parse(input), processo_cnj::format(input));
+            let mask_options = masking::MaskOptions::default();
+            let _ = (cpf::mask_with(input, &mask_options), pix::mask_with(input, &mask_options));
+            let _ = (cnpj::mask_with(input, &mask_options), phone::mask_with(input, &mask_options));
+            let _ = (cest::validate(input), cest::segment(input), cest::format(input));
+            let _ = (ncm::validate(input), ncm::chapter(input), ncm::format(input));
+            let _ = (cnae::validate(input), cnae::section(input), cnae::format(input));
+            let _ = (gtin::validate(input), gtin::is_brazilian_prefix(input));
+            let _ = (cei::validate(input), caepf::validate(input), cno::validate(input));
+            let _ = (certidao::parse(input), certidao::format(input));
+            let _ = (passaporte::validate(input), passaporte::mask(input));
+            let _ = (rg::validate(input, Some(uf::Uf::SP)), rg::mask(input));
+            let _ = (cns::validate(input), cns::mask(input));
+            let _ = (pis::validate(input), pis::format(input), renavam::validate(input));
+            let _ = (ie::possible_ufs(input), ie::format(input, uf::Uf::SP));
+
+            for kind in DocumentKind::iter() {
+                let _ = ocr::validate(input, kind);
+                let _ = matching::similarity(input, input, kind);
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Message locales
+//!
+//! Error messages are written in Brazilian Portuguese by default; API
+//! layers serving other audiences can render them in English instead.
+
+/// Language used to render validation messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// Brazilian Portuguese (the `Display` messages)
+    #[default]
+    PtBr,
+    /// English
+    En,
+}
+
+impl Locale {
+    /// Every supported locale
+    pub const ALL: [Locale; 2] = [Locale::PtBr, Locale::En];
+
+    /// Iterate over every supported locale
+    pub fn iter() -> impl Iterator<Item = Locale> {
+        Locale::ALL.into_iter()
+    }
+
+    /// BCP 47 language tag
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::Locale;
+    ///
+    /// assert_eq!(Locale::PtBr.tag(), "pt-BR");
+    /// assert_eq!(Locale::from_tag("en-US"), Some(Locale::En));
+    /// ```
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::PtBr => "pt-BR",
+            Locale::En => "en",
+        }
+    }
+
+    /// Parse a BCP 47 language tag (e.g. from `Accept-Language`)
+    ///
+    /// Only the primary language subtag is considered, case-insensitively.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.trim().split(['-', '_']).next()?;
+
+        if language.eq_ignore_ascii_case("pt") {
+            Some(Locale::PtBr)
+        } else if language.eq_ignore_ascii_case("en") {
+            Some(Locale::En)
+        } else {
+            None
+        }
+    }
+}
+
+/// Serialized as its language tag (`"pt-BR"`, `"en"`)
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.tag())
+    }
+}
+
+/// Deserialized from any tag accepted by [`Locale::from_tag`]
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let tag = String::deserialize(deserializer)?;
+        Locale::from_tag(&tag)
+            .ok_or_else(|| serde::de::Error::custom(format!("unsupported locale: {}", tag)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Locale::from_tag("pt-BR"), Some(Locale::PtBr));
+        assert_eq!(Locale::from_tag("PT"), Some(Locale::PtBr));
+        assert_eq!(Locale::from_tag("en_GB"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("es-AR"), None);
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn test_tags_round_trip() {
+        for locale in Locale::iter() {
+            assert_eq!(Locale::from_tag(locale.tag()), Some(locale));
+        }
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_serde_uses_tags() {
+        assert_eq!(serde_json::to_string(&Locale::PtBr).unwrap(), r#""pt-BR""#);
+        assert_eq!(serde_json::from_str::<Locale>(r#""en-US""#).unwrap(), Locale::En);
+        assert!(serde_json::from_str::<Locale>(r#""es""#).is_err());
+    }
+}
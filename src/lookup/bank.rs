@@ -0,0 +1,159 @@
+//! COMPE code → financial institution lookup providers
+
+use super::http::Transport;
+use super::{non_empty, LookupError, LookupProvider, LookupResult};
+use crate::error::BrazilianValidationError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Default BrasilAPI banks endpoint
+const BRASILAPI_BANKS_URL: &str = "https://brasilapi.com.br/api/banks/v1";
+
+/// Financial institution participating in the Brazilian payment system
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BankInstitution {
+    /// ISPB (8 digits)
+    pub ispb: String,
+    /// COMPE code (3 digits), absent for institutions without clearing code
+    pub compe: Option<String>,
+    /// Short name
+    pub name: String,
+    /// Full legal name
+    pub full_name: Option<String>,
+}
+
+/// BrasilAPI banks v1 response body
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BrasilApiBankResponse {
+    ispb: String,
+    name: Option<String>,
+    code: Option<u16>,
+    full_name: Option<String>,
+}
+
+impl From<BrasilApiBankResponse> for BankInstitution {
+    fn from(response: BrasilApiBankResponse) -> Self {
+        BankInstitution {
+            ispb: response.ispb,
+            compe: response.code.map(|code| format!("{:03}", code)),
+            name: response.name.unwrap_or_default(),
+            full_name: non_empty(response.full_name),
+        }
+    }
+}
+
+/// Normalize a COMPE code to 3 digits
+fn normalize_compe(code: &str) -> LookupResult<String> {
+    let code = code.trim();
+
+    if !code.chars().all(|c| c.is_ascii_digit()) {
+        return Err(LookupError::Invalid(
+            BrazilianValidationError::InvalidCharacters,
+        ));
+    }
+    if code.is_empty() || code.len() > 3 {
+        return Err(LookupError::Invalid(BrazilianValidationError::InvalidLength {
+            expected: 3,
+            actual: code.len(),
+        }));
+    }
+
+    Ok(format!("{:0>3}", code))
+}
+
+/// Institution lookup by COMPE code backed by [BrasilAPI](https://brasilapi.com.br)
+#[derive(Debug, Clone)]
+pub struct BrasilApiBanks {
+    client: Transport,
+    base_url: String,
+}
+
+impl BrasilApiBanks {
+    /// Create a provider using the public BrasilAPI endpoint
+    ///
+    /// Requests time out after [`DEFAULT_TIMEOUT`](super::DEFAULT_TIMEOUT).
+    #[cfg(feature = "lookup")]
+    pub fn new() -> Self {
+        Self::with_timeout(super::DEFAULT_TIMEOUT)
+    }
+
+    /// Create a provider using the public BrasilAPI endpoint, giving up on
+    /// requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub fn with_timeout(timeout: std::time::Duration) -> Self {
+        Self::with_transport(Transport::reqwest(timeout))
+    }
+
+    /// Create a provider sending requests through the given HTTP client
+    ///
+    /// Accepts a configured `reqwest::Client` or any other [`HttpClient`](super::HttpClient).
+    pub fn with_client(client: impl super::HttpClient + 'static) -> Self {
+        Self::with_transport(Transport::new(client))
+    }
+
+    fn with_transport(client: Transport) -> Self {
+        BrasilApiBanks {
+            client,
+            base_url: BRASILAPI_BANKS_URL.to_string(),
+        }
+    }
+
+    /// Override the endpoint (e.g. an internal mirror)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "lookup")]
+impl Default for BrasilApiBanks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LookupProvider for BrasilApiBanks {
+    type Output = BankInstitution;
+
+    fn name(&self) -> &'static str {
+        "brasilapi"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<BankInstitution> {
+        let compe = normalize_compe(key)?;
+        let url = format!("{}/{}", self.base_url, compe);
+        self.client.get_json::<BrasilApiBankResponse>(&url)
+            .await
+            .map(BankInstitution::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_compe() {
+        assert_eq!(normalize_compe("1").unwrap(), "001");
+        assert_eq!(normalize_compe("341").unwrap(), "341");
+        assert!(normalize_compe("").is_err());
+        assert!(normalize_compe("1234").is_err());
+        assert!(normalize_compe("3a1").is_err());
+    }
+
+    #[test]
+    fn test_brasilapi_response_into_institution() {
+        let response: BrasilApiBankResponse = serde_json::from_str(
+            r#"{"ispb":"00000000","name":"BCO DO BRASIL S.A.","code":1,
+                "fullName":"Banco do Brasil S.A."}"#,
+        )
+        .unwrap();
+
+        let institution = BankInstitution::from(response);
+        assert_eq!(institution.ispb, "00000000");
+        assert_eq!(institution.compe.as_deref(), Some("001"));
+        assert_eq!(institution.full_name.as_deref(), Some("Banco do Brasil S.A."));
+    }
+}
@@ -0,0 +1,97 @@
+//! Blocking lookups for synchronous code
+//!
+//! Runs the async providers on a private single-threaded runtime, so CLI
+//! tools and sync codebases can perform lookups without setting up tokio.
+//! Responses use the same models as the async API.
+//!
+//! Enabled by the `blocking` feature.
+//!
+//! # Examples
+//! ```no_run
+//! use pleme_brazilian_validators::lookup::blocking;
+//!
+//! let address = blocking::lookup_cep("01001-000").unwrap();
+//! println!("{} - {}", address.street, address.city);
+//! ```
+
+use super::{
+    Address, BankInstitution, BrasilApiBanks, BrasilApiCnpj, Company, LookupError,
+    LookupProvider, LookupResult, ViaCep,
+};
+use tokio::runtime::{Builder, Handle};
+
+/// Run any provider's lookup to completion on the current thread
+///
+/// Must not be called from within an async runtime (it would block the
+/// executor); in that case [`LookupError::Unavailable`] is returned.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::lookup::{blocking, LookupError, MockCepProvider};
+///
+/// let provider = MockCepProvider::new();
+/// assert_eq!(blocking::lookup(&provider, "01001-000"), Err(LookupError::NotFound));
+/// ```
+pub fn lookup<P: LookupProvider>(provider: &P, key: &str) -> LookupResult<P::Output> {
+    if Handle::try_current().is_ok() {
+        return Err(LookupError::Unavailable(
+            "consulta bloqueante chamada dentro de um runtime assíncrono".to_string(),
+        ));
+    }
+
+    let runtime = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| LookupError::Unavailable(e.to_string()))?;
+
+    runtime.block_on(provider.lookup(key))
+}
+
+/// Look up the address of a CEP using ViaCEP
+pub fn lookup_cep(cep: &str) -> LookupResult<Address> {
+    lookup(&ViaCep::new(), cep)
+}
+
+/// Look up the registration data of a CNPJ using BrasilAPI
+pub fn lookup_cnpj(cnpj: &str) -> LookupResult<Company> {
+    lookup(&BrasilApiCnpj::new(), cnpj)
+}
+
+/// Look up a financial institution by COMPE code using BrasilAPI
+pub fn lookup_bank(code: &str) -> LookupResult<BankInstitution> {
+    lookup(&BrasilApiBanks::new(), code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lookup::MockCnpjProvider;
+
+    #[test]
+    fn test_lookup_without_runtime() {
+        let provider = MockCnpjProvider::new();
+        provider.fail_next(LookupError::RateLimited);
+
+        assert_eq!(
+            lookup(&provider, "11.222.333/0001-81"),
+            Err(LookupError::RateLimited)
+        );
+        assert_eq!(lookup(&provider, "11222333000181"), Err(LookupError::NotFound));
+    }
+
+    #[test]
+    fn test_invalid_document_is_rejected() {
+        assert!(matches!(lookup_cnpj("11.222.333/0001-00"), Err(LookupError::Invalid(_))));
+        assert!(matches!(lookup_cep("0100"), Err(LookupError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn test_lookup_inside_runtime_is_refused() {
+        let provider = MockCnpjProvider::new();
+        assert!(matches!(
+            lookup(&provider, "11222333000181"),
+            Err(LookupError::Unavailable(_))
+        ));
+        assert!(provider.calls().is_empty());
+    }
+}
@@ -0,0 +1,372 @@
+//! Caching wrapper for lookup providers
+//!
+//! [`Cache`] keeps successful lookups in a [`CacheStore`] for a fixed time
+//! to live. [`MemoryStore`] lives as long as the process; [`FileStore`]
+//! persists entries on disk, so nightly batch jobs re-run over the same
+//! CEPs and CNPJs do not query the providers again. Implement
+//! [`CacheStore`] to use any other key-value store (Redis, SQLite, ...).
+//!
+//! # Examples
+//! ```no_run
+//! use pleme_brazilian_validators::lookup::{Cache, FileStore, ViaCep};
+//! use std::time::Duration;
+//!
+//! # #[cfg(feature = "lookup")]
+//! # fn run() -> std::io::Result<()> {
+//! let store = FileStore::new("/var/cache/cep")?;
+//! let provider = Cache::new(ViaCep::new(), store, Duration::from_secs(30 * 24 * 3600));
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{LookupProvider, LookupResult};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Cached lookup result with its expiry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Lookup result, as JSON
+    pub value: serde_json::Value,
+    /// Expiry time, in seconds since the Unix epoch
+    pub expires_at: u64,
+}
+
+impl CacheEntry {
+    /// Check whether the entry has expired at the given time
+    pub fn is_expired_at(&self, now: SystemTime) -> bool {
+        unix_seconds(now) >= self.expires_at
+    }
+}
+
+/// Key-value storage behind a [`Cache`]
+///
+/// Stores only hold entries; expiry is checked by the cache, so stores may
+/// return expired entries and purge them lazily.
+pub trait CacheStore: Send + Sync {
+    /// Get the entry stored under a key
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+
+    /// Store an entry, replacing any previous one
+    fn put(&self, key: &str, entry: CacheEntry);
+}
+
+/// In-process cache store
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of entries stored, expired ones included
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map(|entries| entries.len())
+            .unwrap_or(0)
+    }
+
+    /// Check whether the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries.lock().ok()?.get(key).cloned()
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(key.to_string(), entry);
+        }
+    }
+}
+
+/// Cache store keeping one JSON file per entry in a directory
+///
+/// Files are replaced atomically, so concurrent jobs sharing the directory
+/// never read a partial entry. I/O errors are treated as cache misses: a
+/// broken cache slows lookups down but never fails them.
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    /// Use a directory for the cache, creating it if needed
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        Ok(FileStore { dir })
+    }
+
+    /// Directory holding the entries
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Delete expired entries, returning how many were removed
+    pub fn purge_expired(&self) -> io::Result<usize> {
+        let now = SystemTime::now();
+        let mut removed = 0;
+
+        for file in std::fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().is_some_and(|ext| ext == "json")
+                && read_entry(&path).is_some_and(|entry| entry.is_expired_at(now))
+            {
+                std::fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// File holding the entry of a key
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+impl CacheStore for FileStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        read_entry(&self.path(key))
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        let path = self.path(key);
+        let Ok(json) = serde_json::to_vec(&entry) else {
+            return;
+        };
+
+        // Write to a temporary file first, then move it into place
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+        if std::fs::write(&tmp, json).is_ok() && std::fs::rename(&tmp, &path).is_err() {
+            let _ = std::fs::remove_file(&tmp);
+        }
+    }
+}
+
+/// Read and parse an entry file, `None` if missing or corrupt
+fn read_entry(path: &Path) -> Option<CacheEntry> {
+    let json = std::fs::read(path).ok()?;
+    serde_json::from_slice(&json).ok()
+}
+
+/// Seconds since the Unix epoch (0 for earlier times)
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// Provider wrapper caching successful lookups for a time to live
+///
+/// Keys are stored under the provider name and the key stripped of
+/// punctuation, so `"01001-000"` and `"01001000"` share an entry. Errors,
+/// including [`NotFound`](super::LookupError::NotFound), are not cached.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::lookup::{Cache, LookupProvider, MockCnpjProvider};
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # use pleme_brazilian_validators::lookup::{Company, CompanyAddress};
+/// # let company = Company {
+/// #     cnpj: "11222333000181".to_string(),
+/// #     legal_name: "EMPRESA EXEMPLO LTDA".to_string(),
+/// #     trade_name: None,
+/// #     registration_status: None,
+/// #     main_activity: None,
+/// #     secondary_activities: Vec::new(),
+/// #     partners: Vec::new(),
+/// #     address: CompanyAddress::default(),
+/// # };
+/// let mock = MockCnpjProvider::new().with_record("11222333000181", company);
+/// let provider = Cache::in_memory(mock, Duration::from_secs(3600));
+///
+/// provider.lookup("11.222.333/0001-81").await.unwrap();
+/// provider.lookup("11222333000181").await.unwrap();
+/// assert_eq!(provider.inner().calls().len(), 1);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cache<P, S = MemoryStore> {
+    inner: P,
+    store: S,
+    ttl: Duration,
+}
+
+impl<P> Cache<P, MemoryStore> {
+    /// Cache lookups in memory
+    pub fn in_memory(inner: P, ttl: Duration) -> Self {
+        Self::new(inner, MemoryStore::new(), ttl)
+    }
+}
+
+impl<P, S> Cache<P, S> {
+    /// Cache lookups in the given store
+    pub fn new(inner: P, store: S, ttl: Duration) -> Self {
+        Cache { inner, store, ttl }
+    }
+
+    /// Access the wrapped provider
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Access the cache store
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+#[async_trait]
+impl<P, S> LookupProvider for Cache<P, S>
+where
+    P: LookupProvider,
+    P::Output: Serialize + DeserializeOwned,
+    S: CacheStore,
+{
+    type Output = P::Output;
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<P::Output> {
+        let cache_key = format!(
+            "{}:{}",
+            self.inner.name(),
+            key.chars()
+                .filter(char::is_ascii_alphanumeric)
+                .collect::<String>()
+        );
+
+        let now = SystemTime::now();
+        let cached = self
+            .store
+            .get(&cache_key)
+            .filter(|entry| !entry.is_expired_at(now))
+            .and_then(|entry| serde_json::from_value(entry.value).ok());
+        if let Some(value) = cached {
+            return Ok(value);
+        }
+
+        let value = self.inner.lookup(key).await?;
+        if let Ok(json) = serde_json::to_value(&value) {
+            let entry = CacheEntry {
+                value: json,
+                expires_at: unix_seconds(now + self.ttl),
+            };
+            self.store.put(&cache_key, entry);
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lookup::{Address, LookupError, MockCepProvider};
+
+    fn address() -> Address {
+        Address {
+            cep: "01001000".to_string(),
+            street: "Praça da Sé".to_string(),
+            complement: None,
+            neighborhood: "Sé".to_string(),
+            city: "São Paulo".to_string(),
+            state: "SP".to_string(),
+            ibge_code: Some("3550308".to_string()),
+            coordinates: None,
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("pleme-cache-{}-{}", name, std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_not_cached() {
+        let provider = Cache::in_memory(MockCepProvider::new(), Duration::from_secs(60));
+
+        assert_eq!(
+            provider.lookup("01001-000").await,
+            Err(LookupError::NotFound)
+        );
+        assert_eq!(
+            provider.lookup("01001-000").await,
+            Err(LookupError::NotFound)
+        );
+        assert_eq!(provider.inner().calls().len(), 2);
+        assert!(provider.store().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_refreshed() {
+        let mock = MockCepProvider::new().with_record("01001000", address());
+        let provider = Cache::in_memory(mock, Duration::ZERO);
+
+        provider.lookup("01001000").await.unwrap();
+        provider.lookup("01001000").await.unwrap();
+        assert_eq!(provider.inner().calls().len(), 2);
+        assert_eq!(provider.store().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_file_store_persists_across_instances() {
+        let dir = temp_dir("persist");
+        let ttl = Duration::from_secs(60);
+
+        let mock = MockCepProvider::new().with_record("01001000", address());
+        let first = Cache::new(mock, FileStore::new(&dir).unwrap(), ttl);
+        assert_eq!(first.lookup("01001-000").await.unwrap(), address());
+
+        // A new process with an empty provider is served from disk
+        let second = Cache::new(MockCepProvider::new(), FileStore::new(&dir).unwrap(), ttl);
+        assert_eq!(second.lookup("01001000").await.unwrap(), address());
+        assert!(second.inner().calls().is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_store_purges_expired_entries() {
+        let store = FileStore::new(temp_dir("purge")).unwrap();
+        let entry = |expires_at| CacheEntry {
+            value: serde_json::Value::Null,
+            expires_at,
+        };
+        store.put("old", entry(0));
+        store.put("new", entry(u64::MAX));
+        std::fs::write(store.dir().join("corrupt.json"), "not json").unwrap();
+
+        assert_eq!(store.purge_expired().unwrap(), 1);
+        assert!(store.get("old").is_none());
+        assert!(store.get("new").is_some());
+        assert!(store.get("corrupt").is_none());
+
+        std::fs::remove_dir_all(store.dir()).unwrap();
+    }
+}
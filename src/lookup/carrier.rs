@@ -0,0 +1,311 @@
+//! Mobile number → carrier lookup
+//!
+//! Number portability means the prefix of a mobile number no longer tells
+//! which carrier serves it. Online providers (portability databases) are
+//! plugged in through [`LookupProvider`] with [`CarrierInfo`] as output;
+//! [`AllocationTable`] is the offline, best-effort alternative returning
+//! the carrier a number range was originally allocated to.
+
+use super::{LookupError, LookupProvider, LookupResult};
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::phone;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Shortest allocation prefix accepted: DDD plus one subscriber digit
+const MIN_PREFIX_LEN: usize = 3;
+
+/// Mobile carrier operating in Brazil
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Carrier {
+    Vivo,
+    Claro,
+    Tim,
+    Oi,
+    Algar,
+    Sercomtel,
+    /// Any other carrier (MVNOs, regional operators), by name
+    Other(String),
+}
+
+impl Carrier {
+    /// Identify a carrier by brand or legal name
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::lookup::Carrier;
+    ///
+    /// assert_eq!(Carrier::from_name("TELEFONICA BRASIL S.A."), Carrier::Vivo);
+    /// assert_eq!(Carrier::from_name("tim s/a"), Carrier::Tim);
+    /// assert_eq!(Carrier::from_name("Nextel"), Carrier::Other("Nextel".to_string()));
+    /// ```
+    pub fn from_name(name: &str) -> Self {
+        let upper = name.trim().to_uppercase();
+        let has_word = |word: &str| {
+            upper
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|token| token == word)
+        };
+
+        if has_word("VIVO") || upper.contains("TELEFONICA") || upper.contains("TELEFÔNICA") {
+            Carrier::Vivo
+        } else if has_word("CLARO") {
+            Carrier::Claro
+        } else if has_word("TIM") {
+            Carrier::Tim
+        } else if has_word("OI") || upper.contains("TELEMAR") {
+            Carrier::Oi
+        } else if has_word("ALGAR") || upper.contains("CTBC") {
+            Carrier::Algar
+        } else if has_word("SERCOMTEL") {
+            Carrier::Sercomtel
+        } else {
+            Carrier::Other(name.trim().to_string())
+        }
+    }
+}
+
+impl fmt::Display for Carrier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Carrier::Vivo => f.write_str("Vivo"),
+            Carrier::Claro => f.write_str("Claro"),
+            Carrier::Tim => f.write_str("TIM"),
+            Carrier::Oi => f.write_str("Oi"),
+            Carrier::Algar => f.write_str("Algar"),
+            Carrier::Sercomtel => f.write_str("Sercomtel"),
+            Carrier::Other(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Carrier serving a mobile number
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarrierInfo {
+    /// Normalized phone number (`+55` followed by DDD and number)
+    pub phone: String,
+    /// Carrier serving (or originally allocated) the number
+    pub carrier: Carrier,
+    /// Whether the number was ported; `None` when the source cannot tell
+    pub ported: Option<bool>,
+}
+
+/// Validate a mobile number, returning its normalized form
+///
+/// Carrier lookups only make sense for mobile numbers; landlines are
+/// rejected.
+pub(crate) fn normalize_mobile(key: &str) -> ValidationResult<String> {
+    let normalized = phone::validate(key)?;
+    if !phone::is_mobile(&normalized) {
+        return Err(BrazilianValidationError::invalid_phone(
+            "número não é de celular",
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Offline table of original number-range allocations
+///
+/// Maps prefixes (DDD followed by the first digits of the subscriber
+/// number) to the carrier the range was allocated to, e.g. from Anatel's
+/// numbering plan. Lookups use the longest matching prefix. Results ignore
+/// portability, so they are a best-effort hint (`ported` is always `None`).
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::lookup::{AllocationTable, Carrier};
+///
+/// let table = AllocationTable::from_csv("prefixo;operadora\n1199;Claro\n11998;Vivo\n").unwrap();
+///
+/// assert_eq!(table.original_carrier("(11) 99876-5432"), Ok(Some(&Carrier::Vivo)));
+/// assert_eq!(table.original_carrier("(11) 99123-4567"), Ok(Some(&Carrier::Claro)));
+/// assert_eq!(table.original_carrier("(21) 99123-4567"), Ok(None));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllocationTable {
+    prefixes: HashMap<String, Carrier>,
+    longest_prefix: usize,
+}
+
+impl AllocationTable {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocate a prefix (DDD + subscriber digits) to a carrier
+    ///
+    /// Non-digit characters are ignored, so `"(11) 9987"` is accepted.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Prefix added (replacing any previous allocation)
+    /// * `Err(BrazilianValidationError)` - Prefix too short or too long
+    pub fn insert(&mut self, prefix: &str, carrier: Carrier) -> ValidationResult<()> {
+        let prefix: String = prefix.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        if !(MIN_PREFIX_LEN..=11).contains(&prefix.len()) {
+            return Err(BrazilianValidationError::invalid_phone(format!(
+                "prefixo de alocação inválido: {}",
+                prefix
+            )));
+        }
+
+        self.longest_prefix = self.longest_prefix.max(prefix.len());
+        self.prefixes.insert(prefix, carrier);
+        Ok(())
+    }
+
+    /// Build a table from `prefix;carrier` lines
+    ///
+    /// Commas are also accepted as separators. Blank lines, lines starting
+    /// with `#` and a header line whose prefix is not numeric are skipped.
+    /// Carrier names go through [`Carrier::from_name`].
+    pub fn from_csv(data: &str) -> LookupResult<Self> {
+        let mut table = Self::new();
+
+        for (number, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((prefix, carrier)) = line.split_once([';', ',']) else {
+                return Err(LookupError::InvalidResponse(format!(
+                    "linha {}: separador ausente",
+                    number + 1
+                )));
+            };
+
+            let is_header = number == 0 && !prefix.trim().starts_with(|c: char| c.is_ascii_digit());
+            if is_header {
+                continue;
+            }
+
+            table
+                .insert(prefix, Carrier::from_name(carrier))
+                .map_err(|e| LookupError::InvalidResponse(format!("linha {}: {}", number + 1, e)))?;
+        }
+
+        Ok(table)
+    }
+
+    /// Number of allocated prefixes
+    pub fn len(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    /// Check whether the table has no allocations
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// Carrier a mobile number was originally allocated to
+    ///
+    /// # Returns
+    /// * `Ok(Some(&Carrier))` - Longest matching prefix found
+    /// * `Ok(None)` - No allocation covers the number
+    /// * `Err(BrazilianValidationError)` - Not a valid mobile number
+    pub fn original_carrier(&self, phone: &str) -> ValidationResult<Option<&Carrier>> {
+        let normalized = normalize_mobile(phone)?;
+        let national = phone::strip_country_code(&normalized);
+
+        let longest = self.longest_prefix.min(national.len());
+        Ok((MIN_PREFIX_LEN..=longest)
+            .rev()
+            .find_map(|len| national.get(..len).and_then(|p| self.prefixes.get(p))))
+    }
+}
+
+#[async_trait]
+impl LookupProvider for AllocationTable {
+    type Output = CarrierInfo;
+
+    fn name(&self) -> &'static str {
+        "allocation"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<CarrierInfo> {
+        let carrier = self
+            .original_carrier(key)?
+            .cloned()
+            .ok_or(LookupError::NotFound)?;
+
+        Ok(CarrierInfo {
+            phone: normalize_mobile(key)?,
+            carrier,
+            ported: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> AllocationTable {
+        let mut table = AllocationTable::new();
+        table.insert("1199", Carrier::Claro).unwrap();
+        table.insert("(11) 9987", Carrier::Tim).unwrap();
+        table.insert("2198", Carrier::Oi).unwrap();
+        table
+    }
+
+    #[test]
+    fn test_carrier_from_name() {
+        assert_eq!(Carrier::from_name("CLARO S.A."), Carrier::Claro);
+        assert_eq!(Carrier::from_name("TELEMAR NORTE LESTE S.A."), Carrier::Oi);
+        assert_eq!(Carrier::from_name("Algar Telecom"), Carrier::Algar);
+        assert_eq!(Carrier::from_name("Vivo"), Carrier::Vivo);
+        // "TIM" must be a whole word, not part of another name
+        assert_eq!(
+            Carrier::from_name("Timbira Telecom"),
+            Carrier::Other("Timbira Telecom".to_string())
+        );
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let table = table();
+        assert_eq!(table.original_carrier("11998765432"), Ok(Some(&Carrier::Tim)));
+        assert_eq!(table.original_carrier("11991234567"), Ok(Some(&Carrier::Claro)));
+        assert_eq!(table.original_carrier("+55 21 98123-4567"), Ok(Some(&Carrier::Oi)));
+        assert_eq!(table.original_carrier("31991234567"), Ok(None));
+    }
+
+    #[test]
+    fn test_landlines_are_rejected() {
+        assert!(table().original_carrier("1134567890").is_err());
+        assert!(table().original_carrier("abc").is_err());
+    }
+
+    #[test]
+    fn test_insert_rejects_bad_prefixes() {
+        let mut table = AllocationTable::new();
+        assert!(table.insert("11", Carrier::Vivo).is_err());
+        assert!(table.insert("119876543210", Carrier::Vivo).is_err());
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let table = AllocationTable::from_csv("# plano\n\n1199,CLARO S.A.\n2198;Oi\n").unwrap();
+        assert_eq!(table.len(), 2);
+
+        assert!(matches!(
+            AllocationTable::from_csv("1199;Claro\n1198 Vivo\n"),
+            Err(LookupError::InvalidResponse(msg)) if msg.starts_with("linha 2")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lookup_provider() {
+        let info = table().lookup("(21) 98123-4567").await.unwrap();
+        assert_eq!(info.phone, "+5521981234567");
+        assert_eq!(info.carrier, Carrier::Oi);
+        assert_eq!(info.ported, None);
+
+        assert_eq!(table().lookup("31991234567").await, Err(LookupError::NotFound));
+    }
+}
@@ -0,0 +1,469 @@
+//! CEP → address lookup providers
+
+use super::http::Transport;
+use super::{non_empty, LookupError, LookupProvider, LookupResult};
+use crate::cep;
+use crate::uf::Uf;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Default ViaCEP endpoint
+const VIACEP_URL: &str = "https://viacep.com.br/ws";
+
+/// Default BrasilAPI CEP endpoint (the API version is appended per request)
+const BRASILAPI_CEP_URL: &str = "https://brasilapi.com.br/api/cep";
+
+/// Mean Earth radius used for distances, in kilometers
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Geographic coordinates (WGS 84, decimal degrees)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Coordinates {
+    /// Latitude, negative south of the Equator
+    pub latitude: f64,
+    /// Longitude, negative west of Greenwich
+    pub longitude: f64,
+}
+
+impl Coordinates {
+    /// Create coordinates, rejecting values outside the valid ranges
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::lookup::Coordinates;
+    ///
+    /// assert!(Coordinates::new(-23.5505, -46.6333).is_some());
+    /// assert!(Coordinates::new(-123.0, -46.6333).is_none());
+    /// ```
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        let valid = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+        valid.then_some(Coordinates {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Great-circle distance to other coordinates, in kilometers
+    ///
+    /// Uses the haversine formula, accurate to well under 1% for the
+    /// distances relevant to delivery-radius checks.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::lookup::Coordinates;
+    ///
+    /// let sao_paulo = Coordinates::new(-23.5505, -46.6333).unwrap();
+    /// let rio = Coordinates::new(-22.9068, -43.1729).unwrap();
+    /// let distance = sao_paulo.distance_km(&rio);
+    /// assert!((355.0..365.0).contains(&distance));
+    /// ```
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+
+        let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+}
+
+/// Address registered for a CEP
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Address {
+    /// Normalized CEP (8 digits)
+    pub cep: String,
+    /// Street (logradouro), empty for single-CEP municipalities
+    pub street: String,
+    /// Complement (e.g. "lado ímpar")
+    pub complement: Option<String>,
+    /// Neighborhood (bairro)
+    pub neighborhood: String,
+    /// Municipality
+    pub city: String,
+    /// State abbreviation (UF)
+    pub state: String,
+    /// IBGE municipality code, when the provider reports it
+    pub ibge_code: Option<String>,
+    /// Location of the CEP, when geocoding was requested and available
+    pub coordinates: Option<Coordinates>,
+}
+
+impl Address {
+    /// Federative unit of the address, if the provider's state is known
+    pub fn uf(&self) -> Option<Uf> {
+        self.state.parse().ok()
+    }
+}
+
+/// ViaCEP response body
+#[derive(Debug, Deserialize)]
+struct ViaCepResponse {
+    #[serde(default)]
+    erro: Option<serde_json::Value>,
+    cep: Option<String>,
+    logradouro: Option<String>,
+    complemento: Option<String>,
+    bairro: Option<String>,
+    localidade: Option<String>,
+    uf: Option<String>,
+    ibge: Option<String>,
+}
+
+impl ViaCepResponse {
+    /// Convert into an [`Address`]; ViaCEP reports unknown CEPs with `erro`
+    fn into_address(self) -> LookupResult<Address> {
+        if self.erro.is_some() {
+            return Err(LookupError::NotFound);
+        }
+
+        let cep = self
+            .cep
+            .map(|c| cep::normalize(&c))
+            .ok_or_else(|| LookupError::InvalidResponse("campo cep ausente".to_string()))?;
+
+        Ok(Address {
+            cep,
+            street: self.logradouro.unwrap_or_default(),
+            complement: non_empty(self.complemento),
+            neighborhood: self.bairro.unwrap_or_default(),
+            city: self.localidade.unwrap_or_default(),
+            state: self.uf.unwrap_or_default(),
+            ibge_code: non_empty(self.ibge),
+            coordinates: None,
+        })
+    }
+}
+
+/// BrasilAPI CEP response body (v2 adds `location` to the v1 fields)
+#[derive(Debug, Deserialize)]
+struct BrasilApiCepResponse {
+    cep: String,
+    state: Option<String>,
+    city: Option<String>,
+    neighborhood: Option<String>,
+    street: Option<String>,
+    location: Option<BrasilApiLocation>,
+}
+
+/// GeoJSON-like point returned by BrasilAPI v2
+#[derive(Debug, Deserialize)]
+struct BrasilApiLocation {
+    coordinates: Option<BrasilApiCoordinates>,
+}
+
+/// Coordinates returned by BrasilAPI v2, as strings or numbers, possibly empty
+#[derive(Debug, Deserialize)]
+struct BrasilApiCoordinates {
+    latitude: Option<serde_json::Value>,
+    longitude: Option<serde_json::Value>,
+}
+
+impl BrasilApiLocation {
+    /// Extract valid coordinates, ignoring missing or malformed values
+    fn into_coordinates(self) -> Option<Coordinates> {
+        let coordinates = self.coordinates?;
+        Coordinates::new(
+            parse_degrees(coordinates.latitude?)?,
+            parse_degrees(coordinates.longitude?)?,
+        )
+    }
+}
+
+/// Parse a coordinate sent either as a JSON number or a numeric string
+fn parse_degrees(value: serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .filter(|degrees: &f64| degrees.is_finite())
+}
+
+impl From<BrasilApiCepResponse> for Address {
+    fn from(response: BrasilApiCepResponse) -> Self {
+        Address {
+            cep: cep::normalize(&response.cep),
+            street: response.street.unwrap_or_default(),
+            complement: None,
+            neighborhood: response.neighborhood.unwrap_or_default(),
+            city: response.city.unwrap_or_default(),
+            state: response.state.unwrap_or_default(),
+            ibge_code: None,
+            coordinates: response
+                .location
+                .and_then(BrasilApiLocation::into_coordinates),
+        }
+    }
+}
+
+/// CEP lookup backed by [ViaCEP](https://viacep.com.br)
+#[derive(Debug, Clone)]
+pub struct ViaCep {
+    client: Transport,
+    base_url: String,
+}
+
+impl ViaCep {
+    /// Create a provider using the public ViaCEP endpoint
+    ///
+    /// Requests time out after [`DEFAULT_TIMEOUT`](super::DEFAULT_TIMEOUT).
+    #[cfg(feature = "lookup")]
+    pub fn new() -> Self {
+        Self::with_timeout(super::DEFAULT_TIMEOUT)
+    }
+
+    /// Create a provider using the public ViaCEP endpoint, giving up on
+    /// requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub fn with_timeout(timeout: std::time::Duration) -> Self {
+        Self::with_transport(Transport::reqwest(timeout))
+    }
+
+    /// Create a provider sending requests through the given HTTP client
+    ///
+    /// Accepts a configured `reqwest::Client` or any other [`HttpClient`](super::HttpClient).
+    pub fn with_client(client: impl super::HttpClient + 'static) -> Self {
+        Self::with_transport(Transport::new(client))
+    }
+
+    fn with_transport(client: Transport) -> Self {
+        ViaCep {
+            client,
+            base_url: VIACEP_URL.to_string(),
+        }
+    }
+
+    /// Override the endpoint (e.g. an internal mirror)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "lookup")]
+impl Default for ViaCep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LookupProvider for ViaCep {
+    type Output = Address;
+
+    fn name(&self) -> &'static str {
+        "viacep"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<Address> {
+        let cep = cep::validate(key)?;
+        let url = format!("{}/{}/json/", self.base_url, cep);
+        self.client.get_json::<ViaCepResponse>(&url)
+            .await?
+            .into_address()
+    }
+}
+
+/// CEP lookup backed by [BrasilAPI](https://brasilapi.com.br)
+///
+/// Call [`BrasilApiCep::with_geocoding`] to query the v2 API, which also
+/// returns the coordinates of the CEP when known.
+///
+/// # Examples
+/// ```no_run
+/// use pleme_brazilian_validators::lookup::{BrasilApiCep, Coordinates, LookupProvider};
+///
+/// # #[cfg(feature = "lookup")]
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let store = Coordinates::new(-23.5614, -46.6559).unwrap();
+/// let address = BrasilApiCep::new().with_geocoding().lookup("01310-100").await?;
+///
+/// if let Some(location) = address.coordinates {
+///     println!("{:.1} km from the store", location.distance_km(&store));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BrasilApiCep {
+    client: Transport,
+    base_url: String,
+    geocoding: bool,
+}
+
+impl BrasilApiCep {
+    /// Create a provider using the public BrasilAPI endpoint
+    ///
+    /// Requests time out after [`DEFAULT_TIMEOUT`](super::DEFAULT_TIMEOUT).
+    #[cfg(feature = "lookup")]
+    pub fn new() -> Self {
+        Self::with_timeout(super::DEFAULT_TIMEOUT)
+    }
+
+    /// Create a provider using the public BrasilAPI endpoint, giving up on
+    /// requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub fn with_timeout(timeout: std::time::Duration) -> Self {
+        Self::with_transport(Transport::reqwest(timeout))
+    }
+
+    /// Create a provider sending requests through the given HTTP client
+    ///
+    /// Accepts a configured `reqwest::Client` or any other [`HttpClient`](super::HttpClient).
+    pub fn with_client(client: impl super::HttpClient + 'static) -> Self {
+        Self::with_transport(Transport::new(client))
+    }
+
+    fn with_transport(client: Transport) -> Self {
+        BrasilApiCep {
+            client,
+            base_url: BRASILAPI_CEP_URL.to_string(),
+            geocoding: false,
+        }
+    }
+
+    /// Override the endpoint (e.g. an internal mirror), without the
+    /// `/v1` or `/v2` version segment
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Query the v2 API, filling [`Address::coordinates`] when available
+    pub fn with_geocoding(mut self) -> Self {
+        self.geocoding = true;
+        self
+    }
+}
+
+#[cfg(feature = "lookup")]
+impl Default for BrasilApiCep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LookupProvider for BrasilApiCep {
+    type Output = Address;
+
+    fn name(&self) -> &'static str {
+        "brasilapi"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<Address> {
+        let cep = cep::validate(key)?;
+        let version = if self.geocoding { "v2" } else { "v1" };
+        let url = format!("{}/{}/{}", self.base_url, version, cep);
+        self.client.get_json::<BrasilApiCepResponse>(&url)
+            .await
+            .map(Address::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viacep_response_into_address() {
+        let response: ViaCepResponse = serde_json::from_str(
+            r#"{"cep":"01001-000","logradouro":"Praça da Sé","complemento":"lado ímpar",
+                "bairro":"Sé","localidade":"São Paulo","uf":"SP","ibge":"3550308"}"#,
+        )
+        .unwrap();
+
+        let address = response.into_address().unwrap();
+        assert_eq!(address.cep, "01001000");
+        assert_eq!(address.street, "Praça da Sé");
+        assert_eq!(address.complement.as_deref(), Some("lado ímpar"));
+        assert_eq!(address.state, "SP");
+        assert_eq!(address.uf(), Some(Uf::SP));
+        assert_eq!(address.ibge_code.as_deref(), Some("3550308"));
+    }
+
+    #[test]
+    fn test_viacep_error_is_not_found() {
+        for body in [r#"{"erro":true}"#, r#"{"erro":"true"}"#] {
+            let response: ViaCepResponse = serde_json::from_str(body).unwrap();
+            assert_eq!(response.into_address(), Err(LookupError::NotFound));
+        }
+    }
+
+    #[test]
+    fn test_brasilapi_response_into_address() {
+        let response: BrasilApiCepResponse = serde_json::from_str(
+            r#"{"cep":"01001000","state":"SP","city":"São Paulo","neighborhood":"Sé",
+                "street":"Praça da Sé","service":"viacep"}"#,
+        )
+        .unwrap();
+
+        let address = Address::from(response);
+        assert_eq!(address.cep, "01001000");
+        assert_eq!(address.city, "São Paulo");
+        assert_eq!(address.complement, None);
+        assert_eq!(address.coordinates, None);
+    }
+
+    #[test]
+    fn test_brasilapi_v2_response_with_coordinates() {
+        let response: BrasilApiCepResponse = serde_json::from_str(
+            r#"{"cep":"89010025","state":"SC","city":"Blumenau","neighborhood":"Centro",
+                "street":"Rua Doutor Luiz de Freitas Melro","service":"viacep",
+                "location":{"type":"Point","coordinates":
+                    {"longitude":"-49.0629788","latitude":"-26.9244749"}}}"#,
+        )
+        .unwrap();
+
+        let coordinates = Address::from(response).coordinates.unwrap();
+        assert_eq!(coordinates.latitude, -26.9244749);
+        assert_eq!(coordinates.longitude, -49.0629788);
+    }
+
+    #[test]
+    fn test_brasilapi_v2_response_without_coordinates() {
+        for location in [
+            r#"{"type":"Point","coordinates":{}}"#,
+            r#"{"type":"Point","coordinates":{"longitude":"","latitude":""}}"#,
+            r#"{"type":"Point","coordinates":{"longitude":-49.06,"latitude":"-95"}}"#,
+        ] {
+            let body = format!(r#"{{"cep":"89010025","location":{}}}"#, location);
+            let response: BrasilApiCepResponse = serde_json::from_str(&body).unwrap();
+            assert_eq!(Address::from(response).coordinates, None);
+        }
+    }
+
+    #[test]
+    fn test_coordinates_distance() {
+        let point = Coordinates::new(-23.5505, -46.6333).unwrap();
+        assert_eq!(point.distance_km(&point), 0.0);
+
+        let antipode = Coordinates::new(23.5505, 133.3667).unwrap();
+        let half_circumference = std::f64::consts::PI * EARTH_RADIUS_KM;
+        assert!((point.distance_km(&antipode) - half_circumference).abs() < 1e-6);
+    }
+
+    /// Client failing the test if a request is made
+    struct NoNetwork;
+
+    #[async_trait]
+    impl crate::lookup::HttpClient for NoNetwork {
+        async fn get(
+            &self,
+            url: &str,
+            _headers: &[(&str, &str)],
+        ) -> LookupResult<crate::lookup::HttpResponse> {
+            panic!("unexpected request to {}", url)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_cep_is_rejected_before_request() {
+        let provider = ViaCep::with_client(NoNetwork);
+        assert!(matches!(
+            provider.lookup("123").await,
+            Err(LookupError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,551 @@
+//! CNPJ → company registration data lookup providers
+//!
+//! The raw provider payloads are public as serde models
+//! ([`BrasilApiCnpjResponse`], [`ReceitaWsResponse`]) for callers that need
+//! a field the normalized [`Company`] does not carry.
+
+use super::http::Transport;
+use super::{non_empty, LookupError, LookupProvider, LookupResult};
+use crate::cnpj::CnpjBase;
+use crate::uf::Uf;
+use crate::{cep, cnpj, cpf};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Default BrasilAPI CNPJ endpoint
+const BRASILAPI_CNPJ_URL: &str = "https://brasilapi.com.br/api/cnpj/v1";
+
+/// Default ReceitaWS endpoint
+const RECEITAWS_URL: &str = "https://receitaws.com.br/v1/cnpj";
+
+/// Company registration data for a CNPJ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Company {
+    /// Normalized CNPJ (14 characters)
+    pub cnpj: String,
+    /// Legal name (razão social)
+    pub legal_name: String,
+    /// Trade name (nome fantasia)
+    pub trade_name: Option<String>,
+    /// Registration status (situação cadastral, e.g. "ATIVA")
+    pub registration_status: Option<String>,
+    /// Main economic activity (CNAE principal)
+    pub main_activity: Option<Activity>,
+    /// Secondary economic activities (CNAEs secundários)
+    pub secondary_activities: Vec<Activity>,
+    /// Partners and administrators (quadro de sócios e administradores)
+    pub partners: Vec<Partner>,
+    /// Registered address
+    pub address: CompanyAddress,
+}
+
+impl Company {
+    /// Federative unit of the registered address, if known
+    pub fn uf(&self) -> Option<Uf> {
+        self.address.state
+    }
+
+    /// Root of the CNPJ, shared by the headquarters and every branch
+    pub fn cnpj_base(&self) -> Option<CnpjBase> {
+        CnpjBase::from_cnpj(&self.cnpj).ok()
+    }
+}
+
+/// Economic activity (CNAE)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Activity {
+    /// CNAE subclass code, digits only (e.g. `"6201501"`)
+    pub code: String,
+    /// Activity description
+    pub description: String,
+}
+
+impl Activity {
+    /// Build an activity, dropping the "not informed" placeholders
+    /// providers use when a company has no secondary activity
+    fn new(code: &str, description: String) -> Option<Self> {
+        let code: String = code.chars().filter(char::is_ascii_digit).collect();
+        if code.is_empty() || code.bytes().all(|b| b == b'0') {
+            return None;
+        }
+        Some(Activity { code, description })
+    }
+}
+
+/// Partner or administrator of a company
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Partner {
+    /// Name of the person or company
+    pub name: String,
+    /// Role in the company (qualificação, e.g. "Sócio-Administrador")
+    pub qualification: Option<String>,
+    /// Document of the partner: masked CPF for individuals, normalized
+    /// CNPJ for companies
+    pub document: Option<String>,
+}
+
+impl Partner {
+    /// Build a partner, masking the CPF of individuals
+    ///
+    /// The Receita Federal already publishes partner CPFs masked
+    /// (`***456789**`); an unmasked CPF from another source is masked here
+    /// so it never reaches logs or caches in the clear.
+    fn new(name: String, qualification: Option<String>, document: Option<String>) -> Self {
+        let document = non_empty(document).map(|document| {
+            if cnpj::validate_unobserved(&document).is_ok() {
+                cnpj::normalize(&document)
+            } else if cpf::validate_unobserved(&document).is_ok() {
+                cpf::mask(&document)
+            } else {
+                document
+            }
+        });
+        Partner {
+            name,
+            qualification: non_empty(qualification),
+            document,
+        }
+    }
+
+    /// CNPJ of the partner, when the partner is a company
+    pub fn cnpj(&self) -> Option<&str> {
+        self.document
+            .as_deref()
+            .filter(|document| document.len() == 14 && document.bytes().all(|b| b.is_ascii_digit()))
+    }
+}
+
+/// Registered address of a company
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompanyAddress {
+    /// Street (logradouro), including its type when the provider splits it
+    pub street: Option<String>,
+    /// Number
+    pub number: Option<String>,
+    /// Complement (complemento)
+    pub complement: Option<String>,
+    /// Neighborhood (bairro)
+    pub neighborhood: Option<String>,
+    /// Municipality
+    pub city: Option<String>,
+    /// Federative unit
+    pub state: Option<Uf>,
+    /// Normalized CEP (8 digits)
+    pub cep: Option<String>,
+}
+
+/// BrasilAPI secondary activity entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrasilApiCnae {
+    pub codigo: u32,
+    pub descricao: String,
+}
+
+/// BrasilAPI partner entry (QSA)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrasilApiPartner {
+    pub nome_socio: String,
+    pub cnpj_cpf_do_socio: Option<String>,
+    pub qualificacao_socio: Option<String>,
+    pub data_entrada_sociedade: Option<String>,
+}
+
+/// BrasilAPI CNPJ v1 response body
+///
+/// Field names follow the JSON payload; convert into a [`Company`] for
+/// normalized, typed data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrasilApiCnpjResponse {
+    pub cnpj: String,
+    pub razao_social: String,
+    pub nome_fantasia: Option<String>,
+    pub descricao_situacao_cadastral: Option<String>,
+    pub cnae_fiscal: Option<u32>,
+    pub cnae_fiscal_descricao: Option<String>,
+    #[serde(default)]
+    pub cnaes_secundarios: Vec<BrasilApiCnae>,
+    #[serde(default)]
+    pub qsa: Vec<BrasilApiPartner>,
+    pub descricao_tipo_de_logradouro: Option<String>,
+    pub logradouro: Option<String>,
+    pub numero: Option<String>,
+    pub complemento: Option<String>,
+    pub bairro: Option<String>,
+    pub municipio: Option<String>,
+    pub uf: Option<String>,
+    pub cep: Option<String>,
+}
+
+impl From<BrasilApiCnpjResponse> for Company {
+    fn from(response: BrasilApiCnpjResponse) -> Self {
+        let street = match (
+            non_empty(response.descricao_tipo_de_logradouro),
+            non_empty(response.logradouro),
+        ) {
+            (Some(kind), Some(street)) => Some(format!("{} {}", kind, street)),
+            (_, street) => street,
+        };
+
+        Company {
+            cnpj: cnpj::normalize(&response.cnpj),
+            legal_name: response.razao_social,
+            trade_name: non_empty(response.nome_fantasia),
+            registration_status: non_empty(response.descricao_situacao_cadastral),
+            main_activity: response.cnae_fiscal.and_then(|code| {
+                Activity::new(
+                    &format!("{:07}", code),
+                    response.cnae_fiscal_descricao.unwrap_or_default(),
+                )
+            }),
+            secondary_activities: response
+                .cnaes_secundarios
+                .into_iter()
+                .filter_map(|cnae| Activity::new(&format!("{:07}", cnae.codigo), cnae.descricao))
+                .collect(),
+            partners: response
+                .qsa
+                .into_iter()
+                .map(|p| Partner::new(p.nome_socio, p.qualificacao_socio, p.cnpj_cpf_do_socio))
+                .collect(),
+            address: CompanyAddress {
+                street,
+                number: non_empty(response.numero),
+                complement: non_empty(response.complemento),
+                neighborhood: non_empty(response.bairro),
+                city: non_empty(response.municipio),
+                state: response.uf.and_then(|uf| uf.parse().ok()),
+                cep: non_empty(response.cep).map(|c| cep::normalize(&c)),
+            },
+        }
+    }
+}
+
+/// ReceitaWS activity entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceitaWsActivity {
+    pub code: String,
+    pub text: String,
+}
+
+/// ReceitaWS partner entry (QSA)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceitaWsPartner {
+    pub nome: String,
+    pub qual: Option<String>,
+}
+
+/// ReceitaWS response body
+///
+/// Field names follow the JSON payload; convert into a [`Company`] with
+/// `Company::try_from`, which fails when ReceitaWS reports an error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceitaWsResponse {
+    pub status: String,
+    pub message: Option<String>,
+    pub cnpj: Option<String>,
+    pub nome: Option<String>,
+    pub fantasia: Option<String>,
+    pub situacao: Option<String>,
+    #[serde(default)]
+    pub atividade_principal: Vec<ReceitaWsActivity>,
+    #[serde(default)]
+    pub atividades_secundarias: Vec<ReceitaWsActivity>,
+    #[serde(default)]
+    pub qsa: Vec<ReceitaWsPartner>,
+    pub logradouro: Option<String>,
+    pub numero: Option<String>,
+    pub complemento: Option<String>,
+    pub bairro: Option<String>,
+    pub municipio: Option<String>,
+    pub uf: Option<String>,
+    pub cep: Option<String>,
+}
+
+impl TryFrom<ReceitaWsResponse> for Company {
+    type Error = LookupError;
+
+    /// ReceitaWS reports failures with status `ERROR`
+    fn try_from(response: ReceitaWsResponse) -> LookupResult<Company> {
+        if response.status != "OK" {
+            return Err(LookupError::NotFound);
+        }
+
+        let (Some(cnpj), Some(legal_name)) = (response.cnpj, response.nome) else {
+            return Err(LookupError::InvalidResponse(
+                response
+                    .message
+                    .unwrap_or_else(|| "campos cnpj/nome ausentes".to_string()),
+            ));
+        };
+
+        Ok(Company {
+            cnpj: cnpj::normalize(&cnpj),
+            legal_name,
+            trade_name: non_empty(response.fantasia),
+            registration_status: non_empty(response.situacao),
+            main_activity: response
+                .atividade_principal
+                .into_iter()
+                .find_map(|a| Activity::new(&a.code, a.text)),
+            secondary_activities: response
+                .atividades_secundarias
+                .into_iter()
+                .filter_map(|a| Activity::new(&a.code, a.text))
+                .collect(),
+            partners: response
+                .qsa
+                .into_iter()
+                .map(|p| Partner::new(p.nome, p.qual, None))
+                .collect(),
+            address: CompanyAddress {
+                street: non_empty(response.logradouro),
+                number: non_empty(response.numero),
+                complement: non_empty(response.complemento),
+                neighborhood: non_empty(response.bairro),
+                city: non_empty(response.municipio),
+                state: response.uf.and_then(|uf| uf.parse().ok()),
+                cep: non_empty(response.cep).map(|c| cep::normalize(&c)),
+            },
+        })
+    }
+}
+
+/// CNPJ lookup backed by [BrasilAPI](https://brasilapi.com.br)
+#[derive(Debug, Clone)]
+pub struct BrasilApiCnpj {
+    client: Transport,
+    base_url: String,
+}
+
+impl BrasilApiCnpj {
+    /// Create a provider using the public BrasilAPI endpoint
+    ///
+    /// Requests time out after [`DEFAULT_TIMEOUT`](super::DEFAULT_TIMEOUT).
+    #[cfg(feature = "lookup")]
+    pub fn new() -> Self {
+        Self::with_timeout(super::DEFAULT_TIMEOUT)
+    }
+
+    /// Create a provider using the public BrasilAPI endpoint, giving up on
+    /// requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub fn with_timeout(timeout: std::time::Duration) -> Self {
+        Self::with_transport(Transport::reqwest(timeout))
+    }
+
+    /// Create a provider sending requests through the given HTTP client
+    ///
+    /// Accepts a configured `reqwest::Client` or any other [`HttpClient`](super::HttpClient).
+    pub fn with_client(client: impl super::HttpClient + 'static) -> Self {
+        Self::with_transport(Transport::new(client))
+    }
+
+    fn with_transport(client: Transport) -> Self {
+        BrasilApiCnpj {
+            client,
+            base_url: BRASILAPI_CNPJ_URL.to_string(),
+        }
+    }
+
+    /// Override the endpoint (e.g. an internal mirror)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "lookup")]
+impl Default for BrasilApiCnpj {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LookupProvider for BrasilApiCnpj {
+    type Output = Company;
+
+    fn name(&self) -> &'static str {
+        "brasilapi"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<Company> {
+        let cnpj = cnpj::validate(key)?;
+        let url = format!("{}/{}", self.base_url, cnpj);
+        self.client.get_json::<BrasilApiCnpjResponse>(&url)
+            .await
+            .map(Company::from)
+    }
+}
+
+/// CNPJ lookup backed by [ReceitaWS](https://receitaws.com.br)
+///
+/// The free tier is limited to a few requests per minute; throttled
+/// requests surface as [`LookupError::RateLimited`]. Wrap the provider in
+/// [`RateLimit`](super::RateLimit) to stay under the quota.
+#[derive(Debug, Clone)]
+pub struct ReceitaWs {
+    client: Transport,
+    base_url: String,
+}
+
+impl ReceitaWs {
+    /// Create a provider using the public ReceitaWS endpoint
+    ///
+    /// Requests time out after [`DEFAULT_TIMEOUT`](super::DEFAULT_TIMEOUT).
+    #[cfg(feature = "lookup")]
+    pub fn new() -> Self {
+        Self::with_timeout(super::DEFAULT_TIMEOUT)
+    }
+
+    /// Create a provider using the public ReceitaWS endpoint, giving up on
+    /// requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub fn with_timeout(timeout: std::time::Duration) -> Self {
+        Self::with_transport(Transport::reqwest(timeout))
+    }
+
+    /// Create a provider sending requests through the given HTTP client
+    ///
+    /// Accepts a configured `reqwest::Client` or any other [`HttpClient`](super::HttpClient).
+    pub fn with_client(client: impl super::HttpClient + 'static) -> Self {
+        Self::with_transport(Transport::new(client))
+    }
+
+    fn with_transport(client: Transport) -> Self {
+        ReceitaWs {
+            client,
+            base_url: RECEITAWS_URL.to_string(),
+        }
+    }
+
+    /// Override the endpoint (e.g. an internal mirror)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "lookup")]
+impl Default for ReceitaWs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LookupProvider for ReceitaWs {
+    type Output = Company;
+
+    fn name(&self) -> &'static str {
+        "receitaws"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<Company> {
+        let cnpj = cnpj::validate(key)?;
+        let url = format!("{}/{}", self.base_url, cnpj);
+        self.client.get_json::<ReceitaWsResponse>(&url)
+            .await?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brasilapi_response_into_company() {
+        let response: BrasilApiCnpjResponse = serde_json::from_str(
+            r#"{"cnpj":"11222333000181","razao_social":"EMPRESA TESTE LTDA","nome_fantasia":"",
+                "descricao_situacao_cadastral":"ATIVA","cnae_fiscal":6201501,
+                "cnae_fiscal_descricao":"Desenvolvimento de programas de computador sob encomenda",
+                "cnaes_secundarios":[{"codigo":6204000,"descricao":"Consultoria em TI"},
+                                     {"codigo":0,"descricao":""}],
+                "qsa":[{"nome_socio":"FULANO DE TAL","cnpj_cpf_do_socio":"***456789**",
+                        "qualificacao_socio":"Sócio-Administrador"},
+                       {"nome_socio":"HOLDING SA","cnpj_cpf_do_socio":"11444777000161",
+                        "qualificacao_socio":"Sócio"}],
+                "descricao_tipo_de_logradouro":"PRACA","logradouro":"DA SE","numero":"1",
+                "complemento":"","bairro":"SE","municipio":"SAO PAULO","uf":"SP","cep":"01001000"}"#,
+        )
+        .unwrap();
+
+        let company = Company::from(response);
+        assert_eq!(company.cnpj, "11222333000181");
+        assert_eq!(company.legal_name, "EMPRESA TESTE LTDA");
+        assert_eq!(company.trade_name, None);
+        assert_eq!(company.registration_status.as_deref(), Some("ATIVA"));
+        assert_eq!(company.main_activity.as_ref().unwrap().code, "6201501");
+        assert_eq!(company.secondary_activities.len(), 1);
+        assert_eq!(company.secondary_activities[0].code, "6204000");
+        assert_eq!(company.partners[0].document.as_deref(), Some("***456789**"));
+        assert_eq!(company.partners[0].cnpj(), None);
+        assert_eq!(company.partners[1].cnpj(), Some("11444777000161"));
+        assert_eq!(company.address.street.as_deref(), Some("PRACA DA SE"));
+        assert_eq!(company.address.complement, None);
+        assert_eq!(company.address.cep.as_deref(), Some("01001000"));
+        assert_eq!(company.uf(), Some(Uf::SP));
+        assert_eq!(company.cnpj_base().unwrap().as_str(), "11222333");
+    }
+
+    #[test]
+    fn test_receitaws_response_into_company() {
+        let response: ReceitaWsResponse = serde_json::from_str(
+            r#"{"status":"OK","cnpj":"11.222.333/0001-81","nome":"EMPRESA TESTE LTDA",
+                "fantasia":"TESTE","situacao":"ATIVA",
+                "atividade_principal":[{"code":"62.01-5-01","text":"Desenvolvimento de software"}],
+                "atividades_secundarias":[{"code":"00.00-0-00","text":"Não informada"}],
+                "qsa":[{"nome":"FULANO DE TAL","qual":"49-Sócio-Administrador"}],
+                "logradouro":"PRACA DA SE","numero":"1","bairro":"SE",
+                "municipio":"SAO PAULO","uf":"SP","cep":"01.001-000"}"#,
+        )
+        .unwrap();
+
+        let company = Company::try_from(response).unwrap();
+        assert_eq!(company.cnpj, "11222333000181");
+        assert_eq!(company.trade_name.as_deref(), Some("TESTE"));
+        let activity = company.main_activity.unwrap();
+        assert_eq!(activity.code, "6201501");
+        assert_eq!(activity.description, "Desenvolvimento de software");
+        assert!(company.secondary_activities.is_empty());
+        assert_eq!(company.partners[0].name, "FULANO DE TAL");
+        assert_eq!(company.partners[0].document, None);
+        assert_eq!(company.address.cep.as_deref(), Some("01001000"));
+        assert_eq!(company.address.state, Some(Uf::SP));
+    }
+
+    #[test]
+    fn test_receitaws_error_is_not_found() {
+        let response: ReceitaWsResponse =
+            serde_json::from_str(r#"{"status":"ERROR","message":"CNPJ inválido"}"#).unwrap();
+        assert_eq!(Company::try_from(response), Err(LookupError::NotFound));
+    }
+
+    #[test]
+    fn test_unmasked_partner_cpf_is_masked() {
+        let partner = Partner::new("FULANO".to_string(), None, Some("12345678909".to_string()));
+        assert_eq!(partner.document.as_deref(), Some("123.***.***-09"));
+        assert_eq!(partner.cnpj(), None);
+    }
+
+    #[test]
+    fn test_company_serializes_state_as_abbreviation() {
+        let company = Company {
+            cnpj: "11222333000181".to_string(),
+            legal_name: "EMPRESA TESTE LTDA".to_string(),
+            trade_name: None,
+            registration_status: None,
+            main_activity: None,
+            secondary_activities: Vec::new(),
+            partners: Vec::new(),
+            address: CompanyAddress {
+                state: Some(Uf::RJ),
+                ..Default::default()
+            },
+        };
+        let json = serde_json::to_string(&company).unwrap();
+        assert!(json.contains(r#""state":"RJ""#));
+        assert_eq!(serde_json::from_str::<Company>(&json).unwrap(), company);
+    }
+}
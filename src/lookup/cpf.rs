@@ -0,0 +1,338 @@
+//! CPF → registration status (situação cadastral) lookup providers
+//!
+//! The Receita Federal registry is only reachable through authenticated
+//! APIs. [`SerproCpf`] calls the official Serpro *Consulta CPF* API with an
+//! access token obtained by the caller from their Serpro contract; any
+//! other source can implement [`LookupProvider`] with [`CpfRegistration`]
+//! as output.
+
+use super::http::Transport;
+use super::{non_empty, LookupError, LookupProvider, LookupResult};
+use crate::cpf;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Default Serpro Consulta CPF endpoint
+const SERPRO_CPF_URL: &str = "https://gateway.apiserpro.serpro.gov.br/consulta-cpf-df/v1/cpf";
+
+/// Registration status of a CPF at the Receita Federal
+///
+/// Serialized in snake_case (`"regular"`, `"deceased"`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CpfStatus {
+    /// Regular
+    Regular,
+    /// Suspensa: registration data is inconsistent or incomplete
+    Suspended,
+    /// Titular falecido: the holder is deceased
+    Deceased,
+    /// Pendente de regularização: the holder missed a tax return
+    PendingRegularization,
+    /// Cancelada, by duplicate registration (multiplicidade) or ex officio
+    Canceled,
+    /// Nula: the registration was obtained by fraud
+    Null,
+}
+
+impl CpfStatus {
+    /// Parse a Receita Federal situação cadastral code
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::lookup::CpfStatus;
+    ///
+    /// assert_eq!(CpfStatus::from_code("0"), Some(CpfStatus::Regular));
+    /// assert_eq!(CpfStatus::from_code("3"), Some(CpfStatus::Deceased));
+    /// assert_eq!(CpfStatus::from_code("7"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim() {
+            "0" => Some(Self::Regular),
+            "2" => Some(Self::Suspended),
+            "3" => Some(Self::Deceased),
+            "4" => Some(Self::PendingRegularization),
+            "5" | "9" => Some(Self::Canceled),
+            "8" => Some(Self::Null),
+            _ => None,
+        }
+    }
+
+    /// Check whether the CPF may be used, e.g. for opening an account
+    pub fn is_regular(&self) -> bool {
+        matches!(self, Self::Regular)
+    }
+}
+
+/// Registration data of a CPF
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CpfRegistration {
+    /// Normalized CPF (11 digits)
+    pub cpf: String,
+    /// Registered name of the holder
+    pub name: Option<String>,
+    /// Registration status
+    pub status: CpfStatus,
+    /// Birth date, ISO 8601 (`YYYY-MM-DD`)
+    pub birth_date: Option<String>,
+}
+
+/// Serpro situação cadastral
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerproSituacao {
+    /// Status code (`"0"` regular, `"3"` deceased, ...)
+    pub codigo: String,
+    /// Status description (e.g. "Regular")
+    pub descricao: Option<String>,
+}
+
+/// Serpro Consulta CPF v1 response body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerproCpfResponse {
+    /// CPF, digits only (número de inscrição)
+    pub ni: String,
+    /// Registered name
+    pub nome: Option<String>,
+    /// Registration status
+    pub situacao: SerproSituacao,
+    /// Birth date, `DDMMYYYY`
+    pub nascimento: Option<String>,
+}
+
+impl TryFrom<SerproCpfResponse> for CpfRegistration {
+    type Error = LookupError;
+
+    fn try_from(response: SerproCpfResponse) -> LookupResult<Self> {
+        let status = CpfStatus::from_code(&response.situacao.codigo).ok_or_else(|| {
+            LookupError::InvalidResponse(format!(
+                "situação cadastral desconhecida: {}",
+                response.situacao.codigo
+            ))
+        })?;
+
+        Ok(CpfRegistration {
+            cpf: cpf::normalize(&response.ni),
+            name: non_empty(response.nome),
+            status,
+            birth_date: response.nascimento.as_deref().and_then(iso_date),
+        })
+    }
+}
+
+/// Convert a `DDMMYYYY` date to `YYYY-MM-DD`
+fn iso_date(date: &str) -> Option<String> {
+    let date = date.trim();
+    if date.len() != 8 || !date.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}-{}-{}", &date[4..8], &date[2..4], &date[0..2]))
+}
+
+/// CPF status lookup backed by the [Serpro](https://servicos.serpro.gov.br)
+/// Consulta CPF API
+///
+/// Requests are authenticated with an OAuth2 access token the caller
+/// obtains with their consumer key and secret. Tokens expire; build a new
+/// provider (cheap, the HTTP client is shared) after renewing it.
+///
+/// # Examples
+/// ```no_run
+/// use pleme_brazilian_validators::lookup::{LookupProvider, SerproCpf};
+///
+/// # #[cfg(feature = "lookup")]
+/// # async fn run(token: String) -> Result<(), Box<dyn std::error::Error>> {
+/// let registration = SerproCpf::new(token).lookup("123.456.789-09").await?;
+/// if !registration.status.is_regular() {
+///     println!("CPF {:?}", registration.status);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SerproCpf {
+    client: Transport,
+    base_url: String,
+    authorization: String,
+}
+
+impl SerproCpf {
+    /// Create a provider using the production Serpro endpoint
+    ///
+    /// Requests time out after [`DEFAULT_TIMEOUT`](super::DEFAULT_TIMEOUT).
+    #[cfg(feature = "lookup")]
+    pub fn new(access_token: impl AsRef<str>) -> Self {
+        Self::with_timeout(access_token, super::DEFAULT_TIMEOUT)
+    }
+
+    /// Create a provider using the production Serpro endpoint, giving up on
+    /// requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub fn with_timeout(access_token: impl AsRef<str>, timeout: std::time::Duration) -> Self {
+        Self::with_transport(Transport::reqwest(timeout), access_token.as_ref())
+    }
+
+    /// Create a provider sending requests through the given HTTP client
+    ///
+    /// Accepts a configured `reqwest::Client` or any other [`HttpClient`](super::HttpClient).
+    pub fn with_client(
+        client: impl super::HttpClient + 'static,
+        access_token: impl AsRef<str>,
+    ) -> Self {
+        Self::with_transport(Transport::new(client), access_token.as_ref())
+    }
+
+    fn with_transport(client: Transport, access_token: &str) -> Self {
+        SerproCpf {
+            client,
+            base_url: SERPRO_CPF_URL.to_string(),
+            authorization: format!("Bearer {}", access_token.trim()),
+        }
+    }
+
+    /// Override the endpoint (e.g. the Serpro trial environment)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+/// Keeps the access token out of logs
+impl fmt::Debug for SerproCpf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerproCpf")
+            .field("client", &self.client)
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl LookupProvider for SerproCpf {
+    type Output = CpfRegistration;
+
+    fn name(&self) -> &'static str {
+        "serpro"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<CpfRegistration> {
+        let cpf = cpf::validate(key)?;
+        let url = format!("{}/{}", self.base_url, cpf);
+        let headers = [
+            ("Authorization", self.authorization.as_str()),
+            ("Accept", "application/json"),
+        ];
+        self.client
+            .get_json_with_headers::<SerproCpfResponse>(&url, &headers)
+            .await?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lookup::{HttpClient, HttpResponse};
+    use std::sync::Mutex;
+
+    /// URL and headers of a request
+    type Request = (String, Vec<(String, String)>);
+
+    /// Client recording the request and answering with a fixed body
+    struct Recording {
+        body: &'static str,
+        request: Mutex<Option<Request>>,
+    }
+
+    #[async_trait]
+    impl HttpClient for &'static Recording {
+        async fn get(&self, url: &str, headers: &[(&str, &str)]) -> LookupResult<HttpResponse> {
+            let headers = headers
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            *self.request.lock().unwrap() = Some((url.to_string(), headers));
+            Ok(HttpResponse {
+                status: 200,
+                body: self.body.as_bytes().to_vec(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_status_codes() {
+        assert_eq!(CpfStatus::from_code("2"), Some(CpfStatus::Suspended));
+        assert_eq!(
+            CpfStatus::from_code("4"),
+            Some(CpfStatus::PendingRegularization)
+        );
+        assert_eq!(CpfStatus::from_code("5"), Some(CpfStatus::Canceled));
+        assert_eq!(CpfStatus::from_code("9"), Some(CpfStatus::Canceled));
+        assert_eq!(CpfStatus::from_code("8"), Some(CpfStatus::Null));
+        assert_eq!(CpfStatus::from_code(""), None);
+        assert!(CpfStatus::Regular.is_regular());
+        assert!(!CpfStatus::Deceased.is_regular());
+    }
+
+    #[test]
+    fn test_serpro_response_into_registration() {
+        let response: SerproCpfResponse = serde_json::from_str(
+            r#"{"ni":"12345678909","nome":"FULANO DE TAL",
+                "situacao":{"codigo":"3","descricao":"Titular Falecido"},
+                "nascimento":"01021980"}"#,
+        )
+        .unwrap();
+
+        let registration = CpfRegistration::try_from(response).unwrap();
+        assert_eq!(registration.cpf, "12345678909");
+        assert_eq!(registration.name.as_deref(), Some("FULANO DE TAL"));
+        assert_eq!(registration.status, CpfStatus::Deceased);
+        assert_eq!(registration.birth_date.as_deref(), Some("1980-02-01"));
+        assert_eq!(
+            serde_json::to_value(&registration).unwrap()["status"],
+            "deceased"
+        );
+    }
+
+    #[test]
+    fn test_unknown_status_is_invalid_response() {
+        let response: SerproCpfResponse =
+            serde_json::from_str(r#"{"ni":"12345678909","situacao":{"codigo":"7"}}"#).unwrap();
+        assert!(matches!(
+            CpfRegistration::try_from(response),
+            Err(LookupError::InvalidResponse(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lookup_sends_bearer_token() {
+        static CLIENT: Recording = Recording {
+            body: r#"{"ni":"12345678909","situacao":{"codigo":"0","descricao":"Regular"}}"#,
+            request: Mutex::new(None),
+        };
+
+        let provider = SerproCpf::with_client(&CLIENT, "token-123");
+        let registration = provider.lookup("123.456.789-09").await.unwrap();
+        assert_eq!(registration.status, CpfStatus::Regular);
+
+        let (url, headers) = CLIENT.request.lock().unwrap().take().unwrap();
+        assert_eq!(url, format!("{}/12345678909", SERPRO_CPF_URL));
+        assert!(headers.contains(&("Authorization".to_string(), "Bearer token-123".to_string())));
+        assert!(!format!("{:?}", provider).contains("token-123"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_cpf_is_rejected_before_request() {
+        static CLIENT: Recording = Recording {
+            body: "{}",
+            request: Mutex::new(None),
+        };
+
+        let provider = SerproCpf::with_client(&CLIENT, "token");
+        assert!(matches!(
+            provider.lookup("123.456.789-00").await,
+            Err(LookupError::Invalid(_))
+        ));
+        assert!(CLIENT.request.lock().unwrap().is_none());
+    }
+}
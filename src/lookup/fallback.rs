@@ -0,0 +1,87 @@
+//! Fallback between lookup providers
+
+use super::{LookupProvider, LookupResult};
+use async_trait::async_trait;
+
+/// Provider wrapper falling back to a second provider on transient failures
+///
+/// Typical use is an online source backed by an offline one, e.g. a
+/// portability database with an [`AllocationTable`](super::AllocationTable)
+/// fallback. Definitive answers (not found, invalid document) from the
+/// primary provider are returned as-is.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::lookup::{
+///     AllocationTable, Carrier, Fallback, LookupError, LookupProvider, MockCarrierProvider,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let online = MockCarrierProvider::new();
+/// online.fail_next(LookupError::Unavailable("timeout".to_string()));
+/// let offline = AllocationTable::from_csv("1198;Tim").unwrap();
+///
+/// let provider = Fallback::new(online, offline);
+/// let info = provider.lookup("11987654321").await.unwrap();
+/// assert_eq!(info.carrier, Carrier::Tim);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fallback<P, Q> {
+    primary: P,
+    secondary: Q,
+}
+
+impl<P, Q> Fallback<P, Q> {
+    /// Use `secondary` when `primary` is unavailable
+    pub fn new(primary: P, secondary: Q) -> Self {
+        Fallback { primary, secondary }
+    }
+}
+
+#[async_trait]
+impl<P, Q> LookupProvider for Fallback<P, Q>
+where
+    P: LookupProvider,
+    Q: LookupProvider<Output = P::Output>,
+{
+    type Output = P::Output;
+
+    fn name(&self) -> &'static str {
+        self.primary.name()
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<P::Output> {
+        match self.primary.lookup(key).await {
+            Err(err) if err.is_transient() => self.secondary.lookup(key).await,
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lookup::{LookupError, MockCnpjProvider};
+
+    const CNPJ: &str = "11222333000181";
+
+    #[tokio::test]
+    async fn test_fallback_only_on_transient_errors() {
+        let primary = MockCnpjProvider::new();
+        primary.fail_next(LookupError::RateLimited);
+        let secondary = MockCnpjProvider::new();
+        secondary.fail_next(LookupError::InvalidResponse("offline".to_string()));
+
+        let provider = Fallback::new(primary, secondary);
+        assert_eq!(
+            provider.lookup(CNPJ).await,
+            Err(LookupError::InvalidResponse("offline".to_string()))
+        );
+        // Primary answers definitively: secondary is not consulted
+        assert_eq!(provider.lookup(CNPJ).await, Err(LookupError::NotFound));
+        assert_eq!(provider.primary.calls().len(), 2);
+        assert_eq!(provider.secondary.calls().len(), 1);
+    }
+}
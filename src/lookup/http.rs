@@ -0,0 +1,176 @@
+//! HTTP transport used by the built-in providers
+//!
+//! Providers only need to GET a URL, optionally with a few request headers
+//! (credentials), and read the status and body.
+//! [`HttpClient`] captures that, so the built-in providers run on any
+//! async runtime and through any HTTP stack: implement it over surf,
+//! hyper, or an existing client configured with proxies and mTLS, and pass
+//! it to the provider's `with_client`.
+//!
+//! With the `lookup` feature, `reqwest::Client` implements the trait and is
+//! the default transport, giving up on requests after [`DEFAULT_TIMEOUT`] so
+//! an unresponsive provider cannot stall a lookup; `lookup-core` leaves out
+//! reqwest and tokio.
+//!
+//! # Examples
+//! ```
+//! use async_trait::async_trait;
+//! use pleme_brazilian_validators::lookup::{HttpClient, HttpResponse, LookupResult, ViaCep};
+//!
+//! struct Offline;
+//!
+//! #[async_trait]
+//! impl HttpClient for Offline {
+//!     async fn get(&self, _url: &str, _headers: &[(&str, &str)]) -> LookupResult<HttpResponse> {
+//!         Ok(HttpResponse { status: 404, body: Vec::new() })
+//!     }
+//! }
+//!
+//! let provider = ViaCep::with_client(Offline);
+//! ```
+
+use super::{LookupError, LookupResult};
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+#[cfg(feature = "lookup")]
+use std::time::Duration;
+
+/// Time after which the default reqwest transport gives up on a request
+///
+/// Providers built with `with_timeout` use their own; clients passed to
+/// `with_client` keep theirs.
+#[cfg(feature = "lookup")]
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Response to a GET request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+/// Minimal asynchronous HTTP client
+///
+/// Transport failures (DNS, TLS, timeouts) should be reported as
+/// [`LookupError::Unavailable`]; HTTP error statuses are returned as
+/// responses and mapped by the providers.
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    /// GET a URL, sending the given request headers
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> LookupResult<HttpResponse>;
+}
+
+#[cfg(feature = "lookup")]
+#[async_trait]
+impl HttpClient for reqwest::Client {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> LookupResult<HttpResponse> {
+        let unavailable = |e: reqwest::Error| LookupError::Unavailable(e.to_string());
+
+        let request = headers
+            .iter()
+            .fold(reqwest::Client::get(self, url), |request, &(name, value)| {
+                request.header(name, value)
+            });
+        let response = request
+            .send()
+            .await
+            .map_err(unavailable)?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await.map_err(unavailable)?.to_vec();
+
+        Ok(HttpResponse { status, body })
+    }
+}
+
+/// Shared handle to the HTTP client of a provider
+#[derive(Clone)]
+pub(super) struct Transport(Arc<dyn HttpClient>);
+
+impl Transport {
+    pub(super) fn new(client: impl HttpClient + 'static) -> Self {
+        Transport(Arc::new(client))
+    }
+
+    /// The default client: reqwest, giving up on requests after `timeout`
+    #[cfg(feature = "lookup")]
+    pub(super) fn reqwest(timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("TLS backend cannot be initialized");
+        Self::new(client)
+    }
+
+    /// GET a JSON document, mapping HTTP failures to [`LookupError`]
+    pub(super) async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> LookupResult<T> {
+        self.get_json_with_headers(url, &[]).await
+    }
+
+    /// GET a JSON document with extra request headers
+    pub(super) async fn get_json_with_headers<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> LookupResult<T> {
+        let response = self.0.get(url, headers).await?;
+
+        match response.status {
+            404 => Err(LookupError::NotFound),
+            429 => Err(LookupError::RateLimited),
+            200..=299 => serde_json::from_slice(&response.body)
+                .map_err(|e| LookupError::InvalidResponse(e.to_string())),
+            status => Err(LookupError::Unavailable(format!("HTTP {}", status))),
+        }
+    }
+}
+
+impl fmt::Debug for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HttpClient")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Client answering every request with a fixed response
+    struct Fixed(u16, &'static str);
+
+    #[async_trait]
+    impl HttpClient for Fixed {
+        async fn get(&self, _url: &str, _headers: &[(&str, &str)]) -> LookupResult<HttpResponse> {
+            Ok(HttpResponse {
+                status: self.0,
+                body: self.1.as_bytes().to_vec(),
+            })
+        }
+    }
+
+    async fn get(status: u16, body: &'static str) -> LookupResult<serde_json::Value> {
+        Transport::new(Fixed(status, body))
+            .get_json("http://example.com")
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_status_mapping() {
+        assert_eq!(get(200, "{}").await, Ok(serde_json::json!({})));
+        assert_eq!(get(404, "").await, Err(LookupError::NotFound));
+        assert_eq!(get(429, "").await, Err(LookupError::RateLimited));
+        assert_eq!(
+            get(503, "").await,
+            Err(LookupError::Unavailable("HTTP 503".to_string()))
+        );
+        assert!(matches!(
+            get(200, "<html>").await,
+            Err(LookupError::InvalidResponse(_))
+        ));
+    }
+}
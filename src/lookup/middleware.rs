@@ -0,0 +1,266 @@
+//! Retry and rate-limit wrappers for lookup providers
+//!
+//! Both wait with `tokio::time::sleep` and need the `lookup` feature.
+//!
+//! Both wrappers implement [`LookupProvider`] themselves, so they compose
+//! with any provider (including each other):
+//!
+//! ```
+//! use pleme_brazilian_validators::lookup::{RateLimit, Retry, RetryPolicy, ViaCep};
+//! use std::time::Duration;
+//!
+//! // ViaCEP throttles aggressively: space requests out, retry transient failures
+//! let provider = Retry::new(
+//!     RateLimit::new(ViaCep::new(), Duration::from_millis(300)),
+//!     RetryPolicy::default(),
+//! );
+//! ```
+
+use super::{LookupProvider, LookupResult};
+use async_trait::async_trait;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Exponential backoff policy for [`Retry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each retry
+    pub multiplier: u32,
+}
+
+impl Default for RetryPolicy {
+    /// 3 retries, starting at 200ms and doubling up to 5s
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the given retry (0-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(retry);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Provider wrapper retrying transient failures with exponential backoff
+///
+/// Only errors for which [`LookupError::is_transient`](super::LookupError::is_transient) holds are retried:
+/// a document that is invalid or not found fails immediately.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::lookup::{
+///     LookupError, LookupProvider, MockCnpjProvider, Retry, RetryPolicy,
+/// };
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mock = MockCnpjProvider::new();
+/// mock.fail_next(LookupError::Unavailable("HTTP 503".to_string()));
+///
+/// let policy = RetryPolicy {
+///     initial_backoff: Duration::from_millis(1),
+///     ..RetryPolicy::default()
+/// };
+/// let provider = Retry::new(mock, policy);
+///
+/// // The 503 is retried, the second attempt reports the real outcome
+/// assert_eq!(provider.lookup("11222333000181").await, Err(LookupError::NotFound));
+/// assert_eq!(provider.inner().calls().len(), 2);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Retry<P> {
+    inner: P,
+    policy: RetryPolicy,
+}
+
+impl<P> Retry<P> {
+    /// Wrap a provider with a retry policy
+    pub fn new(inner: P, policy: RetryPolicy) -> Self {
+        Retry { inner, policy }
+    }
+
+    /// Access the wrapped provider
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<P: LookupProvider> LookupProvider for Retry<P> {
+    type Output = P::Output;
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<P::Output> {
+        let mut retry = 0;
+        loop {
+            match self.inner.lookup(key).await {
+                Err(err) if err.is_transient() && retry < self.policy.max_retries => {
+                    tokio::time::sleep(self.policy.backoff(retry)).await;
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Provider wrapper enforcing a minimum interval between requests
+///
+/// Concurrent lookups are queued and released one interval apart, keeping
+/// the client under the provider's throttling threshold.
+#[derive(Debug)]
+pub struct RateLimit<P> {
+    inner: P,
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl<P> RateLimit<P> {
+    /// Wrap a provider, allowing one request per `interval`
+    pub fn new(inner: P, interval: Duration) -> Self {
+        RateLimit {
+            inner,
+            interval,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Wrap a provider, allowing `requests` requests per second
+    pub fn per_second(inner: P, requests: u32) -> Self {
+        let interval = Duration::from_secs(1)
+            .checked_div(requests)
+            .unwrap_or(Duration::ZERO);
+        Self::new(inner, interval)
+    }
+
+    /// Access the wrapped provider
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Reserve the next request slot, returning how long to wait for it
+    fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let Ok(mut next_slot) = self.next_slot.lock() else {
+            return Duration::ZERO;
+        };
+
+        let slot = next_slot.map_or(now, |slot| slot.max(now));
+        *next_slot = Some(slot + self.interval);
+        slot - now
+    }
+}
+
+#[async_trait]
+impl<P: LookupProvider> LookupProvider for RateLimit<P> {
+    type Output = P::Output;
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<P::Output> {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        self.inner.lookup(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lookup::{LookupError, MockCnpjProvider};
+
+    const CNPJ: &str = "11222333000181";
+
+    fn fast_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(200));
+        assert_eq!(policy.backoff(1), Duration::from_millis(400));
+        assert_eq!(policy.backoff(2), Duration::from_millis(800));
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_retries() {
+        let mock = MockCnpjProvider::new();
+        for _ in 0..3 {
+            mock.fail_next(LookupError::RateLimited);
+        }
+
+        let provider = Retry::new(mock, fast_policy(2));
+        assert_eq!(provider.lookup(CNPJ).await, Err(LookupError::RateLimited));
+        assert_eq!(provider.inner().calls().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_permanent_errors() {
+        let mock = MockCnpjProvider::new();
+        mock.fail_next(LookupError::InvalidResponse("html".to_string()));
+
+        let provider = Retry::new(mock, fast_policy(3));
+        assert!(matches!(
+            provider.lookup(CNPJ).await,
+            Err(LookupError::InvalidResponse(_))
+        ));
+        assert!(matches!(
+            provider.lookup("11222333000100").await,
+            Err(LookupError::Invalid(_))
+        ));
+        assert_eq!(provider.inner().calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_spaces_requests() {
+        let provider = RateLimit::new(MockCnpjProvider::new(), Duration::from_millis(20));
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            let _ = provider.lookup(CNPJ).await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert_eq!(provider.inner().calls().len(), 3);
+    }
+
+    #[test]
+    fn test_rate_limit_per_second() {
+        let provider = RateLimit::per_second(MockCnpjProvider::new(), 4);
+        assert_eq!(provider.interval, Duration::from_millis(250));
+
+        let unlimited = RateLimit::per_second(MockCnpjProvider::new(), 0);
+        assert_eq!(unlimited.reserve(), Duration::ZERO);
+        assert_eq!(unlimited.reserve(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,306 @@
+//! In-memory lookup providers for tests
+//!
+//! Downstream crates can unit-test code that depends on lookups without
+//! touching the network: records live in a map, failures can be scripted
+//! and latency injected to exercise timeouts.
+
+use super::carrier::normalize_mobile;
+use super::{
+    Address, BankInstitution, CarrierInfo, Company, CpfRegistration, LookupError, LookupProvider,
+    LookupResult,
+};
+use crate::error::ValidationResult;
+use crate::{cep, cnpj, cpf};
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+#[cfg(feature = "lookup")]
+use std::time::Duration;
+
+/// In-memory provider returning canned records
+///
+/// Keys are validated and normalized like the real providers do, so
+/// `"01001-000"` and `"01001000"` refer to the same record.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::lookup::{Address, LookupError, LookupProvider, MockCepProvider};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let provider = MockCepProvider::new()
+///     .with_record("01001-000", Address {
+///         cep: "01001000".to_string(),
+///         street: "Praça da Sé".to_string(),
+///         complement: None,
+///         neighborhood: "Sé".to_string(),
+///         city: "São Paulo".to_string(),
+///         state: "SP".to_string(),
+///         ibge_code: None,
+///         coordinates: None,
+///     });
+///
+/// assert_eq!(provider.lookup("01001000").await.unwrap().city, "São Paulo");
+/// assert_eq!(provider.lookup("20040-020").await, Err(LookupError::NotFound));
+/// # }
+/// ```
+pub struct MockProvider<T> {
+    normalize: fn(&str) -> ValidationResult<String>,
+    records: HashMap<String, T>,
+    key_failures: HashMap<String, LookupError>,
+    scripted_failures: Mutex<VecDeque<LookupError>>,
+    #[cfg(feature = "lookup")]
+    latency: Option<Duration>,
+    calls: Mutex<Vec<String>>,
+}
+
+/// Mock CEP → address provider
+pub type MockCepProvider = MockProvider<Address>;
+
+/// Mock CNPJ → company provider
+pub type MockCnpjProvider = MockProvider<Company>;
+
+/// Mock CPF → registration status provider
+pub type MockCpfStatusProvider = MockProvider<CpfRegistration>;
+
+/// Mock COMPE code → institution provider
+pub type MockBankProvider = MockProvider<BankInstitution>;
+
+/// Mock mobile number → carrier provider
+pub type MockCarrierProvider = MockProvider<CarrierInfo>;
+
+impl MockProvider<Address> {
+    /// Create an empty CEP provider
+    pub fn new() -> Self {
+        Self::with_normalizer(cep::validate)
+    }
+}
+
+impl MockProvider<Company> {
+    /// Create an empty CNPJ provider
+    pub fn new() -> Self {
+        Self::with_normalizer(cnpj::validate)
+    }
+}
+
+impl MockProvider<CpfRegistration> {
+    /// Create an empty CPF status provider
+    pub fn new() -> Self {
+        Self::with_normalizer(cpf::validate)
+    }
+}
+
+impl MockProvider<BankInstitution> {
+    /// Create an empty COMPE code provider
+    pub fn new() -> Self {
+        Self::with_normalizer(|code| Ok(format!("{:0>3}", code.trim())))
+    }
+}
+
+impl MockProvider<CarrierInfo> {
+    /// Create an empty carrier provider (mobile numbers only)
+    pub fn new() -> Self {
+        Self::with_normalizer(normalize_mobile)
+    }
+}
+
+impl Default for MockProvider<Address> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for MockProvider<Company> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for MockProvider<CpfRegistration> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for MockProvider<BankInstitution> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for MockProvider<CarrierInfo> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MockProvider<T> {
+    /// Create an empty provider normalizing keys with a custom function
+    pub fn with_normalizer(normalize: fn(&str) -> ValidationResult<String>) -> Self {
+        MockProvider {
+            normalize,
+            records: HashMap::new(),
+            key_failures: HashMap::new(),
+            scripted_failures: Mutex::new(VecDeque::new()),
+            #[cfg(feature = "lookup")]
+            latency: None,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a record returned for a key
+    ///
+    /// # Panics
+    /// Panics if the key is not a valid document (test setup error).
+    pub fn with_record(mut self, key: &str, record: T) -> Self {
+        let key = (self.normalize)(key).expect("mock record key must be a valid document");
+        self.records.insert(key, record);
+        self
+    }
+
+    /// Always fail lookups of a key with the given error
+    ///
+    /// # Panics
+    /// Panics if the key is not a valid document (test setup error).
+    pub fn with_failure(mut self, key: &str, error: LookupError) -> Self {
+        let key = (self.normalize)(key).expect("mock failure key must be a valid document");
+        self.key_failures.insert(key, error);
+        self
+    }
+
+    /// Delay every lookup by a fixed duration
+    ///
+    /// Sleeps on tokio, so it needs the `lookup` feature.
+    #[cfg(feature = "lookup")]
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Fail the next lookup (whatever the key) with the given error
+    ///
+    /// Scripted failures are consumed in the order they were queued.
+    pub fn fail_next(&self, error: LookupError) {
+        if let Ok(mut failures) = self.scripted_failures.lock() {
+            failures.push_back(error);
+        }
+    }
+
+    /// Normalized keys of every lookup received so far
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().map(|calls| calls.clone()).unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> LookupProvider for MockProvider<T> {
+    type Output = T;
+
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    async fn lookup(&self, key: &str) -> LookupResult<T> {
+        #[cfg(feature = "lookup")]
+        if let Some(latency) = self.latency {
+            tokio::time::sleep(latency).await;
+        }
+
+        let key = (self.normalize)(key)?;
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(key.clone());
+        }
+
+        let scripted = self
+            .scripted_failures
+            .lock()
+            .ok()
+            .and_then(|mut failures| failures.pop_front());
+        if let Some(error) = scripted {
+            return Err(error);
+        }
+
+        if let Some(error) = self.key_failures.get(&key) {
+            return Err(error.clone());
+        }
+
+        self.records.get(&key).cloned().ok_or(LookupError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn company() -> Company {
+        Company {
+            cnpj: "11222333000181".to_string(),
+            legal_name: "EMPRESA TESTE LTDA".to_string(),
+            trade_name: None,
+            registration_status: Some("ATIVA".to_string()),
+            main_activity: None,
+            secondary_activities: Vec::new(),
+            partners: Vec::new(),
+            address: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_are_found_by_normalized_key() {
+        let provider = MockCnpjProvider::new().with_record("11.222.333/0001-81", company());
+
+        assert_eq!(provider.lookup("11222333000181").await.unwrap(), company());
+        assert_eq!(provider.calls(), vec!["11222333000181"]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key_is_rejected() {
+        let provider = MockCnpjProvider::new();
+        assert!(matches!(
+            provider.lookup("11.222.333/0001-00").await,
+            Err(LookupError::Invalid(_))
+        ));
+        assert!(provider.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scripted_failures_are_consumed_in_order() {
+        let provider = MockCnpjProvider::new().with_record("11222333000181", company());
+        provider.fail_next(LookupError::Unavailable("timeout".to_string()));
+        provider.fail_next(LookupError::InvalidResponse("html".to_string()));
+
+        assert_eq!(
+            provider.lookup("11222333000181").await,
+            Err(LookupError::Unavailable("timeout".to_string()))
+        );
+        assert_eq!(
+            provider.lookup("11222333000181").await,
+            Err(LookupError::InvalidResponse("html".to_string()))
+        );
+        assert!(provider.lookup("11222333000181").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_key_failures_are_permanent() {
+        let provider = MockCepProvider::new()
+            .with_failure("01001-000", LookupError::Unavailable("503".to_string()));
+
+        for _ in 0..2 {
+            assert!(matches!(
+                provider.lookup("01001000").await,
+                Err(LookupError::Unavailable(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "lookup")]
+    #[tokio::test]
+    async fn test_latency_is_injected() {
+        let provider = MockBankProvider::new().with_latency(Duration::from_millis(20));
+
+        let start = std::time::Instant::now();
+        assert_eq!(provider.lookup("1").await, Err(LookupError::NotFound));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(provider.calls(), vec!["001"]);
+    }
+}
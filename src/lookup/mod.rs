@@ -0,0 +1,136 @@
+//! External lookups for validated documents
+//!
+//! A single [`LookupProvider`] abstraction over the Brazilian data
+//! services: CEP → address, CNPJ → company registration data, CPF →
+//! registration status, COMPE code → financial institution and mobile
+//! number → carrier. Built-in providers call BrasilAPI, ViaCEP, ReceitaWS
+//! and, with caller credentials, Serpro; implement the trait to plug in
+//! internal services, or use the in-memory [`MockProvider`]s in tests. [`Retry`] and [`RateLimit`]
+//! wrap any provider with backoff and client-side throttling, and
+//! [`Fallback`] chains an online provider with an offline one. [`Cache`]
+//! keeps results in memory or on disk ([`FileStore`]) for a time to live.
+//!
+//! Enabled by the `lookup` feature, which sends requests with reqwest on
+//! tokio; the `blocking` feature adds synchronous wrappers in [`blocking`].
+//! The `lookup-core` feature leaves both out: providers then take any
+//! [`HttpClient`] and [`Retry`]/[`RateLimit`], which sleep on tokio, are
+//! unavailable.
+//!
+//! # Examples
+//! ```no_run
+//! use pleme_brazilian_validators::lookup::{LookupProvider, ViaCep};
+//!
+//! # #[cfg(feature = "lookup")]
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let address = ViaCep::new().lookup("01001-000").await?;
+//! println!("{} - {}", address.street, address.city);
+//! # Ok(())
+//! # }
+//! ```
+
+mod bank;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cache;
+mod carrier;
+mod cep;
+mod cnpj;
+mod cpf;
+mod fallback;
+mod http;
+#[cfg(feature = "lookup")]
+mod middleware;
+mod mock;
+
+pub use bank::{BankInstitution, BrasilApiBanks};
+pub use cache::{Cache, CacheEntry, CacheStore, FileStore, MemoryStore};
+pub use carrier::{AllocationTable, Carrier, CarrierInfo};
+pub use cep::{Address, BrasilApiCep, Coordinates, ViaCep};
+pub use cnpj::{
+    Activity, BrasilApiCnae, BrasilApiCnpj, BrasilApiCnpjResponse, BrasilApiPartner, Company,
+    CompanyAddress, Partner, ReceitaWs, ReceitaWsActivity, ReceitaWsPartner, ReceitaWsResponse,
+};
+pub use cpf::{CpfRegistration, CpfStatus, SerproCpf, SerproCpfResponse, SerproSituacao};
+pub use fallback::Fallback;
+#[cfg(feature = "lookup")]
+pub use http::DEFAULT_TIMEOUT;
+pub use http::{HttpClient, HttpResponse};
+#[cfg(feature = "lookup")]
+pub use middleware::{RateLimit, Retry, RetryPolicy};
+pub use mock::{
+    MockBankProvider, MockCarrierProvider, MockCepProvider, MockCnpjProvider,
+    MockCpfStatusProvider, MockProvider,
+};
+
+use crate::error::BrazilianValidationError;
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type alias for lookup operations
+pub type LookupResult<T> = Result<T, LookupError>;
+
+/// Errors that can occur during an external lookup
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum LookupError {
+    /// The queried document failed validation (no request was made)
+    #[error(transparent)]
+    Invalid(#[from] BrazilianValidationError),
+
+    /// The provider has no record for the queried document
+    #[error("Registro não encontrado")]
+    NotFound,
+
+    /// The provider could not be reached or refused the request
+    #[error("Provedor indisponível: {0}")]
+    Unavailable(String),
+
+    /// The provider throttled the request (HTTP 429)
+    #[error("Limite de requisições do provedor excedido")]
+    RateLimited,
+
+    /// The provider answered with an unexpected payload
+    #[error("Resposta inválida do provedor: {0}")]
+    InvalidResponse(String),
+}
+
+impl LookupError {
+    /// Get error code for API responses
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::Invalid(err) => err.error_code(),
+            Self::NotFound => "LOOKUP_NOT_FOUND",
+            Self::Unavailable(_) => "LOOKUP_UNAVAILABLE",
+            Self::RateLimited => "LOOKUP_RATE_LIMITED",
+            Self::InvalidResponse(_) => "LOOKUP_INVALID_RESPONSE",
+        }
+    }
+
+    /// Check whether the failure is temporary and the lookup may be retried
+    ///
+    /// Only provider-side failures are transient; an invalid document, a
+    /// missing record or a malformed response will not change on retry.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Unavailable(_) | Self::RateLimited)
+    }
+}
+
+/// Provider of data about a document, keyed by its string representation
+///
+/// Implementations validate the key with the crate's validators before
+/// querying, so invalid documents never reach the network.
+#[async_trait]
+pub trait LookupProvider: Send + Sync {
+    /// Data returned for a document
+    type Output: Send;
+
+    /// Short provider name, for logs and diagnostics
+    fn name(&self) -> &'static str;
+
+    /// Look up the data for a document
+    async fn lookup(&self, key: &str) -> LookupResult<Self::Output>;
+}
+
+/// Treat empty strings returned by providers as missing values
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.trim().is_empty())
+}
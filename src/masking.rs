@@ -0,0 +1,129 @@
+//! Configurable masking policies
+//!
+//! The `mask` functions of each module reveal a fixed part of the document.
+//! [`MaskOptions`] lets compliance rules choose the mask character, how
+//! many leading and trailing characters stay visible and whether
+//! punctuation is kept; it is accepted by the `mask_with` functions of
+//! [`cpf`](mod@crate::cpf), [`cnpj`](mod@crate::cnpj), [`phone`](crate::phone) and
+//! [`pix`](crate::pix).
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::masking::MaskOptions;
+//! use pleme_brazilian_validators::{cnpj, cpf};
+//!
+//! let options = MaskOptions {
+//!     mask_char: '#',
+//!     visible_leading: 0,
+//!     visible_trailing: 4,
+//!     keep_punctuation: false,
+//! };
+//! assert_eq!(cpf::mask_with("123.456.789-09", &options), "#######8909");
+//! assert_eq!(cnpj::mask_with("11222333000181", &options), "##########0181");
+//! ```
+
+use alloc::string::String;
+
+/// How a document is masked
+///
+/// Letters and digits are counted as maskable characters; punctuation is
+/// never counted. The default reveals the first 3 and last 2 characters,
+/// as [`cpf::mask`](crate::cpf::mask) does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaskOptions {
+    /// Character replacing hidden letters and digits
+    pub mask_char: char,
+    /// Letters and digits left visible at the start
+    pub visible_leading: usize,
+    /// Letters and digits left visible at the end
+    pub visible_trailing: usize,
+    /// Keep the punctuation of the formatted document (`.`, `-`, `/`, ...)
+    pub keep_punctuation: bool,
+}
+
+impl Default for MaskOptions {
+    fn default() -> Self {
+        Self {
+            mask_char: '*',
+            visible_leading: 3,
+            visible_trailing: 2,
+            keep_punctuation: true,
+        }
+    }
+}
+
+impl MaskOptions {
+    /// Mask a value according to the options
+    ///
+    /// When the visible characters cover the whole value, nothing is
+    /// hidden.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::masking::MaskOptions;
+    ///
+    /// let options = MaskOptions::default();
+    /// assert_eq!(options.apply("123.456.789-09"), "123.***.***-09");
+    /// ```
+    pub fn apply(&self, value: &str) -> String {
+        let total = value.chars().filter(|c| c.is_alphanumeric()).count();
+        let hidden = self.visible_leading..total.saturating_sub(self.visible_trailing);
+
+        let mut position = 0;
+        value
+            .chars()
+            .filter_map(|c| {
+                if !c.is_alphanumeric() {
+                    return self.keep_punctuation.then_some(c);
+                }
+                let index = position;
+                position += 1;
+                Some(if hidden.contains(&index) {
+                    self.mask_char
+                } else {
+                    c
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_cpf_mask() {
+        assert_eq!(
+            MaskOptions::default().apply("123.456.789-09"),
+            crate::cpf::mask("12345678909")
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let options = MaskOptions {
+            mask_char: 'X',
+            visible_leading: 2,
+            visible_trailing: 0,
+            keep_punctuation: true,
+        };
+        assert_eq!(options.apply("11.222.333/0001-81"), "11.XXX.XXX/XXXX-XX");
+
+        let everything = MaskOptions {
+            visible_leading: 10,
+            visible_trailing: 10,
+            ..MaskOptions::default()
+        };
+        assert_eq!(everything.apply("(11) 3456-7890"), "(11) 3456-7890");
+
+        let nothing = MaskOptions {
+            visible_leading: 0,
+            visible_trailing: 0,
+            keep_punctuation: false,
+            ..MaskOptions::default()
+        };
+        assert_eq!(nothing.apply("01310-100"), "********");
+        assert_eq!(nothing.apply(""), "");
+    }
+}
@@ -0,0 +1,193 @@
+//! Fuzzy document matching
+//!
+//! Scores how likely two documents are the same one with a typing error,
+//! to support deduplication of customer records where one of the values
+//! was mistyped.
+
+use crate::document::DocumentKind;
+use crate::{boleto, caepf, card, cei, cep, certidao, cest, cnae, cnh, cno, cnpj, cns, cpf, gtin, iban, ibge, ie, ncm, nfe, passaporte, phone, pis, pix, placa, processo_cnj, renavam, rg, titulo_eleitor};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Longest normalized value compared with edit distance
+///
+/// Real documents are far shorter (an email PIX key is at most 77 chars);
+/// longer inputs are compared exactly to keep the quadratic edit distance
+/// bounded on arbitrary input.
+const MAX_FUZZY_LEN: usize = 128;
+
+/// Normalize a document for comparison according to its kind
+fn normalize(value: &str, kind: DocumentKind) -> Vec<char> {
+    let normalized = match kind {
+        DocumentKind::Cpf => cpf::normalize(value),
+        DocumentKind::Cnpj => cnpj::normalize(value),
+        DocumentKind::Cep => cep::normalize(value),
+        DocumentKind::Phone => {
+            let cleaned = phone::normalize(value);
+            phone::strip_country_code(&cleaned).to_string()
+        }
+        DocumentKind::PixKey => pix::normalize(value),
+        DocumentKind::IbgeCode => ibge::normalize(value),
+        DocumentKind::Boleto => boleto::normalize(value),
+        DocumentKind::NfeKey => nfe::normalize(value),
+        DocumentKind::Cnh => cnh::normalize(value),
+        DocumentKind::TituloEleitor => titulo_eleitor::normalize(value),
+        DocumentKind::InscricaoEstadual => ie::normalize(value),
+        DocumentKind::Iban => iban::normalize(value),
+        DocumentKind::Card => card::normalize(value),
+        DocumentKind::Cest => cest::normalize(value),
+        DocumentKind::Pis => pis::normalize(value),
+        DocumentKind::Renavam => renavam::normalize(value),
+        DocumentKind::Ncm => ncm::normalize(value),
+        DocumentKind::Cnae => cnae::normalize(value),
+        DocumentKind::Gtin => gtin::normalize(value),
+        DocumentKind::Cei => cei::normalize(value),
+        DocumentKind::Caepf => caepf::normalize(value),
+        DocumentKind::Cno => cno::normalize(value),
+        DocumentKind::Certidao => certidao::normalize(value),
+        DocumentKind::Passaporte => passaporte::normalize(value),
+        DocumentKind::Rg => rg::normalize(value),
+        DocumentKind::Cns => cns::normalize(value),
+        DocumentKind::ProcessoCnj => processo_cnj::normalize(value),
+        // A converted plate is the same vehicle as its legacy form
+        DocumentKind::Placa => {
+            placa::to_mercosul(value).unwrap_or_else(|| placa::normalize(value))
+        }
+        DocumentKind::BrCode | DocumentKind::Uf | DocumentKind::BankAccount => {
+            value.trim().to_uppercase()
+        }
+    };
+
+    normalized.chars().collect()
+}
+
+/// Edit distance counting insertions, deletions, substitutions and adjacent
+/// transpositions as one edit each (optimal string alignment)
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut matrix = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(matrix[i - 2][j - 2] + 1);
+            }
+
+            matrix[i][j] = distance;
+        }
+    }
+
+    matrix[a.len()][b.len()]
+}
+
+/// Score the similarity of two documents of the same kind
+///
+/// Both values are normalized first, so formatting differences are ignored.
+/// The score is `1.0` for identical documents and decreases by one edit per
+/// wrong, missing or extra digit; swapping two adjacent digits counts as a
+/// single edit.
+///
+/// # Arguments
+/// * `a` - First document
+/// * `b` - Second document
+/// * `kind` - Kind of both documents
+///
+/// # Returns
+/// Score between `0.0` (nothing in common) and `1.0` (same document)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{matching::similarity, DocumentKind};
+///
+/// assert_eq!(similarity("123.456.789-09", "12345678909", DocumentKind::Cpf), 1.0);
+///
+/// // Adjacent digits swapped: one edit out of 11 digits
+/// let score = similarity("12345678909", "12354678909", DocumentKind::Cpf);
+/// assert!((score - 10.0 / 11.0).abs() < f64::EPSILON);
+/// ```
+pub fn similarity(a: &str, b: &str, kind: DocumentKind) -> f64 {
+    let a = normalize(a, kind);
+    let b = normalize(b, kind);
+
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 0.0;
+    }
+    if max_len > MAX_FUZZY_LEN {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+
+    1.0 - edit_distance(&a, &b) as f64 / max_len as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(edit_distance(&chars("123"), &chars("123")), 0);
+        assert_eq!(edit_distance(&chars("123"), &chars("124")), 1);
+        assert_eq!(edit_distance(&chars("123"), &chars("132")), 1);
+        assert_eq!(edit_distance(&chars("123"), &chars("12")), 1);
+        assert_eq!(edit_distance(&chars(""), &chars("123")), 3);
+    }
+
+    #[test]
+    fn test_similarity_identical_documents() {
+        assert_eq!(
+            similarity("11.222.333/0001-81", "11222333000181", DocumentKind::Cnpj),
+            1.0
+        );
+        assert_eq!(
+            similarity("+55 11 98765-4321", "(11) 98765-4321", DocumentKind::Phone),
+            1.0
+        );
+        assert_eq!(
+            similarity("User@Example.com", "user@example.com", DocumentKind::PixKey),
+            1.0
+        );
+        assert_eq!(
+            similarity("abc-1234", "ABC1C34", DocumentKind::Placa),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_similarity_single_digit_error() {
+        let score = similarity("12345678909", "12345678999", DocumentKind::Cpf);
+        assert!((score - 10.0 / 11.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_similarity_missing_digit() {
+        let score = similarity("01310-100", "0131-100", DocumentKind::Cep);
+        assert!((score - 7.0 / 8.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_similarity_unrelated_documents() {
+        assert!(similarity("12345678909", "98765432100", DocumentKind::Cpf) < 0.5);
+        assert_eq!(similarity("", "", DocumentKind::Cpf), 0.0);
+    }
+
+    #[test]
+    fn test_similarity_huge_input_is_compared_exactly() {
+        let huge = "1".repeat(100_000);
+        assert_eq!(similarity(&huge, &huge, DocumentKind::Cpf), 1.0);
+        assert_eq!(similarity(&huge, "11111111111", DocumentKind::Cpf), 0.0);
+    }
+}
@@ -0,0 +1,171 @@
+//! NCM (Nomenclatura Comum do Mercosul) validation and formatting
+//!
+//! The NCM classifies goods in NF-e product lines and import
+//! declarations. It extends the 6-digit Harmonized System with two
+//! Mercosul digits:
+//!
+//! ```text
+//! 84 71 . 30 . 1 2
+//! │  │     │   │ └ subitem
+//! │  │     │   └ item
+//! │  │     └ subheading (HS)
+//! │  └ heading (HS)
+//! └ chapter (01 to 97)
+//! ```
+//!
+//! There is no check digit; validation checks the structure, chapter 77
+//! being reserved by the Harmonized System and never used.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::ncm;
+//!
+//! assert_eq!(ncm::validate("8471.30.12").as_deref(), Ok("84713012"));
+//! assert_eq!(ncm::chapter("84713012"), Some(84));
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Number of digits of an NCM code
+pub const LEN: usize = 8;
+
+/// Chapter reserved by the Harmonized System for future use
+const RESERVED_CHAPTER: u8 = 77;
+
+/// Validate an NCM code
+///
+/// # Arguments
+/// * `ncm` - 8-digit code (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized code (8 digits)
+/// * `Err(BrazilianValidationError)` - Wrong length or nonexistent chapter
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ncm::validate;
+///
+/// assert!(validate("2203.00.00").is_ok());
+/// assert!(validate("9801.00.00").is_err()); // Chapter 98 does not exist
+/// ```
+pub fn validate(ncm: &str) -> ValidationResult<String> {
+    let result = check(ncm);
+    hooks::notify(DocumentKind::Ncm, &result);
+    result
+}
+
+fn check(ncm: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(ncm));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if chapter(&cleaned).is_none() {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Ncm,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Chapter of an NCM code (its first two digits)
+///
+/// Returns `None` for chapters outside 01-97 and for the reserved chapter
+/// 77. Accepts headings and subheadings as well as full codes.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ncm::chapter;
+///
+/// assert_eq!(chapter("0901.21.00"), Some(9));
+/// assert_eq!(chapter("7701"), None);
+/// ```
+pub fn chapter(ncm: &str) -> Option<u8> {
+    let chapter: u8 = normalize(ncm).get(..2)?.parse().ok()?;
+    ((1..=97).contains(&chapter) && chapter != RESERVED_CHAPTER).then_some(chapter)
+}
+
+/// Normalize an NCM code by removing all non-digit characters
+pub fn normalize(ncm: &str) -> String {
+    ncm.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format an NCM code (XXXX.XX.XX)
+///
+/// Returns input unchanged if it does not have 8 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ncm::format;
+///
+/// assert_eq!(format("85171300"), "8517.13.00");
+/// assert_eq!(format("8517"), "8517");
+/// ```
+pub fn format(ncm: &str) -> String {
+    let cleaned = normalize(ncm);
+
+    if cleaned.len() == LEN {
+        format!("{}.{}.{}", &cleaned[..4], &cleaned[4..6], &cleaned[6..])
+    } else {
+        ncm.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_is_ignored() {
+        for ncm in ["8471.30.12", "8471 30 12", "8471-30-12", "84713012"] {
+            assert_eq!(validate(ncm).as_deref(), Ok("84713012"));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 8,
+                actual,
+            })
+        };
+        // A bare HS subheading, and an NCM with a CEST pasted after it
+        assert_eq!(validate("8471.30"), invalid_length(6));
+        assert_eq!(validate("847130120"), invalid_length(9));
+        assert_eq!(validate("8471.30.12 21.064.00"), invalid_length(15));
+    }
+
+    #[test]
+    fn test_chapter_bounds() {
+        for ncm in ["01022110", "09012100", "76169900", "78060000", "97060000"] {
+            assert_eq!(validate(ncm).as_deref(), Ok(ncm));
+        }
+        for ncm in ["00000000", "77011000", "98010000", "99999999"] {
+            assert_eq!(
+                validate(ncm),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Ncm
+                }),
+                "{}",
+                ncm
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeated_digits() {
+        // Without a check digit, only the chapter can rule them out
+        assert_eq!(validate("11111111").as_deref(), Ok("11111111"));
+        assert!(validate("77777777").is_err());
+    }
+}
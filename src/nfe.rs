@@ -0,0 +1,442 @@
+//! NF-e access key (chave de acesso) validation and formatting
+//!
+//! Every NF-e is identified by a 44-digit access key ending in a modulo 11
+//! check digit. DANFEs print the key in groups of four digits
+//! ([`format_key_grouped`]) and as a Code 128C barcode (`key_to_svg`,
+//! `key_to_png`, feature `barcode`).
+//!
+//! The key encodes the invoice: UF, year and month of emission, emitter
+//! CNPJ, model, series, number, emission type and a random numeric code.
+//! [`validate_key`] only checks the check digit; [`validate_access_key`]
+//! also checks the encoded fields and [`parse`] decomposes them.
+//!
+//! ```text
+//! 35 2308 11222333000181 55 001 000000001 1 00000001 1
+//! UF AAMM CNPJ           mod série número  tp código  DV
+//! ```
+
+use crate::document::DocumentKind;
+use crate::dv::{self, Mod11Remainder};
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::uf::Uf;
+use crate::{cnpj, cpf, hooks};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Number of digits of an access key
+pub const KEY_LEN: usize = 44;
+
+/// First year of NF-e emission, as encoded in keys (2006)
+const FIRST_YEAR: u16 = 6;
+
+/// Invoice model (modelo) encoded in an access key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NfeModel {
+    /// NF-e, model 55
+    Nfe,
+    /// NFC-e (consumer invoice), model 65
+    Nfce,
+}
+
+impl NfeModel {
+    /// Model code as encoded in the key
+    pub fn code(&self) -> u8 {
+        match self {
+            NfeModel::Nfe => 55,
+            NfeModel::Nfce => 65,
+        }
+    }
+
+    /// Model for a code, if it is an NF-e or NFC-e
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            55 => Some(NfeModel::Nfe),
+            65 => Some(NfeModel::Nfce),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for NfeModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NfeModel::Nfe => write!(f, "NF-e"),
+            NfeModel::Nfce => write!(f, "NFC-e"),
+        }
+    }
+}
+
+/// Fields of a validated access key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccessKey {
+    /// Normalized key (44 digits)
+    pub key: String,
+    /// Federative unit of the emitter
+    pub uf: Uf,
+    /// Year of emission (e.g. `2023`)
+    pub year: u16,
+    /// Month of emission (`1` to `12`)
+    pub month: u8,
+    /// Emitter CNPJ (14 digits; `000` and the CPF for individual emitters)
+    pub cnpj: String,
+    /// Invoice model
+    pub model: NfeModel,
+    /// Series (`0` to `999`)
+    pub series: u16,
+    /// Invoice number
+    pub number: u32,
+    /// Emission type (tpEmis: `1` normal, `9` offline NFC-e, others contingency)
+    pub emission_type: u8,
+    /// Random numeric code (cNF, 8 digits)
+    pub numeric_code: String,
+}
+
+/// Validate an NF-e access key
+///
+/// # Arguments
+/// * `key` - 44-digit access key (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized key (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::nfe::validate_key;
+///
+/// assert!(validate_key("3523 0811 2223 3300 0181 5500 1000 0000 0110 0000 0011").is_ok());
+/// assert!(validate_key("35230811222333000181550010000000011000000012").is_err());
+/// ```
+pub fn validate_key(key: &str) -> ValidationResult<String> {
+    let result = validate_key_unobserved(key);
+    hooks::notify(DocumentKind::NfeKey, &result);
+    result
+}
+
+/// Validate without reporting to the validation hook (for internal use)
+pub(crate) fn validate_key_unobserved(key: &str) -> ValidationResult<String> {
+    let cleaned = normalize(key);
+
+    if cleaned.len() != KEY_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: KEY_LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let digits = dv::digits(&cleaned).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    if compute_check_digit(&digits[..KEY_LEN - 1]) != digits[KEY_LEN - 1] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::NfeKey,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Validate an NF-e or NFC-e access key and the fields it encodes
+///
+/// On top of the check digit ([`validate_key`]), the UF code must be a
+/// known state, the month of emission plausible, the emitter CNPJ valid
+/// and the model 55 (NF-e) or 65 (NFC-e).
+///
+/// # Returns
+/// * `Ok(String)` - Normalized key (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::nfe::validate_access_key;
+///
+/// assert!(validate_access_key("35230811222333000181550010000000011000000011").is_ok());
+/// // Model 57 (CT-e) has a valid check digit but is not an NF-e
+/// assert!(validate_access_key("35230811222333000181570010000000011000000019").is_err());
+/// ```
+pub fn validate_access_key(key: &str) -> ValidationResult<String> {
+    let result = parse_unobserved(key).map(|parsed| parsed.key);
+    hooks::notify(DocumentKind::NfeKey, &result);
+    result
+}
+
+/// Validate an access key and decompose its fields
+///
+/// Performs the same checks as [`validate_access_key`].
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::nfe::{parse, NfeModel};
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// let key = parse("3523 0811 2223 3300 0181 5500 1000 0000 0110 0000 0011").unwrap();
+/// assert_eq!(key.uf, Uf::SP);
+/// assert_eq!((key.year, key.month), (2023, 8));
+/// assert_eq!(key.cnpj, "11222333000181");
+/// assert_eq!(key.model, NfeModel::Nfe);
+/// assert_eq!((key.series, key.number), (1, 1));
+/// ```
+pub fn parse(key: &str) -> ValidationResult<AccessKey> {
+    let result = parse_unobserved(key);
+    hooks::notify(DocumentKind::NfeKey, &result);
+    result
+}
+
+/// Parse without reporting to the validation hook
+fn parse_unobserved(key: &str) -> ValidationResult<AccessKey> {
+    let key = validate_key_unobserved(key)?;
+    let invalid_format = BrazilianValidationError::InvalidDocumentFormat {
+        document_type: DocumentKind::NfeKey,
+    };
+    // Slices of a validated key are ASCII digits, so they always parse
+    let number = |range: core::ops::Range<usize>| key[range].parse::<u32>().unwrap_or_default();
+
+    let uf = u8::try_from(number(0..2))
+        .ok()
+        .and_then(Uf::from_ibge_code)
+        .ok_or_else(|| invalid_format.clone())?;
+
+    let year = number(2..4) as u16;
+    let month = number(4..6) as u8;
+    if year < FIRST_YEAR || !(1..=12).contains(&month) {
+        return Err(invalid_format);
+    }
+
+    let cnpj = key[6..20].to_string();
+    let individual = cnpj.starts_with("000") && cpf::validate_unobserved(&cnpj[3..]).is_ok();
+    if !individual && cnpj::validate_unobserved(&cnpj).is_err() {
+        return Err(BrazilianValidationError::invalid_cnpj(
+            "CNPJ do emitente inválido na chave de acesso",
+        ));
+    }
+
+    let model = u8::try_from(number(20..22))
+        .ok()
+        .and_then(NfeModel::from_code)
+        .ok_or(invalid_format)?;
+
+    Ok(AccessKey {
+        uf,
+        year: 2000 + year,
+        month,
+        cnpj,
+        model,
+        series: number(22..25) as u16,
+        number: number(25..34),
+        emission_type: number(34..35) as u8,
+        numeric_code: key[35..43].to_string(),
+        key,
+    })
+}
+
+/// Compute the check digit of the first 43 digits of a key
+pub(crate) fn compute_check_digit(digits: &[u32]) -> u32 {
+    let weights = dv::cyclic_weights(digits.len(), 2, 9);
+    dv::mod11_with(digits, &weights, Mod11Remainder::ComplementOrZero).unwrap_or(0)
+}
+
+/// Normalize an access key by removing non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::nfe::normalize;
+///
+/// assert_eq!(normalize("3523 0811 2223"), "352308112223");
+/// ```
+pub fn normalize(key: &str) -> String {
+    key.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format an access key in groups of four digits, as printed on DANFEs
+///
+/// # Returns
+/// Formatted key. Returns input unchanged if it does not have 44 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::nfe::format_key_grouped;
+///
+/// assert_eq!(
+///     format_key_grouped("35230811222333000181550010000000011000000011"),
+///     "3523 0811 2223 3300 0181 5500 1000 0000 0110 0000 0011"
+/// );
+/// ```
+pub fn format_key_grouped(key: &str) -> String {
+    let cleaned = normalize(key);
+    if cleaned.len() != KEY_LEN {
+        return key.to_string();
+    }
+
+    cleaned
+        .as_bytes()
+        .chunks(4)
+        .map(|group| core::str::from_utf8(group).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Code 128C rendering dimensions
+///
+/// The defaults give a symbol of about 290 pixels plus quiet zones, which
+/// fits the DANFE key box at 96 dpi.
+#[cfg(feature = "barcode")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code128Options {
+    /// Width of a module (the narrowest bar), in pixels
+    pub module_width: u32,
+    /// Height of the bars, in pixels
+    pub height: u32,
+    /// Blank margin on each side, in modules
+    pub quiet_zone: u32,
+}
+
+#[cfg(feature = "barcode")]
+impl Default for Code128Options {
+    fn default() -> Self {
+        Code128Options {
+            module_width: 1,
+            height: 50,
+            quiet_zone: 10,
+        }
+    }
+}
+
+/// Render an access key as a Code 128C barcode in SVG
+///
+/// The key is validated first, so the bars always encode a valid key.
+///
+/// Enabled by the `barcode` feature.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::nfe::{key_to_svg, Code128Options};
+///
+/// let svg = key_to_svg("35230811222333000181550010000000011000000011", &Code128Options::default());
+/// assert!(svg.unwrap().starts_with("<svg"));
+/// ```
+#[cfg(feature = "barcode")]
+pub fn key_to_svg(key: &str, options: &Code128Options) -> ValidationResult<String> {
+    Ok(layout(key, options)?.to_svg())
+}
+
+/// Render an access key as a Code 128C barcode in grayscale PNG
+///
+/// Enabled by the `barcode` feature.
+#[cfg(feature = "barcode")]
+pub fn key_to_png(key: &str, options: &Code128Options) -> ValidationResult<Vec<u8>> {
+    layout(key, options)?.to_png()
+}
+
+/// Lay out the bars of a key in pixels
+#[cfg(feature = "barcode")]
+fn layout(key: &str, options: &Code128Options) -> ValidationResult<crate::barcode::Layout> {
+    let key = validate_key_unobserved(key)?;
+    let widths =
+        crate::barcode::code128c(&key).ok_or(BrazilianValidationError::InvalidCharacters)?;
+    crate::barcode::Layout::new(
+        &widths,
+        options.module_width,
+        options.height,
+        options.quiet_zone,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    const KEY: &str = "35230811222333000181550010000000011000000011";
+
+    #[test]
+    fn test_validate_key() {
+        assert_eq!(validate_key(KEY).as_deref(), Ok(KEY));
+        assert_eq!(
+            validate_key("3523081122233300018155001000000001100000001"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 44,
+                actual: 43
+            })
+        );
+        assert_eq!(
+            validate_key("35230811222333000181550010000000011000000013"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::NfeKey
+            })
+        );
+    }
+
+    /// Replace the digits at `at` and fix the check digit
+    fn with_field(at: usize, field: &str) -> String {
+        let mut key = KEY[..KEY_LEN - 1].to_string();
+        key.replace_range(at..at + field.len(), field);
+        let digits = dv::digits(&key).unwrap();
+        format!("{}{}", key, compute_check_digit(&digits))
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse(KEY).unwrap();
+        assert_eq!(parsed.key, KEY);
+        assert_eq!(parsed.uf, Uf::SP);
+        assert_eq!((parsed.year, parsed.month), (2023, 8));
+        assert_eq!(parsed.cnpj, "11222333000181");
+        assert_eq!(parsed.model, NfeModel::Nfe);
+        assert_eq!((parsed.series, parsed.number), (1, 1));
+        assert_eq!(parsed.emission_type, 1);
+        assert_eq!(parsed.numeric_code, "00000001");
+
+        let nfce = parse(&with_field(20, "65")).unwrap();
+        assert_eq!(nfce.model, NfeModel::Nfce);
+        // Individual emitter: 000 followed by the CPF
+        assert_eq!(parse(&with_field(6, "00012345678909")).unwrap().cnpj, "00012345678909");
+    }
+
+    #[test]
+    fn test_validate_access_key_fields() {
+        let invalid_format = Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::NfeKey,
+        });
+        assert_eq!(validate_access_key(&with_field(0, "99")), invalid_format);
+        assert_eq!(validate_access_key(&with_field(2, "2313")), invalid_format);
+        assert_eq!(validate_access_key(&with_field(2, "0508")), invalid_format);
+        assert_eq!(validate_access_key(&with_field(20, "57")), invalid_format);
+        assert!(matches!(
+            validate_access_key(&with_field(6, "11222333000182")),
+            Err(BrazilianValidationError::InvalidCnpj(_))
+        ));
+        // The check digit alone does not look at the fields
+        assert!(validate_key(&with_field(20, "57")).is_ok());
+    }
+
+    #[test]
+    fn test_format_key_grouped() {
+        let grouped = format_key_grouped(KEY);
+        assert_eq!(grouped.split(' ').count(), 11);
+        assert_eq!(format_key_grouped(&grouped), grouped);
+        assert_eq!(format_key_grouped("1234"), "1234");
+    }
+
+    #[cfg(feature = "barcode")]
+    #[test]
+    fn test_key_barcode() {
+        let options = Code128Options::default();
+        // Start, 22 digit pairs and checksum of 11 modules, stop of 13
+        let modules = 24 * 11 + 13 + 2 * options.quiet_zone;
+        let svg = key_to_svg(KEY, &options).unwrap();
+        assert!(svg.contains(&format!("width=\"{}\" height=\"50\"", modules)));
+
+        let png = key_to_png(KEY, &options).unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+
+        assert!(key_to_svg("35230811222333000181550010000000011000000013", &options).is_err());
+
+        let huge = Code128Options {
+            module_width: u32::MAX,
+            ..options
+        };
+        assert_eq!(
+            key_to_png(KEY, &huge),
+            Err(BrazilianValidationError::ImageTooLarge)
+        );
+    }
+}
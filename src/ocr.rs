@@ -0,0 +1,217 @@
+//! OCR-tolerant validation
+//!
+//! Document-capture pipelines often confuse letters and digits when reading
+//! scanned documents (`O` for `0`, `l` for `1`, `S` for `5`, ...). This module
+//! substitutes the common confusions before validating and reports every
+//! substitution applied, so callers can decide whether to trust the result
+//! or ask for manual review.
+
+use crate::cnpj;
+use crate::document::DocumentKind;
+use crate::error::ValidationResult;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Common OCR confusions and the digit they stand for
+const OCR_CONFUSIONS: [(char, char); 10] = [
+    ('O', '0'),
+    ('o', '0'),
+    ('D', '0'),
+    ('Q', '0'),
+    ('I', '1'),
+    ('l', '1'),
+    ('|', '1'),
+    ('Z', '2'),
+    ('S', '5'),
+    ('B', '8'),
+];
+
+/// A character replaced during OCR correction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution {
+    /// Character index in the original input
+    pub index: usize,
+    /// Character read by the OCR engine
+    pub found: char,
+    /// Digit it was replaced with
+    pub replaced_with: char,
+}
+
+/// Result of an OCR-tolerant validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrValidation {
+    /// Normalized document, as returned by the module's `validate`
+    pub normalized: String,
+    /// Substitutions applied before validation (empty if none)
+    pub substitutions: Vec<Substitution>,
+}
+
+impl OcrValidation {
+    /// Check whether any OCR correction was needed
+    pub fn is_corrected(&self) -> bool {
+        !self.substitutions.is_empty()
+    }
+}
+
+/// Replace common OCR confusions with the digits they stand for
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::ocr::correct;
+///
+/// let (corrected, substitutions) = correct("l23.456.789-O9");
+/// assert_eq!(corrected, "123.456.789-09");
+/// assert_eq!(substitutions.len(), 2);
+/// ```
+pub fn correct(input: &str) -> (String, Vec<Substitution>) {
+    let mut substitutions = Vec::new();
+
+    let corrected = input
+        .chars()
+        .enumerate()
+        .map(|(index, c)| {
+            match OCR_CONFUSIONS.iter().find(|(found, _)| *found == c) {
+                Some(&(found, replaced_with)) => {
+                    substitutions.push(Substitution {
+                        index,
+                        found,
+                        replaced_with,
+                    });
+                    replaced_with
+                }
+                None => c,
+            }
+        })
+        .collect();
+
+    (corrected, substitutions)
+}
+
+/// Validate a document read by OCR, correcting common confusions first
+///
+/// PIX keys, BR Codes, UFs, license plates, IBANs and passport numbers are
+/// validated as-is: they legitimately contain letters, so no substitution is
+/// attempted for them. CNPJs that are valid as read are kept too, since
+/// alphanumeric CNPJs have letters.
+///
+/// # Arguments
+/// * `input` - Document string as read by the OCR engine
+/// * `kind` - Expected document kind
+///
+/// # Returns
+/// * `Ok(OcrValidation)` - Normalized document and substitutions applied
+/// * `Err(BrazilianValidationError)` - Invalid even after correction
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{ocr, DocumentKind};
+///
+/// let result = ocr::validate("I23.4S6.789-O9", DocumentKind::Cpf).unwrap();
+/// assert_eq!(result.normalized, "12345678909");
+/// assert!(result.is_corrected());
+///
+/// assert!(ocr::validate("I23.4S6.789-OO", DocumentKind::Cpf).is_err());
+/// ```
+pub fn validate(input: &str, kind: DocumentKind) -> ValidationResult<OcrValidation> {
+    let (corrected, substitutions) = match kind {
+        DocumentKind::PixKey
+        | DocumentKind::BrCode
+        | DocumentKind::Uf
+        | DocumentKind::Placa
+        | DocumentKind::Iban
+        | DocumentKind::Passaporte => (input.to_string(), Vec::new()),
+        DocumentKind::Cnpj if cnpj::validate_unobserved(input).is_ok() => {
+            (input.to_string(), Vec::new())
+        }
+        _ => correct(input),
+    };
+
+    let normalized = kind.validate(&corrected)?;
+
+    Ok(OcrValidation {
+        normalized,
+        substitutions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_correct_reports_positions() {
+        let (corrected, substitutions) = correct("O1310-l00");
+        assert_eq!(corrected, "01310-100");
+        assert_eq!(
+            substitutions,
+            vec![
+                Substitution {
+                    index: 0,
+                    found: 'O',
+                    replaced_with: '0'
+                },
+                Substitution {
+                    index: 6,
+                    found: 'l',
+                    replaced_with: '1'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_without_substitutions() {
+        let result = validate("11.222.333/0001-81", DocumentKind::Cnpj).unwrap();
+        assert_eq!(result.normalized, "11222333000181");
+        assert!(!result.is_corrected());
+    }
+
+    #[test]
+    fn test_validate_corrected_documents() {
+        let result = validate("1l.222.333/OOO1-B1", DocumentKind::Cnpj).unwrap();
+        assert_eq!(result.normalized, "11222333000181");
+        assert_eq!(result.substitutions.len(), 5);
+
+        let result = validate("(1l) 9B765-432l", DocumentKind::Phone).unwrap();
+        assert_eq!(result.normalized, "+5511987654321");
+    }
+
+    #[test]
+    fn test_valid_alphanumeric_cnpj_is_not_corrected() {
+        let result = validate("12.ABC.345/01DE-35", DocumentKind::Cnpj).unwrap();
+        assert_eq!(result.normalized, "12ABC34501DE35");
+        assert!(!result.is_corrected());
+    }
+
+    #[test]
+    fn test_plates_are_not_corrected() {
+        for plate in ["ABC1D23", "BRA2E19", "ABC-1234", "DOS2Z34", "SZB-1234"] {
+            let result = validate(plate, DocumentKind::Placa).unwrap();
+            assert!(!result.is_corrected(), "{plate}");
+        }
+    }
+
+    #[test]
+    fn test_iban_is_not_corrected() {
+        let result = validate("BR1060701190025450000023661C1", DocumentKind::Iban).unwrap();
+        assert_eq!(result.normalized, "BR1060701190025450000023661C1");
+        assert!(!result.is_corrected());
+    }
+
+    #[test]
+    fn test_passport_is_not_corrected() {
+        for passport in ["AB123456", "FS123456"] {
+            let result = validate(passport, DocumentKind::Passaporte).unwrap();
+            assert_eq!(result.normalized, passport);
+            assert!(!result.is_corrected());
+        }
+    }
+
+    #[test]
+    fn test_validate_pix_key_is_not_corrected() {
+        let result = validate("Bob@Example.com", DocumentKind::PixKey).unwrap();
+        assert_eq!(result.normalized, "bob@example.com");
+        assert!(!result.is_corrected());
+    }
+}
@@ -0,0 +1,178 @@
+//! Brazilian passport number validation
+//!
+//! Passports issued by the Polícia Federal are numbered with two letters
+//! (the series, e.g. `FA`, `GB`) followed by six digits. There is no
+//! check digit, so validation is structural, plus the rejection of
+//! placeholder numbers commonly typed into forms.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Number of characters of a passport number
+pub const LEN: usize = 8;
+
+/// Validate a Brazilian passport number
+///
+/// Rejects numbers whose six digits are all the same (`AA000000`,
+/// `FA111111`), which are placeholders rather than issued passports.
+///
+/// # Arguments
+/// * `passaporte` - Passport number (spaces and hyphens are ignored,
+///   case-insensitive)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized number (uppercase, no spaces)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::passaporte::validate;
+///
+/// assert_eq!(validate("fa 123456").unwrap(), "FA123456");
+/// assert!(validate("F1234567").is_err()); // One letter only
+/// assert!(validate("AA000000").is_err()); // Placeholder
+/// ```
+pub fn validate(passaporte: &str) -> ValidationResult<String> {
+    let result = check(passaporte);
+    hooks::notify(DocumentKind::Passaporte, &result);
+    result
+}
+
+fn check(passaporte: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(passaporte));
+
+    if !cleaned.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let (series, number) = cleaned.as_bytes().split_at(2);
+    let well_formed = series.iter().all(u8::is_ascii_uppercase)
+        && number.iter().all(u8::is_ascii_digit)
+        && !number.iter().all(|&b| b == number[0]);
+    if !well_formed {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Passaporte,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Normalize a passport number: spaces and hyphens removed, uppercase
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::passaporte::normalize;
+///
+/// assert_eq!(normalize(" fa-123456 "), "FA123456");
+/// ```
+pub fn normalize(passaporte: &str) -> String {
+    passaporte
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Mask a passport number for display (shows the series and the last 2
+/// digits)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::passaporte::mask;
+///
+/// assert_eq!(mask("FA123456"), "FA****56");
+/// ```
+pub fn mask(passaporte: &str) -> String {
+    let cleaned = normalize(passaporte);
+
+    if cleaned.len() == LEN && cleaned.is_ascii() {
+        format!("{}****{}", &cleaned[..2], &cleaned[6..])
+    } else {
+        passaporte.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spacing_and_case_are_ignored() {
+        for passaporte in ["gb-987 654", " GB 987654 ", "Gb987-654", "GB\t987654"] {
+            assert_eq!(validate(passaporte).as_deref(), Ok("GB987654"));
+        }
+    }
+
+    #[test]
+    fn test_punctuation_and_non_ascii() {
+        for passaporte in [
+            "FA.123456",
+            "FA/123456",
+            "FA_123456",
+            "FÁ123456",
+            "FA１23456",
+        ] {
+            assert_eq!(
+                validate(passaporte),
+                Err(BrazilianValidationError::InvalidCharacters),
+                "{}",
+                passaporte
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length = |actual| {
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 8,
+                actual,
+            })
+        };
+        assert_eq!(validate("FA12345"), invalid_length(7));
+        assert_eq!(validate("FA1234567"), invalid_length(9));
+        assert_eq!(validate(""), invalid_length(0));
+    }
+
+    #[test]
+    fn test_series_and_number_layout() {
+        let invalid_format = Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Passaporte,
+        });
+        // Two letters, then six digits
+        for passaporte in ["12345678", "F1234567", "FAB12345", "FA12345B", "1A123456"] {
+            assert_eq!(validate(passaporte), invalid_format, "{}", passaporte);
+        }
+        assert_eq!(validate("CS000001").as_deref(), Ok("CS000001"));
+    }
+
+    #[test]
+    fn test_placeholders_are_rejected() {
+        let invalid_format = Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Passaporte,
+        });
+        for digit in '0'..='9' {
+            let number = digit.to_string().repeat(6);
+            assert_eq!(validate(&format!("FA{}", number)), invalid_format);
+        }
+        assert_eq!(validate("AAAAAAAA"), invalid_format);
+        // Only the digits matter: a repeated series is fine
+        assert_eq!(validate("AA123456").as_deref(), Ok("AA123456"));
+    }
+
+    #[test]
+    fn test_mask_passthrough() {
+        assert_eq!(mask("FA12"), "FA12");
+    }
+}
@@ -0,0 +1,114 @@
+//! Canonical regular expressions
+//!
+//! The exact patterns the validators use to check the shape of a document,
+//! exported as strings so frontends and database `CHECK` constraints can
+//! enforce the same rules as the backend.
+//!
+//! Patterns are anchored and stick to a portable subset (no `\d`, no
+//! lookaround, no named groups), so they behave the same in the `regex`
+//! crate, ECMAScript (`new RegExp(patterns::CPF)`) and PostgreSQL (`~`).
+//! They only check the format: check digits, DDDs and other rules are
+//! verified by each module's `validate`.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::patterns;
+//! use regex::Regex;
+//!
+//! let cpf = Regex::new(patterns::CPF).unwrap();
+//! assert!(cpf.is_match("123.456.789-09"));
+//! assert!(cpf.is_match("12345678909"));
+//! assert!(!cpf.is_match("1234567890"));
+//! ```
+
+/// CPF, with or without punctuation (`123.456.789-09`, `12345678909`)
+pub const CPF: &str = r"^[0-9]{3}\.?[0-9]{3}\.?[0-9]{3}-?[0-9]{2}$";
+
+/// CNPJ, with or without punctuation (`11.222.333/0001-81`, `11222333000181`)
+///
+/// The root and branch may hold letters (alphanumeric CNPJ,
+/// `12.ABC.345/01DE-35`), in either case since validation uppercases them;
+/// the check digits are always numeric.
+pub const CNPJ: &str = concat!(
+    r"^[0-9A-Za-z]{2}\.?[0-9A-Za-z]{3}\.?[0-9A-Za-z]{3}/?[0-9A-Za-z]{4}",
+    r"-?[0-9]{2}$"
+);
+
+/// CEP, with or without hyphen (`01310-100`, `01310100`)
+pub const CEP: &str = r"^[0-9]{5}-?[0-9]{3}$";
+
+/// Phone number, optionally with `+55` and DDD (`+55 (11) 98765-4321`)
+pub const PHONE: &str = r"^(\+55\s?)?(\(?[0-9]{2}\)?\s?)?([0-9]{4,5}[-\s]?[0-9]{4})$";
+
+/// PIX e-mail key
+pub const PIX_EMAIL: &str = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$";
+
+/// PIX phone key, normalized (`+55` followed by DDD and 9-digit mobile)
+pub const PIX_PHONE: &str = r"^\+55[0-9]{11}$";
+
+/// PIX random key (EVP), a lowercase UUID
+pub const PIX_EVP: &str =
+    r"^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$";
+
+/// Bank boleto typeable line (linha digitável, 47 digits)
+///
+/// Accepts the printed layout (`00190.00009 01234.567891 23456.789012 3
+/// 45670000012345`) or the bare digits.
+pub const BOLETO_LINE: &str = concat!(
+    r"^[0-9]{5}\.?[0-9]{5}\s?[0-9]{5}\.?[0-9]{6}\s?[0-9]{5}\.?[0-9]{6}",
+    r"\s?[0-9]\s?[0-9]{14}$"
+);
+
+/// Collection (arrecadação) boleto typeable line (48 digits)
+///
+/// Four blocks of 11 digits, each followed by its check digit
+/// (`83640000001-1 33120138000-2 81288462711-6 08013618155-1`).
+pub const BOLETO_COLLECTION_LINE: &str = concat!(
+    r"^[0-9]{11}-?[0-9]\s?[0-9]{11}-?[0-9]\s?",
+    r"[0-9]{11}-?[0-9]\s?[0-9]{11}-?[0-9]$"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    fn matches(pattern: &str, input: &str) -> bool {
+        Regex::new(pattern).unwrap().is_match(input)
+    }
+
+    #[test]
+    fn test_document_patterns() {
+        assert!(matches(CNPJ, "11.222.333/0001-81"));
+        assert!(matches(CNPJ, "12ABC34501DE35"));
+        assert!(!matches(CNPJ, "12ABC34501DE3A"));
+        assert!(matches(CEP, "01310-100"));
+        assert!(matches(PHONE, "+55 (11) 98765-4321"));
+        assert!(matches(PIX_PHONE, "+5511987654321"));
+        assert!(matches(PIX_EMAIL, "user@example.com"));
+        assert!(matches(PIX_EVP, "123e4567-e89b-12d3-a456-426614174000"));
+        assert!(!matches(PIX_EVP, "123E4567-E89B-12D3-A456-426614174000"));
+    }
+
+    #[test]
+    fn test_boleto_patterns() {
+        assert!(matches(
+            BOLETO_LINE,
+            "00190.00009 01234.567891 23456.789012 3 45670000012345"
+        ));
+        assert!(matches(BOLETO_LINE, &"1".repeat(47)));
+        assert!(!matches(BOLETO_LINE, &"1".repeat(48)));
+
+        assert!(matches(
+            BOLETO_COLLECTION_LINE,
+            "83640000001-1 33120138000-2 81288462711-6 08013618155-1"
+        ));
+        assert!(matches(BOLETO_COLLECTION_LINE, &"8".repeat(48)));
+    }
+
+    #[test]
+    fn test_patterns_reject_non_ascii_digits() {
+        assert!(!matches(CPF, "١٢٣.٤٥٦.٧٨٩-٠٩"));
+        assert!(!matches(CEP, "０１３１０-１００"));
+    }
+}
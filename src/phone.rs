@@ -1,19 +1,22 @@
 //! Brazilian phone number validation and formatting
 //!
-//! Supports landline and mobile numbers with area codes (DDD).
+//! Supports landline and mobile numbers with area codes (DDD). Toll-free,
+//! shared-cost and short corporate numbers (`0800`, `0300`, `4004`, ...)
+//! are accepted when
+//! [`non_geographic_phones`](crate::config::ValidationOptions::non_geographic_phones)
+//! is enabled.
 
+use crate::document::DocumentKind;
 use crate::error::{BrazilianValidationError, ValidationResult};
-use regex::Regex;
-use std::sync::LazyLock;
-
-/// Regex for Brazilian phone format (various formats accepted)
-static PHONE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"^(\+55\s?)?(\(?\d{2}\)?\s?)?(\d{4,5}[-\s]?\d{4})$").unwrap()
-});
-
-/// Strict regex for normalized phone (digits only with optional +)
-static NORMALIZED_PHONE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\+?55?\d{10,11}$").unwrap());
+use crate::fixed::{self, Cleaned};
+use crate::masking::MaskOptions;
+use crate::uf::{Region, Uf};
+use crate::{ascii, config, denylist, hooks, shape, unicode};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use subtle::ConstantTimeEq;
 
 /// Valid Brazilian area codes (DDD)
 const VALID_DDDS: [&str; 67] = [
@@ -39,10 +42,20 @@ const VALID_DDDS: [&str; 67] = [
     "91", "92", "93", "94", "95", "96", "97", "98", "99",
 ];
 
+/// Prefixes of non-geographic numbers dialed with a leading zero (0XXX XXX XXXX)
+const NON_GEOGRAPHIC_PREFIXES: [(&str, PhoneKind); 3] = [
+    ("0800", PhoneKind::TollFree),
+    ("0300", PhoneKind::PremiumRate),
+    ("0900", PhoneKind::PremiumRate),
+];
+
+/// Prefixes of 8-digit short corporate numbers (XXXX-XXXX)
+const SHORT_SERVICE_PREFIXES: [&str; 3] = ["3003", "3004", "400"];
+
 /// Strip country code prefix and return the national number part
-fn strip_country_code(phone: &str) -> &str {
-    if phone.starts_with("+55") {
-        &phone[3..]
+pub(crate) fn strip_country_code(phone: &str) -> &str {
+    if let Some(national) = phone.strip_prefix("+55") {
+        national
     } else if phone.starts_with("55") && phone.len() > 11 {
         &phone[2..]
     } else {
@@ -56,19 +69,61 @@ fn strip_country_code(phone: &str) -> &str {
 /// * `phone` - Phone string in various formats
 ///
 /// # Returns
-/// * `Ok(String)` - Normalized phone (digits only with country code)
+/// * `Ok(String)` - Normalized phone (digits only with country code).
+///   Non-geographic numbers, when enabled, are returned in national form
+///   (`08001234567`, `40041234`): they cannot be dialed from abroad.
 /// * `Err(BrazilianValidationError)` - Validation error
 ///
 /// # Examples
 /// ```
+/// use pleme_brazilian_validators::config::Config;
 /// use pleme_brazilian_validators::phone::validate;
 ///
 /// assert!(validate("+55 11 98765-4321").is_ok());
 /// assert!(validate("(11) 98765-4321").is_ok());
 /// assert!(validate("11987654321").is_ok());
 /// assert!(validate("12345").is_err()); // Too short
+///
+/// assert!(validate("0800 123 4567").is_err());
+/// let lenient = Config::builder().non_geographic_phones(true).build();
+/// Config::with_options(lenient, || {
+///     assert_eq!(validate("0800 123 4567").as_deref(), Ok("08001234567"));
+///     assert_eq!(validate("4004-1234").as_deref(), Ok("40041234"));
+/// });
 /// ```
 pub fn validate(phone: &str) -> ValidationResult<String> {
+    let result = validate_unobserved(phone)
+        .and_then(|phone| denylist::check(DocumentKind::Phone, &phone).map(|()| phone));
+    hooks::notify(DocumentKind::Phone, &result);
+    result
+}
+
+/// Validate without reporting to the validation hook (for internal lookups)
+pub(crate) fn validate_unobserved(phone: &str) -> ValidationResult<String> {
+    let phone = &*unicode::normalize_if_enabled(phone);
+    let options = config::Config::current();
+
+    if options.non_geographic_phones {
+        let cleaned = normalize(phone);
+        if let Some(kind) = non_geographic_kind(&cleaned) {
+            // Strict mode only accepts the digits or the canonical format
+            if options.strict && phone != cleaned && phone != format_non_geographic(&cleaned, kind)
+            {
+                return Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Phone,
+                });
+            }
+            return Ok(cleaned);
+        }
+    }
+
+    // Strict mode only accepts the canonical formats
+    if options.strict && !is_phone_format(phone) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Phone,
+        });
+    }
+
     let cleaned = normalize(phone);
     let without_country = strip_country_code(&cleaned);
 
@@ -100,6 +155,71 @@ pub fn validate(phone: &str) -> ValidationResult<String> {
     Ok(format!("+55{}", without_country))
 }
 
+/// Parse a full dial string into the canonical `+55` number
+///
+/// Understands the prefixes found in call detail records on top of the
+/// formats accepted by [`validate`]:
+/// * trunk zero before the DDD (`0 11 98765-4321`)
+/// * trunk zero and carrier selection code, CSP (`0 21 11 98765-4321`)
+/// * international prefix, optionally with a CSP (`0055 11 ...`, `00 21 55 11 ...`)
+///
+/// # Arguments
+/// * `dial` - Dial string, with or without punctuation
+///
+/// # Returns
+/// * `Ok(String)` - Normalized phone, as returned by [`validate`]
+/// * `Err(BrazilianValidationError)` - Unrecognized prefix or invalid number
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::parse_dial_string;
+///
+/// assert_eq!(parse_dial_string("0 21 11 98765-4321").unwrap(), "+5511987654321");
+/// assert_eq!(parse_dial_string("0055 11 98765 4321").unwrap(), "+5511987654321");
+/// assert_eq!(parse_dial_string("011 3456-7890").unwrap(), "+551134567890");
+/// assert!(parse_dial_string("001 212 555 0100").is_err());
+/// ```
+pub fn parse_dial_string(dial: &str) -> ValidationResult<String> {
+    let dial = &*unicode::normalize_if_enabled(dial);
+    if dial.trim_start().starts_with('+') {
+        return validate(dial);
+    }
+
+    let digits: String = dial.chars().filter(char::is_ascii_digit).collect();
+    let national = national_from_dial(&digits).ok_or(
+        BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Phone,
+        },
+    )?;
+    validate(&format!("+55{}", national))
+}
+
+/// Strip the dialing prefixes from a digit string, leaving DDD and number
+///
+/// National numbers have 10 or 11 digits, which tells the prefixes apart:
+/// after a trunk zero, 12 or 13 more digits mean a CSP precedes the DDD.
+fn national_from_dial(digits: &str) -> Option<&str> {
+    let is_national = |rest: &str| matches!(rest.len(), 10 | 11);
+
+    if let Some(international) = digits.strip_prefix("00") {
+        // 00 55 or 00 CSP 55
+        return [international, international.get(2..)?]
+            .into_iter()
+            .filter_map(|rest| rest.strip_prefix("55"))
+            .find(|rest| is_national(rest));
+    }
+
+    let rest = match digits.strip_prefix('0') {
+        Some(trunk) if trunk.len() >= 12 => trunk.get(2..)?,
+        Some(trunk) => trunk,
+        None => match digits.strip_prefix("55") {
+            Some(national) if digits.len() > 11 => national,
+            _ => digits,
+        },
+    };
+    is_national(rest).then_some(rest)
+}
+
 /// Normalize a phone string by removing all non-digit characters (keeps +)
 ///
 /// # Examples
@@ -123,17 +243,32 @@ pub fn normalize(phone: &str) -> String {
 ///
 /// # Returns
 /// Formatted phone string. Returns input unchanged if invalid length.
+/// Non-geographic numbers are formatted as `0800-123-4567` and `4004-1234`
+/// only when
+/// [`non_geographic_phones`](crate::config::ValidationOptions::non_geographic_phones)
+/// is enabled.
 ///
 /// # Examples
 /// ```
+/// use pleme_brazilian_validators::config::Config;
 /// use pleme_brazilian_validators::phone::format;
 ///
 /// assert_eq!(format("11987654321"), "(11) 98765-4321");
 /// assert_eq!(format("+5511987654321"), "+55 (11) 98765-4321");
 /// assert_eq!(format("1134567890"), "(11) 3456-7890");
+///
+/// let lenient = Config::builder().non_geographic_phones(true).build();
+/// Config::with_options(lenient, || {
+///     assert_eq!(format("08001234567"), "0800-123-4567");
+///     assert_eq!(format("40041234"), "4004-1234");
+/// });
 /// ```
 pub fn format(phone: &str) -> String {
     let cleaned = normalize(phone);
+    if let Some(kind) = enabled_non_geographic_kind(&cleaned) {
+        return format_non_geographic(&cleaned, kind);
+    }
+
     let without_country = strip_country_code(&cleaned);
     let prefix = if cleaned != without_country { "+55 " } else { "" };
 
@@ -156,6 +291,214 @@ pub fn format(phone: &str) -> String {
     }
 }
 
+/// Maximum length of a formatted phone, `+55 (XX) XXXXX-XXXX`
+pub const FORMATTED_MAX_LEN: usize = 19;
+
+/// Format a phone into a caller-provided buffer, without allocating
+///
+/// Writes the same output as [`format`](format()) at the start of `buf` and returns
+/// it as a `&str`. A buffer of [`FORMATTED_MAX_LEN`] bytes always
+/// suffices.
+///
+/// # Returns
+/// `None` if the input is neither a 10 or 11-digit number (with or
+/// without country code) nor an enabled non-geographic number, or `buf` is
+/// too small.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone;
+///
+/// let mut buf = [0u8; phone::FORMATTED_MAX_LEN];
+/// assert_eq!(phone::format_into("+5511987654321", &mut buf), Some("+55 (11) 98765-4321"));
+/// assert_eq!(phone::format_into("1134567890", &mut buf), Some("(11) 3456-7890"));
+/// ```
+pub fn format_into<'a>(phone: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let cleaned = Cleaned::<FORMATTED_MAX_LEN>::new(phone, true)?;
+    let cleaned = cleaned.as_str();
+    let without_country = strip_country_code(cleaned);
+
+    let pattern = match enabled_non_geographic_kind(cleaned) {
+        Some(PhoneKind::ShortService) => "####-####",
+        Some(_) => "####-###-####",
+        None => match (cleaned != without_country, without_country.len()) {
+            (true, 11) => "+55 (##) #####-####",
+            (true, 10) => "+55 (##) ####-####",
+            (false, 11) => "(##) #####-####",
+            (false, 10) => "(##) ####-####",
+            _ => return None,
+        },
+    };
+    fixed::write_pattern(without_country, pattern, buf)
+}
+
+/// Check if a string matches a Brazilian phone format (does not validate DDD)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::is_phone_format;
+///
+/// assert!(is_phone_format("+55 11 98765-4321"));
+/// assert!(is_phone_format("(11) 3456-7890"));
+/// assert!(!is_phone_format("98-76"));
+/// ```
+pub fn is_phone_format(phone: &str) -> bool {
+    shape::is_phone(phone)
+}
+
+/// Kind of phone line
+///
+/// Serialized in snake case (`"mobile"`, `"toll_free"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PhoneKind {
+    /// Mobile number (9 digits after the DDD)
+    Mobile,
+    /// Landline (8 digits after the DDD)
+    Landline,
+    /// Toll-free number (`0800 XXX XXXX`)
+    TollFree,
+    /// Non-geographic number charged to the caller: shared cost
+    /// (`0300 XXX XXXX`) or premium rate (`0900 XXX XXXX`)
+    PremiumRate,
+    /// Short corporate number, charged as a local call (`4004-XXXX`,
+    /// `3003-XXXX`)
+    ShortService,
+}
+
+impl PhoneKind {
+    /// Every kind of phone line
+    pub const ALL: [PhoneKind; 5] = [
+        PhoneKind::Mobile,
+        PhoneKind::Landline,
+        PhoneKind::TollFree,
+        PhoneKind::PremiumRate,
+        PhoneKind::ShortService,
+    ];
+
+    /// Iterate over every kind of phone line
+    pub fn iter() -> impl Iterator<Item = PhoneKind> {
+        PhoneKind::ALL.into_iter()
+    }
+}
+
+/// Everything known about a valid phone number, see [`inspect`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct PhoneInfo {
+    /// E.164 form (`+5511987654321`)
+    pub e164: String,
+    /// Display format (`+55 (11) 98765-4321`)
+    pub formatted: String,
+    /// `(11) *****-4321`
+    pub masked: String,
+    /// Area code
+    pub ddd: String,
+    /// Federative unit of the DDD
+    pub uf: Uf,
+    /// Mobile or landline
+    pub kind: PhoneKind,
+    /// IANA time zone of the DDD, see [`ddd_info`]
+    pub timezone: &'static str,
+}
+
+/// Validate a phone number and derive its DDD, state, kind and time zone
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::{self, PhoneKind};
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// let info = phone::inspect("(92) 3234-5678").unwrap();
+/// assert_eq!(info.e164, "+559232345678");
+/// assert_eq!((info.uf, info.kind), (Uf::AM, PhoneKind::Landline));
+/// assert_eq!(info.timezone, "America/Manaus");
+/// ```
+pub fn inspect(phone: &str) -> ValidationResult<PhoneInfo> {
+    let e164 = validate(phone)?;
+    let ddd = extract_ddd(&e164).unwrap_or_default();
+    let uf = uf_for_ddd(&ddd).ok_or_else(|| BrazilianValidationError::invalid_phone("DDD inválido"))?;
+    let kind = if is_mobile(&e164) {
+        PhoneKind::Mobile
+    } else {
+        PhoneKind::Landline
+    };
+
+    Ok(PhoneInfo {
+        formatted: format(&e164),
+        masked: mask(&e164),
+        uf,
+        kind,
+        timezone: ddd_timezone(&ddd, uf),
+        ddd,
+        e164,
+    })
+}
+
+/// Classify a phone number by its shape
+///
+/// Does not validate the DDD nor check
+/// [`non_geographic_phones`](crate::config::ValidationOptions::non_geographic_phones);
+/// returns `None` for numbers of no known shape.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::{kind, PhoneKind};
+///
+/// assert_eq!(kind("(11) 98765-4321"), Some(PhoneKind::Mobile));
+/// assert_eq!(kind("(11) 3456-7890"), Some(PhoneKind::Landline));
+/// assert_eq!(kind("0800 123 4567"), Some(PhoneKind::TollFree));
+/// assert_eq!(kind("4004-1234"), Some(PhoneKind::ShortService));
+/// assert_eq!(kind("12345"), None);
+/// ```
+pub fn kind(phone: &str) -> Option<PhoneKind> {
+    let cleaned = normalize(phone);
+    if let Some(kind) = non_geographic_kind(&cleaned) {
+        Some(kind)
+    } else if is_mobile(&cleaned) {
+        Some(PhoneKind::Mobile)
+    } else if is_landline(&cleaned) {
+        Some(PhoneKind::Landline)
+    } else {
+        None
+    }
+}
+
+/// Kind of a normalized non-geographic number, if they are enabled
+fn enabled_non_geographic_kind(cleaned: &str) -> Option<PhoneKind> {
+    if config::Config::current().non_geographic_phones {
+        non_geographic_kind(cleaned)
+    } else {
+        None
+    }
+}
+
+/// Canonical layout of a normalized non-geographic number
+fn format_non_geographic(cleaned: &str, kind: PhoneKind) -> String {
+    match kind {
+        PhoneKind::ShortService => format!("{}-{}", &cleaned[..4], &cleaned[4..]),
+        _ => format!("{}-{}-{}", &cleaned[..4], &cleaned[4..7], &cleaned[7..]),
+    }
+}
+
+/// Kind of a normalized non-geographic number, `None` for other numbers
+fn non_geographic_kind(cleaned: &str) -> Option<PhoneKind> {
+    match cleaned.len() {
+        11 => NON_GEOGRAPHIC_PREFIXES
+            .into_iter()
+            .find(|(prefix, _)| cleaned.starts_with(prefix))
+            .map(|(_, kind)| kind),
+        8 if SHORT_SERVICE_PREFIXES.iter().any(|prefix| cleaned.starts_with(prefix)) => {
+            Some(PhoneKind::ShortService)
+        }
+        _ => None,
+    }
+}
+
 /// Check if a phone number is a mobile number
 ///
 /// # Examples
@@ -186,6 +529,64 @@ pub fn is_landline(phone: &str) -> bool {
     without_country.len() == 10
 }
 
+/// Add the ninth digit to a legacy 8-digit mobile number
+///
+/// Mobile numbers gained a leading 9 between 2012 (DDD 11) and 2016 (the
+/// last DDDs), so every DDD is migrated. Before that, mobile numbers
+/// started with 6 to 9 and landlines with 2 to 5; only the former get the
+/// extra digit.
+///
+/// # Returns
+/// The number in the form returned by [`validate`], or `None` if it is not
+/// a valid mobile number. Numbers that already have 9 digits are returned
+/// unchanged, so the function can be applied to mixed data.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::add_ninth_digit;
+///
+/// assert_eq!(add_ninth_digit("(11) 8765-4321").as_deref(), Some("+5511987654321"));
+/// assert_eq!(add_ninth_digit("(11) 98765-4321").as_deref(), Some("+5511987654321"));
+/// assert_eq!(add_ninth_digit("(11) 3456-7890"), None); // Landline
+/// ```
+pub fn add_ninth_digit(phone: &str) -> Option<String> {
+    let normalized = validate(phone).ok()?;
+    // Non-geographic numbers are returned without the country code
+    let national = normalized.strip_prefix("+55")?;
+    match national.len() {
+        11 => Some(normalized),
+        _ if is_legacy_mobile_subscriber(&national[2..]) => {
+            Some(format!("+55{}9{}", &national[..2], &national[2..]))
+        }
+        _ => None,
+    }
+}
+
+/// Remove the ninth digit from a mobile number, for legacy systems
+///
+/// The inverse of [`add_ninth_digit`]: only numbers that had an 8-digit
+/// form (a 6 to 9 after the leading 9) are converted.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::strip_ninth_digit;
+///
+/// assert_eq!(strip_ninth_digit("+55 11 98765-4321").as_deref(), Some("+551187654321"));
+/// assert_eq!(strip_ninth_digit("(11) 95123-4567"), None); // No legacy form
+/// assert_eq!(strip_ninth_digit("(11) 3456-7890"), None);
+/// ```
+pub fn strip_ninth_digit(phone: &str) -> Option<String> {
+    let normalized = validate(phone).ok()?;
+    let national = normalized.strip_prefix("+55")?;
+    let subscriber = national.get(2..)?.strip_prefix('9')?;
+    is_legacy_mobile_subscriber(subscriber).then(|| format!("+55{}{}", &national[..2], subscriber))
+}
+
+/// Whether an 8-digit subscriber number was in the pre-2012 mobile range
+fn is_legacy_mobile_subscriber(subscriber: &str) -> bool {
+    subscriber.len() == 8 && matches!(subscriber.as_bytes()[0], b'6'..=b'9')
+}
+
 /// Extract the DDD (area code) from a phone number
 ///
 /// # Examples
@@ -207,6 +608,9 @@ pub fn extract_ddd(phone: &str) -> Option<String> {
 
 /// Get the state(s) for a given DDD
 ///
+/// Accent-free when
+/// [`ascii_names`](crate::config::ValidationOptions::ascii_names) is enabled.
+///
 /// # Examples
 /// ```
 /// use pleme_brazilian_validators::phone::get_state_for_ddd;
@@ -215,7 +619,7 @@ pub fn extract_ddd(phone: &str) -> Option<String> {
 /// assert_eq!(get_state_for_ddd("21"), Some("Rio de Janeiro (Capital e Região)"));
 /// ```
 pub fn get_state_for_ddd(ddd: &str) -> Option<&'static str> {
-    match ddd {
+    let description = match ddd {
         // São Paulo
         "11" => Some("São Paulo (Capital e Grande SP)"),
         "12" => Some("São Paulo (Vale do Paraíba)"),
@@ -270,9 +674,170 @@ pub fn get_state_for_ddd(ddd: &str) -> Option<&'static str> {
         "87" => Some("Pernambuco"),
         "98" | "99" => Some("Maranhão"),
         _ => None,
+    };
+
+    description.map(ascii::name)
+}
+
+/// Get the federative unit served by a DDD
+///
+/// DDD 61 also covers a few municipalities of Goiás around Brasília; it is
+/// reported as [`Uf::DF`].
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::uf_for_ddd;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(uf_for_ddd("11"), Some(Uf::SP));
+/// assert_eq!(uf_for_ddd("98"), Some(Uf::MA));
+/// assert_eq!(uf_for_ddd("20"), None);
+/// ```
+pub fn uf_for_ddd(ddd: &str) -> Option<Uf> {
+    let uf = match ddd {
+        "11" | "12" | "13" | "14" | "15" | "16" | "17" | "18" | "19" => Uf::SP,
+        "21" | "22" | "24" => Uf::RJ,
+        "27" | "28" => Uf::ES,
+        "31" | "32" | "33" | "34" | "35" | "37" | "38" => Uf::MG,
+        "41" | "42" | "43" | "44" | "45" | "46" => Uf::PR,
+        "47" | "48" | "49" => Uf::SC,
+        "51" | "53" | "54" | "55" => Uf::RS,
+        "61" => Uf::DF,
+        "62" | "64" => Uf::GO,
+        "63" => Uf::TO,
+        "65" | "66" => Uf::MT,
+        "67" => Uf::MS,
+        "68" => Uf::AC,
+        "69" => Uf::RO,
+        "71" | "73" | "74" | "75" | "77" => Uf::BA,
+        "79" => Uf::SE,
+        "81" | "87" => Uf::PE,
+        "82" => Uf::AL,
+        "83" => Uf::PB,
+        "84" => Uf::RN,
+        "85" | "88" => Uf::CE,
+        "86" | "89" => Uf::PI,
+        "91" | "93" | "94" => Uf::PA,
+        "92" | "97" => Uf::AM,
+        "95" => Uf::RR,
+        "96" => Uf::AP,
+        "98" | "99" => Uf::MA,
+        _ => return None,
+    };
+    Some(uf)
+}
+
+/// Area code details, see [`ddd_info`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct DddInfo {
+    /// Area code (`"11"`)
+    pub ddd: &'static str,
+    /// Federative unit served, see [`uf_for_ddd`]
+    pub uf: Uf,
+    /// Geographic region of the federative unit
+    pub region: Region,
+    /// Area served, see [`get_state_for_ddd`]
+    pub area: &'static str,
+    /// IANA time zone of the area
+    pub timezone: &'static str,
+}
+
+/// Get the federative unit, region, area and time zone of a DDD
+///
+/// The time zone is the state's ([`Uf::timezone`]) except for DDD 93,
+/// western Pará, which IANA tracks as `America/Santarem`. DDD 97 also
+/// covers a few municipalities one hour behind Manaus, and DDD 81 the
+/// Fernando de Noronha archipelago; those are reported with the state's
+/// zone.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::ddd_info;
+/// use pleme_brazilian_validators::uf::{Region, Uf};
+///
+/// let info = ddd_info("92").unwrap();
+/// assert_eq!((info.uf, info.region), (Uf::AM, Region::Norte));
+/// assert_eq!(info.timezone, "America/Manaus");
+/// assert_eq!(ddd_info("93").unwrap().timezone, "America/Santarem");
+/// assert_eq!(ddd_info("20"), None);
+/// ```
+pub fn ddd_info(ddd: &str) -> Option<DddInfo> {
+    let ddd = VALID_DDDS.into_iter().find(|valid| *valid == ddd)?;
+    let uf = uf_for_ddd(ddd)?;
+    Some(DddInfo {
+        ddd,
+        uf,
+        region: uf.region(),
+        area: get_state_for_ddd(ddd)?,
+        timezone: ddd_timezone(ddd, uf),
+    })
+}
+
+/// IANA time zone of a DDD served by `uf`
+fn ddd_timezone(ddd: &str, uf: Uf) -> &'static str {
+    match ddd {
+        "93" => "America/Santarem",
+        _ => uf.timezone(),
     }
 }
 
+/// Keep only valid mobile numbers, normalized to E.164 and deduplicated
+///
+/// Numbers written differently but denoting the same line
+/// (`(11) 98765-4321`, `+55 11 987654321`) are kept once, at the position
+/// of their first occurrence. Landlines and invalid numbers are dropped.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::filter_mobiles;
+///
+/// let contacts = ["(11) 98765-4321", "+55 11 987654321", "(11) 3456-7890", "123"];
+/// assert_eq!(filter_mobiles(contacts), vec!["+5511987654321"]);
+/// ```
+pub fn filter_mobiles<I>(phones: I) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    dedup_mobiles(phones.into_iter().map(|phone| mobile_e164(phone.as_ref())))
+}
+
+/// Parallel version of [`filter_mobiles`] for large contact lists
+///
+/// Validation runs on the rayon thread pool; the output is identical to
+/// [`filter_mobiles`], including order. Enabled by the `parallel` feature.
+#[cfg(feature = "parallel")]
+pub fn par_filter_mobiles<I>(phones: I) -> Vec<String>
+where
+    I: rayon::iter::IntoParallelIterator,
+    I::Item: AsRef<str>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+{
+    use rayon::iter::ParallelIterator;
+
+    let normalized: Vec<Option<String>> = phones
+        .into_par_iter()
+        .map(|phone| mobile_e164(phone.as_ref()))
+        .collect();
+    dedup_mobiles(normalized)
+}
+
+/// Validate a number, returning its E.164 form only if it is a mobile
+fn mobile_e164(phone: &str) -> Option<String> {
+    validate(phone).ok().filter(|normalized| is_mobile(normalized))
+}
+
+/// Drop missing and repeated numbers, keeping first occurrences in order
+fn dedup_mobiles(numbers: impl IntoIterator<Item = Option<String>>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    numbers
+        .into_iter()
+        .flatten()
+        .filter(|number| seen.insert(number.clone()))
+        .collect()
+}
+
 /// Mask a phone number for display
 ///
 /// # Examples
@@ -292,9 +857,313 @@ pub fn mask(phone: &str) -> String {
     }
 }
 
+/// Mask a phone number according to a masking policy
+///
+/// The national format (`(XX) XXXXX-XXXX`, without the country code) is
+/// masked; other input is returned unchanged, as by [`mask`].
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::masking::MaskOptions;
+/// use pleme_brazilian_validators::phone::mask_with;
+///
+/// let options = MaskOptions { visible_leading: 2, visible_trailing: 4, ..MaskOptions::default() };
+/// assert_eq!(mask_with("+5511987654321", &options), "(11) *****-4321");
+/// ```
+pub fn mask_with(phone: &str, options: &MaskOptions) -> String {
+    let cleaned = normalize(phone);
+    let without_country = strip_country_code(&cleaned);
+
+    match without_country.len() {
+        10 | 11 => options.apply(&format(without_country)),
+        _ => phone.to_string(),
+    }
+}
+
+/// Brazilian country calling code
+pub const COUNTRY_CODE: &str = "55";
+
+/// Convert a phone number to E.164 (`+55DDXXXXXXXXX`)
+///
+/// Accepts every format [`validate`] does. Non-geographic numbers have no
+/// E.164 form, as they cannot be dialed from abroad, and are rejected.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::to_e164;
+///
+/// assert_eq!(to_e164("(11) 98765-4321").as_deref(), Ok("+5511987654321"));
+/// assert_eq!(to_e164("21 3456-7890").as_deref(), Ok("+552134567890"));
+/// ```
+pub fn to_e164(phone: &str) -> ValidationResult<String> {
+    let normalized = validate(phone)?;
+    if normalized.starts_with("+55") {
+        Ok(normalized)
+    } else {
+        Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Phone,
+        })
+    }
+}
+
+/// Parse a number in strict E.164 form, as sent by telephony systems
+///
+/// Unlike [`Phone::parse`], only `+55` followed by the national digits is
+/// accepted: no punctuation, spaces or trunk prefixes.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::{from_e164, PhoneKind};
+///
+/// let phone = from_e164("+5511987654321").unwrap();
+/// assert_eq!(phone.ddd(), Some("11"));
+/// assert_eq!(phone.kind(), PhoneKind::Mobile);
+///
+/// assert!(from_e164("+55 11 98765-4321").is_err());
+/// assert!(from_e164("+12125550100").is_err()); // Not Brazilian
+/// ```
+pub fn from_e164(e164: &str) -> ValidationResult<Phone> {
+    let is_e164 = e164
+        .strip_prefix('+')
+        .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()));
+    if !is_e164 || !e164[1..].starts_with(COUNTRY_CODE) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Phone,
+        });
+    }
+    Phone::parse(e164)
+}
+
+/// Build a WhatsApp click-to-chat link (`https://wa.me/55...`)
+///
+/// # Arguments
+/// * `phone` - Mobile number in any format accepted by [`validate`]
+/// * `message` - Optional text pre-filled in the chat, percent-encoded
+///
+/// # Returns
+/// * `Ok(String)` - The link
+/// * `Err(BrazilianValidationError)` - Invalid number or not a mobile
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::whatsapp_link;
+///
+/// assert_eq!(
+///     whatsapp_link("(11) 98765-4321", None).unwrap(),
+///     "https://wa.me/5511987654321"
+/// );
+/// assert_eq!(
+///     whatsapp_link("11987654321", Some("Olá, pedido #42")).unwrap(),
+///     "https://wa.me/5511987654321?text=Ol%C3%A1%2C%20pedido%20%2342"
+/// );
+/// assert!(whatsapp_link("(11) 3456-7890", None).is_err()); // Landline
+/// ```
+pub fn whatsapp_link(phone: &str, message: Option<&str>) -> ValidationResult<String> {
+    let e164 = to_e164(phone)?;
+    if !is_mobile(&e164) {
+        return Err(BrazilianValidationError::invalid_phone("não é celular"));
+    }
+
+    let mut link = format!("https://wa.me/{}", &e164[1..]);
+    if let Some(message) = message {
+        link.push_str("?text=");
+        link.push_str(&percent_encode(message));
+    }
+    Ok(link)
+}
+
+/// Percent-encode every byte outside the URL unreserved set
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Validated phone number
+///
+/// Can only be built through [`validate`], so a `Phone` carried through a
+/// domain model never needs to be checked again. `Display` prints the
+/// formatted form; `AsRef<str>` and serde use the `+55` E.164 form.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::phone::Phone;
+///
+/// let phone: Phone = "(11) 98765-4321".parse().unwrap();
+/// assert_eq!(phone.as_str(), "+5511987654321");
+/// assert_eq!(phone.digits(), "11987654321");
+/// assert_eq!(phone.to_string(), "+55 (11) 98765-4321");
+/// assert_eq!(phone.masked(), "(11) *****-4321");
+/// assert_eq!(phone.country_code(), "55");
+/// assert_eq!((phone.ddd(), phone.subscriber_number()), (Some("11"), "987654321"));
+///
+/// assert!("(20) 98765-4321".parse::<Phone>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Phone(String);
+
+impl Phone {
+    /// Validate a phone number in any accepted format
+    pub fn parse(phone: &str) -> ValidationResult<Self> {
+        validate(phone).map(Phone)
+    }
+
+    /// E.164 form (`+5511987654321`)
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// National number digits: DDD and subscriber number, no country code
+    pub fn digits(&self) -> &str {
+        strip_country_code(&self.0)
+    }
+
+    /// `+55 (XX) XXXXX-XXXX`
+    pub fn formatted(&self) -> String {
+        format(&self.0)
+    }
+
+    /// `(XX) *****-XXXX`
+    pub fn masked(&self) -> String {
+        mask(&self.0)
+    }
+
+    /// Country calling code, always `"55"`
+    pub fn country_code(&self) -> &'static str {
+        COUNTRY_CODE
+    }
+
+    /// Area code, `None` for non-geographic numbers
+    pub fn ddd(&self) -> Option<&str> {
+        self.0.strip_prefix("+55").map(|national| &national[..2])
+    }
+
+    /// Number after the DDD; the whole number for non-geographic numbers
+    pub fn subscriber_number(&self) -> &str {
+        self.0.strip_prefix("+55").map_or(&self.0, |national| &national[2..])
+    }
+
+    /// Kind of line
+    pub fn kind(&self) -> PhoneKind {
+        // Every validated number has a kind
+        kind(&self.0).unwrap_or(PhoneKind::Landline)
+    }
+
+    /// Compare with another phone number in constant time
+    ///
+    /// Both numbers are in E.164 form, so formatting differences do not
+    /// matter; the comparison of their bytes does not short-circuit.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.0.as_bytes().ct_eq(other.0.as_bytes()).into()
+    }
+}
+
+impl core::fmt::Debug for Phone {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Phone").field(&self.0).finish()
+    }
+}
+
+impl core::fmt::Display for Phone {
+    /// Formats as `+55 (XX) XXXXX-XXXX`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.formatted())
+    }
+}
+
+impl core::str::FromStr for Phone {
+    type Err = BrazilianValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Phone {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl AsRef<str> for Phone {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Wipes the stored number, leaving a value that no longer holds a phone
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Phone {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// With the `zeroize` feature the number is wiped from memory on drop
+#[cfg(feature = "zeroize")]
+impl Drop for Phone {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Phone {}
+
+impl crate::document::BrazilianDocument for Phone {
+    const KIND: DocumentKind = DocumentKind::Phone;
+
+    fn validate(input: &str) -> ValidationResult<Self> {
+        Phone::parse(input)
+    }
+
+    fn formatted(&self) -> String {
+        Phone::formatted(self)
+    }
+
+    fn normalized(&self) -> &str {
+        self.as_str()
+    }
+
+    fn masked(&self) -> String {
+        Phone::masked(self)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Phone {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Deserializing validates, accepting any format `validate` does; errors
+/// carry the error code and the localized message
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for Phone {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).map_err(BrazilianValidationError::into_de_error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_validate_valid_phone() {
@@ -320,6 +1189,37 @@ mod tests {
         assert!(validate("11887654321").is_err());
     }
 
+    #[test]
+    fn test_parse_dial_string() {
+        let mobile = Ok("+5511987654321".to_string());
+        assert_eq!(parse_dial_string("(11) 98765-4321"), mobile);
+        assert_eq!(parse_dial_string("+55 11 98765-4321"), mobile);
+        assert_eq!(parse_dial_string("5511987654321"), mobile);
+        assert_eq!(parse_dial_string("011 98765-4321"), mobile);
+        assert_eq!(parse_dial_string("0 15 11 98765-4321"), mobile);
+        assert_eq!(parse_dial_string("00 55 11 98765-4321"), mobile);
+        assert_eq!(parse_dial_string("00 21 55 11 98765-4321"), mobile);
+
+        let landline = Ok("+551134567890".to_string());
+        assert_eq!(parse_dial_string("0 11 3456-7890"), landline);
+        assert_eq!(parse_dial_string("0 41 11 3456-7890"), landline);
+        assert_eq!(parse_dial_string("0055 11 3456-7890"), landline);
+    }
+
+    #[test]
+    fn test_parse_dial_string_rejects_unknown_prefixes() {
+        let unrecognized = Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Phone,
+        });
+        assert_eq!(parse_dial_string("001 212 555 0100"), unrecognized);
+        assert_eq!(parse_dial_string("0 21 11 98765-43210"), unrecognized);
+        assert_eq!(parse_dial_string(""), unrecognized);
+
+        // Recognized shape, invalid number
+        assert!(parse_dial_string("0 21 00 98765-4321").is_err());
+        assert!(parse_dial_string("0 21 11 88765-4321").is_err());
+    }
+
     #[test]
     fn test_normalize() {
         assert_eq!(normalize("+55 11 98765-4321"), "+5511987654321");
@@ -333,6 +1233,30 @@ mod tests {
         assert_eq!(format("1134567890"), "(11) 3456-7890");
     }
 
+    #[test]
+    fn test_format_into_matches_format() {
+        let mut buf = [0u8; FORMATTED_MAX_LEN];
+        for input in [
+            "11987654321",
+            "+5511987654321",
+            "5511987654321",
+            "1134567890",
+            "+55 11 3456-7890",
+        ] {
+            assert_eq!(format_into(input, &mut buf), Some(format(input).as_str()));
+        }
+        assert_eq!(format_into("12345", &mut buf), None);
+        assert_eq!(format_into("+55 11 98765-4321 ramal 1234", &mut buf), None);
+        assert_eq!(format_into("11987654321", &mut [0u8; 14]), None);
+    }
+
+    #[test]
+    fn test_is_phone_format() {
+        assert!(is_phone_format("+55 11 98765-4321"));
+        assert!(is_phone_format("11987654321"));
+        assert!(!is_phone_format("12345"));
+    }
+
     #[test]
     fn test_is_mobile() {
         assert!(is_mobile("11987654321"));
@@ -364,9 +1288,255 @@ mod tests {
         assert_eq!(get_state_for_ddd("00"), None);
     }
 
+    #[test]
+    fn test_every_valid_ddd_has_a_uf() {
+        for ddd in VALID_DDDS {
+            assert!(uf_for_ddd(ddd).is_some(), "DDD {}", ddd);
+        }
+        assert_eq!(uf_for_ddd("61"), Some(Uf::DF));
+    }
+
+    #[test]
+    fn test_ddd_info() {
+        for ddd in VALID_DDDS {
+            let info = ddd_info(ddd).unwrap();
+            assert_eq!(info.ddd, ddd);
+            assert_eq!(Some(info.uf), uf_for_ddd(ddd));
+            assert_eq!(info.region, info.uf.region());
+        }
+        let info = ddd_info("13").unwrap();
+        assert_eq!(info.area, "São Paulo (Baixada Santista)");
+        assert_eq!(info.timezone, "America/Sao_Paulo");
+        assert_eq!(ddd_info("68").unwrap().timezone, "America/Rio_Branco");
+        assert_eq!(ddd_info("1"), None);
+        assert_eq!(ddd_info("00"), None);
+    }
+
+    #[test]
+    fn test_non_geographic_numbers_need_the_option() {
+        for number in ["0800 123 4567", "0300 123 4567", "4004-1234", "3003 1234"] {
+            assert!(validate(number).is_err(), "{}", number);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let lenient = config::Config::builder().non_geographic_phones(true).build();
+            config::Config::with_options(lenient, || {
+                assert_eq!(validate("0800-123-4567").as_deref(), Ok("08001234567"));
+                assert_eq!(validate("0900 123 4567").as_deref(), Ok("09001234567"));
+                assert_eq!(validate("3003-1234").as_deref(), Ok("30031234"));
+                assert!(validate("0700 123 4567").is_err());
+                assert!(validate("5004-1234").is_err());
+                // Geographic numbers are unaffected
+                assert_eq!(validate("(11) 98765-4321").as_deref(), Ok("+5511987654321"));
+                assert!(inspect("0800 123 4567").is_err());
+            });
+        }
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(kind("+55 11 98765-4321"), Some(PhoneKind::Mobile));
+        assert_eq!(kind("0300-123-4567"), Some(PhoneKind::PremiumRate));
+        assert_eq!(kind("0900 123 4567"), Some(PhoneKind::PremiumRate));
+        assert_eq!(kind("40031234"), Some(PhoneKind::ShortService));
+        assert_eq!(kind("0800 123"), None);
+        assert_eq!(PhoneKind::iter().count(), 5);
+    }
+
+    #[test]
+    fn test_format_non_geographic_needs_the_option() {
+        // Formatted like any other number, as before the option existed
+        assert_eq!(format("08001234567"), "(08) 00123-4567");
+        assert_eq!(format("40041234"), "40041234");
+        assert_eq!(format("3004-1234"), "3004-1234");
+        let mut buf = [0u8; FORMATTED_MAX_LEN];
+        assert_eq!(format_into("08001234567", &mut buf), Some("(08) 00123-4567"));
+        assert_eq!(format_into("40041234", &mut buf), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_non_geographic() {
+        let lenient = config::Config::builder().non_geographic_phones(true).build();
+        config::Config::with_options(lenient, || {
+            assert_eq!(format("0300 123 4567"), "0300-123-4567");
+            assert_eq!(format("3004-1234"), "3004-1234");
+
+            let mut buf = [0u8; FORMATTED_MAX_LEN];
+            assert_eq!(format_into("40041234", &mut buf), Some("4004-1234"));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_strict_non_geographic_format() {
+        let options = config::Config::builder()
+            .non_geographic_phones(true)
+            .strict(true)
+            .build();
+        config::Config::with_options(options, || {
+            assert_eq!(validate("0800-123-4567").as_deref(), Ok("08001234567"));
+            assert_eq!(validate("08001234567").as_deref(), Ok("08001234567"));
+            assert_eq!(validate("4004-1234").as_deref(), Ok("40041234"));
+            assert!(validate("0800 123 4567").is_err());
+            assert!(validate("tel 4004-1234").is_err());
+        });
+    }
+
+    #[test]
+    fn test_ninth_digit_round_trip() {
+        for legacy in ["+556187654321", "+559196543210", "+554979876543"] {
+            let migrated = add_ninth_digit(legacy).unwrap();
+            assert_eq!(migrated.len(), 14);
+            assert!(is_mobile(&migrated));
+            assert_eq!(strip_ninth_digit(&migrated).as_deref(), Some(legacy));
+        }
+    }
+
+    #[test]
+    fn test_ninth_digit_rejects_non_mobiles() {
+        assert_eq!(add_ninth_digit("(21) 2345-6789"), None);
+        assert_eq!(add_ninth_digit("(20) 8765-4321"), None); // Invalid DDD
+        assert_eq!(add_ninth_digit("8765-4321"), None); // No DDD
+        assert_eq!(strip_ninth_digit("(21) 2345-6789"), None);
+        assert_eq!(strip_ninth_digit("(21) 90345-6789"), None);
+
+        #[cfg(feature = "std")]
+        {
+            let lenient = config::Config::builder().non_geographic_phones(true).build();
+            config::Config::with_options(lenient, || {
+                assert_eq!(add_ninth_digit("0800 987 6543"), None);
+                assert_eq!(strip_ninth_digit("0900 987 6543"), None);
+            });
+        }
+    }
+
     #[test]
     fn test_mask() {
         assert_eq!(mask("11987654321"), "(11) *****-4321");
         assert_eq!(mask("1134567890"), "(11) ****-7890");
     }
+
+    #[test]
+    fn test_filter_mobiles() {
+        let phones = vec![
+            "+55 21 99876-5432".to_string(),
+            "11987654321".to_string(),
+            "(21) 99876-5432".to_string(),
+            "(00) 98765-4321".to_string(),
+            "1134567890".to_string(),
+        ];
+        assert_eq!(
+            filter_mobiles(&phones),
+            vec!["+5521998765432", "+5511987654321"]
+        );
+        assert!(filter_mobiles(Vec::<String>::new()).is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_filter_mobiles_matches_sequential() {
+        let phones: Vec<String> = (0..2000)
+            .map(|i| format!("(11) 9{:04}-{:04}", i % 700, i))
+            .chain(["1134567890".to_string(), "abc".to_string()])
+            .collect();
+        assert_eq!(par_filter_mobiles(&phones), filter_mobiles(&phones));
+    }
+
+    #[test]
+    fn test_inspect_mobile() {
+        let info = inspect("+55 (11) 98765-4321").unwrap();
+        assert_eq!(info.e164, "+5511987654321");
+        assert_eq!(info.formatted, "+55 (11) 98765-4321");
+        assert_eq!(info.masked, "(11) *****-4321");
+        assert_eq!(info.ddd, "11");
+        assert_eq!((info.uf, info.kind), (Uf::SP, PhoneKind::Mobile));
+        assert_eq!(info.timezone, "America/Sao_Paulo");
+        assert!(inspect("12345").is_err());
+    }
+
+    #[test]
+    fn test_phone_type() {
+        let landline = Phone::parse("11 3456-7890").unwrap();
+        assert_eq!(landline.as_str(), "+551134567890");
+        assert_eq!(landline.digits(), "1134567890");
+        assert_eq!(landline.formatted(), "+55 (11) 3456-7890");
+        assert_eq!(landline, "+55 (11) 3456-7890".parse().unwrap());
+        assert!(Phone::try_from("12345").is_err());
+    }
+
+    #[test]
+    fn test_phone_type_ct_eq() {
+        let phone = Phone::parse("(11) 98765-4321").unwrap();
+        assert!(phone.ct_eq(&Phone::parse("+55 11 98765 4321").unwrap()));
+        assert!(!phone.ct_eq(&Phone::parse("(11) 98765-4320").unwrap()));
+    }
+
+    #[test]
+    fn test_e164_round_trip() {
+        for phone in ["(11) 98765-4321", "21 3456-7890", "+55 92 3234-5678"] {
+            let e164 = to_e164(phone).unwrap();
+            let parsed = from_e164(&e164).unwrap();
+            assert_eq!(parsed.as_str(), e164);
+            let ddd = parsed.ddd().unwrap();
+            let rebuilt = format!("+{}{}{}", parsed.country_code(), ddd, parsed.subscriber_number());
+            assert_eq!(rebuilt, e164);
+        }
+        assert!(from_e164("5511987654321").is_err());
+        assert!(from_e164("+").is_err());
+        assert!(from_e164("+5520987654321").is_err());
+    }
+
+    #[test]
+    fn test_whatsapp_link() {
+        assert_eq!(
+            whatsapp_link("+55 (21) 99876-5432", Some("")).unwrap(),
+            "https://wa.me/5521998765432?text="
+        );
+        assert_eq!(percent_encode("a b&c=d/ç~"), "a%20b%26c%3Dd%2F%C3%A7~");
+        assert!(whatsapp_link("12345", None).is_err());
+
+        #[cfg(feature = "std")]
+        {
+            let lenient = config::Config::builder().non_geographic_phones(true).build();
+            config::Config::with_options(lenient, || {
+                assert!(whatsapp_link("0800 123 4567", None).is_err());
+            });
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_non_geographic_phone_type() {
+        let lenient = config::Config::builder().non_geographic_phones(true).build();
+        config::Config::with_options(lenient, || {
+            let phone = Phone::parse("0800 123 4567").unwrap();
+            assert_eq!(phone.ddd(), None);
+            assert_eq!(phone.subscriber_number(), "08001234567");
+            assert_eq!(phone.kind(), PhoneKind::TollFree);
+            assert!(to_e164("0800 123 4567").is_err());
+        });
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_phone_type_serde() {
+        let phone: Phone = serde_json::from_str(r#""(11) 98765-4321""#).unwrap();
+        assert_eq!(serde_json::to_string(&phone).unwrap(), r#""+5511987654321""#);
+        assert!(serde_json::from_str::<Phone>(r#""12345""#).is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_wipes_number() {
+        use zeroize::Zeroize;
+
+        let mut phone = Phone::parse("(11) 98765-4321").unwrap();
+        phone.zeroize();
+        assert!(phone.as_str().is_empty());
+
+        fn wipes_on_drop<T: zeroize::ZeroizeOnDrop>(_: &T) {}
+        wipes_on_drop(&Phone::parse("(11) 98765-4321").unwrap());
+    }
 }
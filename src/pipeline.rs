@@ -0,0 +1,260 @@
+//! Composable validation pipelines
+//!
+//! Every validator in the crate has the shape
+//! `fn(&str) -> ValidationResult<String>`, returning the normalized
+//! document. The [`Validator`] trait captures that shape (closures included)
+//! and the combinators below build new validators from existing ones, so a
+//! rule like "CPF or CNPJ, then not in the denylist" is declared once and
+//! reused across services.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::pipeline::{and_then_normalize, any_of, Validator};
+//! use pleme_brazilian_validators::{cnpj, cpf, BrazilianValidationError};
+//!
+//! let denylist = ["12345678909"];
+//! let taxpayer = and_then_normalize(any_of(cpf::validate, cnpj::validate), move |doc: &str| {
+//!     if denylist.contains(&doc) {
+//!         Err(BrazilianValidationError::invalid_cpf("documento bloqueado"))
+//!     } else {
+//!         Ok(doc.to_string())
+//!     }
+//! });
+//!
+//! assert_eq!(taxpayer.validate("11.222.333/0001-81").unwrap(), "11222333000181");
+//! assert!(taxpayer.validate("123.456.789-09").is_err()); // denylisted
+//! assert!(taxpayer.validate("123").is_err()); // neither CPF nor CNPJ
+//! ```
+
+use crate::error::{BrazilianValidationError, ValidationResult};
+use alloc::string::String;
+
+/// A rule checking a document and returning its normalized form
+///
+/// Implemented for every `Fn(&str) -> ValidationResult<String>`, which
+/// covers the module validators (`cpf::validate`, `cep::validate`, ...).
+pub trait Validator {
+    /// Validate the input, returning the normalized document
+    fn validate(&self, input: &str) -> ValidationResult<String>;
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> ValidationResult<String>,
+{
+    fn validate(&self, input: &str) -> ValidationResult<String> {
+        self(input)
+    }
+}
+
+/// Validator requiring both rules to accept the input
+///
+/// Both rules see the original input; the output of the first is returned.
+/// See [`all_of`].
+#[derive(Debug, Clone, Copy)]
+pub struct AllOf<A, B> {
+    first: A,
+    second: B,
+}
+
+/// Require both validators to accept the input
+///
+/// Fails with the first error encountered, checking `first` before
+/// `second`. Nest calls to combine more rules.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pipeline::{all_of, Validator};
+/// use pleme_brazilian_validators::{phone, BrazilianValidationError};
+///
+/// let mobile = all_of(phone::validate, |p: &str| {
+///     if phone::is_mobile(p) {
+///         Ok(p.to_string())
+///     } else {
+///         Err(BrazilianValidationError::invalid_phone("não é celular"))
+///     }
+/// });
+///
+/// assert_eq!(mobile.validate("(11) 98765-4321").unwrap(), "+5511987654321");
+/// assert!(mobile.validate("(11) 3456-7890").is_err());
+/// ```
+pub fn all_of<A: Validator, B: Validator>(first: A, second: B) -> AllOf<A, B> {
+    AllOf { first, second }
+}
+
+impl<A: Validator, B: Validator> Validator for AllOf<A, B> {
+    fn validate(&self, input: &str) -> ValidationResult<String> {
+        let normalized = self.first.validate(input)?;
+        self.second.validate(input)?;
+        Ok(normalized)
+    }
+}
+
+/// Validator accepting the input if either rule does. See [`any_of`].
+#[derive(Debug, Clone, Copy)]
+pub struct AnyOf<A, B> {
+    first: A,
+    second: B,
+}
+
+/// Accept the input if either validator accepts it
+///
+/// Returns the output of the first validator that succeeds; if both fail,
+/// the error of `second` is returned.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pipeline::{any_of, Validator};
+/// use pleme_brazilian_validators::{cnpj, cpf};
+///
+/// let taxpayer = any_of(cpf::validate, cnpj::validate);
+/// assert_eq!(taxpayer.validate("123.456.789-09").unwrap(), "12345678909");
+/// assert_eq!(taxpayer.validate("11.222.333/0001-81").unwrap(), "11222333000181");
+/// ```
+pub fn any_of<A: Validator, B: Validator>(first: A, second: B) -> AnyOf<A, B> {
+    AnyOf { first, second }
+}
+
+impl<A: Validator, B: Validator> Validator for AnyOf<A, B> {
+    fn validate(&self, input: &str) -> ValidationResult<String> {
+        self.first
+            .validate(input)
+            .or_else(|_| self.second.validate(input))
+    }
+}
+
+/// Validator transforming the error of another. See [`map_err`].
+#[derive(Debug, Clone, Copy)]
+pub struct MapErr<V, F> {
+    validator: V,
+    f: F,
+}
+
+/// Transform the error returned by a validator
+///
+/// Useful to replace low-level errors with a domain-specific message.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pipeline::{any_of, map_err, Validator};
+/// use pleme_brazilian_validators::{cnpj, cpf, BrazilianValidationError};
+///
+/// let taxpayer = map_err(any_of(cpf::validate, cnpj::validate), |_| {
+///     BrazilianValidationError::invalid_cnpj("informe um CPF ou CNPJ")
+/// });
+///
+/// assert_eq!(
+///     taxpayer.validate("123").unwrap_err().to_string(),
+///     "CNPJ inválido: informe um CPF ou CNPJ"
+/// );
+/// ```
+pub fn map_err<V, F>(validator: V, f: F) -> MapErr<V, F>
+where
+    V: Validator,
+    F: Fn(BrazilianValidationError) -> BrazilianValidationError,
+{
+    MapErr { validator, f }
+}
+
+impl<V, F> Validator for MapErr<V, F>
+where
+    V: Validator,
+    F: Fn(BrazilianValidationError) -> BrazilianValidationError,
+{
+    fn validate(&self, input: &str) -> ValidationResult<String> {
+        self.validator.validate(input).map_err(&self.f)
+    }
+}
+
+/// Validator feeding the normalized output of one rule into another.
+/// See [`and_then_normalize`].
+#[derive(Debug, Clone, Copy)]
+pub struct AndThenNormalize<A, B> {
+    first: A,
+    then: B,
+}
+
+/// Validate, then run a second rule on the normalized document
+///
+/// Unlike [`all_of`], the second rule receives the output of the first
+/// (e.g. bare digits), which keeps denylists and lookups format-agnostic.
+/// The output of `then` is returned.
+pub fn and_then_normalize<A: Validator, B: Validator>(first: A, then: B) -> AndThenNormalize<A, B> {
+    AndThenNormalize { first, then }
+}
+
+impl<A: Validator, B: Validator> Validator for AndThenNormalize<A, B> {
+    fn validate(&self, input: &str) -> ValidationResult<String> {
+        let normalized = self.first.validate(input)?;
+        self.then.validate(&normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cep, cnpj, cpf, phone};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    fn reject_all(_: &str) -> ValidationResult<String> {
+        Err(BrazilianValidationError::InvalidCharacters)
+    }
+
+    #[test]
+    fn test_functions_are_validators() {
+        assert_eq!(cep::validate.validate("01310-100").unwrap(), "01310100");
+        assert!(reject_all.validate("x").is_err());
+    }
+
+    #[test]
+    fn test_all_of_checks_original_input() {
+        let formatted_only = all_of(cpf::validate, |input: &str| {
+            if cpf::is_cpf_format(input) && input.contains('.') {
+                Ok(input.to_string())
+            } else {
+                Err(BrazilianValidationError::InvalidCharacters)
+            }
+        });
+
+        assert_eq!(formatted_only.validate("123.456.789-09").unwrap(), "12345678909");
+        assert_eq!(
+            formatted_only.validate("12345678909"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+        assert!(matches!(
+            formatted_only.validate("123.456.789-00"),
+            Err(BrazilianValidationError::InvalidCheckDigits { .. })
+        ));
+    }
+
+    #[test]
+    fn test_any_of_returns_last_error() {
+        let either = any_of(cpf::validate, reject_all);
+        assert!(either.validate("12345678909").is_ok());
+        assert_eq!(
+            either.validate("11222333000181"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn test_nested_pipeline() {
+        let contact = any_of(phone::validate, any_of(cpf::validate, cnpj::validate));
+        let pipeline = and_then_normalize(contact, |doc: &str| Ok(format!("id:{}", doc)));
+
+        assert_eq!(pipeline.validate("(11) 98765-4321").unwrap(), "id:+5511987654321");
+        assert_eq!(pipeline.validate("11.222.333/0001-81").unwrap(), "id:11222333000181");
+        assert!(pipeline.validate("nope").is_err());
+    }
+
+    #[test]
+    fn test_map_err_leaves_success_untouched() {
+        let validator = map_err(cep::validate, |_| BrazilianValidationError::InvalidCharacters);
+        assert_eq!(validator.validate("01310-100").unwrap(), "01310100");
+        assert_eq!(
+            validator.validate("0"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! PIS/PASEP (NIS) validation and formatting
+//!
+//! The PIS (private sector workers), PASEP (public servants) and NIT/NIS
+//! (social programs) share one numbering: 11 digits, the last one a
+//! modulo 11 check digit over the 10 base digits weighted 3, 2, 9 down to
+//! 2. Definitive CNS numbers ([`crate::cns`]) embed it.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{dv, hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of digits of a PIS/PASEP number
+pub const LEN: usize = 11;
+
+/// Weights of the 10 base digits
+const WEIGHTS: [u32; 10] = [3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Validate a PIS/PASEP number
+///
+/// Validates length, the check digit, and rejects sequences of a repeated
+/// digit.
+///
+/// # Arguments
+/// * `pis` - PIS/PASEP/NIS number (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized number (11 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pis::validate;
+///
+/// assert!(validate("120.54353.86-0").is_ok());
+/// assert!(validate("120.54353.86-1").is_err()); // Invalid check digit
+/// assert!(validate("111.11111.11-1").is_err()); // All same digits
+/// ```
+pub fn validate(pis: &str) -> ValidationResult<String> {
+    let result = check(pis);
+    hooks::notify(DocumentKind::Pis, &result);
+    result
+}
+
+fn check(pis: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(pis));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if cleaned.bytes().all(|b| b == cleaned.as_bytes()[0]) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Pis,
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    if compute_check_digit(&digits[..LEN - 1]) != digits[LEN - 1] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Pis,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute the check digit of the 10 base digits
+///
+/// Shared with the RENAVAM, which uses the same weights.
+pub(crate) fn compute_check_digit(base: &[u32]) -> u32 {
+    let sum: u32 = base.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum();
+    dv::Mod11Remainder::Complement.check_digit(sum % 11)
+}
+
+/// Normalize a PIS/PASEP number by removing all non-digit characters
+pub fn normalize(pis: &str) -> String {
+    pis.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a PIS/PASEP number (XXX.XXXXX.XX-X)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pis::format;
+///
+/// assert_eq!(format("12054353860"), "120.54353.86-0");
+/// assert_eq!(format("123"), "123");
+/// ```
+pub fn format(pis: &str) -> String {
+    let cleaned = normalize(pis);
+
+    if cleaned.len() == LEN {
+        format!(
+            "{}.{}.{}-{}",
+            &cleaned[..3],
+            &cleaned[3..8],
+            &cleaned[8..10],
+            &cleaned[10..]
+        )
+    } else {
+        pis.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_digit_overflow_is_zero() {
+        // 11 - 1 = 10 and 11 - 0 = 11 both become 0
+        assert_eq!(compute_check_digit(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]), 0);
+        assert!(validate("12345678900").is_ok());
+        assert!(validate("17000000005").is_ok());
+    }
+
+    #[test]
+    fn test_rejections() {
+        assert_eq!(
+            validate("1205435386"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 11,
+                actual: 10
+            })
+        );
+        assert_eq!(
+            validate("000.00000.00-0"),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::Pis
+            })
+        );
+        assert_eq!(
+            validate("12054353861"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Pis
+            })
+        );
+    }
+}
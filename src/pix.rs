@@ -2,27 +2,32 @@
 //!
 //! Brazilian instant payment system key validation supporting
 //! CPF, CNPJ, email, phone, and random key formats.
+//!
+//! The [`brcode`] submodule handles "copia e cola" payment payloads, and
+//! `dict` models the key-registration payloads of the DICT directory
+//! (feature `serialization`).
 
+use crate::document::DocumentKind;
 use crate::error::{BrazilianValidationError, ValidationResult};
-use crate::{cpf, cnpj};
-use regex::Regex;
-use std::sync::LazyLock;
-
-/// Regex for email format
-static EMAIL_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap());
+use crate::masking::MaskOptions;
+use crate::{cnpj, cpf, denylist, hooks, phone, shape};
+use alloc::format;
+use alloc::string::{String, ToString};
+use subtle::ConstantTimeEq;
 
-/// Regex for PIX phone format (+55 followed by 11 digits)
-static PIX_PHONE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\+55\d{11}$").unwrap());
-
-/// Regex for random PIX key (UUID format)
-static RANDOM_KEY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap()
-});
+pub mod brcode;
+#[cfg(feature = "serialization")]
+pub mod dict;
 
 /// PIX key types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Serialized as `"cpf"`, `"cnpj"`, `"email"`, `"phone"` or `"random"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PixKeyType {
     /// CPF (individual taxpayer ID)
     Cpf,
@@ -36,8 +41,32 @@ pub enum PixKeyType {
     Random,
 }
 
-impl std::fmt::Display for PixKeyType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PixKeyType {
+    /// Every PIX key type
+    pub const ALL: [PixKeyType; 5] = [
+        PixKeyType::Cpf,
+        PixKeyType::Cnpj,
+        PixKeyType::Email,
+        PixKeyType::Phone,
+        PixKeyType::Random,
+    ];
+
+    /// Iterate over every PIX key type
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::pix::PixKeyType;
+    ///
+    /// let labels: Vec<_> = PixKeyType::iter().map(|kind| kind.to_string()).collect();
+    /// assert_eq!(labels, ["CPF", "CNPJ", "E-mail", "Telefone", "Chave aleatória"]);
+    /// ```
+    pub fn iter() -> impl Iterator<Item = PixKeyType> {
+        PixKeyType::ALL.into_iter()
+    }
+}
+
+impl core::fmt::Display for PixKeyType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             PixKeyType::Cpf => write!(f, "CPF"),
             PixKeyType::Cnpj => write!(f, "CNPJ"),
@@ -79,16 +108,24 @@ impl std::fmt::Display for PixKeyType {
 /// assert!(validate("123e4567-e89b-12d3-a456-426614174000").is_ok());
 /// ```
 pub fn validate(key: &str) -> ValidationResult<()> {
+    let result = validate_unobserved(key)
+        .and_then(|()| denylist::check(DocumentKind::PixKey, &normalize(key)));
+    hooks::notify(DocumentKind::PixKey, &result);
+    result
+}
+
+/// Validate without reporting to the validation hook (for internal lookups)
+pub(crate) fn validate_unobserved(key: &str) -> ValidationResult<()> {
     let key = key.trim();
 
     // Try each format in order of specificity
     if is_cpf_format(key) {
-        cpf::validate(key)?;
+        cpf::validate_unobserved(key)?;
         return Ok(());
     }
 
     if is_cnpj_format(key) {
-        cnpj::validate(key)?;
+        cnpj::validate_unobserved(key)?;
         return Ok(());
     }
 
@@ -159,17 +196,26 @@ pub fn detect_type(key: &str) -> Option<PixKeyType> {
 /// assert_eq!(key_type, PixKeyType::Cpf);
 /// ```
 pub fn validate_with_type(key: &str) -> ValidationResult<(PixKeyType, String)> {
+    let result = validate_with_type_unobserved(key).and_then(|(key_type, key)| {
+        denylist::check(DocumentKind::PixKey, &key).map(|()| (key_type, key))
+    });
+    hooks::notify(DocumentKind::PixKey, &result);
+    result
+}
+
+/// Validate and detect type without reporting to the validation hook
+pub(crate) fn validate_with_type_unobserved(key: &str) -> ValidationResult<(PixKeyType, String)> {
     let key = key.trim();
 
     // Try CPF
     if is_cpf_format(key) {
-        let normalized = cpf::validate(key)?;
+        let normalized = cpf::validate_unobserved(key)?;
         return Ok((PixKeyType::Cpf, normalized));
     }
 
     // Try CNPJ
     if is_cnpj_format(key) {
-        let normalized = cnpj::validate(key)?;
+        let normalized = cnpj::validate_unobserved(key)?;
         return Ok((PixKeyType::Cnpj, normalized));
     }
 
@@ -195,27 +241,27 @@ pub fn validate_with_type(key: &str) -> ValidationResult<(PixKeyType, String)> {
 
 /// Check if key matches CPF format
 fn is_cpf_format(key: &str) -> bool {
-    cpf::CPF_REGEX.is_match(key)
+    shape::is_cpf(key)
 }
 
 /// Check if key matches CNPJ format
 fn is_cnpj_format(key: &str) -> bool {
-    cnpj::CNPJ_REGEX.is_match(key)
+    shape::is_cnpj(key)
 }
 
 /// Check if key matches email format
 fn is_email_format(key: &str) -> bool {
-    EMAIL_REGEX.is_match(key)
+    shape::is_pix_email(key)
 }
 
 /// Check if key matches PIX phone format (+55 with 11 digits)
 fn is_phone_format(key: &str) -> bool {
-    PIX_PHONE_REGEX.is_match(key)
+    shape::is_pix_phone(key)
 }
 
 /// Check if key matches random key format (UUID)
 fn is_random_key_format(key: &str) -> bool {
-    RANDOM_KEY_REGEX.is_match(&key.to_lowercase())
+    shape::is_pix_evp(key)
 }
 
 /// Normalize a PIX key based on its type
@@ -234,9 +280,7 @@ pub fn normalize(key: &str) -> String {
         cpf::normalize(key)
     } else if is_cnpj_format(key) {
         cnpj::normalize(key)
-    } else if is_email_format(key) {
-        key.to_lowercase()
-    } else if is_random_key_format(key) {
+    } else if is_email_format(key) || is_random_key_format(key) {
         key.to_lowercase()
     } else {
         key.to_string()
@@ -271,16 +315,50 @@ pub fn mask(key: &str) -> String {
     }
 }
 
+/// Mask a PIX key according to a masking policy
+///
+/// CPF, CNPJ and phone keys are masked as by the `mask_with` function of
+/// their module; only the local part of e-mail keys is masked, and random
+/// keys are masked as they are.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::masking::MaskOptions;
+/// use pleme_brazilian_validators::pix::mask_with;
+///
+/// let options = MaskOptions { visible_leading: 1, visible_trailing: 0, ..MaskOptions::default() };
+/// assert_eq!(mask_with("user@example.com", &options), "u***@example.com");
+/// assert_eq!(mask_with("12345678909", &options), "1**.***.***-**");
+/// ```
+pub fn mask_with(key: &str, options: &MaskOptions) -> String {
+    let key = key.trim();
+
+    if is_cpf_format(key) {
+        cpf::mask_with(key, options)
+    } else if is_cnpj_format(key) {
+        cnpj::mask_with(key, options)
+    } else if is_email_format(key) {
+        match key.split_once('@') {
+            Some((local, domain)) => format!("{}@{}", options.apply(local), domain),
+            None => key.to_string(),
+        }
+    } else if is_phone_format(key) {
+        phone::mask_with(key, options)
+    } else if is_random_key_format(key) {
+        options.apply(key)
+    } else {
+        key.to_string()
+    }
+}
+
 /// Mask an email address
 fn mask_email(email: &str) -> String {
-    if let Some(at_pos) = email.find('@') {
-        let local = &email[..at_pos];
-        let domain = &email[at_pos..];
-
-        if local.len() > 1 {
-            format!("{}***{}", &local[0..1], domain)
-        } else {
-            format!("***{}", domain)
+    if let Some((local, domain)) = email.split_once('@') {
+        let mut chars = local.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(first), Some(_)) => format!("{}***@{}", first, domain),
+            _ => format!("***@{}", domain),
         }
     } else {
         email.to_string()
@@ -289,24 +367,169 @@ fn mask_email(email: &str) -> String {
 
 /// Mask a phone number
 fn mask_phone(phone: &str) -> String {
-    if phone.len() >= 14 {
-        // +5511987654321 -> +55 (11) *****-4321
-        format!(
-            "+55 ({}) *****-{}",
-            &phone[3..5],
-            &phone[phone.len() - 4..]
-        )
-    } else {
-        phone.to_string()
+    // +5511987654321 -> +55 (11) *****-4321
+    match (phone.get(3..5), phone.get(phone.len().saturating_sub(4)..)) {
+        (Some(ddd), Some(last_digits)) if phone.len() >= 14 => {
+            format!("+55 ({}) *****-{}", ddd, last_digits)
+        }
+        _ => phone.to_string(),
     }
 }
 
 /// Mask a random key (UUID)
 fn mask_random_key(key: &str) -> String {
-    if key.len() >= 8 {
-        format!("{}****-****-****-****-****", &key[0..4])
-    } else {
-        key.to_string()
+    match key.get(0..4) {
+        Some(prefix) if key.len() >= 8 => format!("{}****-****-****-****-****", prefix),
+        _ => key.to_string(),
+    }
+}
+
+/// Compare two PIX keys in constant time
+///
+/// Both keys are normalized according to their type first (e.g. emails and
+/// random keys are case-insensitive).
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pix::ct_eq;
+///
+/// assert!(ct_eq("User@Example.com", "user@example.com"));
+/// assert!(ct_eq("123.456.789-09", "12345678909"));
+/// assert!(!ct_eq("user@example.com", "other@example.com"));
+/// ```
+pub fn ct_eq(a: &str, b: &str) -> bool {
+    normalize(a).as_bytes().ct_eq(normalize(b).as_bytes()).into()
+}
+
+/// Validated PIX key
+///
+/// Can only be built through [`validate_with_type`], so the key type is
+/// known and the key is normalized. `Display`, `AsRef<str>` and serde use
+/// the normalized key.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pix::{PixKey, PixKeyType};
+///
+/// let key: PixKey = "User@Example.com".parse().unwrap();
+/// assert_eq!(key.key_type(), PixKeyType::Email);
+/// assert_eq!(key.as_str(), "user@example.com");
+/// assert_eq!(key.masked(), "u***@example.com");
+///
+/// assert!("123.456.789-00".parse::<PixKey>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PixKey {
+    key_type: PixKeyType,
+    key: String,
+}
+
+impl PixKey {
+    /// Validate a PIX key of any type
+    pub fn parse(key: &str) -> ValidationResult<Self> {
+        validate_with_type(key).map(|(key_type, key)| PixKey { key_type, key })
+    }
+
+    /// Type of the key
+    pub fn key_type(&self) -> PixKeyType {
+        self.key_type
+    }
+
+    /// Normalized key
+    pub fn as_str(&self) -> &str {
+        &self.key
+    }
+
+    /// Key formatted according to its type (CPF, CNPJ and phone
+    /// punctuation); e-mails and random keys are returned as they are
+    pub fn formatted(&self) -> String {
+        match self.key_type {
+            PixKeyType::Cpf => cpf::format(&self.key),
+            PixKeyType::Cnpj => cnpj::format(&self.key),
+            PixKeyType::Phone => phone::format(&self.key),
+            PixKeyType::Email | PixKeyType::Random => self.key.clone(),
+        }
+    }
+
+    /// Key masked according to its type, as by [`mask`]
+    pub fn masked(&self) -> String {
+        mask(&self.key)
+    }
+
+    /// Compare with another key in constant time, as by [`ct_eq`]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.key.as_bytes().ct_eq(other.key.as_bytes()).into()
+    }
+}
+
+impl core::fmt::Display for PixKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.key)
+    }
+}
+
+impl core::str::FromStr for PixKey {
+    type Err = BrazilianValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for PixKey {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl AsRef<str> for PixKey {
+    fn as_ref(&self) -> &str {
+        &self.key
+    }
+}
+
+impl crate::document::BrazilianDocument for PixKey {
+    const KIND: DocumentKind = DocumentKind::PixKey;
+
+    fn validate(input: &str) -> ValidationResult<Self> {
+        PixKey::parse(input)
+    }
+
+    fn formatted(&self) -> String {
+        PixKey::formatted(self)
+    }
+
+    fn normalized(&self) -> &str {
+        self.as_str()
+    }
+
+    fn masked(&self) -> String {
+        PixKey::masked(self)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl serde::Serialize for PixKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.key)
+    }
+}
+
+/// Deserializing validates, accepting any format `validate` does; errors
+/// carry the error code and the localized message
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for PixKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).map_err(BrazilianValidationError::into_de_error)
     }
 }
 
@@ -361,6 +584,39 @@ mod tests {
         assert_eq!(detect_type("invalid"), None);
     }
 
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_key_type_serde() {
+        assert_eq!(serde_json::to_string(&PixKeyType::Random).unwrap(), r#""random""#);
+        for kind in PixKeyType::iter() {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(serde_json::from_str::<PixKeyType>(&json).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn test_pix_key_type() {
+        let key = PixKey::parse("+5511987654321").unwrap();
+        assert_eq!(key.key_type(), PixKeyType::Phone);
+        assert_eq!(key.as_str(), "+5511987654321");
+        assert_eq!(key.formatted(), "+55 (11) 98765-4321");
+
+        let key: PixKey = "11.222.333/0001-81".parse().unwrap();
+        assert_eq!(key.to_string(), "11222333000181");
+        assert_eq!(key.formatted(), "11.222.333/0001-81");
+        assert!(PixKey::try_from("invalid@").is_err());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_pix_key_serde() {
+        let key: PixKey = serde_json::from_str(r#""User@Example.com""#).unwrap();
+        assert_eq!(serde_json::to_string(&key).unwrap(), r#""user@example.com""#);
+
+        let error = serde_json::from_str::<PixKey>(r#""chave""#).unwrap_err();
+        assert!(error.to_string().starts_with("INVALID_PIX_KEY: "), "{}", error);
+    }
+
     #[test]
     fn test_validate_with_type() {
         let (key_type, normalized) = validate_with_type("123.456.789-09").unwrap();
@@ -389,4 +645,18 @@ mod tests {
             "123e****-****-****-****-****"
         );
     }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq("User@Example.COM", "user@example.com"));
+        assert!(ct_eq(
+            "123E4567-E89B-12D3-A456-426614174000",
+            "123e4567-e89b-12d3-a456-426614174000"
+        ));
+        assert!(!ct_eq("+5511987654321", "+5511987654320"));
+
+        let key = PixKey::parse("User@Example.COM").unwrap();
+        assert!(key.ct_eq(&PixKey::parse("user@example.com").unwrap()));
+        assert!(!key.ct_eq(&PixKey::parse("12345678909").unwrap()));
+    }
 }
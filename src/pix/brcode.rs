@@ -0,0 +1,809 @@
+//! PIX BR Code ("copia e cola") payloads
+//!
+//! A BR Code is an EMV-MPM payload: a sequence of TLV fields, each written
+//! as a two-digit tag, a two-digit length and the value, terminated by a
+//! CRC16-CCITT checksum (tag `63`).
+//!
+//! [`parse`] extracts the fields of a static PIX payload and checks the
+//! checksum, reporting the tag and offset of any malformed field as a
+//! [`BrCodeError`]; [`PixPayload::validate_semantics`] then checks the
+//! field contents against the limits of the BCB specification.
+//!
+//! [`build`] goes the other way, encoding a [`PixPayload`] into a static
+//! payload ready to be rendered as a QR code.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::pix;
+use crate::report::ValidationReport;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Globally unique identifier of PIX merchant account information
+const PIX_GUI: &str = "br.gov.bcb.pix";
+
+/// Maximum merchant name length (tag `59`)
+pub const MAX_MERCHANT_NAME_LEN: usize = 25;
+
+/// Maximum merchant city length (tag `60`)
+pub const MAX_MERCHANT_CITY_LEN: usize = 15;
+
+/// Maximum transaction amount length (tag `54`)
+pub const MAX_AMOUNT_LEN: usize = 13;
+
+/// Maximum transaction ID length (tag `62`, subtag `05`)
+pub const MAX_TXID_LEN: usize = 25;
+
+/// Transaction ID meaning "no transaction ID"
+const NO_TXID: &str = "***";
+
+/// Maximum length of a TLV value: the length is written with two digits
+const MAX_VALUE_LEN: usize = 99;
+
+/// Fields of a static PIX BR Code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PixPayload {
+    /// Receiver PIX key (tag `26`, subtag `01`)
+    pub key: String,
+    /// Free-text message to the payer (tag `26`, subtag `02`)
+    pub description: Option<String>,
+    /// Merchant category code (tag `52`, `0000` when not informed)
+    pub merchant_category_code: String,
+    /// ISO 4217 numeric currency code (tag `53`, `986` for BRL)
+    pub currency: String,
+    /// Transaction amount as written in the payload (tag `54`)
+    pub amount: Option<String>,
+    /// ISO 3166-1 alpha-2 country code (tag `58`)
+    pub country_code: String,
+    /// Merchant name (tag `59`)
+    pub merchant_name: String,
+    /// Merchant city (tag `60`)
+    pub merchant_city: String,
+    /// Transaction ID (tag `62`, subtag `05`; `***` when absent)
+    pub txid: Option<String>,
+    /// Other additional data subfields (tag `62`), as (subtag, value)
+    /// pairs in payload order
+    pub additional_data: Vec<(String, String)>,
+}
+
+impl PixPayload {
+    /// Start a payload for a receiver, with no amount or transaction ID
+    ///
+    /// The key is normalized (a formatted CPF becomes digits only);
+    /// merchant category `0000`, currency BRL and country `BR` are filled
+    /// in. Complete it with the `with_*` methods and encode it with
+    /// [`build`].
+    pub fn new(
+        key: &str,
+        merchant_name: impl Into<String>,
+        merchant_city: impl Into<String>,
+    ) -> Self {
+        PixPayload {
+            key: pix::normalize(key),
+            description: None,
+            merchant_category_code: "0000".to_string(),
+            currency: "986".to_string(),
+            amount: None,
+            country_code: "BR".to_string(),
+            merchant_name: merchant_name.into(),
+            merchant_city: merchant_city.into(),
+            txid: None,
+            additional_data: Vec::new(),
+        }
+    }
+
+    /// Set the amount, in centavos
+    pub fn with_amount_cents(mut self, cents: u64) -> Self {
+        self.amount = Some(format!("{}.{:02}", cents / 100, cents % 100));
+        self
+    }
+
+    /// Set the transaction ID
+    pub fn with_txid(mut self, txid: impl Into<String>) -> Self {
+        self.txid = Some(txid.into());
+        self
+    }
+
+    /// Set the message to the payer
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Check the field contents against the BR Code specification
+    ///
+    /// Parsing only guarantees the payload is well formed. This checks what
+    /// acquirers must reject before presenting a payment: field lengths,
+    /// country code, amount format, transaction ID charset and the PIX key
+    /// itself. Every problem is reported under the field's name.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::pix::brcode;
+    ///
+    /// let payload = "00020126580014br.gov.bcb.pix0136123e4567-e89b-12d3-a456-426614174000\
+    ///                520400005303986540510.005802BR5913FULANO DE TAL6009SAO PAULO\
+    ///                62070503***6304E53B";
+    /// let mut parsed = brcode::parse(payload).unwrap();
+    /// assert!(parsed.validate_semantics().is_valid());
+    ///
+    /// parsed.merchant_city = "SAO JOSE DOS CAMPOS".to_string();
+    /// parsed.amount = Some("10,00".to_string());
+    /// let report = parsed.validate_semantics();
+    /// assert_eq!(report.len(), 2);
+    /// assert_eq!(report.field_errors("merchant_city").count(), 1);
+    /// ```
+    pub fn validate_semantics(&self) -> ValidationReport {
+        let mut report = ValidationReport::new();
+
+        report.check("key", pix::validate_unobserved(&self.key));
+        report.check(
+            "merchant_name",
+            check_text(&self.merchant_name, MAX_MERCHANT_NAME_LEN),
+        );
+        report.check(
+            "merchant_city",
+            check_text(&self.merchant_city, MAX_MERCHANT_CITY_LEN),
+        );
+
+        if self.country_code != "BR" {
+            report.add("country_code", invalid_format());
+        }
+        if let Some(amount) = &self.amount {
+            report.check("amount", check_amount(amount));
+        }
+        if let Some(txid) = &self.txid {
+            report.check("txid", check_txid(txid));
+        }
+
+        report
+    }
+}
+
+/// Reason a BR Code could not be parsed
+///
+/// Structural errors carry the byte offset in the trimmed payload and the
+/// tag involved (`"26.01"` for subfields), so a QR code generated by a
+/// third-party system can be fixed without decoding it by hand.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum BrCodeError {
+    /// The payload is empty
+    #[error("BR Code vazio")]
+    Empty,
+
+    /// The payload ends in the middle of a tag/length header
+    #[error("Cabeçalho de campo incompleto na posição {offset}")]
+    TruncatedHeader { offset: usize },
+
+    /// The tag is not two ASCII digits
+    #[error("Identificador de campo inválido na posição {offset}")]
+    InvalidTag { offset: usize },
+
+    /// The length is not two ASCII digits, or is wrong for the tag
+    #[error("Tamanho inválido no campo {tag} (posição {offset})")]
+    InvalidLength { tag: String, offset: usize },
+
+    /// The declared length runs past the end of the enclosing data
+    #[error(
+        "Valor truncado no campo {tag} (posição {offset}): \
+         declarado {declared}, disponível {available}"
+    )]
+    TruncatedValue {
+        tag: String,
+        offset: usize,
+        declared: usize,
+        available: usize,
+    },
+
+    /// A mandatory field is absent
+    #[error("Campo obrigatório ausente: {tag}")]
+    MissingField { tag: String },
+
+    /// The field has a value the parser cannot accept
+    #[error("Valor inválido no campo {tag} (posição {offset})")]
+    InvalidValue { tag: String, offset: usize },
+
+    /// The CRC16 in tag `63` does not match the payload
+    #[error("CRC inválido: esperado {expected}, encontrado {found}")]
+    CrcMismatch { expected: String, found: String },
+}
+
+impl BrCodeError {
+    /// Get error code for API responses
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::Empty => "BRCODE_EMPTY",
+            Self::TruncatedHeader { .. } => "BRCODE_TRUNCATED_HEADER",
+            Self::InvalidTag { .. } => "BRCODE_INVALID_TAG",
+            Self::InvalidLength { .. } => "BRCODE_INVALID_LENGTH",
+            Self::TruncatedValue { .. } => "BRCODE_TRUNCATED_VALUE",
+            Self::MissingField { .. } => "BRCODE_MISSING_FIELD",
+            Self::InvalidValue { .. } => "BRCODE_INVALID_VALUE",
+            Self::CrcMismatch { .. } => "BRCODE_CRC_MISMATCH",
+        }
+    }
+
+    /// Byte offset of the malformed field, when the error has one
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::TruncatedHeader { offset }
+            | Self::InvalidTag { offset }
+            | Self::InvalidLength { offset, .. }
+            | Self::TruncatedValue { offset, .. }
+            | Self::InvalidValue { offset, .. } => Some(*offset),
+            Self::Empty | Self::MissingField { .. } | Self::CrcMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<BrCodeError> for BrazilianValidationError {
+    fn from(err: BrCodeError) -> Self {
+        match err {
+            BrCodeError::CrcMismatch { .. } => BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::BrCode,
+            },
+            _ => invalid_format(),
+        }
+    }
+}
+
+/// TLV field located in a payload
+#[derive(Debug, Clone, Copy)]
+struct Field<'a> {
+    tag: &'a str,
+    /// Byte offset of the tag in the whole payload
+    offset: usize,
+    value: &'a str,
+}
+
+/// Parse a static PIX BR Code
+///
+/// Checks the TLV structure, the CRC16 checksum and the presence of the
+/// mandatory fields. Field contents are not checked; see
+/// [`PixPayload::validate_semantics`].
+///
+/// # Returns
+/// * `Ok(PixPayload)` - Extracted fields
+/// * `Err(BrCodeError)` - What is malformed, and where
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pix::brcode::{self, BrCodeError};
+///
+/// let payload = "00020126580014br.gov.bcb.pix0136123e4567-e89b-12d3-a456-426614174000\
+///                520400005303986540510.005802BR5913FULANO DE TAL6009SAO PAULO\
+///                62070503***6304E53B";
+/// let parsed = brcode::parse(payload).unwrap();
+/// assert_eq!(parsed.key, "123e4567-e89b-12d3-a456-426614174000");
+/// assert_eq!(parsed.amount.as_deref(), Some("10.00"));
+/// assert_eq!(parsed.merchant_city, "SAO PAULO");
+///
+/// assert_eq!(
+///     brcode::parse(&payload.replace("E53B", "0000")),
+///     Err(BrCodeError::CrcMismatch { expected: "E53B".to_string(), found: "0000".to_string() })
+/// );
+/// ```
+pub fn parse(payload: &str) -> Result<PixPayload, BrCodeError> {
+    let payload = payload.trim();
+    if payload.is_empty() {
+        return Err(BrCodeError::Empty);
+    }
+    let fields = parse_tlv(payload, 0, "")?;
+
+    let crc = find(&fields, "63").ok_or_else(|| missing("63"))?;
+    if crc.value.len() != 4 {
+        return Err(BrCodeError::InvalidLength {
+            tag: crc.tag.to_string(),
+            offset: crc.offset,
+        });
+    }
+    if crc.offset + 8 != payload.len() {
+        return Err(BrCodeError::InvalidValue {
+            tag: crc.tag.to_string(),
+            offset: crc.offset,
+        });
+    }
+    let expected = crc16(&payload[..crc.offset + 4]);
+    if !expected.eq_ignore_ascii_case(crc.value) {
+        return Err(BrCodeError::CrcMismatch {
+            expected,
+            found: crc.value.to_string(),
+        });
+    }
+
+    let required = |tag: &str| find(&fields, tag).ok_or_else(|| missing(tag));
+
+    let format = required("00")?;
+    if format.value != "01" {
+        return Err(BrCodeError::InvalidValue {
+            tag: format.tag.to_string(),
+            offset: format.offset,
+        });
+    }
+
+    let mut account = None;
+    for field in fields.iter().filter(|f| ("26"..="51").contains(&f.tag)) {
+        let sub = parse_tlv(field.value, field.offset + 4, field.tag)?;
+        if find(&sub, "00").is_some_and(|gui| gui.value.eq_ignore_ascii_case(PIX_GUI)) {
+            account = Some(sub);
+            break;
+        }
+    }
+    let account = account.ok_or_else(|| missing("26"))?;
+
+    let additional = match find(&fields, "62") {
+        Some(additional) => parse_tlv(additional.value, additional.offset + 4, additional.tag)?,
+        None => Vec::new(),
+    };
+    let txid = find(&additional, "05").map(|f| f.value.to_string());
+    let additional_data = additional
+        .iter()
+        .filter(|f| f.tag != "05")
+        .map(|f| (f.tag.to_string(), f.value.to_string()))
+        .collect();
+
+    Ok(PixPayload {
+        key: find(&account, "01")
+            .ok_or_else(|| missing("26.01"))?
+            .value
+            .to_string(),
+        description: find(&account, "02").map(|f| f.value.to_string()),
+        merchant_category_code: required("52")?.value.to_string(),
+        currency: required("53")?.value.to_string(),
+        amount: find(&fields, "54").map(|f| f.value.to_string()),
+        country_code: required("58")?.value.to_string(),
+        merchant_name: required("59")?.value.to_string(),
+        merchant_city: required("60")?.value.to_string(),
+        txid,
+        additional_data,
+    })
+}
+
+/// Encode a static PIX BR Code
+///
+/// Checks the fields with [`PixPayload::validate_semantics`], then writes
+/// them in the order of the specification and appends the CRC16. A
+/// missing transaction ID is written as `***`.
+///
+/// # Returns
+/// * `Ok(String)` - The "copia e cola" payload
+/// * `Err(ValidationReport)` - Every problem found, under the field names
+///   of [`PixPayload`]
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::pix::brcode::{self, PixPayload};
+///
+/// let payload = PixPayload::new("123e4567-e89b-12d3-a456-426614174000", "FULANO DE TAL", "SAO PAULO")
+///     .with_amount_cents(1000);
+/// let encoded = brcode::build(&payload).unwrap();
+/// assert_eq!(
+///     encoded,
+///     "00020126580014br.gov.bcb.pix0136123e4567-e89b-12d3-a456-426614174000\
+///      520400005303986540510.005802BR5913FULANO DE TAL6009SAO PAULO\
+///      62070503***6304E53B"
+/// );
+///
+/// let report = brcode::build(&payload.with_txid("pedido 42")).unwrap_err();
+/// assert_eq!(report.field_errors("txid").count(), 1);
+/// ```
+pub fn build(payload: &PixPayload) -> Result<String, ValidationReport> {
+    let mut report = payload.validate_semantics();
+    for (tag, _) in &payload.additional_data {
+        if tag.len() != 2 || !tag.bytes().all(|b| b.is_ascii_digit()) || tag == "05" {
+            report.add("additional_data", invalid_format());
+        }
+    }
+
+    let mut account = String::new();
+    push_field(&mut account, "00", PIX_GUI);
+    push_field(&mut account, "01", &payload.key);
+    if let Some(description) = &payload.description {
+        push_field(&mut account, "02", description);
+    }
+
+    let mut additional = String::new();
+    push_field(
+        &mut additional,
+        "05",
+        payload.txid.as_deref().unwrap_or(NO_TXID),
+    );
+    for (tag, value) in &payload.additional_data {
+        push_field(&mut additional, tag, value);
+    }
+
+    let account_field = if payload.description.is_some() {
+        "description"
+    } else {
+        "key"
+    };
+    let fields = [
+        ("00", "01", ""),
+        ("26", account.as_str(), account_field),
+        ("52", payload.merchant_category_code.as_str(), "merchant_category_code"),
+        ("53", payload.currency.as_str(), "currency"),
+        ("54", payload.amount.as_deref().unwrap_or(""), "amount"),
+        ("58", payload.country_code.as_str(), "country_code"),
+        ("59", payload.merchant_name.as_str(), "merchant_name"),
+        ("60", payload.merchant_city.as_str(), "merchant_city"),
+        ("62", additional.as_str(), "additional_data"),
+    ];
+    for &(_, value, field) in &fields {
+        if value.len() > MAX_VALUE_LEN {
+            report.add(
+                field,
+                BrazilianValidationError::InvalidLength {
+                    expected: MAX_VALUE_LEN,
+                    actual: value.len(),
+                },
+            );
+        }
+    }
+    report.into_result()?;
+
+    let mut encoded = String::new();
+    for (tag, value, _) in fields {
+        if !value.is_empty() {
+            push_field(&mut encoded, tag, value);
+        }
+    }
+    encoded.push_str("6304");
+    let crc = crc16(&encoded);
+    encoded.push_str(&crc);
+    Ok(encoded)
+}
+
+/// Append a TLV field; the value must be at most 99 bytes long
+fn push_field(out: &mut String, tag: &str, value: &str) {
+    out.push_str(tag);
+    out.push_str(&format!("{:02}", value.len()));
+    out.push_str(value);
+}
+
+/// Split TLV data into fields
+///
+/// `base` is the offset of `data` in the whole payload and `parent` the
+/// tag of the enclosing field (empty at the top level).
+fn parse_tlv<'a>(data: &'a str, base: usize, parent: &str) -> Result<Vec<Field<'a>>, BrCodeError> {
+    let mut fields = Vec::new();
+    let mut pos = 0;
+
+    while pos < data.len() {
+        let offset = base + pos;
+        let header = data
+            .as_bytes()
+            .get(pos..pos + 4)
+            .ok_or(BrCodeError::TruncatedHeader { offset })?;
+        if !header[..2].iter().all(u8::is_ascii_digit) {
+            return Err(BrCodeError::InvalidTag { offset });
+        }
+
+        // ASCII digits, so the slice ends on char boundaries
+        let tag = &data[pos..pos + 2];
+        let path = if parent.is_empty() {
+            tag.to_string()
+        } else {
+            format!("{}.{}", parent, tag)
+        };
+        if !header[2..].iter().all(u8::is_ascii_digit) {
+            return Err(BrCodeError::InvalidLength { tag: path, offset });
+        }
+        let declared: usize =
+            data[pos + 2..pos + 4]
+                .parse()
+                .map_err(|_| BrCodeError::InvalidLength {
+                    tag: path.clone(),
+                    offset,
+                })?;
+        let start = pos + 4;
+        let value =
+            data.get(start..start + declared)
+                .ok_or_else(|| BrCodeError::TruncatedValue {
+                    tag: path.clone(),
+                    offset,
+                    declared,
+                    available: data.len() - start,
+                })?;
+
+        fields.push(Field { tag, offset, value });
+        pos = start + declared;
+    }
+
+    Ok(fields)
+}
+
+/// Find the first field with a tag
+fn find<'a>(fields: &[Field<'a>], tag: &str) -> Option<Field<'a>> {
+    fields.iter().find(|f| f.tag == tag).copied()
+}
+
+fn missing(tag: &str) -> BrCodeError {
+    BrCodeError::MissingField {
+        tag: tag.to_string(),
+    }
+}
+
+/// CRC16-CCITT (polynomial `0x1021`, initial value `0xFFFF`) as 4 hex digits
+fn crc16(data: &str) -> String {
+    let crc = data.bytes().fold(0xFFFFu16, |crc, byte| {
+        (0..8).fold(crc ^ (u16::from(byte) << 8), |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    });
+    format!("{:04X}", crc)
+}
+
+/// Check a mandatory text field against its maximum length
+fn check_text(value: &str, max_len: usize) -> ValidationResult<()> {
+    let len = value.chars().count();
+    if value.trim().is_empty() || len > max_len {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: max_len,
+            actual: len,
+        });
+    }
+    Ok(())
+}
+
+/// Check an amount: positive, `.` as decimal separator, at most 2 decimals
+fn check_amount(amount: &str) -> ValidationResult<()> {
+    if amount.len() > MAX_AMOUNT_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: MAX_AMOUNT_LEN,
+            actual: amount.len(),
+        });
+    }
+
+    let (units, cents) = amount.split_once('.').unwrap_or((amount, ""));
+    let well_formed = !units.is_empty()
+        && cents.len() <= 2
+        && units
+            .bytes()
+            .chain(cents.bytes())
+            .all(|b| b.is_ascii_digit())
+        && (!amount.contains('.') || !cents.is_empty());
+    let positive = amount.bytes().any(|b| (b'1'..=b'9').contains(&b));
+
+    if well_formed && positive {
+        Ok(())
+    } else {
+        Err(invalid_format())
+    }
+}
+
+/// Check a transaction ID: `***` or up to 25 ASCII letters and digits
+fn check_txid(txid: &str) -> ValidationResult<()> {
+    if txid == NO_TXID {
+        return Ok(());
+    }
+    if txid.is_empty() || txid.len() > MAX_TXID_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: MAX_TXID_LEN,
+            actual: txid.len(),
+        });
+    }
+    if !txid.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+/// Format error for a BR Code field; the report field names which one
+fn invalid_format() -> BrazilianValidationError {
+    BrazilianValidationError::InvalidDocumentFormat {
+        document_type: DocumentKind::BrCode,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const PAYLOAD: &str = "00020126580014br.gov.bcb.pix0136123e4567-e89b-12d3-a456-426614174000\
+                           520400005303986540510.005802BR5913FULANO DE TAL6009SAO PAULO\
+                           62070503***6304E53B";
+
+    #[test]
+    fn test_crc16() {
+        assert_eq!(crc16("123456789"), "29B1");
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse(PAYLOAD).unwrap();
+        assert_eq!(
+            parsed,
+            PixPayload {
+                key: "123e4567-e89b-12d3-a456-426614174000".to_string(),
+                description: None,
+                merchant_category_code: "0000".to_string(),
+                currency: "986".to_string(),
+                amount: Some("10.00".to_string()),
+                country_code: "BR".to_string(),
+                merchant_name: "FULANO DE TAL".to_string(),
+                merchant_city: "SAO PAULO".to_string(),
+                txid: Some("***".to_string()),
+                additional_data: Vec::new(),
+            }
+        );
+        assert_eq!(parse(&PAYLOAD.replace("E53B", "e53b")), Ok(parsed));
+    }
+
+    #[test]
+    fn test_parse_reports_malformed_field() {
+        assert_eq!(parse("  "), Err(BrCodeError::Empty));
+        assert_eq!(
+            parse("000201260"),
+            Err(BrCodeError::TruncatedHeader { offset: 6 })
+        );
+        assert_eq!(
+            parse("0005é1"),
+            Err(BrCodeError::TruncatedValue {
+                tag: "00".to_string(),
+                offset: 0,
+                declared: 5,
+                available: 3,
+            })
+        );
+        assert_eq!(
+            parse("000201AB02"),
+            Err(BrCodeError::InvalidTag { offset: 6 })
+        );
+        assert_eq!(
+            parse(&PAYLOAD.replace("5802BR", "58X2BR")),
+            Err(BrCodeError::InvalidLength {
+                tag: "58".to_string(),
+                offset: 92
+            })
+        );
+        assert_eq!(
+            parse(&PAYLOAD[..PAYLOAD.len() - 10]),
+            Err(BrCodeError::TruncatedValue {
+                tag: "62".to_string(),
+                offset: 128,
+                declared: 7,
+                available: 5,
+            })
+        );
+    }
+
+    /// Append a correct CRC to a payload without tag `63`
+    fn with_crc(body: &str) -> String {
+        let body = format!("{}6304", body);
+        let crc = crc16(&body);
+        body + &crc
+    }
+
+    #[test]
+    fn test_parse_reports_subfield_path() {
+        let body = &PAYLOAD[..PAYLOAD.len() - 8];
+        assert_eq!(parse(&with_crc(body)).as_ref(), parse(PAYLOAD).as_ref());
+
+        let err = parse(&with_crc(&body.replace("0136123e", "0199123e"))).unwrap_err();
+        assert_eq!(err.offset(), Some(28));
+        assert!(matches!(err, BrCodeError::TruncatedValue { tag, .. } if tag == "26.01"));
+    }
+
+    #[test]
+    fn test_parse_additional_data() {
+        let body = PAYLOAD[..PAYLOAD.len() - 8].replace(
+            "62070503***",
+            "62280505PED420115loja-centro-07a",
+        );
+        let parsed = parse(&with_crc(&body)).unwrap();
+        assert_eq!(parsed.txid.as_deref(), Some("PED42"));
+        assert_eq!(
+            parsed.additional_data,
+            vec![("01".to_string(), "loja-centro-07a".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_crc_and_missing_fields() {
+        assert_eq!(
+            parse(&PAYLOAD.replace("FULANO", "BELTRA")),
+            Err(BrCodeError::CrcMismatch {
+                expected: crc16(&PAYLOAD.replace("FULANO", "BELTRA")[..PAYLOAD.len() - 4]),
+                found: "E53B".to_string(),
+            })
+        );
+        assert_eq!(
+            parse(&PAYLOAD[..PAYLOAD.len() - 8]),
+            Err(BrCodeError::MissingField {
+                tag: "63".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_converts_to_validation_error() {
+        let err: BrazilianValidationError = parse("0002").unwrap_err().into();
+        assert_eq!(err.error_code(), "INVALID_DOCUMENT_FORMAT");
+        let err: BrazilianValidationError =
+            parse(&PAYLOAD.replace("E53B", "0000")).unwrap_err().into();
+        assert_eq!(err.error_code(), "INVALID_CHECK_DIGITS");
+    }
+
+    #[test]
+    fn test_build_round_trips() {
+        assert_eq!(build(&parse(PAYLOAD).unwrap()).unwrap(), PAYLOAD);
+
+        let payload = PixPayload::new("123.456.789-09", "LOJA EXEMPLO", "CURITIBA")
+            .with_amount_cents(12_345)
+            .with_txid("PEDIDO42")
+            .with_description("Pedido 42");
+        let encoded = build(&payload).unwrap();
+        let parsed = parse(&encoded).unwrap();
+        assert_eq!(parsed.key, "12345678909");
+        assert_eq!(parsed.amount.as_deref(), Some("123.45"));
+        assert_eq!(parsed, payload);
+    }
+
+    #[test]
+    fn test_build_reports_invalid_fields() {
+        let payload = PixPayload::new("user@example.com", "LOJA", "RIO")
+            .with_amount_cents(0)
+            .with_description("x".repeat(70));
+        let report = build(&payload).unwrap_err();
+        let fields: Vec<_> = report.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["amount", "description"]);
+
+        let mut payload = PixPayload::new("user@example.com", "LOJA", "RIO");
+        payload.additional_data.push(("5".to_string(), "x".to_string()));
+        assert_eq!(
+            build(&payload).unwrap_err().field_errors("additional_data").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_validate_semantics() {
+        let mut payload = parse(PAYLOAD).unwrap();
+        assert!(payload.validate_semantics().is_valid());
+
+        payload.merchant_name = "A".repeat(26);
+        payload.merchant_city = String::new();
+        payload.country_code = "US".to_string();
+        payload.txid = Some("pedido-42".to_string());
+        payload.key = "not a key".to_string();
+
+        let report = payload.validate_semantics();
+        let fields: Vec<_> = report.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "key",
+                "merchant_name",
+                "merchant_city",
+                "country_code",
+                "txid"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_amount() {
+        for amount in ["10.00", "0.5", "1", "1234567890.99"] {
+            assert!(check_amount(amount).is_ok(), "{}", amount);
+        }
+        for amount in [
+            "",
+            "0.00",
+            "10,00",
+            "1.",
+            ".50",
+            "1.234",
+            "-1.00",
+            "12345678901.00",
+        ] {
+            assert!(check_amount(amount).is_err(), "{}", amount);
+        }
+    }
+}
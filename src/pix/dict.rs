@@ -0,0 +1,482 @@
+//! DICT key-registration payloads
+//!
+//! Typed request and response bodies of the DICT (Diretório de Identificadores
+//! de Contas Transacionais) entry API, serialized with the field names of
+//! the BCB specification. [`CreateEntryRequest::validate`] checks what the
+//! directory would reject — key format and type, owner document, ISPB and
+//! account fields — so a PSP finds the problem before the API call.
+//!
+//! `GetEntry` takes the key in the URL and has no request body; its response
+//! is [`GetEntryResponse`].
+//!
+//! Enabled by the `serialization` feature.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::pix::dict::CreateEntryRequest;
+//!
+//! let request: CreateEntryRequest = serde_json::from_str(r#"{
+//!     "Entry": {
+//!         "Key": "12345678909",
+//!         "KeyType": "CPF",
+//!         "Account": {
+//!             "Participant": "12345678",
+//!             "Branch": "0001",
+//!             "AccountNumber": "0007654321",
+//!             "AccountType": "CACC",
+//!             "OpeningDate": "2020-01-10T10:00:00Z"
+//!         },
+//!         "Owner": {
+//!             "Type": "NATURAL_PERSON",
+//!             "TaxIdNumber": "12345678909",
+//!             "Name": "Fulano de Tal"
+//!         }
+//!     },
+//!     "Reason": "USER_REQUESTED",
+//!     "RequestId": "a946d533-7f22-42a5-9a9b-e87cd55c0f4d"
+//! }"#).unwrap();
+//!
+//! assert!(request.validate().is_valid());
+//! ```
+
+use super::PixKeyType;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::report::ValidationReport;
+use crate::{cnpj, cpf, pix};
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of owner names and trade names
+pub const MAX_NAME_LEN: usize = 100;
+
+/// Maximum length of an account number
+pub const MAX_ACCOUNT_NUMBER_LEN: usize = 20;
+
+/// Type of the account a key points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AccountType {
+    /// Checking account (conta corrente)
+    Cacc,
+    /// Savings account (conta poupança)
+    Svgs,
+    /// Salary account (conta salário)
+    Slry,
+    /// Payment account (conta de pagamento)
+    Tran,
+}
+
+/// Whether the key owner is a person or a company
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OwnerType {
+    /// Individual, identified by CPF
+    NaturalPerson,
+    /// Company, identified by CNPJ
+    LegalPerson,
+}
+
+/// Why an entry is being created
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Reason {
+    /// Requested by the account holder
+    UserRequested,
+    /// Created by the PSP to reconcile its base with the directory
+    Reconciliation,
+}
+
+/// Account the key points to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Account {
+    /// ISPB of the participant holding the account (8 digits)
+    pub participant: String,
+    /// Branch number (up to 4 digits; absent for payment accounts)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Account number, digits only
+    pub account_number: String,
+    /// Account type
+    pub account_type: AccountType,
+    /// Account opening time (RFC 3339)
+    pub opening_date: String,
+}
+
+/// Holder of the account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Owner {
+    /// Person or company
+    #[serde(rename = "Type")]
+    pub owner_type: OwnerType,
+    /// CPF or CNPJ of the owner, digits only
+    pub tax_id_number: String,
+    /// Full or legal name
+    pub name: String,
+    /// Trade name of companies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_name: Option<String>,
+}
+
+/// Key and the account it points to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Entry {
+    /// PIX key, in normalized form
+    pub key: String,
+    /// Key type, serialized with the DICT names (`CPF`, `EMAIL`, `EVP`, ...)
+    #[serde(with = "key_type")]
+    pub key_type: PixKeyType,
+    /// Account the key points to
+    pub account: Account,
+    /// Account holder
+    pub owner: Owner,
+    /// Registration time, set by the directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<String>,
+    /// Time the current owner took the key, set by the directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_ownership_date: Option<String>,
+}
+
+impl Entry {
+    /// Check the entry against the DICT rules
+    ///
+    /// Errors are reported under `key`, `account.*` and `owner.*`. CPF and
+    /// CNPJ keys must belong to the owner of the account.
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::new();
+        self.validate_into(&mut report, "");
+        report
+    }
+
+    /// Add the entry errors to a report, with field names under `prefix`
+    fn validate_into(&self, report: &mut ValidationReport, prefix: &str) {
+        let field = |name: &str| format!("{}{}", prefix, name);
+
+        report.check(field("key"), check_key(&self.key, self.key_type));
+
+        let account = &self.account;
+        report.check(
+            field("account.participant"),
+            check_digits(&account.participant, 8, 8),
+        );
+        if let Some(branch) = &account.branch {
+            report.check(field("account.branch"), check_digits(branch, 1, 4));
+        }
+        report.check(
+            field("account.account_number"),
+            check_digits(&account.account_number, 1, MAX_ACCOUNT_NUMBER_LEN),
+        );
+
+        let owner = &self.owner;
+        report.check(
+            field("owner.tax_id_number"),
+            check_tax_id(&owner.tax_id_number, owner.owner_type),
+        );
+        report.check(field("owner.name"), check_name(&owner.name));
+        if let Some(trade_name) = &owner.trade_name {
+            report.check(field("owner.trade_name"), check_name(trade_name));
+        }
+
+        let document_key = matches!(self.key_type, PixKeyType::Cpf | PixKeyType::Cnpj);
+        if document_key && self.key != owner.tax_id_number {
+            report.add(
+                field("key"),
+                BrazilianValidationError::invalid_pix_key("chave não pertence ao titular"),
+            );
+        }
+    }
+}
+
+/// Body of `CreateEntry`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateEntryRequest {
+    /// Entry to register
+    pub entry: Entry,
+    /// Why the entry is created
+    pub reason: Reason,
+    /// Idempotency key (UUID)
+    pub request_id: String,
+}
+
+impl CreateEntryRequest {
+    /// Check the request against the DICT rules
+    ///
+    /// Entry errors are reported under `entry.*`, e.g. `entry.owner.name`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::pix::dict::*;
+    /// use pleme_brazilian_validators::pix::PixKeyType;
+    ///
+    /// let request = CreateEntryRequest {
+    ///     entry: Entry {
+    ///         key: "user@example.com".to_string(),
+    ///         key_type: PixKeyType::Email,
+    ///         account: Account {
+    ///             participant: "1234567".to_string(),
+    ///             branch: Some("0001".to_string()),
+    ///             account_number: "0007654321".to_string(),
+    ///             account_type: AccountType::Cacc,
+    ///             opening_date: "2020-01-10T10:00:00Z".to_string(),
+    ///         },
+    ///         owner: Owner {
+    ///             owner_type: OwnerType::LegalPerson,
+    ///             tax_id_number: "12345678909".to_string(),
+    ///             name: "Empresa Exemplo Ltda".to_string(),
+    ///             trade_name: None,
+    ///         },
+    ///         creation_date: None,
+    ///         key_ownership_date: None,
+    ///     },
+    ///     reason: Reason::UserRequested,
+    ///     request_id: "a946d533-7f22-42a5-9a9b-e87cd55c0f4d".to_string(),
+    /// };
+    ///
+    /// let report = request.validate();
+    /// assert_eq!(report.field_errors("entry.account.participant").count(), 1);
+    /// assert_eq!(report.field_errors("entry.owner.tax_id_number").count(), 1);
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::new();
+        self.entry.validate_into(&mut report, "entry.");
+        if !pix::is_random_key_format(&self.request_id) {
+            report.add(
+                "request_id",
+                BrazilianValidationError::invalid_pix_key("identificador de requisição inválido"),
+            );
+        }
+        report
+    }
+}
+
+/// Body of the `CreateEntry` response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateEntryResponse {
+    /// Time the directory answered (RFC 3339)
+    pub response_time: String,
+    /// Directory-assigned identifier for support requests
+    pub correlation_id: String,
+    /// Registered entry
+    pub entry: Entry,
+}
+
+/// Body of the `GetEntry` response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetEntryResponse {
+    /// Time the directory answered (RFC 3339)
+    pub response_time: String,
+    /// Directory-assigned identifier for support requests
+    pub correlation_id: String,
+    /// Entry found for the key
+    pub entry: Entry,
+}
+
+/// Check a key is valid, normalized and of the declared type
+fn check_key(key: &str, key_type: PixKeyType) -> ValidationResult<()> {
+    let (detected, normalized) = pix::validate_with_type_unobserved(key)?;
+    if detected != key_type {
+        return Err(BrazilianValidationError::invalid_pix_key(format!(
+            "tipo declarado {} não corresponde à chave",
+            key_type
+        )));
+    }
+    if normalized != key {
+        return Err(BrazilianValidationError::invalid_pix_key(
+            "chave deve estar normalizada",
+        ));
+    }
+    Ok(())
+}
+
+/// Check the owner document matches the owner type and is digits only
+fn check_tax_id(tax_id: &str, owner_type: OwnerType) -> ValidationResult<()> {
+    let normalized = match owner_type {
+        OwnerType::NaturalPerson => cpf::validate_unobserved(tax_id)?,
+        OwnerType::LegalPerson => cnpj::validate_unobserved(tax_id)?,
+    };
+    if normalized != tax_id {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+/// Check a numeric field of `min..=max` digits
+fn check_digits(value: &str, min: usize, max: usize) -> ValidationResult<()> {
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    if !(min..=max).contains(&value.len()) {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: max,
+            actual: value.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Check a name is not blank and fits the DICT limit
+fn check_name(name: &str) -> ValidationResult<()> {
+    let len = name.chars().count();
+    if name.trim().is_empty() || len > MAX_NAME_LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: MAX_NAME_LEN,
+            actual: len,
+        });
+    }
+    Ok(())
+}
+
+/// Serde for [`PixKeyType`] with the DICT names
+mod key_type {
+    use super::PixKeyType;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    const NAMES: [(PixKeyType, &str); 5] = [
+        (PixKeyType::Cpf, "CPF"),
+        (PixKeyType::Cnpj, "CNPJ"),
+        (PixKeyType::Email, "EMAIL"),
+        (PixKeyType::Phone, "PHONE"),
+        (PixKeyType::Random, "EVP"),
+    ];
+
+    pub fn serialize<S: Serializer>(
+        key_type: &PixKeyType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let (_, name) = NAMES
+            .iter()
+            .find(|(kind, _)| kind == key_type)
+            .unwrap_or(&NAMES[0]);
+        serializer.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PixKeyType, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        NAMES
+            .iter()
+            .find(|(_, known)| *known == name)
+            .map(|&(kind, _)| kind)
+            .ok_or_else(|| {
+                de::Error::unknown_variant(&name, &["CPF", "CNPJ", "EMAIL", "PHONE", "EVP"])
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry() -> Entry {
+        Entry {
+            key: "12345678909".to_string(),
+            key_type: PixKeyType::Cpf,
+            account: Account {
+                participant: "12345678".to_string(),
+                branch: Some("0001".to_string()),
+                account_number: "0007654321".to_string(),
+                account_type: AccountType::Cacc,
+                opening_date: "2020-01-10T10:00:00Z".to_string(),
+            },
+            owner: Owner {
+                owner_type: OwnerType::NaturalPerson,
+                tax_id_number: "12345678909".to_string(),
+                name: "Fulano de Tal".to_string(),
+                trade_name: None,
+            },
+            creation_date: None,
+            key_ownership_date: None,
+        }
+    }
+
+    #[test]
+    fn test_serializes_with_dict_field_names() {
+        let json = serde_json::to_value(entry()).unwrap();
+        assert_eq!(json["KeyType"], "CPF");
+        assert_eq!(json["Account"]["AccountType"], "CACC");
+        assert_eq!(json["Owner"]["Type"], "NATURAL_PERSON");
+        assert!(json.get("CreationDate").is_none());
+
+        let mut evp = entry();
+        evp.key_type = PixKeyType::Random;
+        assert_eq!(serde_json::to_value(&evp).unwrap()["KeyType"], "EVP");
+        let parsed: Entry = serde_json::from_value(serde_json::to_value(&evp).unwrap()).unwrap();
+        assert_eq!(parsed, evp);
+    }
+
+    #[test]
+    fn test_rejects_unknown_key_type() {
+        let mut json = serde_json::to_value(entry()).unwrap();
+        json["KeyType"] = "random".into();
+        assert!(serde_json::from_value::<Entry>(json).is_err());
+    }
+
+    #[test]
+    fn test_valid_entry() {
+        assert!(entry().validate().is_valid());
+
+        let mut email = entry();
+        email.key = "user@example.com".to_string();
+        email.key_type = PixKeyType::Email;
+        assert!(email.validate().is_valid());
+    }
+
+    #[test]
+    fn test_key_must_match_type_and_be_normalized() {
+        let mut entry = entry();
+        entry.key_type = PixKeyType::Phone;
+        assert_eq!(entry.validate().field_errors("key").count(), 1);
+
+        entry.key = "User@Example.com".to_string();
+        entry.key_type = PixKeyType::Email;
+        assert_eq!(entry.validate().field_errors("key").count(), 1);
+    }
+
+    #[test]
+    fn test_document_key_must_belong_to_owner() {
+        let mut entry = entry();
+        entry.owner.tax_id_number = "11144477735".to_string();
+        let report = entry.validate();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.field_errors("key").count(), 1);
+    }
+
+    #[test]
+    fn test_owner_and_account_fields() {
+        let mut entry = entry();
+        entry.owner.owner_type = OwnerType::LegalPerson;
+        entry.owner.name = " ".to_string();
+        entry.account.branch = Some("00001".to_string());
+        entry.account.account_number = "1234-5".to_string();
+
+        let report = entry.validate();
+        assert_eq!(report.field_errors("owner.tax_id_number").count(), 1);
+        assert_eq!(report.field_errors("owner.name").count(), 1);
+        assert_eq!(report.field_errors("account.branch").count(), 1);
+        assert_eq!(report.field_errors("account.account_number").count(), 1);
+    }
+
+    #[test]
+    fn test_create_entry_request() {
+        let mut request = CreateEntryRequest {
+            entry: entry(),
+            reason: Reason::Reconciliation,
+            request_id: "a946d533-7f22-42a5-9a9b-e87cd55c0f4d".to_string(),
+        };
+        assert!(request.validate().is_valid());
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["Reason"],
+            "RECONCILIATION"
+        );
+
+        request.request_id = "42".to_string();
+        request.entry.owner.tax_id_number = "123.456.789-09".to_string();
+        let report = request.validate();
+        assert_eq!(report.field_errors("request_id").count(), 1);
+        assert_eq!(report.field_errors("entry.owner.tax_id_number").count(), 1);
+    }
+}
@@ -0,0 +1,246 @@
+//! Vehicle license plate (placa) validation and formatting
+//!
+//! Brazilian plates come in two formats: the legacy `AAA-9999` (three
+//! letters, four digits) and the Mercosul `AAA9A99` adopted from 2018,
+//! where the fifth character is a letter. Converted legacy plates keep
+//! their characters, the fifth digit becoming the letter of the same rank
+//! (`0` → `A`, ..., `9` → `J`).
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Number of characters of a plate, without punctuation
+pub const LEN: usize = 7;
+
+/// Format of a license plate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlateFormat {
+    /// Legacy gray plate: `AAA-9999`
+    Legacy,
+    /// Mercosul plate: `AAA9A99`
+    Mercosul,
+}
+
+impl fmt::Display for PlateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlateFormat::Legacy => write!(f, "Padrão antigo"),
+            PlateFormat::Mercosul => write!(f, "Mercosul"),
+        }
+    }
+}
+
+/// Validate a license plate in either format
+///
+/// # Arguments
+/// * `placa` - Plate (case, hyphens and spaces are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized plate (7 uppercase characters)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::placa::validate;
+///
+/// assert_eq!(validate("abc-1234").unwrap(), "ABC1234");
+/// assert_eq!(validate("BRA2E19").unwrap(), "BRA2E19");
+/// assert!(validate("AB1-2345").is_err());
+/// ```
+pub fn validate(placa: &str) -> ValidationResult<String> {
+    let result = check(placa);
+    hooks::notify(DocumentKind::Placa, &result);
+    result
+}
+
+fn check(placa: &str) -> ValidationResult<String> {
+    let cleaned = normalize(placa);
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if !cleaned.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+
+    if detect_format(&cleaned).is_none() {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Placa,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Normalize a plate: uppercase, without hyphens, dots or spaces
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::placa::normalize;
+///
+/// assert_eq!(normalize(" abc-1234 "), "ABC1234");
+/// ```
+pub fn normalize(placa: &str) -> String {
+    placa
+        .chars()
+        .filter(|c| !matches!(c, '-' | '.' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Detect the format of a plate
+///
+/// Returns `None` if the plate matches neither format.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::placa::{detect_format, PlateFormat};
+///
+/// assert_eq!(detect_format("ABC-1234"), Some(PlateFormat::Legacy));
+/// assert_eq!(detect_format("bra2e19"), Some(PlateFormat::Mercosul));
+/// assert_eq!(detect_format("ABC12345"), None);
+/// ```
+pub fn detect_format(placa: &str) -> Option<PlateFormat> {
+    let cleaned = normalize(placa);
+    let bytes = cleaned.as_bytes();
+    if bytes.len() != LEN
+        || !bytes[..3].iter().all(u8::is_ascii_uppercase)
+        || !bytes[3].is_ascii_digit()
+        || !bytes[5..].iter().all(u8::is_ascii_digit)
+    {
+        return None;
+    }
+
+    match bytes[4] {
+        b'0'..=b'9' => Some(PlateFormat::Legacy),
+        b'A'..=b'Z' => Some(PlateFormat::Mercosul),
+        _ => None,
+    }
+}
+
+/// Format a plate: `AAA-9999` for legacy plates, `AAA9A99` for Mercosul
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::placa::format;
+///
+/// assert_eq!(format("abc1234"), "ABC-1234");
+/// assert_eq!(format("bra-2e19"), "BRA2E19");
+/// assert_eq!(format("invalid"), "invalid");
+/// ```
+pub fn format(placa: &str) -> String {
+    let cleaned = normalize(placa);
+
+    match detect_format(&cleaned) {
+        Some(PlateFormat::Legacy) => format!("{}-{}", &cleaned[..3], &cleaned[3..]),
+        Some(PlateFormat::Mercosul) => cleaned,
+        None => placa.to_string(),
+    }
+}
+
+/// Convert a plate to the Mercosul format
+///
+/// Legacy plates get the fifth digit replaced by the letter of the same
+/// rank, as done by the DETRANs when a vehicle switches plates; Mercosul
+/// plates are returned normalized.
+///
+/// # Returns
+/// `None` if the plate matches neither format.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::placa::to_mercosul;
+///
+/// assert_eq!(to_mercosul("ABC-1234").as_deref(), Some("ABC1C34"));
+/// assert_eq!(to_mercosul("BRA2E19").as_deref(), Some("BRA2E19"));
+/// ```
+pub fn to_mercosul(placa: &str) -> Option<String> {
+    let mut cleaned = normalize(placa);
+
+    if detect_format(&cleaned)? == PlateFormat::Legacy {
+        let digit = cleaned.as_bytes()[4] - b'0';
+        let letter = char::from(b'A' + digit);
+        cleaned.replace_range(4..5, letter.encode_utf8(&mut [0; 4]));
+    }
+    Some(cleaned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_separators_and_case_are_ignored() {
+        for (placa, normalized) in [
+            ("abc-1234", "ABC1234"),
+            ("ABC 1234", "ABC1234"),
+            ("abc.1234", "ABC1234"),
+            (" bra2e19 ", "BRA2E19"),
+        ] {
+            assert_eq!(validate(placa).as_deref(), Ok(normalized));
+        }
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        for (placa, actual) in [("ABC-123", 6), ("ABC-12345", 8), ("", 0)] {
+            assert_eq!(
+                validate(placa),
+                Err(BrazilianValidationError::InvalidLength {
+                    expected: 7,
+                    actual
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_characters_out_of_place() {
+        // Digit among the letters, letter in the 4th, 6th or 7th position,
+        // and plates of a single repeated character
+        for placa in [
+            "1BC1234", "ABCD123", "ABC12E4", "ABC123E", "AAAAAAA", "1111111",
+        ] {
+            assert_eq!(
+                validate(placa),
+                Err(BrazilianValidationError::InvalidDocumentFormat {
+                    document_type: DocumentKind::Placa
+                }),
+                "{placa}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_ascii_characters() {
+        for placa in ["ABC_123", "ÁBC123", "ABC+123"] {
+            assert_eq!(
+                validate(placa),
+                Err(BrazilianValidationError::InvalidCharacters),
+                "{placa}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mercosul_conversion() {
+        for (legacy, mercosul) in [
+            ("ABC1034", "ABC1A34"),
+            ("XYZ-9999", "XYZ9J99"),
+            ("abc 1234", "ABC1C34"),
+        ] {
+            let converted = to_mercosul(legacy).unwrap();
+            assert_eq!(converted, mercosul);
+            assert_eq!(detect_format(&converted), Some(PlateFormat::Mercosul));
+        }
+        assert_eq!(to_mercosul("ABC12345"), None);
+    }
+}
@@ -0,0 +1,366 @@
+//! Processo judicial number validation (numeração única CNJ)
+//!
+//! Since 2010 (CNJ Resolution 65/2008) every lawsuit in Brazil has a
+//! 20-digit number, with ISO 7064 modulo 97 check digits:
+//!
+//! ```text
+//! NNNNNNN-DD.AAAA.J.TR.OOOO
+//! │       │  │    │ │  └ origin unit (vara, comarca)
+//! │       │  │    │ └ tribunal
+//! │       │  │    └ judiciary segment
+//! │       │  └ year the case was filed
+//! │       └ check digits
+//! └ sequential number within the origin unit and year
+//! ```
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::processo_cnj;
+//!
+//! let processo = processo_cnj::parse("1234567-47.2023.8.26.0100").unwrap();
+//! assert_eq!(processo.year, 2023);
+//! assert_eq!(processo.tribunal, 26);
+//! assert_eq!(processo_cnj::format("12345674720238260100"), "1234567-47.2023.8.26.0100");
+//! ```
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::hooks;
+use crate::uf::Uf;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Number of digits of a processo number
+pub const LEN: usize = 20;
+
+/// States in the order of their tribunal codes (`TR`) in the state and
+/// electoral segments: alphabetical by state name
+const TRIBUNAL_UFS: [Uf; 27] = [
+    Uf::AC,
+    Uf::AL,
+    Uf::AP,
+    Uf::AM,
+    Uf::BA,
+    Uf::CE,
+    Uf::DF,
+    Uf::ES,
+    Uf::GO,
+    Uf::MA,
+    Uf::MT,
+    Uf::MS,
+    Uf::MG,
+    Uf::PA,
+    Uf::PB,
+    Uf::PR,
+    Uf::PE,
+    Uf::PI,
+    Uf::RJ,
+    Uf::RN,
+    Uf::RS,
+    Uf::RO,
+    Uf::RR,
+    Uf::SC,
+    Uf::SE,
+    Uf::SP,
+    Uf::TO,
+];
+
+/// Judiciary segment (`J`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// Supremo Tribunal Federal (1)
+    Stf,
+    /// Conselho Nacional de Justiça (2)
+    Cnj,
+    /// Superior Tribunal de Justiça (3)
+    Stj,
+    /// Justiça Federal (4)
+    Federal,
+    /// Justiça do Trabalho (5)
+    Trabalho,
+    /// Justiça Eleitoral (6)
+    Eleitoral,
+    /// Justiça Militar da União (7)
+    MilitarUniao,
+    /// Justiça Estadual (8)
+    Estadual,
+    /// Justiça Militar Estadual (9)
+    MilitarEstadual,
+}
+
+impl Segment {
+    /// Segment code as written in the number
+    pub fn code(&self) -> u8 {
+        match self {
+            Segment::Stf => 1,
+            Segment::Cnj => 2,
+            Segment::Stj => 3,
+            Segment::Federal => 4,
+            Segment::Trabalho => 5,
+            Segment::Eleitoral => 6,
+            Segment::MilitarUniao => 7,
+            Segment::Estadual => 8,
+            Segment::MilitarEstadual => 9,
+        }
+    }
+
+    /// Segment for a code
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Segment::Stf),
+            2 => Some(Segment::Cnj),
+            3 => Some(Segment::Stj),
+            4 => Some(Segment::Federal),
+            5 => Some(Segment::Trabalho),
+            6 => Some(Segment::Eleitoral),
+            7 => Some(Segment::MilitarUniao),
+            8 => Some(Segment::Estadual),
+            9 => Some(Segment::MilitarEstadual),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Segment::Stf => write!(f, "Supremo Tribunal Federal"),
+            Segment::Cnj => write!(f, "Conselho Nacional de Justiça"),
+            Segment::Stj => write!(f, "Superior Tribunal de Justiça"),
+            Segment::Federal => write!(f, "Justiça Federal"),
+            Segment::Trabalho => write!(f, "Justiça do Trabalho"),
+            Segment::Eleitoral => write!(f, "Justiça Eleitoral"),
+            Segment::MilitarUniao => write!(f, "Justiça Militar da União"),
+            Segment::Estadual => write!(f, "Justiça Estadual"),
+            Segment::MilitarEstadual => write!(f, "Justiça Militar Estadual"),
+        }
+    }
+}
+
+/// Fields of a validated processo number
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Processo {
+    /// Normalized number (20 digits)
+    pub number: String,
+    /// Sequential number within the origin unit and year (`NNNNNNN`)
+    pub sequence: u32,
+    /// Year the case was filed (`AAAA`)
+    pub year: u16,
+    /// Judiciary segment (`J`)
+    pub segment: Segment,
+    /// Tribunal within the segment (`TR`): the region for federal and labor
+    /// courts, the state for state and electoral courts, `0` for superior
+    /// courts
+    pub tribunal: u8,
+    /// Origin unit (`OOOO`), `0` for cases filed directly in the tribunal
+    pub origin: u16,
+}
+
+impl Processo {
+    /// State of the tribunal, for the state and electoral segments
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::processo_cnj;
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// let processo = processo_cnj::parse("5000001-53.2021.8.13.0024").unwrap();
+    /// assert_eq!(processo.uf(), Some(Uf::MG));
+    /// ```
+    pub fn uf(&self) -> Option<Uf> {
+        match self.segment {
+            Segment::Estadual | Segment::Eleitoral => TRIBUNAL_UFS
+                .get(usize::from(self.tribunal).checked_sub(1)?)
+                .copied(),
+            _ => None,
+        }
+    }
+}
+
+/// Validate a processo number
+///
+/// # Arguments
+/// * `number` - Processo number (punctuation is ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized number (digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::processo_cnj::validate;
+///
+/// assert!(validate("0000001-47.2010.4.03.6100").is_ok());
+/// assert!(validate("0000001-48.2010.4.03.6100").is_err());
+/// ```
+pub fn validate(number: &str) -> ValidationResult<String> {
+    let result = parse_unobserved(number).map(|parsed| parsed.number);
+    hooks::notify(DocumentKind::ProcessoCnj, &result);
+    result
+}
+
+/// Validate a processo number and decompose its fields
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::processo_cnj::{parse, Segment};
+///
+/// let processo = parse("0001234-71.2019.5.02.0001").unwrap();
+/// assert_eq!(processo.sequence, 1234);
+/// assert_eq!(processo.segment, Segment::Trabalho);
+/// assert_eq!((processo.tribunal, processo.origin), (2, 1));
+/// ```
+pub fn parse(number: &str) -> ValidationResult<Processo> {
+    let result = parse_unobserved(number);
+    hooks::notify(DocumentKind::ProcessoCnj, &result);
+    result
+}
+
+/// Parse without reporting to the validation hook
+fn parse_unobserved(number: &str) -> ValidationResult<Processo> {
+    let cleaned = normalize(number);
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let expected = check_digits(&cleaned[..7], &cleaned[9..]);
+    if cleaned[7..9] != expected {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::ProcessoCnj,
+        });
+    }
+
+    // Slices of a normalized number are ASCII digits, so they always parse
+    let field = |range: core::ops::Range<usize>| cleaned[range].parse::<u32>().unwrap_or_default();
+
+    let segment = Segment::from_code(field(13..14) as u8).ok_or(
+        BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::ProcessoCnj,
+        },
+    )?;
+
+    Ok(Processo {
+        sequence: field(0..7),
+        year: field(9..13) as u16,
+        segment,
+        tribunal: field(14..16) as u8,
+        origin: field(16..20) as u16,
+        number: cleaned,
+    })
+}
+
+/// Check digits of a processo number
+///
+/// Remainder of `NNNNNNN AAAA J TR OOOO 00` by 97, subtracted from 98.
+fn check_digits(sequence: &str, rest: &str) -> String {
+    let remainder = sequence
+        .chars()
+        .chain(rest.chars())
+        .chain("00".chars())
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |remainder, digit| (remainder * 10 + digit) % 97);
+    format!("{:02}", 98 - remainder)
+}
+
+/// Normalize a processo number by removing non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::processo_cnj::normalize;
+///
+/// assert_eq!(normalize("1234567-47.2023.8.26.0100"), "12345674720238260100");
+/// ```
+pub fn normalize(number: &str) -> String {
+    number.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a processo number as `NNNNNNN-DD.AAAA.J.TR.OOOO`
+///
+/// Returns input unchanged if it does not have 20 digits.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::processo_cnj::format;
+///
+/// assert_eq!(format("00000014720104036100"), "0000001-47.2010.4.03.6100");
+/// ```
+pub fn format(number: &str) -> String {
+    let cleaned = normalize(number);
+    if cleaned.len() != LEN {
+        return number.to_string();
+    }
+
+    format!(
+        "{}-{}.{}.{}.{}.{}",
+        &cleaned[..7],
+        &cleaned[7..9],
+        &cleaned[9..13],
+        &cleaned[13..14],
+        &cleaned[14..16],
+        &cleaned[16..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let processo = parse("1234567-47.2023.8.26.0100").unwrap();
+        assert_eq!(processo.number, "12345674720238260100");
+        assert_eq!(processo.sequence, 1_234_567);
+        assert_eq!(processo.year, 2023);
+        assert_eq!(processo.segment, Segment::Estadual);
+        assert_eq!(processo.tribunal, 26);
+        assert_eq!(processo.origin, 100);
+        assert_eq!(processo.uf(), Some(Uf::SP));
+
+        let federal = parse("00000014720104036100").unwrap();
+        assert_eq!(federal.segment, Segment::Federal);
+        assert_eq!(federal.uf(), None);
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        assert_eq!(
+            validate("1234567-48.2023.8.26.0100"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::ProcessoCnj
+            })
+        );
+        assert_eq!(
+            validate("1234567-47.2023.8.26.010"),
+            Err(BrazilianValidationError::InvalidLength {
+                expected: 20,
+                actual: 19
+            })
+        );
+        // Segment 0 does not exist, even with valid check digits
+        let dd = check_digits("1234567", "20230260100");
+        assert_eq!(
+            validate(&format!("1234567{}20230260100", dd)),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::ProcessoCnj
+            })
+        );
+    }
+
+    #[test]
+    fn test_segment_codes_round_trip() {
+        for code in 1..=9 {
+            assert_eq!(Segment::from_code(code).map(|s| s.code()), Some(code));
+        }
+        assert_eq!(Segment::from_code(0), None);
+    }
+
+    #[test]
+    fn test_format_passthrough() {
+        assert_eq!(format("123"), "123");
+    }
+}
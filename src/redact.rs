@@ -0,0 +1,228 @@
+//! LGPD redaction of documents in `tracing` logs
+//!
+//! [`Sensitive`] keeps a single value masked wherever it is formatted.
+//! [`RedactFields`] covers the values nobody thought to wrap: installed as
+//! the field formatter of a `tracing_subscriber::fmt` subscriber, it masks
+//! every CPF, CNPJ, CEP, phone number and PIX key found in event and span
+//! fields, as [`scan::redact`] does, with the configured
+//! [`mask_style`](crate::config::ValidationOptions::mask_style).
+//!
+//! Enabled by the `redact` feature.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::redact::RedactFields;
+//! use tracing_subscriber::fmt::format::DefaultFields;
+//!
+//! let subscriber = tracing_subscriber::fmt()
+//!     .fmt_fields(RedactFields::new(DefaultFields::new()))
+//!     .finish();
+//! tracing::subscriber::with_default(subscriber, || {
+//!     // Logged as `cliente="123.***.***-09"`
+//!     tracing::info!(cliente = "123.456.789-09", "pedido criado");
+//! });
+//! ```
+
+use crate::config::Config;
+use crate::scan;
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::field::{MakeVisitor, VisitFmt, VisitOutput};
+
+/// Wrapper whose `Debug`/`Display` always print the masked document
+///
+/// The same type as [`Secret`](crate::secret::Secret), under the name
+/// used in logging code. Record it in events with `%`:
+/// `tracing::info!(cpf = %Sensitive::new(cpf))`.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::redact::Sensitive;
+///
+/// let phone = Sensitive::new("11987654321");
+/// assert_eq!(phone.to_string(), "(11) *****-4321");
+/// ```
+pub use crate::secret::Secret as Sensitive;
+
+/// Field formatter masking the documents found in field values
+///
+/// Wraps another field formatter, usually
+/// `tracing_subscriber::fmt::format::DefaultFields`, and hands it the
+/// redacted values. Text and `Debug` values are scanned; integers are
+/// scanned too, since CPFs and CNPJs are often stored as numbers.
+#[derive(Debug, Clone, Default)]
+pub struct RedactFields<M> {
+    inner: M,
+}
+
+impl<M> RedactFields<M> {
+    /// Wrap a field formatter
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, M: MakeVisitor<T>> MakeVisitor<T> for RedactFields<M> {
+    type Visitor = RedactVisitor<M::Visitor>;
+
+    fn make_visitor(&self, target: T) -> Self::Visitor {
+        RedactVisitor {
+            inner: self.inner.make_visitor(target),
+        }
+    }
+}
+
+/// Field visitor created by [`RedactFields`]
+#[derive(Debug)]
+pub struct RedactVisitor<V> {
+    inner: V,
+}
+
+/// Already-redacted text, printed as is by `Debug`
+struct Redacted(String);
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Mask the documents found in a field value
+fn redact(value: &str) -> String {
+    scan::redact(value, Config::current().mask_style)
+}
+
+impl<V: Visit> RedactVisitor<V> {
+    /// Forward an integer, masked if its digits form a document
+    fn record_integer(&mut self, field: &Field, digits: String, forward: impl FnOnce(&mut V)) {
+        let redacted = redact(&digits);
+        if redacted == digits {
+            forward(&mut self.inner);
+        } else {
+            self.inner.record_debug(field, &Redacted(redacted));
+        }
+    }
+}
+
+impl<V: Visit> Visit for RedactVisitor<V> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.inner.record_str(field, &redact(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record_integer(field, value.to_string(), |inner| {
+            inner.record_u64(field, value)
+        });
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record_integer(field, value.to_string(), |inner| {
+            inner.record_i64(field, value)
+        });
+    }
+
+    fn record_u128(&mut self, field: &Field, value: u128) {
+        self.record_integer(field, value.to_string(), |inner| {
+            inner.record_u128(field, value)
+        });
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.record_integer(field, value.to_string(), |inner| {
+            inner.record_i128(field, value)
+        });
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.inner.record_f64(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.inner.record_bool(field, value);
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.inner
+            .record_debug(field, &Redacted(redact(&value.to_string())));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.inner
+            .record_debug(field, &Redacted(redact(&format!("{:?}", value))));
+    }
+}
+
+impl<V: VisitOutput<O>, O> VisitOutput<O> for RedactVisitor<V> {
+    fn finish(self) -> O {
+        self.inner.finish()
+    }
+}
+
+impl<V: VisitFmt> VisitFmt for RedactVisitor<V> {
+    fn writer(&mut self) -> &mut dyn fmt::Write {
+        self.inner.writer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::format::DefaultFields;
+
+    /// Log writer capturing the output in memory
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Run `log` under a redacting subscriber and return what it wrote
+    fn capture(log: impl FnOnce()) -> String {
+        let output = Capture::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .fmt_fields(RedactFields::new(DefaultFields::new()))
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        tracing::subscriber::with_default(subscriber, log);
+        let bytes = output.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_fields_are_redacted() {
+        let output = capture(|| {
+            tracing::info!(
+                cpf = "123.456.789-09",
+                cnpj = 11222333000181u64,
+                contato = ?("tel (11) 98765-4321"),
+                itens = 3,
+                "pedido de 123.456.789-09"
+            );
+        });
+        assert!(output.contains("cpf=\"123.***.***-09\""), "{}", output);
+        assert!(output.contains("cnpj=11.***.***/**01-81"), "{}", output);
+        assert!(output.contains("(11) *****-4321"), "{}", output);
+        assert!(output.contains("itens=3"), "{}", output);
+        assert!(output.contains("pedido de 123.***.***-09"), "{}", output);
+        assert!(!output.contains("456"), "{}", output);
+    }
+
+    #[test]
+    fn test_sensitive_in_fields() {
+        let cpf = Sensitive::new("12345678909");
+        let output = capture(|| tracing::info!(cpf = %cpf, "cadastro"));
+        assert!(output.contains("cpf=123.***.***-09"), "{}", output);
+    }
+}
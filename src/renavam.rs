@@ -0,0 +1,124 @@
+//! RENAVAM (Registro Nacional de Veículos Automotores) validation
+//!
+//! The RENAVAM code identifies a vehicle in the national registry. It has
+//! 11 digits, the last one a modulo 11 check digit computed like the PIS
+//! ([`crate::pis`]). Codes issued before 2013 had 9 digits and are padded
+//! with leading zeros.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{hooks, pis, unicode};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Number of digits of a RENAVAM code
+pub const LEN: usize = 11;
+
+/// Number of digits of a code issued before 2013
+pub const LEGACY_LEN: usize = 9;
+
+/// Validate a RENAVAM code
+///
+/// Legacy 9-digit codes are accepted and padded to 11 digits. Sequences of
+/// a repeated digit are rejected.
+///
+/// # Arguments
+/// * `renavam` - RENAVAM code (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized code (11 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::renavam::validate;
+///
+/// assert_eq!(validate("00639884962").as_deref(), Ok("00639884962"));
+/// assert_eq!(validate("639884962").as_deref(), Ok("00639884962")); // Legacy
+/// assert!(validate("00639884963").is_err()); // Invalid check digit
+/// ```
+pub fn validate(renavam: &str) -> ValidationResult<String> {
+    let result = check(renavam);
+    hooks::notify(DocumentKind::Renavam, &result);
+    result
+}
+
+fn check(renavam: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(renavam));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    if cleaned.bytes().all(|b| b == cleaned.as_bytes()[0]) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::Renavam,
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    if pis::compute_check_digit(&digits[..LEN - 1]) != digits[LEN - 1] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Renavam,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Normalize a RENAVAM code to its digits, padding legacy 9-digit codes
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::renavam::normalize;
+///
+/// assert_eq!(normalize("6398849-62"), "00639884962");
+/// assert_eq!(normalize("123"), "123");
+/// ```
+pub fn normalize(renavam: &str) -> String {
+    let digits: String = renavam.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    if digits.len() == LEGACY_LEN {
+        format!("00{}", digits)
+    } else {
+        digits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_codes_are_padded() {
+        assert_eq!(validate("639.884.962").as_deref(), Ok("00639884962"));
+        assert_eq!(
+            validate("6398849624").unwrap_err(),
+            BrazilianValidationError::InvalidLength {
+                expected: 11,
+                actual: 10
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejections() {
+        assert_eq!(
+            validate("00000000000"),
+            Err(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::Renavam
+            })
+        );
+        assert_eq!(
+            validate("06398849620"),
+            Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Renavam
+            })
+        );
+        assert!(validate("06398849624").is_ok());
+    }
+}
@@ -0,0 +1,237 @@
+//! Multi-field validation reports
+//!
+//! Collects the errors of every field in a form or DTO instead of stopping
+//! at the first one, and converts them into the `{ field: [messages] }`
+//! shape expected by frontend form libraries.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::{cep, cpf, report::ValidationReport, Locale};
+//!
+//! let mut report = ValidationReport::new();
+//! let cpf = report.check("cpf", cpf::validate("123.456.789-09"));
+//! let cep = report.check("address.cep", cep::validate("123"));
+//!
+//! assert!(cpf.is_some() && cep.is_none());
+//! assert_eq!(
+//!     report.to_field_map(Locale::En)["address.cep"],
+//!     vec!["Invalid length: expected 8, got 3".to_string()]
+//! );
+//! ```
+
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::locale::Locale;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Validation error attached to a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Field path as known by the client (e.g. `"address.cep"`)
+    pub field: String,
+    /// Error raised for the field
+    pub error: BrazilianValidationError,
+}
+
+/// Errors collected while validating several fields
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an error for a field
+    pub fn add(&mut self, field: impl Into<String>, error: BrazilianValidationError) {
+        self.errors.push(FieldError {
+            field: field.into(),
+            error,
+        });
+    }
+
+    /// Record the outcome of validating a field
+    ///
+    /// # Returns
+    /// * `Some(T)` - The validated value
+    /// * `None` - Validation failed and the error was recorded
+    pub fn check<T>(&mut self, field: impl Into<String>, result: ValidationResult<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.add(field, error);
+                None
+            }
+        }
+    }
+
+    /// Check whether no error was recorded
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Number of errors recorded
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Check whether no error was recorded (same as [`is_valid`](Self::is_valid))
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Errors in the order they were recorded
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Errors recorded for one field
+    pub fn field_errors<'a>(
+        &'a self,
+        field: &'a str,
+    ) -> impl Iterator<Item = &'a BrazilianValidationError> + 'a {
+        self.errors
+            .iter()
+            .filter(move |e| e.field == field)
+            .map(|e| &e.error)
+    }
+
+    /// Turn the report into a result: `Ok(())` if no error was recorded
+    pub fn into_result(self) -> Result<(), ValidationReport> {
+        if self.is_valid() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Map each field to its messages in the given locale
+    ///
+    /// Fields are sorted by name; messages keep the order they were
+    /// recorded in. The map serializes to the `{ "field": ["message"] }`
+    /// object used by form libraries such as React Hook Form, Formik or
+    /// VeeValidate.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::{report::ValidationReport, BrazilianValidationError, Locale};
+    ///
+    /// let mut report = ValidationReport::new();
+    /// report.add("cpf", BrazilianValidationError::InvalidLength { expected: 11, actual: 9 });
+    ///
+    /// let map = report.to_field_map(Locale::PtBr);
+    /// assert_eq!(map["cpf"], vec!["Tamanho inválido: esperado 11, recebido 9".to_string()]);
+    /// ```
+    pub fn to_field_map(&self, locale: Locale) -> BTreeMap<String, Vec<String>> {
+        let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for FieldError { field, error } in &self.errors {
+            map.entry(field.clone())
+                .or_default()
+                .push(error.localized(locale));
+        }
+        map
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} erro(s) de validação", self.errors.len())?;
+        for (i, FieldError { field, error }) in self.errors.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { "; " };
+            write!(f, "{}{}: {}", separator, field, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ValidationReport {}
+
+impl Extend<FieldError> for ValidationReport {
+    fn extend<I: IntoIterator<Item = FieldError>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
+
+impl FromIterator<FieldError> for ValidationReport {
+    fn from_iter<I: IntoIterator<Item = FieldError>>(iter: I) -> Self {
+        ValidationReport {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for ValidationReport {
+    type Item = FieldError;
+    type IntoIter = alloc::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cnpj, cpf, phone};
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn report() -> ValidationReport {
+        let mut report = ValidationReport::new();
+        report.check("cpf", cpf::validate("111.111.111-11"));
+        report.check("company.cnpj", cnpj::validate("11.222.333/0001-81"));
+        report.check("phone", phone::validate("123"));
+        report.add("cpf", BrazilianValidationError::InvalidCharacters);
+        report
+    }
+
+    #[test]
+    fn test_collects_errors_per_field() {
+        let report = report();
+        assert_eq!(report.len(), 3);
+        assert_eq!(report.field_errors("cpf").count(), 2);
+        assert_eq!(report.field_errors("company.cnpj").count(), 0);
+    }
+
+    #[test]
+    fn test_field_map_groups_messages() {
+        let map = report().to_field_map(Locale::En);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["cpf", "phone"]);
+        assert_eq!(
+            map["cpf"],
+            vec!["Invalid CPF", "Document contains invalid characters"]
+        );
+    }
+
+    #[test]
+    fn test_field_map_default_locale_matches_display() {
+        let report = report();
+        let map = report.to_field_map(Locale::PtBr);
+        for FieldError { field, error } in report.errors() {
+            assert!(map[field].contains(&error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_into_result() {
+        assert_eq!(ValidationReport::new().into_result(), Ok(()));
+        let err = report().into_result().unwrap_err();
+        assert!(err.to_string().starts_with("3 erro(s) de validação: cpf: CPF inválido"));
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_field_map_serializes_for_forms() {
+        let mut report = ValidationReport::new();
+        report.check("cep", crate::cep::validate("00000-000"));
+        assert_eq!(
+            serde_json::to_string(&report.to_field_map(Locale::En)).unwrap(),
+            r#"{"cep":["Invalid CEP"]}"#
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! RG (Registro Geral) validation and formatting
+//!
+//! Identity cards are issued by each state's security department, and
+//! only São Paulo (SSP-SP) publishes its check-digit algorithm: 8 digits
+//! weighted 2 to 9, then a modulo 11 check digit, `X` standing for 10.
+//! Other states' numbers are only checked for structure, as their length
+//! and check digit rules vary and are not public.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::uf::Uf;
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Number of characters of a São Paulo RG (8 digits and the check digit)
+pub const SP_LEN: usize = 9;
+
+/// Shortest RG accepted for other states
+pub const MIN_LEN: usize = 5;
+
+/// Longest RG accepted for other states
+pub const MAX_LEN: usize = 14;
+
+/// Validate an RG
+///
+/// With `Some(Uf::SP)` the number must have 9 characters and a valid
+/// check digit. For other states, or when the issuing state is unknown,
+/// any number of 5 to 14 digits is accepted, the last one possibly `X`.
+///
+/// # Arguments
+/// * `rg` - RG number (punctuation is ignored, `x` is accepted)
+/// * `issuing_state` - State that issued the card, if known
+///
+/// # Returns
+/// * `Ok(String)` - Normalized RG (digits, and a final `X`)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::rg::validate;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(validate("43.201.987-x", Some(Uf::SP)).unwrap(), "43201987X");
+/// assert!(validate("12.345.678-9", Some(Uf::SP)).is_err()); // Invalid check digit
+/// assert!(validate("12.345.678-9", Some(Uf::RJ)).is_ok());
+/// assert!(validate("MG-12.345.678", None).is_err());
+/// ```
+pub fn validate(rg: &str, issuing_state: Option<Uf>) -> ValidationResult<String> {
+    let result = check(rg, issuing_state);
+    hooks::notify(DocumentKind::Rg, &result);
+    result
+}
+
+fn check(rg: &str, issuing_state: Option<Uf>) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(rg));
+
+    if !cleaned.is_ascii() {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+    let (body, last) = cleaned.split_at(cleaned.len().saturating_sub(1));
+    let well_formed = body.bytes().all(|b| b.is_ascii_digit())
+        && last.bytes().all(|b| b.is_ascii_digit() || b == b'X');
+    if !well_formed {
+        return Err(BrazilianValidationError::InvalidCharacters);
+    }
+
+    if issuing_state == Some(Uf::SP) {
+        if cleaned.len() != SP_LEN {
+            return Err(BrazilianValidationError::InvalidLength {
+                expected: SP_LEN,
+                actual: cleaned.len(),
+            });
+        }
+        if compute_sp_check_digit(body) != last.chars().next() {
+            return Err(BrazilianValidationError::InvalidCheckDigits {
+                document_type: DocumentKind::Rg,
+            });
+        }
+    } else if !(MIN_LEN..=MAX_LEN).contains(&cleaned.len()) {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: MAX_LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute the SSP-SP check digit of the 8 base digits
+///
+/// Weights 2 to 9 from left to right; the check digit is the complement
+/// of the remainder by 11, `X` for 10 and `0` for 11.
+fn compute_sp_check_digit(base: &str) -> Option<char> {
+    let sum: u32 = base
+        .chars()
+        .zip(2..=9)
+        .map(|(c, weight)| c.to_digit(10).map(|d| d * weight))
+        .sum::<Option<u32>>()?;
+    match 11 - sum % 11 {
+        10 => Some('X'),
+        11 => Some('0'),
+        digit => char::from_digit(digit, 10),
+    }
+}
+
+/// Normalize an RG: punctuation and whitespace removed, `x` uppercased
+///
+/// Other letters are kept, so that validation rejects them.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::rg::normalize;
+///
+/// assert_eq!(normalize("43.201.987-x"), "43201987X");
+/// ```
+pub fn normalize(rg: &str) -> String {
+    rg.chars()
+        .filter(|c| !matches!(c, '.' | '-' | '/' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Format an RG in the São Paulo layout (XX.XXX.XXX-X)
+///
+/// Returns input unchanged if it does not have 9 characters.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::rg::format;
+///
+/// assert_eq!(format("123456782"), "12.345.678-2");
+/// assert_eq!(format("1234567"), "1234567");
+/// ```
+pub fn format(rg: &str) -> String {
+    let cleaned = normalize(rg);
+
+    if cleaned.len() == SP_LEN && cleaned.is_ascii() {
+        format!(
+            "{}.{}.{}-{}",
+            &cleaned[..2],
+            &cleaned[2..5],
+            &cleaned[5..8],
+            &cleaned[8..]
+        )
+    } else {
+        rg.to_string()
+    }
+}
+
+/// Mask an RG for display (shows the last 3 characters)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::rg::mask;
+///
+/// assert_eq!(mask("43201987X"), "**.***.*87-X");
+/// assert_eq!(mask("1234567"), "****567");
+/// ```
+pub fn mask(rg: &str) -> String {
+    let cleaned = normalize(rg);
+    if !cleaned.is_ascii() || !(MIN_LEN..=MAX_LEN).contains(&cleaned.len()) {
+        return rg.to_string();
+    }
+
+    let visible_from = cleaned.len() - 3;
+    let masked: String = cleaned
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i < visible_from { '*' } else { c })
+        .collect();
+    if masked.len() == SP_LEN {
+        format(&masked)
+    } else {
+        masked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_is_ignored() {
+        for rg in ["43.201.987-x", "43 201 987 X", "43201987/X", "43201987x"] {
+            assert_eq!(validate(rg, Some(Uf::SP)).as_deref(), Ok("43201987X"));
+        }
+        assert_eq!(
+            validate("1.234.567", Some(Uf::MG)).as_deref(),
+            Ok("1234567")
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        for rg in ["MG 1.234.567", "12X4567", "1234_567", "１２３４５６７８２"] {
+            assert_eq!(
+                validate(rg, None),
+                Err(BrazilianValidationError::InvalidCharacters),
+                "{}",
+                rg
+            );
+        }
+        // X only stands for a final 10
+        assert_eq!(
+            validate("X32019874", Some(Uf::SP)),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let invalid_length =
+            |expected, actual| Err(BrazilianValidationError::InvalidLength { expected, actual });
+        assert_eq!(validate("12345678", Some(Uf::SP)), invalid_length(9, 8));
+        assert_eq!(validate("1234567823", Some(Uf::SP)), invalid_length(9, 10));
+        // Other states accept 5 to 14 characters
+        assert_eq!(validate("1234", Some(Uf::RJ)), invalid_length(14, 4));
+        assert_eq!(validate(&"1".repeat(15), None), invalid_length(14, 15));
+        assert_eq!(validate("", None), invalid_length(14, 0));
+        assert!(validate("12345", None).is_ok());
+        assert!(validate(&"1".repeat(14), None).is_ok());
+    }
+
+    #[test]
+    fn test_sp_check_digit() {
+        for rg in [
+            "123456782",
+            "246781312",
+            "111111110",
+            "43201987X",
+            "300000005",
+        ] {
+            assert_eq!(validate(rg, Some(Uf::SP)).as_deref(), Ok(rg));
+        }
+        let bad_check_digit = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::Rg,
+        });
+        for rg in [
+            "123456783",
+            "432019870",
+            "213456782",
+            "111111111",
+            "999999999",
+        ] {
+            assert_eq!(validate(rg, Some(Uf::SP)), bad_check_digit, "{}", rg);
+        }
+        // Not checked for states whose algorithm is not public
+        assert_eq!(
+            validate("123456783", Some(Uf::RJ)).as_deref(),
+            Ok("123456783")
+        );
+    }
+
+    #[test]
+    fn test_format_and_mask_passthrough() {
+        assert_eq!(format("12.345"), "12.345");
+        assert_eq!(mask("12"), "12");
+    }
+}
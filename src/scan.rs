@@ -0,0 +1,293 @@
+//! Detection and redaction of documents in free text
+//!
+//! Finds CPF, CNPJ, CEP, phone numbers and PIX keys embedded in arbitrary
+//! text (application logs, chat transcripts, e-mails) so they can be
+//! scrubbed before storage, as required by the LGPD.
+//!
+//! Formatted documents (`123.456.789-09`, `(11) 98765-4321`, `01310-100`)
+//! are detected by shape alone. Bare digit runs and CNPJs holding letters
+//! (`12.ABC.345/01DE-35`, `12abc34501de35`) are only treated as documents
+//! when they pass full validation, to avoid masking unrelated numbers and
+//! words such as order IDs, timestamps and product codes.
+//!
+//! Detection and redaction need the `std` feature; [`MaskStyle`] is always
+//! available.
+
+#[cfg(feature = "std")]
+use crate::document::DocumentKind;
+#[cfg(feature = "std")]
+use crate::{cep, cnh, cnpj, cpf, phone, pix, titulo_eleitor};
+#[cfg(feature = "std")]
+use regex::Regex;
+#[cfg(feature = "std")]
+use std::sync::LazyLock;
+
+/// Regex for document candidates, tried left to right (most specific first)
+///
+/// ASCII-only (`(?-u)`) so that `\d` never matches non-ASCII digits, which the
+/// per-document `mask` functions would leave unmasked.
+#[cfg(feature = "std")]
+static DOCUMENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?-u)(?P<email>[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,})",
+        r"|(?P<random>\b[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}\b)",
+        r"|(?P<cnpj>\b[0-9A-Za-z]{2}\.[0-9A-Za-z]{3}\.[0-9A-Za-z]{3}/[0-9A-Za-z]{4}-\d{2}\b)",
+        r"|(?P<cpf>\b\d{3}\.\d{3}\.\d{3}-\d{2}\b)",
+        r"|(?P<phone>(?:\+55\s?)?\(\d{2}\)\s?\d{4,5}-?\d{4}\b",
+        r"|\+55\s?\d{2}\s\d{4,5}-?\d{4}\b",
+        r"|\b\d{2}\s\d{4,5}-\d{4}\b)",
+        r"|(?P<cep>\b\d{5}-\d{3}\b)",
+        r"|(?P<intl>\+55\d{10,11}\b)",
+        r"|(?P<digits>\b\d{10,14}\b)",
+        r"|(?P<alnum>\b[0-9A-Za-z]{12}\d{2}\b)",
+    ))
+    .unwrap()
+});
+
+/// How detected documents are replaced during redaction
+///
+/// Serialized as `"partial"`, `"full"` or `"label"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum MaskStyle {
+    /// Partial reveal using each document's `mask` (e.g. `123.***.***-09`)
+    #[default]
+    Partial,
+    /// Every letter and digit replaced with `*`, punctuation kept
+    Full,
+    /// Whole document replaced with its kind (e.g. `[CPF]`)
+    Label,
+}
+
+impl MaskStyle {
+    /// Every masking style
+    pub const ALL: [MaskStyle; 3] = [MaskStyle::Partial, MaskStyle::Full, MaskStyle::Label];
+
+    /// Iterate over every masking style
+    pub fn iter() -> impl Iterator<Item = MaskStyle> {
+        MaskStyle::ALL.into_iter()
+    }
+}
+
+/// Document detected in free text (byte offsets into the scanned text)
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Detection {
+    pub(crate) kind: DocumentKind,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+/// Find every document in a text, in order of appearance
+#[cfg(feature = "std")]
+pub(crate) fn detect(text: &str) -> Vec<Detection> {
+    DOCUMENT_REGEX
+        .captures_iter(text)
+        .filter_map(|caps| {
+            let m = caps.get(0)?;
+            let kind = if caps.name("email").is_some() || caps.name("random").is_some() {
+                DocumentKind::PixKey
+            } else if caps.name("cnpj").is_some() {
+                let raw = m.as_str();
+                if raw.bytes().any(|b| b.is_ascii_alphabetic())
+                    && cnpj::validate_unobserved(raw).is_err()
+                {
+                    return None;
+                }
+                DocumentKind::Cnpj
+            } else if caps.name("alnum").is_some() {
+                cnpj::validate_unobserved(m.as_str()).ok()?;
+                DocumentKind::Cnpj
+            } else if caps.name("cpf").is_some() {
+                DocumentKind::Cpf
+            } else if caps.name("phone").is_some() || caps.name("intl").is_some() {
+                DocumentKind::Phone
+            } else if caps.name("cep").is_some() {
+                DocumentKind::Cep
+            } else {
+                classify_digits(m.as_str())?
+            };
+
+            Some(Detection {
+                kind,
+                start: m.start(),
+                end: m.end(),
+            })
+        })
+        .collect()
+}
+
+/// Classify a bare digit run, accepting only fully valid documents
+#[cfg(feature = "std")]
+fn classify_digits(digits: &str) -> Option<DocumentKind> {
+    match digits.len() {
+        14 if cnpj::validate_unobserved(digits).is_ok() => Some(DocumentKind::Cnpj),
+        11 if cpf::validate_unobserved(digits).is_ok() => Some(DocumentKind::Cpf),
+        10..=13 if phone::validate_unobserved(digits).is_ok() => Some(DocumentKind::Phone),
+        _ => None,
+    }
+}
+
+/// Mask a single detected document according to the style
+#[cfg(feature = "std")]
+pub(crate) fn mask_document(kind: DocumentKind, raw: &str, style: MaskStyle) -> String {
+    match style {
+        MaskStyle::Partial => match kind {
+            DocumentKind::Cpf => cpf::mask(raw),
+            DocumentKind::Cnpj => cnpj::mask(raw),
+            DocumentKind::Cep => cep::mask(raw),
+            DocumentKind::Phone => phone::mask(raw),
+            DocumentKind::PixKey => pix::mask(raw),
+            DocumentKind::Cnh => cnh::mask(raw),
+            DocumentKind::TituloEleitor => titulo_eleitor::mask(raw),
+            // Never detected in free text; hide them entirely
+            _ => mask_document(kind, raw, MaskStyle::Full),
+        },
+        MaskStyle::Full => raw
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { '*' } else { c })
+            .collect(),
+        MaskStyle::Label => format!("[{}]", kind),
+    }
+}
+
+/// Redact every document found in a text
+///
+/// Replaces each detected CPF, CNPJ, CEP, phone number and PIX key with
+/// its masked form, leaving the rest of the text untouched.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::scan::{redact, MaskStyle};
+///
+/// let log = "cliente 123.456.789-09 tel (11) 98765-4321";
+/// assert_eq!(
+///     redact(log, MaskStyle::Partial),
+///     "cliente 123.***.***-09 tel (11) *****-4321"
+/// );
+/// assert_eq!(redact(log, MaskStyle::Label), "cliente [CPF] tel [Telefone]");
+/// ```
+#[cfg(feature = "std")]
+pub fn redact(text: &str, style: MaskStyle) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for detection in detect(text) {
+        redacted.push_str(&text[last..detection.start]);
+        redacted.push_str(&mask_document(
+            detection.kind,
+            &text[detection.start..detection.end],
+            style,
+        ));
+        last = detection.end;
+    }
+
+    redacted.push_str(&text[last..]);
+    redacted
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_formatted_documents() {
+        let text = "CPF 123.456.789-09, CNPJ 11.222.333/0001-81, CEP 01310-100";
+        let kinds: Vec<_> = detect(text).iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![DocumentKind::Cpf, DocumentKind::Cnpj, DocumentKind::Cep]
+        );
+    }
+
+    #[test]
+    fn test_detect_bare_digits_requires_validation() {
+        assert!(detect("pedido 12345678900").is_empty());
+        assert_eq!(detect("cpf 12345678909")[0].kind, DocumentKind::Cpf);
+        assert_eq!(detect("cnpj 11222333000181")[0].kind, DocumentKind::Cnpj);
+        assert_eq!(detect("tel 11987654321")[0].kind, DocumentKind::Phone);
+    }
+
+    #[test]
+    fn test_redact_partial() {
+        assert_eq!(
+            redact("pix: user@example.com", MaskStyle::Partial),
+            "pix: u***@example.com"
+        );
+        assert_eq!(
+            redact("CNPJ 11.222.333/0001-81", MaskStyle::Partial),
+            "CNPJ 11.***.***/**01-81"
+        );
+        assert_eq!(
+            redact("CNPJ 12.ABC.345/01DE-35", MaskStyle::Partial),
+            "CNPJ 12.***.***/**DE-35"
+        );
+        assert_eq!(redact("CEP 01310-100", MaskStyle::Partial), "CEP 01310-***");
+    }
+
+    #[test]
+    fn test_redact_alphanumeric_cnpj() {
+        for cnpj in ["12ABC34501DE35", "12abc34501de35", "12.abc.345/01de-35"] {
+            assert_eq!(
+                redact(&format!("CNPJ {cnpj}."), MaskStyle::Partial),
+                "CNPJ 12.***.***/**DE-35."
+            );
+            assert_eq!(
+                redact(&format!("CNPJ {cnpj}"), MaskStyle::Label),
+                "CNPJ [CNPJ]"
+            );
+        }
+    }
+
+    #[test]
+    fn test_alphanumeric_candidates_require_validation() {
+        // Wrong check digits, and a word that merely has the shape
+        for text in [
+            "CNPJ 12ABC34501DE36",
+            "12.abc.345/01de-36",
+            "ref ABCDEFGHIJKL12",
+        ] {
+            assert!(detect(text).is_empty(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_redact_full() {
+        assert_eq!(
+            redact("CPF 123.456.789-09.", MaskStyle::Full),
+            "CPF ***.***.***-**."
+        );
+        assert_eq!(
+            redact("chave +5511987654321", MaskStyle::Full),
+            "chave +*************"
+        );
+    }
+
+    #[test]
+    fn test_redact_label() {
+        assert_eq!(
+            redact(
+                "chave 123e4567-e89b-12d3-a456-426614174000 ok",
+                MaskStyle::Label
+            ),
+            "chave [Chave PIX] ok"
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_digits_are_not_detected() {
+        // Arabic-Indic digits are not documents for the validators
+        let text = "cpf ١٢٣.٤٥٦.٧٨٩-٠٩";
+        assert_eq!(redact(text, MaskStyle::Partial), text);
+    }
+
+    #[test]
+    fn test_redact_without_documents() {
+        let text = "nenhum documento aqui, pedido 42";
+        assert_eq!(redact(text, MaskStyle::Partial), text);
+    }
+}
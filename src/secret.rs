@@ -0,0 +1,166 @@
+//! Leak-resistant wrapper for personal documents
+//!
+//! [`Secret`] keeps a document out of logs: its `Debug` and `Display`
+//! implementations only ever print the masked form, and the raw value is
+//! reachable solely through an explicit [`Secret::expose`] call.
+
+use crate::config::Config;
+use crate::scan;
+use std::fmt;
+
+/// Placeholder printed when no document can be recognized in the value
+const REDACTED: &str = "***";
+
+/// Wrapper whose `Debug`/`Display` always print the masked document
+///
+/// Documents inside the value are masked as in [`scan::redact`]. If nothing
+/// recognizable is found the whole value is hidden, so a malformed document
+/// never leaks through formatting either.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::secret::Secret;
+///
+/// let cpf = Secret::new("123.456.789-09".to_string());
+///
+/// assert_eq!(format!("{}", cpf), "123.***.***-09");
+/// assert_eq!(format!("{:?}", cpf), "Secret(\"123.***.***-09\")");
+/// assert_eq!(cpf.expose(), "123.456.789-09");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    /// Wrap a value
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// Access the raw value
+    ///
+    /// This is the only way to read the unmasked document, which makes
+    /// every place that handles it explicit and easy to audit.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AsRef<str>> Secret<T> {
+    /// Get the masked form printed by `Debug` and `Display`
+    ///
+    /// Documents are masked with the configured
+    /// [`mask_style`](crate::config::ValidationOptions::mask_style).
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::secret::Secret;
+    ///
+    /// assert_eq!(Secret::new("11987654321").masked(), "(11) *****-4321");
+    /// assert_eq!(Secret::new("not a document").masked(), "***");
+    /// ```
+    pub fn masked(&self) -> String {
+        let raw = self.0.as_ref();
+        hide_unmasked(raw, scan::redact(raw, Config::current().mask_style))
+    }
+}
+
+/// Hide a value that masking left unchanged, i.e. where nothing was recognized
+pub(crate) fn hide_unmasked(raw: &str, masked: String) -> String {
+    if masked == raw && !raw.is_empty() {
+        REDACTED.to_string()
+    } else {
+        masked
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Wrapping a value that wipes itself on drop keeps that guarantee, e.g.
+/// `Secret<Zeroizing<String>>`
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Secret<T> {}
+
+impl<T: AsRef<str>> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Secret").field(&self.masked()).finish()
+    }
+}
+
+impl<T: AsRef<str>> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.masked())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_masks_documents() {
+        assert_eq!(
+            Secret::new("11222333000181").to_string(),
+            "11.***.***/**01-81"
+        );
+        assert_eq!(
+            Secret::new("user@example.com").to_string(),
+            "u***@example.com"
+        );
+    }
+
+    #[test]
+    fn test_debug_masks_documents() {
+        let secret = Secret::new("12345678909".to_string());
+        assert_eq!(format!("{:?}", secret), "Secret(\"123.***.***-09\")");
+        assert!(!format!("{:#?}", secret).contains("12345678909"));
+    }
+
+    #[test]
+    fn test_unrecognized_value_is_hidden() {
+        // Invalid check digits: not detected as a CPF, still never printed
+        let secret = Secret::new("12345678900");
+        assert_eq!(secret.to_string(), "***");
+        assert_eq!(*secret.expose(), "12345678900");
+    }
+
+    #[test]
+    fn test_configured_mask_style() {
+        use crate::config::Config;
+        use crate::scan::MaskStyle;
+
+        let options = Config::builder().mask_style(MaskStyle::Label).build();
+        Config::with_options(options, || {
+            assert_eq!(Secret::new("12345678909").to_string(), "[CPF]");
+        });
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_clears_value() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut secret = Secret::new("12345678909".to_string());
+        secret.zeroize();
+        assert!(secret.expose().is_empty());
+
+        let wiped_on_drop = Secret::new(Zeroizing::new("12345678909".to_string()));
+        assert_eq!(wiped_on_drop.to_string(), "123.***.***-09");
+    }
+
+    #[test]
+    fn test_expose_returns_raw_value() {
+        let secret: Secret<String> = "123.456.789-09".to_string().into();
+        assert_eq!(secret.expose(), "123.456.789-09");
+    }
+}
@@ -0,0 +1,491 @@
+//! PII-safe serde helpers
+//!
+//! Serialize documents in their masked form so DTOs that are logged or
+//! returned by internal endpoints never expose the raw value.
+//!
+//! The [`cpf`], [`cnpj`], [`cep`] and [`phone`] modules are field adapters
+//! for plain `String` fields: `#[serde(with = "...::serde::cpf")]` validates
+//! on deserialization and emits the normalized document, while
+//! `#[serde(with = "...::serde::cpf::formatted")]` emits it formatted.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::serde::Masked;
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Customer {
+//!     #[serde(serialize_with = "pleme_brazilian_validators::serde::masked_cpf")]
+//!     cpf: String,
+//!     contact: Masked<String>,
+//! }
+//! ```
+
+use crate::config::Config;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{scan, secret};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+
+/// Serialize a CPF field in masked form (`123.***.***-09`)
+///
+/// Like the other `masked_*` helpers, a value that is not recognized as
+/// the expected document is replaced with `***` rather than emitted as is.
+///
+/// Use with `#[serde(serialize_with = "pleme_brazilian_validators::serde::masked_cpf")]`.
+pub fn masked_cpf<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str> + ?Sized,
+    S: Serializer,
+{
+    serialize_masked(value.as_ref(), crate::cpf::mask, serializer)
+}
+
+/// Serialize a CNPJ field in masked form (`11.***.***/**01-81`)
+pub fn masked_cnpj<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str> + ?Sized,
+    S: Serializer,
+{
+    serialize_masked(value.as_ref(), crate::cnpj::mask, serializer)
+}
+
+/// Serialize a CEP field in masked form (`01310-***`)
+pub fn masked_cep<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str> + ?Sized,
+    S: Serializer,
+{
+    serialize_masked(value.as_ref(), crate::cep::mask, serializer)
+}
+
+/// Serialize a phone field in masked form (`(11) *****-4321`)
+pub fn masked_phone<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str> + ?Sized,
+    S: Serializer,
+{
+    serialize_masked(value.as_ref(), crate::phone::mask, serializer)
+}
+
+/// Serialize a PIX key field in masked form (masking depends on key type)
+pub fn masked_pix_key<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str> + ?Sized,
+    S: Serializer,
+{
+    serialize_masked(value.as_ref(), crate::pix::mask, serializer)
+}
+
+/// Serialize the output of a `mask` function, hiding values it left unchanged
+fn serialize_masked<S>(
+    raw: &str,
+    mask: fn(&str) -> String,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&secret::hide_unmasked(raw, mask(raw)))
+}
+
+/// Wrapper that serializes its value with every document masked
+///
+/// Any CPF, CNPJ, CEP, phone or PIX key found in the value is masked with
+/// the configured [`mask_style`](crate::config::ValidationOptions::mask_style)
+/// (partial by default, see [`scan::redact`]), so it works both for fields
+/// holding a single document and for free-text fields. A value in which no
+/// document is recognized is replaced with `***`, as by
+/// [`Secret::masked`](crate::secret::Secret::masked). `Debug` prints the
+/// same masked form. Deserialization is transparent and yields the raw value.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::serde::Masked;
+///
+/// let cpf = Masked("123.456.789-09".to_string());
+/// assert_eq!(cpf.0, "123.456.789-09");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Masked<T>(pub T);
+
+impl<T> Masked<T> {
+    /// Unwrap the raw value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<str>> Masked<T> {
+    /// Value with every document masked, or `***` if none is recognized
+    fn masked(&self) -> String {
+        let raw = self.0.as_ref();
+        let style = Config::current().mask_style;
+        secret::hide_unmasked(raw, scan::redact(raw, style))
+    }
+}
+
+impl<T: AsRef<str>> fmt::Debug for Masked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Masked").field(&self.masked()).finish()
+    }
+}
+
+impl<T> From<T> for Masked<T> {
+    fn from(value: T) -> Self {
+        Masked(value)
+    }
+}
+
+impl<T: AsRef<str>> Serialize for Masked<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.masked())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Masked<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Masked)
+    }
+}
+
+/// Deserialize a string and validate it, yielding the validator's output
+fn validated<'de, D>(
+    deserializer: D,
+    validate: fn(&str) -> ValidationResult<String>,
+) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    validate(&value).map_err(BrazilianValidationError::into_de_error)
+}
+
+/// CPF field adapter: validated on input, normalized on output
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Customer {
+///     #[serde(with = "pleme_brazilian_validators::serde::cpf")]
+///     cpf: String,
+///     #[serde(with = "pleme_brazilian_validators::serde::cpf::formatted")]
+///     guarantor: String,
+/// }
+///
+/// let json = r#"{"cpf":"123.456.789-09","guarantor":"52998224725"}"#;
+/// let customer: Customer = serde_json::from_str(json).unwrap();
+/// assert_eq!(customer.cpf, "12345678909");
+/// assert_eq!(
+///     serde_json::to_string(&customer).unwrap(),
+///     r#"{"cpf":"12345678909","guarantor":"529.982.247-25"}"#
+/// );
+///
+/// assert!(serde_json::from_str::<Customer>(r#"{"cpf":"123","guarantor":""}"#).is_err());
+/// ```
+pub mod cpf {
+    use super::*;
+
+    /// Serialize a CPF as its 11 digits
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<str> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_str(&crate::cpf::normalize(value.as_ref()))
+    }
+
+    /// Deserialize and validate a CPF in any format, yielding its 11 digits
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        validated(deserializer, crate::cpf::validate)
+    }
+
+    /// CPF field adapter: validated on input, formatted on output
+    pub mod formatted {
+        use super::*;
+
+        /// Serialize a CPF as `XXX.XXX.XXX-XX`
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: AsRef<str> + ?Sized,
+            S: Serializer,
+        {
+            serializer.serialize_str(&crate::cpf::format(value.as_ref()))
+        }
+
+        /// Deserialize and validate a CPF in any format, yielding it formatted
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer).map(|cpf| crate::cpf::format(&cpf))
+        }
+    }
+}
+
+/// CNPJ field adapter: validated on input, normalized on output
+///
+/// Alphanumeric CNPJs are accepted and normalized to uppercase.
+pub mod cnpj {
+    use super::*;
+
+    /// Serialize a CNPJ as its 14 characters
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<str> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_str(&crate::cnpj::normalize(value.as_ref()))
+    }
+
+    /// Deserialize and validate a CNPJ in any format, yielding its 14 characters
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        validated(deserializer, crate::cnpj::validate)
+    }
+
+    /// CNPJ field adapter: validated on input, formatted on output
+    pub mod formatted {
+        use super::*;
+
+        /// Serialize a CNPJ as `XX.XXX.XXX/XXXX-XX`
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: AsRef<str> + ?Sized,
+            S: Serializer,
+        {
+            serializer.serialize_str(&crate::cnpj::format(value.as_ref()))
+        }
+
+        /// Deserialize and validate a CNPJ in any format, yielding it formatted
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer).map(|cnpj| crate::cnpj::format(&cnpj))
+        }
+    }
+}
+
+/// CEP field adapter: validated on input, normalized on output
+pub mod cep {
+    use super::*;
+
+    /// Serialize a CEP as its 8 digits
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<str> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_str(&crate::cep::normalize(value.as_ref()))
+    }
+
+    /// Deserialize and validate a CEP in any format, yielding its 8 digits
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        validated(deserializer, crate::cep::validate)
+    }
+
+    /// CEP field adapter: validated on input, formatted on output
+    pub mod formatted {
+        use super::*;
+
+        /// Serialize a CEP as `XXXXX-XXX`
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: AsRef<str> + ?Sized,
+            S: Serializer,
+        {
+            serializer.serialize_str(&crate::cep::format(value.as_ref()))
+        }
+
+        /// Deserialize and validate a CEP in any format, yielding it formatted
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer).map(|cep| crate::cep::format(&cep))
+        }
+    }
+}
+
+/// Phone field adapter: validated on input, E.164 on output
+pub mod phone {
+    use super::*;
+
+    /// Serialize a phone number without punctuation (`+5511987654321`)
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<str> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_str(&crate::phone::normalize(value.as_ref()))
+    }
+
+    /// Deserialize and validate a phone number, yielding it as `+55...`
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        validated(deserializer, crate::phone::validate)
+    }
+
+    /// Phone field adapter: validated on input, formatted on output
+    pub mod formatted {
+        use super::*;
+
+        /// Serialize a phone number as `+55 (XX) XXXXX-XXXX`
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: AsRef<str> + ?Sized,
+            S: Serializer,
+        {
+            serializer.serialize_str(&crate::phone::format(value.as_ref()))
+        }
+
+        /// Deserialize and validate a phone number, yielding it formatted
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer).map(|phone| crate::phone::format(&phone))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Customer {
+        #[serde(serialize_with = "masked_cpf")]
+        cpf: String,
+        #[serde(serialize_with = "masked_cnpj")]
+        cnpj: &'static str,
+        #[serde(serialize_with = "masked_phone")]
+        phone: String,
+        #[serde(serialize_with = "masked_pix_key")]
+        pix_key: String,
+    }
+
+    #[test]
+    fn test_masked_field_helpers() {
+        let customer = Customer {
+            cpf: "12345678909".to_string(),
+            cnpj: "11222333000181",
+            phone: "11987654321".to_string(),
+            pix_key: "user@example.com".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&customer).unwrap(),
+            r#"{"cpf":"123.***.***-09","cnpj":"11.***.***/**01-81","phone":"(11) *****-4321","pix_key":"u***@example.com"}"#
+        );
+    }
+
+    #[test]
+    fn test_masked_wrapper() {
+        let masked = Masked("CPF 123.456.789-09".to_string());
+        assert_eq!(
+            serde_json::to_string(&masked).unwrap(),
+            r#""CPF 123.***.***-09""#
+        );
+    }
+
+    #[test]
+    fn test_masked_wrapper_debug_is_masked() {
+        let cpf = Masked("123.456.789-09".to_string());
+        let debug = format!("{:?}", cpf);
+        assert_eq!(debug, r#"Masked("123.***.***-09")"#);
+        assert!(!debug.contains("456") && !debug.contains("789"));
+
+        assert_eq!(format!("{:?}", Masked("12345678900")), r#"Masked("***")"#);
+    }
+
+    #[test]
+    fn test_masked_field_helpers_hide_malformed_values() {
+        let customer = Customer {
+            cpf: "123.456.789-0".to_string(),
+            cnpj: "not a cnpj",
+            phone: "98-76".to_string(),
+            pix_key: "chave?".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&customer).unwrap(),
+            r#"{"cpf":"***","cnpj":"***","phone":"***","pix_key":"***"}"#
+        );
+
+        #[derive(Serialize)]
+        struct Address {
+            #[serde(serialize_with = "masked_cep")]
+            cep: &'static str,
+        }
+        let address = Address { cep: "0131-100" };
+        assert_eq!(serde_json::to_string(&address).unwrap(), r#"{"cep":"***"}"#);
+    }
+
+    #[test]
+    fn test_masked_wrapper_hides_unrecognized_values() {
+        // Bad check digits: not detected as a CPF, so not masked by redaction
+        let masked = Masked("12345678900");
+        assert_eq!(serde_json::to_string(&masked).unwrap(), r#""***""#);
+        assert_eq!(serde_json::to_string(&Masked("")).unwrap(), r#""""#);
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Company {
+        #[serde(with = "cnpj")]
+        cnpj: String,
+        #[serde(with = "cep::formatted")]
+        cep: String,
+        #[serde(with = "phone")]
+        phone: String,
+        #[serde(with = "phone::formatted")]
+        fax: String,
+    }
+
+    #[test]
+    fn test_field_adapters() {
+        let json = r#"{"cnpj":"12.abc.345/01de-35","cep":"01310100","phone":"(11) 98765-4321","fax":"1134567890"}"#;
+        let company: Company = serde_json::from_str(json).unwrap();
+        assert_eq!(company.cnpj, "12ABC34501DE35");
+        assert_eq!(company.cep, "01310-100");
+        assert_eq!(company.phone, "+5511987654321");
+        assert_eq!(company.fax, "+55 (11) 3456-7890");
+
+        assert_eq!(
+            serde_json::to_string(&company).unwrap(),
+            r#"{"cnpj":"12ABC34501DE35","cep":"01310-100","phone":"+5511987654321","fax":"+55 (11) 3456-7890"}"#
+        );
+    }
+
+    #[test]
+    fn test_field_adapters_reject_invalid_documents() {
+        let json = r#"{"cnpj":"11222333000180","cep":"01310100","phone":"11987654321","fax":"1134567890"}"#;
+        let error = serde_json::from_str::<Company>(json).unwrap_err();
+        assert!(error.to_string().contains("CNPJ"), "{}", error);
+    }
+
+    #[test]
+    fn test_masked_wrapper_deserializes_raw_value() {
+        let masked: Masked<String> = serde_json::from_str(r#""12345678909""#).unwrap();
+        assert_eq!(masked.into_inner(), "12345678909");
+    }
+}
@@ -0,0 +1,260 @@
+//! Hand-rolled format checks
+//!
+//! Byte-scanning equivalents of the [`patterns`](crate::patterns) regular
+//! expressions, so the core validators need neither `regex` nor `std`.
+//! Each function accepts exactly the strings its pattern matches.
+
+/// Position in the input being matched
+#[derive(Clone, Copy)]
+struct Cursor<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Cursor { rest: input }
+    }
+
+    /// Consume exactly `count` bytes accepted by `class`
+    fn take(&mut self, count: usize, class: fn(&u8) -> bool) -> bool {
+        let bytes = self.rest.as_bytes();
+        if bytes.len() < count || !bytes[..count].iter().all(class) {
+            return false;
+        }
+        self.rest = &self.rest[count..];
+        true
+    }
+
+    /// Consume an optional `separator`, then `count` bytes accepted by `class`
+    fn field(&mut self, separator: u8, count: usize, class: fn(&u8) -> bool) -> bool {
+        self.skip(separator);
+        self.take(count, class)
+    }
+
+    /// Consume `byte` if it comes next
+    fn skip(&mut self, byte: u8) {
+        if let Some(rest) = self.rest.strip_prefix(char::from(byte)) {
+            self.rest = rest;
+        }
+    }
+
+    /// Consume `prefix` if it comes next, reporting whether it did
+    fn accept(&mut self, prefix: &str) -> bool {
+        match self.rest.strip_prefix(prefix) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consume one whitespace character (`\s`, Unicode aware) if it comes next
+    fn skip_whitespace(&mut self) {
+        let mut chars = self.rest.chars();
+        if chars.next().is_some_and(char::is_whitespace) {
+            self.rest = chars.as_str();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+}
+
+fn digit(byte: &u8) -> bool {
+    byte.is_ascii_digit()
+}
+
+fn alphanumeric(byte: &u8) -> bool {
+    byte.is_ascii_alphanumeric()
+}
+
+fn hex(byte: &u8) -> bool {
+    byte.is_ascii_hexdigit()
+}
+
+/// [`patterns::CPF`](crate::patterns::CPF)
+pub(crate) fn is_cpf(input: &str) -> bool {
+    let mut cursor = Cursor::new(input);
+    cursor.take(3, digit)
+        && cursor.field(b'.', 3, digit)
+        && cursor.field(b'.', 3, digit)
+        && cursor.field(b'-', 2, digit)
+        && cursor.is_empty()
+}
+
+/// [`patterns::CNPJ`](crate::patterns::CNPJ)
+pub(crate) fn is_cnpj(input: &str) -> bool {
+    let mut cursor = Cursor::new(input);
+    cursor.take(2, alphanumeric)
+        && cursor.field(b'.', 3, alphanumeric)
+        && cursor.field(b'.', 3, alphanumeric)
+        && cursor.field(b'/', 4, alphanumeric)
+        && cursor.field(b'-', 2, digit)
+        && cursor.is_empty()
+}
+
+/// [`patterns::CEP`](crate::patterns::CEP)
+pub(crate) fn is_cep(input: &str) -> bool {
+    let mut cursor = Cursor::new(input);
+    cursor.take(5, digit) && cursor.field(b'-', 3, digit) && cursor.is_empty()
+}
+
+/// [`patterns::PHONE`](crate::patterns::PHONE)
+pub(crate) fn is_phone(input: &str) -> bool {
+    let mut cursor = Cursor::new(input);
+    if cursor.accept("+55") {
+        cursor.skip_whitespace();
+    }
+
+    // The optional DDD is made of digits too: try with and without it
+    let mut with_ddd = cursor;
+    if with_ddd.field(b'(', 2, digit) {
+        with_ddd.skip(b')');
+        with_ddd.skip_whitespace();
+        if is_subscriber(with_ddd) {
+            return true;
+        }
+    }
+    is_subscriber(cursor)
+}
+
+/// `[0-9]{4,5}[-\s]?[0-9]{4}$`
+fn is_subscriber(cursor: Cursor) -> bool {
+    [4, 5].into_iter().any(|prefix| {
+        let mut cursor = cursor;
+        if !cursor.take(prefix, digit) {
+            return false;
+        }
+        if !cursor.accept("-") {
+            cursor.skip_whitespace();
+        }
+        cursor.take(4, digit) && cursor.is_empty()
+    })
+}
+
+/// [`patterns::PIX_EMAIL`](crate::patterns::PIX_EMAIL)
+pub(crate) fn is_pix_email(input: &str) -> bool {
+    let Some((local, domain)) = input.split_once('@') else {
+        return false;
+    };
+    // The top-level domain cannot hold a dot, so it follows the last one
+    let Some((host, tld)) = domain.rsplit_once('.') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && local
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"._%+-".contains(&b))
+        && !host.is_empty()
+        && host
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-')
+        && tld.len() >= 2
+        && tld.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// [`patterns::PIX_PHONE`](crate::patterns::PIX_PHONE)
+pub(crate) fn is_pix_phone(input: &str) -> bool {
+    let mut cursor = Cursor::new(input);
+    cursor.accept("+55") && cursor.take(11, digit) && cursor.is_empty()
+}
+
+/// [`patterns::PIX_EVP`](crate::patterns::PIX_EVP), ignoring case
+pub(crate) fn is_pix_evp(input: &str) -> bool {
+    let mut cursor = Cursor::new(input);
+    cursor.take(8, hex)
+        && [4, 4, 4, 12]
+            .into_iter()
+            .all(|count| cursor.accept("-") && cursor.take(count, hex))
+        && cursor.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+    use regex::Regex;
+
+    /// Inputs exercising every optional part of the patterns
+    const INPUTS: &[&str] = &[
+        "",
+        "123.456.789-09",
+        "12345678909",
+        "123456789-09",
+        "123.456.78909",
+        "123..456.789-09",
+        "1234567890",
+        "123456789090",
+        "١٢٣.٤٥٦.٧٨٩-٠٩",
+        "11.222.333/0001-81",
+        "11222333000181",
+        "12.ABC.345/01DE-35",
+        "12ABC34501DE3A",
+        "12.abc.345/01de-35",
+        "01310-100",
+        "01310100",
+        "01310--100",
+        "０１３１０-１００",
+        "+55 (11) 98765-4321",
+        "+55(11)98765-4321",
+        "+5511987654321",
+        "+55 11 98765 4321",
+        "+55\u{a0}11\u{2003}987654321",
+        "(11) 3456-7890",
+        "(11 3456-7890",
+        "11) 34567890",
+        "11987654321",
+        "987654321",
+        "98765432",
+        "9876543",
+        "1198765432100",
+        "98765-4321",
+        "98765 - 4321",
+        "+55 98765-4321",
+        "+55  11 98765-4321",
+        "55 11 98765-4321",
+        "user@example.com",
+        "first.last+pix@sub.example.com.br",
+        "user@example.c",
+        "user@example",
+        "@example.com",
+        "user@.com",
+        "user@@example.com",
+        "us er@example.com",
+        "user@exam_ple.com",
+        "user@example.c0m",
+        "ü@é.com",
+        "123e4567-e89b-12d3-a456-426614174000",
+        "123E4567-E89B-12D3-A456-426614174000",
+        "123e4567-e89b-12d3-a456-4266141740000",
+        "123e4567e89b12d3a456426614174000",
+        "123e4567-e89b-12d3-a456-42661417400g",
+    ];
+
+    fn assert_same(pattern: &str, check: fn(&str) -> bool) {
+        let regex = Regex::new(pattern).unwrap();
+        for input in INPUTS {
+            assert_eq!(
+                check(input),
+                regex.is_match(input),
+                "{pattern} on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_checks_match_patterns() {
+        assert_same(patterns::CPF, is_cpf);
+        assert_same(patterns::CNPJ, is_cnpj);
+        assert_same(patterns::CEP, is_cep);
+        assert_same(patterns::PHONE, is_phone);
+        assert_same(patterns::PIX_EMAIL, is_pix_email);
+        assert_same(patterns::PIX_PHONE, is_pix_phone);
+        assert_same(patterns::PIX_EVP, |input| {
+            is_pix_evp(input) && !input.bytes().any(|b| b.is_ascii_uppercase())
+        });
+    }
+}
@@ -0,0 +1,280 @@
+//! Título de Eleitor (voter registration) validation and formatting
+//!
+//! A voter registration number has 12 digits: an 8-digit sequence, the
+//! 2-digit code of the state where the voter registered (`01` São Paulo to
+//! `27` Tocantins, `28` for voters abroad) and two modulo 11 check digits.
+//! São Paulo and Minas Gerais titles turn a check digit of 0 into 1.
+
+use crate::document::DocumentKind;
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::uf::Uf;
+use crate::{hooks, unicode};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Number of digits of a voter registration number
+pub const LEN: usize = 12;
+
+/// Code of the voters registered abroad (ZZ)
+pub const ABROAD: u8 = 28;
+
+/// Federative unit of each electoral state code, from `01`
+const STATES: [Uf; 27] = [
+    Uf::SP,
+    Uf::MG,
+    Uf::RJ,
+    Uf::RS,
+    Uf::BA,
+    Uf::PR,
+    Uf::CE,
+    Uf::PE,
+    Uf::SC,
+    Uf::GO,
+    Uf::MA,
+    Uf::PB,
+    Uf::PA,
+    Uf::ES,
+    Uf::PI,
+    Uf::RN,
+    Uf::AL,
+    Uf::MT,
+    Uf::MS,
+    Uf::DF,
+    Uf::SE,
+    Uf::AM,
+    Uf::RO,
+    Uf::AC,
+    Uf::AP,
+    Uf::RR,
+    Uf::TO,
+];
+
+/// Validate a voter registration number
+///
+/// Validates length, the state code (`01` to `28`) and both check digits.
+///
+/// # Arguments
+/// * `titulo` - Título de eleitor (non-digit characters are ignored)
+///
+/// # Returns
+/// * `Ok(String)` - Normalized number (12 digits only)
+/// * `Err(BrazilianValidationError)` - Validation error
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::titulo_eleitor::validate;
+///
+/// assert!(validate("0043 5687 0906").is_ok());
+/// assert!(validate("004356870907").is_err()); // Invalid check digits
+/// assert!(validate("004356873006").is_err()); // No state 30
+/// ```
+pub fn validate(titulo: &str) -> ValidationResult<String> {
+    let result = check(titulo);
+    hooks::notify(DocumentKind::TituloEleitor, &result);
+    result
+}
+
+fn check(titulo: &str) -> ValidationResult<String> {
+    let cleaned = normalize(&unicode::normalize_if_enabled(titulo));
+
+    if cleaned.len() != LEN {
+        return Err(BrazilianValidationError::InvalidLength {
+            expected: LEN,
+            actual: cleaned.len(),
+        });
+    }
+
+    let digits: Vec<u32> = cleaned.bytes().map(|b| u32::from(b - b'0')).collect();
+    let state = digits[8] * 10 + digits[9];
+    if !(1..=u32::from(ABROAD)).contains(&state) {
+        return Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::TituloEleitor,
+        });
+    }
+
+    if compute_check_digits(&digits[..10]) != [digits[10], digits[11]] {
+        return Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::TituloEleitor,
+        });
+    }
+
+    Ok(cleaned)
+}
+
+/// Compute both check digits for the sequence and state code (10 digits)
+pub(crate) fn compute_check_digits(base: &[u32]) -> [u32; 2] {
+    // São Paulo (01) and Minas Gerais (02) use 1 where others use 0
+    let sp_or_mg = base[8] == 0 && (base[9] == 1 || base[9] == 2);
+    let check_digit = |sum: u32| match sum % 11 {
+        10 => 0,
+        0 if sp_or_mg => 1,
+        remainder => remainder,
+    };
+
+    // First digit: the sequence, weights 2 to 9 from the left
+    let check1 = check_digit(base[..8].iter().zip(2..=9).map(|(d, w)| d * w).sum());
+    // Second digit: state code and first check digit, weights 7 to 9
+    let check2 = check_digit(base[8] * 7 + base[9] * 8 + check1 * 9);
+
+    [check1, check2]
+}
+
+/// Get the federative unit where the voter registered
+///
+/// The state code is read without validating the number. Returns `None`
+/// for voters registered abroad (code `28`) and for codes out of range.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::titulo_eleitor::extract_state;
+/// use pleme_brazilian_validators::uf::Uf;
+///
+/// assert_eq!(extract_state("0043 5687 0906"), Some(Uf::SC));
+/// assert_eq!(extract_state("123"), None);
+/// ```
+pub fn extract_state(titulo: &str) -> Option<Uf> {
+    let code = state_code(titulo)?;
+    STATES.get(usize::from(code).checked_sub(1)?).copied()
+}
+
+/// Get the electoral state code (`1` to `28`, 9th and 10th digits)
+///
+/// Unlike [`extract_state`], tells voters abroad ([`ABROAD`]) apart from
+/// malformed numbers.
+pub fn state_code(titulo: &str) -> Option<u8> {
+    let cleaned = normalize(titulo);
+    if cleaned.len() != LEN {
+        return None;
+    }
+    cleaned[8..10]
+        .parse()
+        .ok()
+        .filter(|code| (1..=ABROAD).contains(code))
+}
+
+/// Normalize a voter registration number by removing non-digit characters
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::titulo_eleitor::normalize;
+///
+/// assert_eq!(normalize("0043 5687 0906"), "004356870906");
+/// ```
+pub fn normalize(titulo: &str) -> String {
+    titulo.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Format a voter registration number as printed on the card (XXXX XXXX XXXX)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::titulo_eleitor::format;
+///
+/// assert_eq!(format("004356870906"), "0043 5687 0906");
+/// ```
+pub fn format(titulo: &str) -> String {
+    let cleaned = normalize(titulo);
+
+    if cleaned.len() == LEN {
+        format!("{} {} {}", &cleaned[..4], &cleaned[4..8], &cleaned[8..])
+    } else {
+        titulo.to_string()
+    }
+}
+
+/// Mask a voter registration number for display (shows the state code
+/// and check digits)
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::titulo_eleitor::mask;
+///
+/// assert_eq!(mask("004356870906"), "**** **** 0906");
+/// ```
+pub fn mask(titulo: &str) -> String {
+    let cleaned = normalize(titulo);
+
+    if cleaned.len() == LEN {
+        format!("**** **** {}", &cleaned[8..])
+    } else {
+        titulo.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_printed_layouts_are_accepted() {
+        for titulo in ["0043 5687 0906", "0043.5687.09-06", "004356870906"] {
+            assert_eq!(
+                validate(titulo).as_deref(),
+                Ok("004356870906"),
+                "{titulo:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sao_paulo_and_minas_gerais_rule() {
+        // First remainder is 0: São Paulo writes 1
+        assert!(validate("100000010116").is_ok());
+        assert!(validate("100000010108").is_err());
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        // Older cards printed 10 or 11 digits, without the leading zeros
+        for (titulo, actual) in [
+            ("4356870906", 10),
+            ("43568709066", 11),
+            ("0043568709060", 13),
+        ] {
+            assert_eq!(
+                validate(titulo),
+                Err(BrazilianValidationError::InvalidLength {
+                    expected: 12,
+                    actual
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_state_code_must_exist() {
+        let bad_state = Err(BrazilianValidationError::InvalidDocumentFormat {
+            document_type: DocumentKind::TituloEleitor,
+        });
+        // 00 and 29 are out of range; all zeros fails on the state as well
+        assert_eq!(validate("004356870006"), bad_state);
+        assert_eq!(validate("004356872906"), bad_state);
+        assert_eq!(validate("000000000000"), bad_state);
+        assert!(validate("876543212828").is_ok());
+    }
+
+    #[test]
+    fn test_each_check_digit_is_verified() {
+        let bad_digits = Err(BrazilianValidationError::InvalidCheckDigits {
+            document_type: DocumentKind::TituloEleitor,
+        });
+        assert_eq!(validate("004356870916"), bad_digits);
+        assert_eq!(validate("004356870907"), bad_digits);
+        // Repeated digits are only rejected by their check digits
+        assert_eq!(validate("111111111111"), bad_digits);
+        assert!(validate("111111111104").is_ok());
+    }
+
+    #[test]
+    fn test_states() {
+        assert_eq!(extract_state("102345670485"), Some(Uf::RS));
+        assert_eq!(extract_state("000000002700"), Some(Uf::TO));
+        assert_eq!(extract_state("876543212828"), None);
+        assert_eq!(state_code("876543212828"), Some(ABROAD));
+        assert_eq!(state_code("000000002900"), None);
+        for (i, uf) in STATES.iter().enumerate() {
+            assert!(!STATES[..i].contains(uf));
+        }
+    }
+}
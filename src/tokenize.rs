@@ -0,0 +1,207 @@
+//! Format-preserving tokenization of CPF and CNPJ
+//!
+//! Deterministically maps a document to another syntactically valid
+//! document under a secret key, using FF1 format-preserving encryption
+//! (NIST SP 800-38G) with AES-256 over the base digits. Check digits are
+//! recomputed, so tokenized datasets keep passing downstream validation
+//! without exposing real documents.
+//!
+//! Enabled by the `tokenize` feature.
+
+use crate::error::{BrazilianValidationError, ValidationResult};
+use crate::{cnpj, cpf};
+use aes::Aes256;
+use fpe::ff1::{FlexibleNumeralString, FF1};
+
+/// Tweak separating the CPF token space from the CNPJ one
+const CPF_TWEAK: &[u8] = b"CPF";
+
+/// Tweak separating the CNPJ token space from the CPF one
+const CNPJ_TWEAK: &[u8] = b"CNPJ";
+
+/// Document layout handled by the tokenizer
+struct Layout {
+    /// Number of base digits encrypted (check digits excluded)
+    base_len: usize,
+    tweak: &'static [u8],
+    compute_check_digits: fn(&[u32]) -> [u32; 2],
+    validate: fn(&str) -> ValidationResult<String>,
+}
+
+const CPF_LAYOUT: Layout = Layout {
+    base_len: 9,
+    tweak: CPF_TWEAK,
+    compute_check_digits: cpf::compute_check_digits,
+    validate: cpf::validate_unobserved,
+};
+
+const CNPJ_LAYOUT: Layout = Layout {
+    base_len: 12,
+    tweak: CNPJ_TWEAK,
+    compute_check_digits: cnpj::compute_check_digits,
+    validate: cnpj::validate_unobserved,
+};
+
+/// Keyed format-preserving tokenizer for CPF and CNPJ
+///
+/// The same key always produces the same token for a given document, and
+/// tokens can be reversed with [`Tokenizer::detokenize_cpf`] /
+/// [`Tokenizer::detokenize_cnpj`].
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{cpf, tokenize::Tokenizer};
+///
+/// let tokenizer = Tokenizer::new(&[7u8; 32]);
+///
+/// let token = tokenizer.tokenize_cpf("123.456.789-09").unwrap();
+/// assert!(cpf::validate(&token).is_ok());
+/// assert_ne!(token, "12345678909");
+/// assert_eq!(tokenizer.detokenize_cpf(&token).unwrap(), "12345678909");
+/// ```
+pub struct Tokenizer {
+    ff1: FF1<Aes256>,
+}
+
+impl Tokenizer {
+    /// Create a tokenizer from a 256-bit key
+    pub fn new(key: &[u8; 32]) -> Self {
+        let ff1 = FF1::<Aes256>::new(key, 10).expect("radix 10 is valid for FF1");
+        Tokenizer { ff1 }
+    }
+
+    /// Tokenize a CPF into another valid CPF
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Normalized token CPF (11 digits)
+    /// * `Err(BrazilianValidationError)` - Input is not a valid CPF
+    pub fn tokenize_cpf(&self, cpf: &str) -> ValidationResult<String> {
+        self.transform(cpf, &CPF_LAYOUT, Direction::Encrypt)
+    }
+
+    /// Recover the original CPF from a token
+    pub fn detokenize_cpf(&self, token: &str) -> ValidationResult<String> {
+        self.transform(token, &CPF_LAYOUT, Direction::Decrypt)
+    }
+
+    /// Tokenize a CNPJ into another valid CNPJ
+    ///
+    /// Only numeric CNPJs are supported: the token keeps the digits-only
+    /// format.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Normalized token CNPJ (14 digits)
+    /// * `Err(BrazilianValidationError)` - Input is not a valid numeric CNPJ
+    pub fn tokenize_cnpj(&self, cnpj: &str) -> ValidationResult<String> {
+        self.transform(cnpj, &CNPJ_LAYOUT, Direction::Encrypt)
+    }
+
+    /// Recover the original CNPJ from a token
+    pub fn detokenize_cnpj(&self, token: &str) -> ValidationResult<String> {
+        self.transform(token, &CNPJ_LAYOUT, Direction::Decrypt)
+    }
+
+    /// Encrypt or decrypt the base digits, cycle-walking past bases that
+    /// would produce an invalid document (repeated-digit sequences)
+    fn transform(
+        &self,
+        document: &str,
+        layout: &Layout,
+        direction: Direction,
+    ) -> ValidationResult<String> {
+        let normalized = (layout.validate)(document)?;
+        if !normalized.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(BrazilianValidationError::InvalidCharacters);
+        }
+        let mut base: Vec<u16> = normalized
+            .bytes()
+            .take(layout.base_len)
+            .map(|b| u16::from(b - b'0'))
+            .collect();
+
+        loop {
+            let numerals = FlexibleNumeralString::from(base);
+            let result = match direction {
+                Direction::Encrypt => self.ff1.encrypt(layout.tweak, &numerals),
+                Direction::Decrypt => self.ff1.decrypt(layout.tweak, &numerals),
+            };
+            base = result
+                .map_err(|_| BrazilianValidationError::InvalidCharacters)?
+                .into();
+
+            let document = build_document(&base, layout);
+            if (layout.validate)(&document).is_ok() {
+                return Ok(document);
+            }
+        }
+    }
+}
+
+/// Direction of the FF1 transformation
+#[derive(Clone, Copy)]
+enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+/// Append check digits to base numerals
+fn build_document(base: &[u16], layout: &Layout) -> String {
+    let digits: Vec<u32> = base.iter().map(|&d| u32::from(d)).collect();
+    let check_digits = (layout.compute_check_digits)(&digits);
+
+    digits
+        .iter()
+        .chain(check_digits.iter())
+        .filter_map(|&d| char::from_digit(d, 10))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 32] = [42; 32];
+
+    #[test]
+    fn test_cpf_round_trip() {
+        let tokenizer = Tokenizer::new(&KEY);
+        for cpf in ["12345678909", "529.982.247-25", "11144477735"] {
+            let token = tokenizer.tokenize_cpf(cpf).unwrap();
+            assert!(cpf::validate(&token).is_ok());
+            assert_eq!(tokenizer.detokenize_cpf(&token).unwrap(), cpf::normalize(cpf));
+        }
+    }
+
+    #[test]
+    fn test_cnpj_round_trip() {
+        let tokenizer = Tokenizer::new(&KEY);
+        let token = tokenizer.tokenize_cnpj("11.222.333/0001-81").unwrap();
+        assert!(cnpj::validate(&token).is_ok());
+        assert_eq!(tokenizer.detokenize_cnpj(&token).unwrap(), "11222333000181");
+    }
+
+    #[test]
+    fn test_tokenization_is_deterministic_per_key() {
+        let a = Tokenizer::new(&KEY);
+        let b = Tokenizer::new(&[1; 32]);
+        assert_eq!(
+            a.tokenize_cpf("12345678909").unwrap(),
+            a.tokenize_cpf("123.456.789-09").unwrap()
+        );
+        assert_ne!(
+            a.tokenize_cpf("12345678909").unwrap(),
+            b.tokenize_cpf("12345678909").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_input_is_rejected() {
+        let tokenizer = Tokenizer::new(&KEY);
+        assert!(tokenizer.tokenize_cpf("123.456.789-00").is_err());
+        assert!(tokenizer.tokenize_cnpj("11111111111111").is_err());
+        assert_eq!(
+            tokenizer.tokenize_cnpj("12.ABC.345/01DE-35"),
+            Err(BrazilianValidationError::InvalidCharacters)
+        );
+    }
+}
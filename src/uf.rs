@@ -0,0 +1,575 @@
+//! Brazilian federative units (UF)
+//!
+//! The 26 states plus the Federal District, with their official
+//! abbreviations, names, IBGE codes, geographic regions, capitals and
+//! DDDs. Modules that
+//! report a state (CEP ranges, DDDs, lookups) use [`Uf`] instead of ad-hoc
+//! strings.
+
+use crate::ascii;
+use crate::document::DocumentKind;
+use crate::error::BrazilianValidationError;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+/// Geographic region (macrorregião) as defined by IBGE
+///
+/// Serialized in snake case (`"centro_oeste"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Region {
+    Norte,
+    Nordeste,
+    CentroOeste,
+    Sudeste,
+    Sul,
+}
+
+impl Region {
+    /// Every region
+    pub const ALL: [Region; 5] = [
+        Region::Norte,
+        Region::Nordeste,
+        Region::CentroOeste,
+        Region::Sudeste,
+        Region::Sul,
+    ];
+
+    /// Iterate over every region
+    pub fn iter() -> impl Iterator<Item = Region> {
+        Region::ALL.into_iter()
+    }
+
+    /// Official region name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Region::Norte => "Norte",
+            Region::Nordeste => "Nordeste",
+            Region::CentroOeste => "Centro-Oeste",
+            Region::Sudeste => "Sudeste",
+            Region::Sul => "Sul",
+        }
+    }
+
+    /// Federative units in the region
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::{Region, Uf};
+    ///
+    /// assert_eq!(Region::Sul.ufs().collect::<Vec<_>>(), vec![Uf::PR, Uf::SC, Uf::RS]);
+    /// ```
+    pub fn ufs(self) -> impl Iterator<Item = Uf> {
+        Uf::ALL.into_iter().filter(move |uf| uf.region() == self)
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Federative unit: one of the 26 states or the Federal District
+///
+/// Variants are ordered by IBGE code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Uf {
+    RO,
+    AC,
+    AM,
+    RR,
+    PA,
+    AP,
+    TO,
+    MA,
+    PI,
+    CE,
+    RN,
+    PB,
+    PE,
+    AL,
+    SE,
+    BA,
+    MG,
+    ES,
+    RJ,
+    SP,
+    PR,
+    SC,
+    RS,
+    MS,
+    MT,
+    GO,
+    DF,
+}
+
+impl Uf {
+    /// Every federative unit, ordered by IBGE code
+    pub const ALL: [Uf; 27] = [
+        Uf::RO,
+        Uf::AC,
+        Uf::AM,
+        Uf::RR,
+        Uf::PA,
+        Uf::AP,
+        Uf::TO,
+        Uf::MA,
+        Uf::PI,
+        Uf::CE,
+        Uf::RN,
+        Uf::PB,
+        Uf::PE,
+        Uf::AL,
+        Uf::SE,
+        Uf::BA,
+        Uf::MG,
+        Uf::ES,
+        Uf::RJ,
+        Uf::SP,
+        Uf::PR,
+        Uf::SC,
+        Uf::RS,
+        Uf::MS,
+        Uf::MT,
+        Uf::GO,
+        Uf::DF,
+    ];
+
+    /// Iterate over every federative unit, ordered by IBGE code
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// let options: Vec<_> = Uf::iter().map(|uf| (uf.abbreviation(), uf.name())).collect();
+    /// assert_eq!(options.len(), 27);
+    /// assert_eq!(options[0], ("RO", "Rondônia"));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Uf> {
+        Uf::ALL.into_iter()
+    }
+
+    /// Two-letter abbreviation (`"SP"`)
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Uf::RO => "RO",
+            Uf::AC => "AC",
+            Uf::AM => "AM",
+            Uf::RR => "RR",
+            Uf::PA => "PA",
+            Uf::AP => "AP",
+            Uf::TO => "TO",
+            Uf::MA => "MA",
+            Uf::PI => "PI",
+            Uf::CE => "CE",
+            Uf::RN => "RN",
+            Uf::PB => "PB",
+            Uf::PE => "PE",
+            Uf::AL => "AL",
+            Uf::SE => "SE",
+            Uf::BA => "BA",
+            Uf::MG => "MG",
+            Uf::ES => "ES",
+            Uf::RJ => "RJ",
+            Uf::SP => "SP",
+            Uf::PR => "PR",
+            Uf::SC => "SC",
+            Uf::RS => "RS",
+            Uf::MS => "MS",
+            Uf::MT => "MT",
+            Uf::GO => "GO",
+            Uf::DF => "DF",
+        }
+    }
+
+    /// Official name (`"São Paulo"`)
+    ///
+    /// Accent-free (`"Sao Paulo"`) when
+    /// [`ascii_names`](crate::config::ValidationOptions::ascii_names) is enabled.
+    pub fn name(&self) -> &'static str {
+        ascii::name(match self {
+            Uf::RO => "Rondônia",
+            Uf::AC => "Acre",
+            Uf::AM => "Amazonas",
+            Uf::RR => "Roraima",
+            Uf::PA => "Pará",
+            Uf::AP => "Amapá",
+            Uf::TO => "Tocantins",
+            Uf::MA => "Maranhão",
+            Uf::PI => "Piauí",
+            Uf::CE => "Ceará",
+            Uf::RN => "Rio Grande do Norte",
+            Uf::PB => "Paraíba",
+            Uf::PE => "Pernambuco",
+            Uf::AL => "Alagoas",
+            Uf::SE => "Sergipe",
+            Uf::BA => "Bahia",
+            Uf::MG => "Minas Gerais",
+            Uf::ES => "Espírito Santo",
+            Uf::RJ => "Rio de Janeiro",
+            Uf::SP => "São Paulo",
+            Uf::PR => "Paraná",
+            Uf::SC => "Santa Catarina",
+            Uf::RS => "Rio Grande do Sul",
+            Uf::MS => "Mato Grosso do Sul",
+            Uf::MT => "Mato Grosso",
+            Uf::GO => "Goiás",
+            Uf::DF => "Distrito Federal",
+        })
+    }
+
+    /// Two-digit IBGE code (`35` for São Paulo)
+    pub fn ibge_code(&self) -> u8 {
+        match self {
+            Uf::RO => 11,
+            Uf::AC => 12,
+            Uf::AM => 13,
+            Uf::RR => 14,
+            Uf::PA => 15,
+            Uf::AP => 16,
+            Uf::TO => 17,
+            Uf::MA => 21,
+            Uf::PI => 22,
+            Uf::CE => 23,
+            Uf::RN => 24,
+            Uf::PB => 25,
+            Uf::PE => 26,
+            Uf::AL => 27,
+            Uf::SE => 28,
+            Uf::BA => 29,
+            Uf::MG => 31,
+            Uf::ES => 32,
+            Uf::RJ => 33,
+            Uf::SP => 35,
+            Uf::PR => 41,
+            Uf::SC => 42,
+            Uf::RS => 43,
+            Uf::MS => 50,
+            Uf::MT => 51,
+            Uf::GO => 52,
+            Uf::DF => 53,
+        }
+    }
+
+    /// Capital (`"São Paulo"`); the Federal District's is Brasília
+    ///
+    /// Accent-free when
+    /// [`ascii_names`](crate::config::ValidationOptions::ascii_names) is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// assert_eq!(Uf::PR.capital(), "Curitiba");
+    /// ```
+    pub fn capital(&self) -> &'static str {
+        ascii::name(match self {
+            Uf::RO => "Porto Velho",
+            Uf::AC => "Rio Branco",
+            Uf::AM => "Manaus",
+            Uf::RR => "Boa Vista",
+            Uf::PA => "Belém",
+            Uf::AP => "Macapá",
+            Uf::TO => "Palmas",
+            Uf::MA => "São Luís",
+            Uf::PI => "Teresina",
+            Uf::CE => "Fortaleza",
+            Uf::RN => "Natal",
+            Uf::PB => "João Pessoa",
+            Uf::PE => "Recife",
+            Uf::AL => "Maceió",
+            Uf::SE => "Aracaju",
+            Uf::BA => "Salvador",
+            Uf::MG => "Belo Horizonte",
+            Uf::ES => "Vitória",
+            Uf::RJ => "Rio de Janeiro",
+            Uf::SP => "São Paulo",
+            Uf::PR => "Curitiba",
+            Uf::SC => "Florianópolis",
+            Uf::RS => "Porto Alegre",
+            Uf::MS => "Campo Grande",
+            Uf::MT => "Cuiabá",
+            Uf::GO => "Goiânia",
+            Uf::DF => "Brasília",
+        })
+    }
+
+    /// Area codes (DDDs) serving the state, the capital's first
+    ///
+    /// The counterpart of [`phone::uf_for_ddd`](crate::phone::uf_for_ddd).
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// assert_eq!(Uf::RJ.ddds(), ["21", "22", "24"]);
+    /// assert_eq!(Uf::DF.ddds(), ["61"]);
+    /// ```
+    pub fn ddds(&self) -> &'static [&'static str] {
+        match self {
+            Uf::RO => &["69"],
+            Uf::AC => &["68"],
+            Uf::AM => &["92", "97"],
+            Uf::RR => &["95"],
+            Uf::PA => &["91", "93", "94"],
+            Uf::AP => &["96"],
+            Uf::TO => &["63"],
+            Uf::MA => &["98", "99"],
+            Uf::PI => &["86", "89"],
+            Uf::CE => &["85", "88"],
+            Uf::RN => &["84"],
+            Uf::PB => &["83"],
+            Uf::PE => &["81", "87"],
+            Uf::AL => &["82"],
+            Uf::SE => &["79"],
+            Uf::BA => &["71", "73", "74", "75", "77"],
+            Uf::MG => &["31", "32", "33", "34", "35", "37", "38"],
+            Uf::ES => &["27", "28"],
+            Uf::RJ => &["21", "22", "24"],
+            Uf::SP => &["11", "12", "13", "14", "15", "16", "17", "18", "19"],
+            Uf::PR => &["41", "42", "43", "44", "45", "46"],
+            Uf::SC => &["48", "47", "49"],
+            Uf::RS => &["51", "53", "54", "55"],
+            Uf::MS => &["67"],
+            Uf::MT => &["65", "66"],
+            Uf::GO => &["62", "64"],
+            Uf::DF => &["61"],
+        }
+    }
+
+    /// IANA time zone of the state capital (`"America/Sao_Paulo"`)
+    ///
+    /// States spanning several time zones (Amazonas, Mato Grosso, Pará)
+    /// report their capital's; no state observes daylight saving time.
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// assert_eq!(Uf::AM.timezone(), "America/Manaus");
+    /// assert_eq!(Uf::DF.timezone(), "America/Sao_Paulo");
+    /// ```
+    pub fn timezone(&self) -> &'static str {
+        match self {
+            Uf::AC => "America/Rio_Branco",
+            Uf::AM => "America/Manaus",
+            Uf::RR => "America/Boa_Vista",
+            Uf::RO => "America/Porto_Velho",
+            Uf::MT => "America/Cuiaba",
+            Uf::MS => "America/Campo_Grande",
+            Uf::PA | Uf::AP => "America/Belem",
+            Uf::TO => "America/Araguaina",
+            Uf::MA | Uf::PI | Uf::CE | Uf::RN | Uf::PB => "America/Fortaleza",
+            Uf::PE => "America/Recife",
+            Uf::AL | Uf::SE => "America/Maceio",
+            Uf::BA => "America/Bahia",
+            Uf::MG | Uf::ES | Uf::RJ | Uf::SP | Uf::PR | Uf::SC | Uf::RS | Uf::GO | Uf::DF => {
+                "America/Sao_Paulo"
+            }
+        }
+    }
+
+    /// Look up a federative unit by IBGE code
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// assert_eq!(Uf::from_ibge_code(35), Some(Uf::SP));
+    /// assert_eq!(Uf::from_ibge_code(34), None);
+    /// ```
+    pub fn from_ibge_code(code: u8) -> Option<Self> {
+        Uf::ALL.into_iter().find(|uf| uf.ibge_code() == code)
+    }
+
+    /// Geographic region; the first digit of the IBGE code
+    pub fn region(&self) -> Region {
+        match self.ibge_code() / 10 {
+            1 => Region::Norte,
+            2 => Region::Nordeste,
+            3 => Region::Sudeste,
+            4 => Region::Sul,
+            _ => Region::CentroOeste,
+        }
+    }
+}
+
+impl fmt::Display for Uf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+impl FromStr for Uf {
+    type Err = BrazilianValidationError;
+
+    /// Parse an abbreviation or full name, ignoring case and accents
+    ///
+    /// # Examples
+    /// ```
+    /// use pleme_brazilian_validators::uf::Uf;
+    ///
+    /// assert_eq!("sp".parse::<Uf>(), Ok(Uf::SP));
+    /// assert_eq!("Sao Paulo".parse::<Uf>(), Ok(Uf::SP));
+    /// assert_eq!(" ESPÍRITO SANTO ".parse::<Uf>(), Ok(Uf::ES));
+    /// assert!("XX".parse::<Uf>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = fold(s);
+
+        Uf::ALL
+            .into_iter()
+            .find(|uf| key == fold(uf.abbreviation()) || key == fold(uf.name()))
+            .ok_or(BrazilianValidationError::InvalidDocumentFormat {
+                document_type: DocumentKind::Uf,
+            })
+    }
+}
+
+impl TryFrom<&str> for Uf {
+    type Error = BrazilianValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Lowercase, strip Portuguese accents and collapse whitespace
+fn fold(s: &str) -> String {
+    s.split_whitespace()
+        .flat_map(|word| core::iter::once(' ').chain(word.chars()))
+        .skip(1)
+        .flat_map(char::to_lowercase)
+        .map(ascii::fold_char)
+        .collect()
+}
+
+/// Serialized as the abbreviation (`"SP"`); deserializes from anything
+/// [`FromStr`] accepts
+#[cfg(feature = "serialization")]
+impl serde::Serialize for Uf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.abbreviation())
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for Uf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_all_round_trips() {
+        for uf in Uf::ALL {
+            assert_eq!(uf.abbreviation().parse::<Uf>(), Ok(uf));
+            assert_eq!(uf.name().parse::<Uf>(), Ok(uf));
+            assert_eq!(Uf::from_ibge_code(uf.ibge_code()), Some(uf));
+        }
+    }
+
+    #[test]
+    fn test_all_is_sorted_by_ibge_code() {
+        assert!(Uf::ALL.windows(2).all(|w| w[0] < w[1]));
+        assert!(Uf::ALL.windows(2).all(|w| w[0].ibge_code() < w[1].ibge_code()));
+    }
+
+    #[test]
+    fn test_regions() {
+        assert_eq!(Uf::AM.region(), Region::Norte);
+        assert_eq!(Uf::BA.region(), Region::Nordeste);
+        assert_eq!(Uf::DF.region(), Region::CentroOeste);
+        assert_eq!(Uf::ES.region(), Region::Sudeste);
+        assert_eq!(Uf::RS.region(), Region::Sul);
+
+        let counts: Vec<usize> = [
+            Region::Norte,
+            Region::Nordeste,
+            Region::CentroOeste,
+            Region::Sudeste,
+            Region::Sul,
+        ]
+        .into_iter()
+        .map(|r| r.ufs().count())
+        .collect();
+        assert_eq!(counts, vec![7, 9, 4, 4, 3]);
+    }
+
+    #[test]
+    fn test_parse_is_accent_and_space_insensitive() {
+        assert_eq!("rio  grande do   norte".parse::<Uf>(), Ok(Uf::RN));
+        assert_eq!("GOIAS".parse::<Uf>(), Ok(Uf::GO));
+        assert_eq!(Uf::try_from("Amapá"), Ok(Uf::AP));
+        assert!("".parse::<Uf>().is_err());
+        assert!("Rio".parse::<Uf>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Uf::MG.to_string(), "MG");
+        assert_eq!(Region::CentroOeste.to_string(), "Centro-Oeste");
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_serde_uses_abbreviation() {
+        assert_eq!(serde_json::to_string(&Uf::DF).unwrap(), r#""DF""#);
+        assert_eq!(serde_json::from_str::<Uf>(r#""São Paulo""#).unwrap(), Uf::SP);
+        assert!(serde_json::from_str::<Uf>(r#""XX""#).is_err());
+        assert_eq!(
+            serde_json::to_string(&Region::CentroOeste).unwrap(),
+            r#""centro_oeste""#
+        );
+    }
+
+    #[test]
+    fn test_ddds_match_phone_table() {
+        let mut count = 0;
+        for uf in Uf::ALL {
+            for ddd in uf.ddds() {
+                assert_eq!(crate::phone::uf_for_ddd(ddd), Some(uf), "{}", ddd);
+                count += 1;
+            }
+        }
+        let served = (11..100)
+            .filter(|ddd| crate::phone::uf_for_ddd(&ddd.to_string()).is_some())
+            .count();
+        assert_eq!(count, served);
+    }
+
+    #[cfg(feature = "dataset")]
+    #[test]
+    fn test_capitals_are_in_municipality_table() {
+        for uf in Uf::ALL {
+            let code = crate::ibge::municipality_code(uf.capital(), uf);
+            assert!(code.is_some(), "{}", uf.capital());
+        }
+    }
+
+    #[test]
+    fn test_regions_cover_every_uf() {
+        let count: usize = Region::iter().map(|region| region.ufs().count()).sum();
+        assert_eq!(count, Uf::ALL.len());
+    }
+}
@@ -0,0 +1,238 @@
+//! Unicode digit normalization
+//!
+//! Documents copy-pasted from PDFs, spreadsheets and messaging apps often
+//! carry full-width digits (`１２３`), Arabic-Indic digits (`١٢٣`) or
+//! look-alike punctuation (`–`, `．`) that the validators do not accept as
+//! digits. This module maps them to ASCII before validating and reports
+//! every substitution, like [`ocr`](crate::ocr) does for OCR confusions.
+//!
+//! With [`unicode_digits`](crate::config::ValidationOptions::unicode_digits)
+//! enabled, the plain `validate` functions of CPF, CNPJ, CEP and phone
+//! numbers apply the same mapping silently.
+//!
+//! # Examples
+//! ```
+//! use pleme_brazilian_validators::{unicode, DocumentKind};
+//!
+//! let result = unicode::validate("１２３．４５６．７８９－０９", DocumentKind::Cpf).unwrap();
+//! assert_eq!(result.normalized, "12345678909");
+//! assert!(result.is_normalized());
+//! ```
+
+use crate::config::Config;
+use crate::document::DocumentKind;
+use crate::error::ValidationResult;
+use crate::ocr::Substitution;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// First code point of each run of ten digits `0..=9`
+const DIGIT_BLOCKS: [u32; 10] = [
+    0x0660,  // Arabic-Indic
+    0x06F0,  // Extended Arabic-Indic (Persian, Urdu)
+    0x0966,  // Devanagari
+    0xFF10,  // Full-width
+    0x2080,  // Subscripts
+    0x1D7CE, // Mathematical bold
+    0x1D7D8, // Mathematical double-struck
+    0x1D7E2, // Mathematical sans-serif
+    0x1D7EC, // Mathematical sans-serif bold
+    0x1D7F6, // Mathematical monospace
+];
+
+/// Punctuation look-alikes found in document masks
+const PUNCTUATION: [(char, char); 14] = [
+    ('\u{FF0E}', '.'), // Full-width full stop
+    ('\u{FF0D}', '-'), // Full-width hyphen-minus
+    ('\u{FF0F}', '/'), // Full-width solidus
+    ('\u{FF08}', '('), // Full-width parentheses
+    ('\u{FF09}', ')'),
+    ('\u{FF0B}', '+'), // Full-width plus
+    ('\u{2010}', '-'), // Hyphen
+    ('\u{2011}', '-'), // Non-breaking hyphen
+    ('\u{2012}', '-'), // Figure dash
+    ('\u{2013}', '-'), // En dash
+    ('\u{2014}', '-'), // Em dash
+    ('\u{2212}', '-'), // Minus sign
+    ('\u{00A0}', ' '), // No-break space
+    ('\u{202F}', ' '), // Narrow no-break space
+];
+
+/// Result of a validation with Unicode normalization
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnicodeValidation {
+    /// Normalized document, as returned by the module's `validate`
+    pub normalized: String,
+    /// Characters mapped to ASCII before validation (empty if none)
+    pub substitutions: Vec<Substitution>,
+}
+
+impl UnicodeValidation {
+    /// Check whether any character had to be mapped to ASCII
+    pub fn is_normalized(&self) -> bool {
+        !self.substitutions.is_empty()
+    }
+}
+
+/// ASCII equivalent of a Unicode digit or punctuation look-alike
+fn fold(c: char) -> Option<char> {
+    if c.is_ascii() {
+        return None;
+    }
+
+    // Superscripts are split between Latin-1 and their own block
+    match c {
+        '⁰' => return Some('0'),
+        '¹' => return Some('1'),
+        '²' => return Some('2'),
+        '³' => return Some('3'),
+        '⁴'..='⁹' => return char::from_digit(c as u32 - 0x2070, 10),
+        _ => {}
+    }
+
+    let code = c as u32;
+    DIGIT_BLOCKS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&code))
+        .and_then(|zero| char::from_digit(code - zero, 10))
+        .or_else(|| {
+            PUNCTUATION
+                .iter()
+                .find(|(found, _)| *found == c)
+                .map(|&(_, ascii)| ascii)
+        })
+}
+
+/// Map Unicode digits and punctuation look-alikes to ASCII
+///
+/// Characters without an ASCII equivalent are kept as-is.
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::unicode::normalize;
+///
+/// let (normalized, substitutions) = normalize("٠١٣١٠–١٠٠");
+/// assert_eq!(normalized, "01310-100");
+/// assert_eq!(substitutions.len(), 9);
+/// ```
+pub fn normalize(input: &str) -> (String, Vec<Substitution>) {
+    let mut substitutions = Vec::new();
+
+    let normalized = input
+        .chars()
+        .enumerate()
+        .map(|(index, c)| match fold(c) {
+            Some(replaced_with) => {
+                substitutions.push(Substitution {
+                    index,
+                    found: c,
+                    replaced_with,
+                });
+                replaced_with
+            }
+            None => c,
+        })
+        .collect();
+
+    (normalized, substitutions)
+}
+
+/// Validate a document after mapping Unicode digits to ASCII
+///
+/// # Arguments
+/// * `input` - Document string, possibly with non-ASCII digits
+/// * `kind` - Expected document kind
+///
+/// # Returns
+/// * `Ok(UnicodeValidation)` - Normalized document and substitutions applied
+/// * `Err(BrazilianValidationError)` - Invalid even after normalization
+///
+/// # Examples
+/// ```
+/// use pleme_brazilian_validators::{unicode, DocumentKind};
+///
+/// let result = unicode::validate("(11) 98765-4321", DocumentKind::Phone).unwrap();
+/// assert!(!result.is_normalized());
+///
+/// assert!(unicode::validate("١٢٣٤", DocumentKind::Cep).is_err());
+/// ```
+pub fn validate(input: &str, kind: DocumentKind) -> ValidationResult<UnicodeValidation> {
+    let (normalized_input, substitutions) = normalize(input);
+
+    Ok(UnicodeValidation {
+        normalized: kind.validate(&normalized_input)?,
+        substitutions,
+    })
+}
+
+/// Apply the mapping if enabled in the configuration
+pub(crate) fn normalize_if_enabled(input: &str) -> Cow<'_, str> {
+    if Config::current().unicode_digits && !input.is_ascii() {
+        Cow::Owned(normalize(input).0)
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::{cep, cnpj, cpf, phone};
+    use alloc::vec;
+
+    #[test]
+    fn test_digit_blocks() {
+        for zero in DIGIT_BLOCKS {
+            let digits: String = (zero..zero + 10).filter_map(char::from_u32).collect();
+            assert_eq!(normalize(&digits).0, "0123456789", "{:X}", zero);
+        }
+        assert_eq!(normalize("⁰¹²³⁴⁵⁶⁷⁸⁹").0, "0123456789");
+        assert_eq!(normalize("xⁱ").0, "xⁱ");
+    }
+
+    #[test]
+    fn test_substitutions_are_reported() {
+        let (normalized, substitutions) = normalize("CEP ０1310");
+        assert_eq!(normalized, "CEP 01310");
+        assert_eq!(
+            substitutions,
+            vec![Substitution {
+                index: 4,
+                found: '０',
+                replaced_with: '0'
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unmapped_characters_are_kept() {
+        assert_eq!(normalize("é🇧🇷x").0, "é🇧🇷x");
+        assert!(normalize("12345678909").1.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_config_option() {
+        let input = "۱۲۳.۴۵۶.۷۸۹-۰۹";
+        assert!(cpf::validate(input).is_err());
+
+        let options = Config::builder().unicode_digits(true).build();
+        Config::with_options(options, || {
+            assert_eq!(cpf::validate(input).as_deref(), Ok("12345678909"));
+            assert!(cnpj::validate("１１.２２２.３３３/０００１-８１").is_ok());
+            assert!(cep::validate("01310–100").is_ok());
+            assert!(phone::validate("(１１) ９８７６５-４３２１").is_ok());
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_strict_mode_sees_normalized_input() {
+        let options = Config::builder().strict(true).unicode_digits(true).build();
+        Config::with_options(options, || {
+            assert!(cpf::validate("１２３．４５６．７８９－０９").is_ok());
+        });
+    }
+}